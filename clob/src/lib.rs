@@ -3,9 +3,30 @@
 extern crate alloc;
 
 use alloc::{vec, vec::Vec};
-use alloy_primitives::{B256, U256};
+use alloy_primitives::{keccak256, Address, FixedBytes, B256, U256, U8};
 use alloy_sol_types::sol;
-use stylus_sdk::prelude::*;
+
+// Note: Using deprecated Call until sol_interface! macro is updated to use new trait paths
+#[allow(deprecated)]
+use stylus_sdk::call::Call;
+use stylus_sdk::{abi::Bytes, prelude::*};
+
+/// Maximum maker or taker fee, in basis points (1%).
+pub const MAX_FEE_BPS: u64 = 100;
+
+/// Basis points denominator (100%).
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Value `onERC1155Received` returns to accept a transfer
+/// (`bytes4(keccak256("onERC1155Received(address,address,uint256,uint256,bytes)"))`).
+pub const ERC1155_RECEIVED: [u8; 4] = [0xf2, 0x3a, 0x6e, 0x61];
+
+/// Value `onERC1155BatchReceived` returns to accept a batch transfer
+/// (`bytes4(keccak256("onERC1155BatchReceived(address,address,uint256[],uint256[],bytes)"))`).
+pub const ERC1155_BATCH_RECEIVED: [u8; 4] = [0xbc, 0x19, 0x7c, 0x81];
+
+/// Maximum number of taker fee discount tiers set with `set_taker_fee_tiers`.
+pub const MAX_FEE_TIERS: usize = 8;
 
 /// Represents the side of an order in the orderbook.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,59 +50,1532 @@ impl OrderSide {
             _ => Err(CLOBError::Unimplemented(Unimplemented {})),
         }
     }
+
+    /// Converts order side to u8 for encoding.
+    #[must_use]
+    pub const fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the side a taker of this side trades against.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Buy => Self::Sell,
+            Self::Sell => Self::Buy,
+        }
+    }
+}
+
+sol! {
+    /// Emitted when a limit order is placed and rests in the book.
+    ///
+    /// `price` is per option token in quote terms (18 decimals normalized) and
+    /// `quantity` is in option token units.
+    event OrderPlaced(
+        uint256 indexed orderId,
+        address indexed maker,
+        bytes32 indexed tokenId,
+        uint8 side,
+        uint256 price,
+        uint256 quantity
+    );
+
+    /// Emitted when a resting order is closed unfilled: cancelled by its maker, or closed
+    /// by a fill that would leave less than `min_order_quantity`. `remaining` is refunded.
+    event OrderCancelled(
+        uint256 indexed orderId,
+        address indexed maker,
+        bytes32 indexed tokenId,
+        uint256 remaining
+    );
+
+    /// Emitted for each resting order a market order fills.
+    ///
+    /// `price` is the maker's price, `quantity` the filled option tokens and `takerSide`
+    /// the side of the market order (0 = Buy, 1 = Sell). `premium` is the quote tokens
+    /// the option seller received net of `fee`, both in the quote token's decimals.
+    event Trade(
+        uint256 indexed makerOrderId,
+        address indexed maker,
+        address indexed taker,
+        bytes32 tokenId,
+        uint8 takerSide,
+        uint256 price,
+        uint256 quantity,
+        uint256 premium,
+        uint256 fee
+    );
+
+    /// Emitted when `flash_arb` buys and exercises options in one transaction.
+    ///
+    /// `premium` is the quote tokens paid for the options including fees, `strikePayment`
+    /// the strike paid to exercise them (series quote token decimals) and
+    /// `underlyingOut` the underlying the taker received (native decimals).
+    event FlashArb(
+        address indexed taker,
+        bytes32 indexed tokenId,
+        uint256 quantity,
+        uint256 premium,
+        uint256 strikePayment,
+        uint256 underlyingOut
+    );
 }
 
 sol! {
     /// Errors that can occur in the CLOB contract.
     #[derive(Debug)]
     error Unimplemented();
+    #[derive(Debug)]
+    error ZeroQuantity();
+    #[derive(Debug)]
+    error ZeroPrice();
+    #[derive(Debug)]
+    error WouldCross(uint256 price, uint256 best_price);
+    #[derive(Debug)]
+    error AlreadyInitialized();
+    #[derive(Debug)]
+    error NotInitialized();
+    #[derive(Debug)]
+    error ZeroAddress();
+    #[derive(Debug)]
+    error InvalidDecimals(uint8 decimals);
+    #[derive(Debug)]
+    error TransferFailed();
+    #[derive(Debug)]
+    error Overflow();
+    #[derive(Debug)]
+    error OrderNotFound(uint256 order_id);
+    #[derive(Debug)]
+    error Unauthorized(address caller);
+    #[derive(Debug)]
+    error InsufficientLiquidity(uint256 requested, uint256 available);
+    #[derive(Debug)]
+    error SeriesExpired(bytes32 token_id, uint256 expiry);
+    #[derive(Debug)]
+    error SeriesNotExpired(bytes32 token_id, uint256 expiry);
+    #[derive(Debug)]
+    error OptionsCallFailed();
+    #[derive(Debug)]
+    error FeeTooHigh(uint256 fee_bps, uint256 max_fee_bps);
+    #[derive(Debug)]
+    error InvalidFeeTiers();
+    #[derive(Debug)]
+    error Reentrancy();
+    #[derive(Debug)]
+    error PremiumTooHigh(uint256 premium, uint256 max_premium);
+    #[derive(Debug)]
+    error InsufficientUnderlyingOut(uint256 received, uint256 min_underlying_out);
+    #[derive(Debug)]
+    error UnexercisableQuantity(uint256 quantity);
+    #[derive(Debug)]
+    error ExerciseFailed();
 }
 
 #[derive(SolidityError, Debug)]
 pub enum CLOBError {
     /// Stub implementation placeholder - function not yet implemented.
     Unimplemented(Unimplemented),
+    /// Order quantity must be greater than zero.
+    ZeroQuantity(ZeroQuantity),
+    /// Order price must be greater than zero.
+    ZeroPrice(ZeroPrice),
+    /// Post-only limit order would match the opposite side of the book.
+    WouldCross(WouldCross),
+    /// `initialize` has already been called.
+    AlreadyInitialized(AlreadyInitialized),
+    /// `initialize` has not been called yet.
+    NotInitialized(NotInitialized),
+    /// Options contract or quote token address is zero.
+    ZeroAddress(ZeroAddress),
+    /// Quote token decimals exceed 18.
+    InvalidDecimals(InvalidDecimals),
+    /// Option token or quote token transfer failed (e.g. insufficient balance or approval).
+    TransferFailed(TransferFailed),
+    /// Arithmetic overflow during calculation.
+    Overflow(Overflow),
+    /// Order does not exist or is no longer resting.
+    OrderNotFound(OrderNotFound),
+    /// Caller is not the maker of the order.
+    Unauthorized(Unauthorized),
+    /// Opposite side of the book cannot fill the whole market order.
+    InsufficientLiquidity(InsufficientLiquidity),
+    /// Series is at or past its expiry (or was never written) and cannot be traded.
+    SeriesExpired(SeriesExpired),
+    /// Series has not expired yet, so its orders cannot be pruned.
+    SeriesNotExpired(SeriesNotExpired),
+    /// Reading series metadata from the Options contract failed.
+    OptionsCallFailed(OptionsCallFailed),
+    /// Maker or taker fee exceeds `MAX_FEE_BPS`.
+    FeeTooHigh(FeeTooHigh),
+    /// Fee tier arrays differ in length, thresholds are not strictly increasing, or
+    /// there are more than `MAX_FEE_TIERS`.
+    InvalidFeeTiers(InvalidFeeTiers),
+    /// Called re-entrantly from an external token call.
+    Reentrancy(Reentrancy),
+    /// `flash_arb` premium including fees exceeds `max_premium`.
+    PremiumTooHigh(PremiumTooHigh),
+    /// `flash_arb` delivered less underlying than `min_underlying_out`.
+    InsufficientUnderlyingOut(InsufficientUnderlyingOut),
+    /// `flash_arb` quantity is not a whole number of underlying base units.
+    UnexercisableQuantity(UnexercisableQuantity),
+    /// The Options contract rejected the exercise or its cost quote.
+    ExerciseFailed(ExerciseFailed),
+}
+
+sol_interface! {
+    /// ERC20 interface for the quote token, and the strike and underlying tokens of
+    /// `flash_arb`.
+    interface IERC20 {
+        function balanceOf(address account) external view returns (uint256);
+        function approve(address spender, uint256 value) external returns (bool);
+        function transfer(address to, uint256 value) external returns (bool);
+        function transferFrom(address from, address to, uint256 value) external returns (bool);
+    }
+
+    /// ERC-1155 transfer, series metadata and call exercise of the Options contract
+    /// (token IDs are `bytes32` series hashes).
+    ///
+    /// The CLOB must be an approved operator of the maker. `optionMetadataOf` returns
+    /// the series terms in `OptionMetadataView` order: the underlying, the quote
+    /// (strike) token, the underlying decimals and the expiry (the first, second, third
+    /// and sixth fields) are used. `getExerciseCost` and `exerciseCallTo` take the
+    /// quantity in the underlying's native decimals.
+    interface IOptions {
+        function safeTransferFrom(address from, address to, bytes32 id, uint256 amount, bytes data) external;
+        function optionMetadataOf(bytes32 id) external view returns (address, address, uint8, uint8, uint256, uint256, uint8, address, uint8, uint256, uint256, address, uint8);
+        function getExerciseCost(bytes32 id, uint256 quantity) external view returns (uint256, uint256);
+        function exerciseCallTo(bytes32 id, uint256 quantity, address recipient) external;
+    }
+}
+
+sol_storage! {
+    /// A resting limit order.
+    ///
+    /// Orders at the same `(token_id, side, price)` form a doubly linked FIFO queue
+    /// through `prev`/`next` (order IDs, zero for none).
+    pub struct Order {
+        /// Address that placed the order and receives the fills
+        address maker;
+        /// ERC-1155 token ID of the option series
+        bytes32 token_id;
+        /// Price per option token (quote, 18 decimals normalized)
+        uint256 price;
+        /// Quantity placed (option token units)
+        uint256 quantity;
+        /// Quantity not yet filled or cancelled (option token units)
+        uint256 remaining;
+        /// Order side (0=Buy, 1=Sell)
+        uint8 side;
+        /// Previous order at the same price level (zero for the head)
+        uint256 prev;
+        /// Next order at the same price level (zero for the tail)
+        uint256 next;
+    }
+
+    /// FIFO queue of the orders resting at one price of one side of a book.
+    ///
+    /// Non-empty levels of a side form a doubly linked list sorted from the best price
+    /// (`best_bid`/`best_ask`) through `worse` (prices, zero for none).
+    pub struct PriceLevel {
+        /// Oldest order at this price (zero when the level is empty)
+        uint256 head;
+        /// Newest order at this price (zero when the level is empty)
+        uint256 tail;
+        /// Next better non-empty price of the same side (zero for the best level)
+        uint256 better;
+        /// Next worse non-empty price of the same side (zero for the worst level)
+        uint256 worse;
+        /// Sum of the remaining quantities of the orders at this price
+        uint256 total_quantity;
+    }
+
+    #[entrypoint]
+    pub struct CLOB {
+        /// Highest resting buy price per series (zero when there are no bids)
+        mapping(bytes32 => uint256) best_bid;
+        /// Lowest resting sell price per series (zero when there are no asks)
+        mapping(bytes32 => uint256) best_ask;
+        /// Options contract whose ERC-1155 option tokens are traded
+        address options;
+        /// ERC20 token premiums are paid in
+        address quote;
+        /// Decimals of the quote token
+        uint8 quote_decimals;
+        /// ID of the most recently placed order (IDs start at 1)
+        uint256 last_order_id;
+        /// Mapping from order ID to order
+        mapping(uint256 => Order) orders;
+        /// Mapping from level_key(token_id, side, price) to price level
+        mapping(bytes32 => PriceLevel) price_levels;
+        /// Account that initialized the book and can configure it
+        address owner;
+        /// Smallest remainder a fill may leave in a maker order (zero disables)
+        uint256 min_order_quantity;
+        /// Mapping from maker to every order ID they placed, in order (closed orders
+        /// are skipped on read)
+        mapping(address => uint256[]) maker_orders;
+        /// Fee charged on each fill for the resting order's side (basis points)
+        uint256 maker_fee_bps;
+        /// Fee charged on each fill for the incoming order's side (basis points)
+        uint256 taker_fee_bps;
+        /// Address receiving trading fees
+        address fee_recipient;
+        /// Mapping from taker to cumulative premium of their fills (quote token decimals)
+        mapping(address => uint256) taker_volume;
+        /// Minimum taker volume of each taker fee tier, strictly increasing
+        uint256[] taker_fee_tier_thresholds;
+        /// Taker fee of each taker fee tier (basis points)
+        uint256[] taker_fee_tier_bps;
+        /// Reentrancy lock held while an order is placed, matched, cancelled or pruned
+        bool locked;
+    }
+}
+
+/// Computes the quote tokens a buy order locks: `price * quantity`.
+///
+/// Rounded up so the locked premium always covers the fill.
+///
+/// # Parameters
+/// - `price`: Price per option token (18 decimals normalized)
+/// - `quantity`: Quantity of option tokens (18 decimals)
+/// - `quote_decimals`: Decimals of the quote token (at most 18)
+///
+/// # Returns
+/// Quote amount in the quote token's native decimals
+///
+/// # Errors
+/// - `Overflow`: `price * quantity` overflows
+pub(crate) fn quote_amount(
+    price: U256,
+    quantity: U256,
+    quote_decimals: u8,
+) -> Result<U256, CLOBError> {
+    let scaled = price
+        .checked_mul(quantity)
+        .ok_or(CLOBError::Overflow(Overflow {}))?;
+    let divisor = U256::from(10).pow(U256::from(36 - u32::from(quote_decimals)));
+
+    Ok(scaled.div_ceil(divisor))
+}
+
+/// Computes the quote tokens exchanged when an order's remaining quantity drops from
+/// `remaining_before` to `remaining_after`.
+///
+/// Measured against `quote_amount` of the cumulative filled quantity, so the fills of an
+/// order add up to exactly the premium it locked, whatever the rounding of each fill.
+///
+/// # Parameters
+/// - `price`: Price per option token (18 decimals normalized)
+/// - `quantity`: Quantity the order was placed with
+/// - `remaining_before`: Remaining quantity before the fill
+/// - `remaining_after`: Remaining quantity after the fill
+/// - `quote_decimals`: Decimals of the quote token (at most 18)
+///
+/// # Returns
+/// Quote amount in the quote token's native decimals
+///
+/// # Errors
+/// - `Overflow`: `price * quantity` overflows or the remaining quantities are inconsistent
+pub(crate) fn fill_cost(
+    price: U256,
+    quantity: U256,
+    remaining_before: U256,
+    remaining_after: U256,
+    quote_decimals: u8,
+) -> Result<U256, CLOBError> {
+    let filled_before = quantity
+        .checked_sub(remaining_before)
+        .ok_or(CLOBError::Overflow(Overflow {}))?;
+    let filled_after = quantity
+        .checked_sub(remaining_after)
+        .ok_or(CLOBError::Overflow(Overflow {}))?;
+
+    quote_amount(price, filled_after, quote_decimals)?
+        .checked_sub(quote_amount(price, filled_before, quote_decimals)?)
+        .ok_or(CLOBError::Overflow(Overflow {}))
+}
+
+/// Computes the trading fee on a fill's premium: `cost * fee_bps / 10_000`.
+///
+/// Rounded up, so the protocol is favored by less than one unit of the quote token.
+///
+/// # Parameters
+/// - `cost`: Premium of the fill (quote token's native decimals)
+/// - `fee_bps`: Combined maker and taker fee (basis points, at most `BPS_DENOMINATOR`)
+///
+/// # Returns
+/// Fee in the quote token's native decimals, never more than `cost`
+///
+/// # Errors
+/// - `Overflow`: `cost * fee_bps` overflows
+pub(crate) fn fill_fee(cost: U256, fee_bps: U256) -> Result<U256, CLOBError> {
+    Ok(cost
+        .checked_mul(fee_bps)
+        .ok_or(CLOBError::Overflow(Overflow {}))?
+        .div_ceil(U256::from(BPS_DENOMINATOR)))
+}
+
+/// A resting order filled by a market order or a crossing limit order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Fill {
+    /// ID of the filled maker order
+    pub order_id: U256,
+    /// Maker of the filled order
+    pub maker: Address,
+    /// Maker's price (18 decimals normalized)
+    pub price: U256,
+    /// Option tokens filled
+    pub quantity: U256,
+    /// Premium exchanged (quote token's native decimals)
+    pub cost: U256,
+    /// Maker and taker fees taken out of `cost` (quote token's native decimals)
+    pub fee: U256,
+    /// Remainder below `min_order_quantity` closed with the fill (option token units)
+    pub dust: U256,
+    /// Assets returned to the maker for `dust`: option tokens for sell orders, quote
+    /// tokens (native decimals) for buy orders
+    pub refund: U256,
+}
+
+impl CLOB {
+    /// Records the best bid of a series (zero clears the bid side).
+    pub(crate) fn set_best_bid(&mut self, token_id: B256, price: U256) {
+        self.best_bid.insert(token_id, price);
+    }
+
+    /// Records the best ask of a series (zero clears the ask side).
+    pub(crate) fn set_best_ask(&mut self, token_id: B256, price: U256) {
+        self.best_ask.insert(token_id, price);
+    }
+}
+
+impl CLOB {
+    /// Generates the key of the price level of `(token_id, side, price)`.
+    fn level_key(token_id: B256, side: OrderSide, price: U256) -> B256 {
+        keccak256(
+            [
+                token_id.as_slice(),
+                &[side.to_u8()],
+                price.to_be_bytes::<32>().as_slice(),
+            ]
+            .concat(),
+        )
+    }
+
+    /// Returns whether `price` is better than `than` for resting orders of `side`.
+    fn improves(side: OrderSide, price: U256, than: U256) -> bool {
+        match side {
+            OrderSide::Buy => price > than,
+            OrderSide::Sell => price < than,
+        }
+    }
+
+    /// Returns the best resting price of one side of a series (zero when empty).
+    pub(crate) fn best_price(&self, token_id: B256, side: OrderSide) -> U256 {
+        match side {
+            OrderSide::Buy => self.best_bid.get(token_id),
+            OrderSide::Sell => self.best_ask.get(token_id),
+        }
+    }
+
+    /// Records the best resting price of one side of a series.
+    pub(crate) fn set_best_price(&mut self, token_id: B256, side: OrderSide, price: U256) {
+        match side {
+            OrderSide::Buy => self.set_best_bid(token_id, price),
+            OrderSide::Sell => self.set_best_ask(token_id, price),
+        }
+    }
+
+    /// Links a newly non-empty price level into the sorted levels of its side.
+    ///
+    /// Walks from the best price until the first worse level, so the cost grows with
+    /// the number of better levels.
+    pub(crate) fn insert_level(&mut self, token_id: B256, side: OrderSide, price: U256) {
+        let best = self.best_price(token_id, side);
+        let mut better = U256::ZERO;
+        let mut worse = best;
+        while !worse.is_zero() && !Self::improves(side, price, worse) {
+            better = worse;
+            worse = self
+                .price_levels
+                .get(Self::level_key(token_id, side, worse))
+                .worse
+                .get();
+        }
+
+        {
+            let mut level = self
+                .price_levels
+                .setter(Self::level_key(token_id, side, price));
+            level.better.set(better);
+            level.worse.set(worse);
+        }
+        if better.is_zero() {
+            self.set_best_price(token_id, side, price);
+        } else {
+            self.price_levels
+                .setter(Self::level_key(token_id, side, better))
+                .worse
+                .set(price);
+        }
+        if !worse.is_zero() {
+            self.price_levels
+                .setter(Self::level_key(token_id, side, worse))
+                .better
+                .set(price);
+        }
+    }
+
+    /// Unlinks an emptied price level from the sorted levels of its side.
+    ///
+    /// Removing the best level moves the best price to the next worse level.
+    pub(crate) fn remove_level(&mut self, token_id: B256, side: OrderSide, price: U256) {
+        let key = Self::level_key(token_id, side, price);
+        let (better, worse) = {
+            let level = self.price_levels.get(key);
+            (level.better.get(), level.worse.get())
+        };
+
+        if better.is_zero() {
+            self.set_best_price(token_id, side, worse);
+        } else {
+            self.price_levels
+                .setter(Self::level_key(token_id, side, better))
+                .worse
+                .set(worse);
+        }
+        if !worse.is_zero() {
+            self.price_levels
+                .setter(Self::level_key(token_id, side, worse))
+                .better
+                .set(better);
+        }
+
+        let mut level = self.price_levels.setter(key);
+        level.better.set(U256::ZERO);
+        level.worse.set(U256::ZERO);
+    }
+
+    /// Removes an order from the FIFO queue of its price level.
+    ///
+    /// Removes the level as well when the order was the last one at its price.
+    ///
+    /// # Errors
+    /// - `Unimplemented`: Stored side is invalid (never expected)
+    pub(crate) fn unlink_order(&mut self, order_id: U256) -> Result<(), CLOBError> {
+        let (token_id, price, side, prev, next) = {
+            let order = self.orders.get(order_id);
+            (
+                order.token_id.get(),
+                order.price.get(),
+                OrderSide::from_u8(order.side.get().to::<u8>())?,
+                order.prev.get(),
+                order.next.get(),
+            )
+        };
+        let key = Self::level_key(token_id, side, price);
+
+        if prev.is_zero() {
+            self.price_levels.setter(key).head.set(next);
+        } else {
+            self.orders.setter(prev).next.set(next);
+        }
+        if next.is_zero() {
+            self.price_levels.setter(key).tail.set(prev);
+        } else {
+            self.orders.setter(next).prev.set(prev);
+        }
+
+        {
+            let mut order = self.orders.setter(order_id);
+            order.prev.set(U256::ZERO);
+            order.next.set(U256::ZERO);
+        }
+
+        if prev.is_zero() && next.is_zero() {
+            self.remove_level(token_id, side, price);
+        }
+
+        Ok(())
+    }
+
+    /// Stores a new order and appends it to the tail of its price level.
+    ///
+    /// A new price level is linked into the sorted levels of its side, which moves the
+    /// best bid or ask of the series to `price` if it improves it.
+    ///
+    /// # Returns
+    /// ID of the new order
+    ///
+    /// # Errors
+    /// - `Overflow`: Order ID space exhausted
+    pub(crate) fn insert_order(
+        &mut self,
+        maker: Address,
+        token_id: B256,
+        price: U256,
+        quantity: U256,
+        side: OrderSide,
+    ) -> Result<U256, CLOBError> {
+        let order_id = self
+            .last_order_id
+            .get()
+            .checked_add(U256::from(1))
+            .ok_or(CLOBError::Overflow(Overflow {}))?;
+        self.last_order_id.set(order_id);
+
+        let level_key = Self::level_key(token_id, side, price);
+        let tail = self.price_levels.get(level_key).tail.get();
+        if tail.is_zero() {
+            self.insert_level(token_id, side, price);
+        }
+
+        {
+            let mut order = self.orders.setter(order_id);
+            order.maker.set(maker);
+            order.token_id.set(token_id);
+            order.price.set(price);
+            order.quantity.set(quantity);
+            order.remaining.set(quantity);
+            order.side.set(U8::from(side.to_u8()));
+            order.prev.set(tail);
+        }
+        self.maker_orders.setter(maker).push(order_id);
+
+        if tail.is_zero() {
+            self.price_levels.setter(level_key).head.set(order_id);
+        } else {
+            self.orders.setter(tail).next.set(order_id);
+        }
+        let total_quantity = self
+            .price_levels
+            .get(level_key)
+            .total_quantity
+            .get()
+            .checked_add(quantity)
+            .ok_or(CLOBError::Overflow(Overflow {}))?;
+        let mut level = self.price_levels.setter(level_key);
+        level.tail.set(order_id);
+        level.total_quantity.set(total_quantity);
+
+        Ok(order_id)
+    }
+
+    /// Subtracts quantity that left the book from the total of its price level.
+    ///
+    /// # Errors
+    /// - `Overflow`: `quantity` exceeds the level total (never expected)
+    pub(crate) fn reduce_level_quantity(
+        &mut self,
+        token_id: B256,
+        side: OrderSide,
+        price: U256,
+        quantity: U256,
+    ) -> Result<(), CLOBError> {
+        let key = Self::level_key(token_id, side, price);
+        let total_quantity = self
+            .price_levels
+            .get(key)
+            .total_quantity
+            .get()
+            .checked_sub(quantity)
+            .ok_or(CLOBError::Overflow(Overflow {}))?;
+        self.price_levels
+            .setter(key)
+            .total_quantity
+            .set(total_quantity);
+        Ok(())
+    }
+
+    /// Sums the resting quantity of one side of a series, best price first.
+    ///
+    /// Stops as soon as `wanted` is reached, so the result is capped near `wanted`.
+    ///
+    /// # Errors
+    /// - `Overflow`: Resting quantity overflows
+    pub(crate) fn available_liquidity(
+        &self,
+        token_id: B256,
+        side: OrderSide,
+        wanted: U256,
+    ) -> Result<U256, CLOBError> {
+        let mut available = U256::ZERO;
+        let mut price = self.best_price(token_id, side);
+        while !price.is_zero() && available < wanted {
+            let level = self
+                .price_levels
+                .get(Self::level_key(token_id, side, price));
+            let mut order_id = level.head.get();
+            while !order_id.is_zero() && available < wanted {
+                let order = self.orders.get(order_id);
+                available = available
+                    .checked_add(order.remaining.get())
+                    .ok_or(CLOBError::Overflow(Overflow {}))?;
+                order_id = order.next.get();
+            }
+            price = level.worse.get();
+        }
+
+        Ok(available)
+    }
+
+    /// Fills `quantity` from the resting orders of `maker_side`, best price first and
+    /// FIFO within a price.
+    ///
+    /// With a `limit` price, matching stops at the first level that doesn't cross it (a
+    /// buy limit below the ask, a sell limit above the bid) and the fills may add up to
+    /// less than `quantity`. Without one, the whole `quantity` must be filled.
+    ///
+    /// A fill that would leave a maker order with less than `min_order_quantity` closes
+    /// the order instead; the caller refunds the dust to the maker.
+    ///
+    /// Each fill's maker and taker fees are computed together on its premium with
+    /// `fill_fee`, the taker fee at `taker`'s tier before this match. The premium of the
+    /// fills is then added to `taker`'s volume.
+    ///
+    /// Only updates the book; the caller moves the assets of the returned fills. Without
+    /// a limit, the caller must have checked that the side holds at least `quantity`.
+    ///
+    /// # Errors
+    /// - `Overflow`: Premium of a fill overflows
+    /// - `InsufficientLiquidity`: Without a limit, the side ran out before `quantity`
+    ///   was filled
+    pub(crate) fn match_orders(
+        &mut self,
+        taker: Address,
+        token_id: B256,
+        maker_side: OrderSide,
+        quantity: U256,
+        limit: Option<U256>,
+    ) -> Result<Vec<Fill>, CLOBError> {
+        let quote_decimals = self.quote_decimals.get().to::<u8>();
+        let min_order_quantity = self.min_order_quantity.get();
+        let fee_bps = self.maker_fee_bps.get() + self.taker_fee_tier(taker).1;
+        let mut fills = Vec::new();
+        let mut unfilled = quantity;
+
+        while !unfilled.is_zero() {
+            let price = self.best_price(token_id, maker_side);
+            if let Some(limit) = limit {
+                if price.is_zero() || Self::improves(maker_side, limit, price) {
+                    break;
+                }
+            } else if price.is_zero() {
+                return Err(CLOBError::InsufficientLiquidity(InsufficientLiquidity {
+                    requested: quantity,
+                    available: quantity - unfilled,
+                }));
+            }
+
+            let order_id = self
+                .price_levels
+                .get(Self::level_key(token_id, maker_side, price))
+                .head
+                .get();
+            let (maker, order_quantity, remaining) = {
+                let order = self.orders.get(order_id);
+                (
+                    order.maker.get(),
+                    order.quantity.get(),
+                    order.remaining.get(),
+                )
+            };
+
+            let filled = unfilled.min(remaining);
+            let mut remaining_after = remaining - filled;
+            let cost = fill_cost(
+                price,
+                order_quantity,
+                remaining,
+                remaining_after,
+                quote_decimals,
+            )?;
+            let fee = fill_fee(cost, fee_bps)?;
+
+            let dust = if remaining_after < min_order_quantity {
+                remaining_after
+            } else {
+                U256::ZERO
+            };
+            let refund = match maker_side {
+                OrderSide::Buy => fill_cost(
+                    price,
+                    order_quantity,
+                    remaining_after,
+                    remaining_after - dust,
+                    quote_decimals,
+                )?,
+                OrderSide::Sell => dust,
+            };
+            remaining_after -= dust;
+
+            self.orders.setter(order_id).remaining.set(remaining_after);
+            self.reduce_level_quantity(token_id, maker_side, price, remaining - remaining_after)?;
+            if remaining_after.is_zero() {
+                self.unlink_order(order_id)?;
+            }
+
+            fills.push(Fill {
+                order_id,
+                maker,
+                price,
+                quantity: filled,
+                cost,
+                fee,
+                dust,
+                refund,
+            });
+            unfilled -= filled;
+        }
+
+        let premium = fills
+            .iter()
+            .try_fold(U256::ZERO, |premium, fill| premium.checked_add(fill.cost))
+            .ok_or(CLOBError::Overflow(Overflow {}))?;
+        let volume = self
+            .taker_volume
+            .get(taker)
+            .checked_add(premium)
+            .ok_or(CLOBError::Overflow(Overflow {}))?;
+        self.taker_volume.insert(taker, volume);
+
+        Ok(fills)
+    }
+
+    /// Returns the highest taker fee tier `taker`'s volume has reached and its taker fee.
+    ///
+    /// Tier 0 is `taker_fee_bps`; a tier's fee never exceeds it.
+    pub(crate) fn taker_fee_tier(&self, taker: Address) -> (usize, U256) {
+        let volume = self.taker_volume.get(taker);
+        let base_fee_bps = self.taker_fee_bps.get();
+        (0..self.taker_fee_tier_thresholds.len())
+            .rev()
+            .find(|&i| {
+                self.taker_fee_tier_thresholds
+                    .get(i)
+                    .is_some_and(|threshold| volume >= threshold)
+            })
+            .map_or((0, base_fee_bps), |i| {
+                let tier_fee_bps = self.taker_fee_tier_bps.get(i).unwrap_or(base_fee_bps);
+                (i + 1, tier_fee_bps.min(base_fee_bps))
+            })
+    }
+
+    /// Moves the assets of a taker's fills and emits their `Trade` logs.
+    ///
+    /// Buy takers pay each maker directly and the option tokens go from this contract to
+    /// `recipient` (left in place when it is this contract); sell takers deliver option
+    /// tokens to each maker and `recipient` is paid the locked premiums in one transfer. Fees come out of the premium the seller receives and are
+    /// paid to `fee_recipient` in one transfer. Dust closed by a fill is refunded to its
+    /// maker with `OrderCancelled`.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Taker lacks the option tokens or quote tokens, or the approval
+    /// - `Overflow`: Proceeds, fees or filled quantity overflow
+    pub(crate) fn settle_fills(
+        &mut self,
+        taker: Address,
+        recipient: Address,
+        token_id: B256,
+        side: OrderSide,
+        fills: &[Fill],
+    ) -> Result<(), CLOBError> {
+        let contract_addr = self.vm().contract_address();
+        let mut proceeds = U256::ZERO;
+        let mut fees = U256::ZERO;
+        let mut filled = U256::ZERO;
+        for fill in fills {
+            filled = filled
+                .checked_add(fill.quantity)
+                .ok_or(CLOBError::Overflow(Overflow {}))?;
+            fees = fees
+                .checked_add(fill.fee)
+                .ok_or(CLOBError::Overflow(Overflow {}))?;
+            let premium = fill.cost - fill.fee;
+            match side {
+                OrderSide::Buy => {
+                    if !premium.is_zero() {
+                        self.transfer_quote_from(taker, fill.maker, premium)?;
+                    }
+                }
+                OrderSide::Sell => {
+                    self.transfer_options(taker, fill.maker, token_id, fill.quantity)?;
+                    proceeds = proceeds
+                        .checked_add(premium)
+                        .ok_or(CLOBError::Overflow(Overflow {}))?;
+                }
+            }
+
+            if !fill.refund.is_zero() {
+                match side {
+                    OrderSide::Buy => {
+                        self.transfer_options(contract_addr, fill.maker, token_id, fill.refund)?;
+                    }
+                    OrderSide::Sell => self.transfer_quote(fill.maker, fill.refund)?,
+                }
+            }
+        }
+        match side {
+            OrderSide::Buy => {
+                if recipient != contract_addr {
+                    self.transfer_options(contract_addr, recipient, token_id, filled)?;
+                }
+                if !fees.is_zero() {
+                    let recipient = self.fee_recipient.get();
+                    self.transfer_quote_from(taker, recipient, fees)?;
+                }
+            }
+            OrderSide::Sell => {
+                if !proceeds.is_zero() {
+                    self.transfer_quote(recipient, proceeds)?;
+                }
+                if !fees.is_zero() {
+                    let recipient = self.fee_recipient.get();
+                    self.transfer_quote(recipient, fees)?;
+                }
+            }
+        }
+
+        for fill in fills {
+            log(
+                self.vm(),
+                Trade {
+                    makerOrderId: fill.order_id,
+                    maker: fill.maker,
+                    taker,
+                    tokenId: token_id,
+                    takerSide: side.to_u8(),
+                    price: fill.price,
+                    quantity: fill.quantity,
+                    premium: fill.cost - fill.fee,
+                    fee: fill.fee,
+                },
+            );
+            if !fill.dust.is_zero() {
+                log(
+                    self.vm(),
+                    OrderCancelled {
+                        orderId: fill.order_id,
+                        maker: fill.maker,
+                        tokenId: token_id,
+                        remaining: fill.dust,
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the expiry of a series from the Options contract.
+    ///
+    /// Series that were never written have a zero expiry.
+    ///
+    /// # Errors
+    /// - `OptionsCallFailed`: Metadata call reverted
+    #[allow(deprecated)]
+    pub(crate) fn series_expiry(&mut self, token_id: B256) -> Result<U256, CLOBError> {
+        IOptions::new(self.options.get())
+            .option_metadata_of(Call::new_in(self), token_id)
+            .map(|metadata| metadata.5)
+            .map_err(|_| CLOBError::OptionsCallFailed(OptionsCallFailed {}))
+    }
+
+    /// Rejects trading a series at or after its expiry.
+    ///
+    /// # Errors
+    /// - `SeriesExpired`: Current time is at or past the expiry
+    /// - `OptionsCallFailed`: Metadata call reverted
+    pub(crate) fn check_tradable(&mut self, token_id: B256) -> Result<(), CLOBError> {
+        let expiry = self.series_expiry(token_id)?;
+        if U256::from(self.vm().block_timestamp()) >= expiry {
+            return Err(CLOBError::SeriesExpired(SeriesExpired { token_id, expiry }));
+        }
+
+        Ok(())
+    }
+
+    /// Closes a resting order and refunds its locked assets to the maker.
+    ///
+    /// Buy orders are refunded the premium locked for the unfilled quantity, sell
+    /// orders the unfilled option tokens. Emits `OrderCancelled`.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Refund transfer failed
+    /// - `Overflow`: Refund amount overflows
+    /// - `Unimplemented`: Stored side is invalid (never expected)
+    pub(crate) fn close_order(&mut self, order_id: U256) -> Result<(), CLOBError> {
+        let (maker, token_id, price, quantity, remaining, side) = {
+            let order = self.orders.get(order_id);
+            (
+                order.maker.get(),
+                order.token_id.get(),
+                order.price.get(),
+                order.quantity.get(),
+                order.remaining.get(),
+                order.side.get().to::<u8>(),
+            )
+        };
+
+        let side = OrderSide::from_u8(side)?;
+        let refund = match side {
+            OrderSide::Buy => fill_cost(
+                price,
+                quantity,
+                remaining,
+                U256::ZERO,
+                self.quote_decimals.get().to::<u8>(),
+            )?,
+            OrderSide::Sell => remaining,
+        };
+
+        self.orders.setter(order_id).remaining.set(U256::ZERO);
+        self.reduce_level_quantity(token_id, side, price, remaining)?;
+        self.unlink_order(order_id)?;
+
+        // External call after all state updates
+        let contract_addr = self.vm().contract_address();
+        match side {
+            OrderSide::Buy => self.transfer_quote(maker, refund)?,
+            OrderSide::Sell => self.transfer_options(contract_addr, maker, token_id, refund)?,
+        }
+
+        log(
+            self.vm(),
+            OrderCancelled {
+                orderId: order_id,
+                maker,
+                tokenId: token_id,
+                remaining,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Transfers option tokens with the Options contract's ERC-1155 `safeTransferFrom`.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Transfer reverted
+    #[allow(deprecated)]
+    pub(crate) fn transfer_options(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: B256,
+        amount: U256,
+    ) -> Result<(), CLOBError> {
+        IOptions::new(self.options.get())
+            .safe_transfer_from(
+                Call::new_in(self),
+                from,
+                to,
+                token_id,
+                amount,
+                Vec::<u8>::new().into(),
+            )
+            .map_err(|_| CLOBError::TransferFailed(TransferFailed {}))
+    }
+
+    /// Transfers quote tokens held by this contract to `to`.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Transfer reverted or returned false
+    #[allow(deprecated)]
+    pub(crate) fn transfer_quote(&mut self, to: Address, amount: U256) -> Result<(), CLOBError> {
+        let success = IERC20::new(self.quote.get())
+            .transfer(Call::new_in(self), to, amount)
+            .map_err(|_| CLOBError::TransferFailed(TransferFailed {}))?;
+        if !success {
+            return Err(CLOBError::TransferFailed(TransferFailed {}));
+        }
+
+        Ok(())
+    }
+
+    /// Transfers quote tokens from `from` (who approved this contract) to `to`.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Transfer reverted or returned false
+    #[allow(deprecated)]
+    pub(crate) fn transfer_quote_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), CLOBError> {
+        let success = IERC20::new(self.quote.get())
+            .transfer_from(Call::new_in(self), from, to, amount)
+            .map_err(|_| CLOBError::TransferFailed(TransferFailed {}))?;
+        if !success {
+            return Err(CLOBError::TransferFailed(TransferFailed {}));
+        }
+
+        Ok(())
+    }
+
+    /// Transfers `amount` of `token` from `from` (who approved this contract) to `to`.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Transfer reverted or returned false
+    #[allow(deprecated)]
+    pub(crate) fn transfer_token_from(
+        &mut self,
+        token: Address,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), CLOBError> {
+        let success = IERC20::new(token)
+            .transfer_from(Call::new_in(self), from, to, amount)
+            .map_err(|_| CLOBError::TransferFailed(TransferFailed {}))?;
+        if !success {
+            return Err(CLOBError::TransferFailed(TransferFailed {}));
+        }
+
+        Ok(())
+    }
+
+    /// Sets this contract's allowance of `token` for `spender`.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Approval reverted or returned false
+    #[allow(deprecated)]
+    pub(crate) fn approve_token(
+        &mut self,
+        token: Address,
+        spender: Address,
+        amount: U256,
+    ) -> Result<(), CLOBError> {
+        let success = IERC20::new(token)
+            .approve(Call::new_in(self), spender, amount)
+            .map_err(|_| CLOBError::TransferFailed(TransferFailed {}))?;
+        if !success {
+            return Err(CLOBError::TransferFailed(TransferFailed {}));
+        }
+
+        Ok(())
+    }
+
+    /// Returns `account`'s balance of `token`.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Balance query reverted
+    #[allow(deprecated)]
+    pub(crate) fn token_balance(
+        &mut self,
+        token: Address,
+        account: Address,
+    ) -> Result<U256, CLOBError> {
+        IERC20::new(token)
+            .balance_of(Call::new_in(self), account)
+            .map_err(|_| CLOBError::TransferFailed(TransferFailed {}))
+    }
+
+    /// Pulls the assets backing an order from its maker into this contract.
+    ///
+    /// Sell orders lock `quantity` option tokens; buy orders lock
+    /// `quote_amount(price, quantity)` quote tokens.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Transfer reverted or returned false
+    /// - `Overflow`: Quote amount overflows
+    pub(crate) fn lock_order_assets(
+        &mut self,
+        maker: Address,
+        token_id: B256,
+        price: U256,
+        quantity: U256,
+        side: OrderSide,
+    ) -> Result<(), CLOBError> {
+        let contract_addr = self.vm().contract_address();
+
+        match side {
+            OrderSide::Sell => self.transfer_options(maker, contract_addr, token_id, quantity),
+            OrderSide::Buy => {
+                let amount = quote_amount(price, quantity, self.quote_decimals.get().to::<u8>())?;
+                self.transfer_quote_from(maker, contract_addr, amount)
+            }
+        }
+    }
+
+    /// Runs `f` while holding the reentrancy lock.
+    ///
+    /// The contract is built with the SDK's `reentrant` feature so the Options contract
+    /// can call its ERC-1155 receiver hooks mid-transfer; this lock keeps token
+    /// callbacks (a maker contract's hook, a malicious quote token) from placing,
+    /// matching or cancelling orders mid-operation. The lock is released on both
+    /// success and error.
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Lock is already held
+    /// - Any error returned by `f`
+    pub(crate) fn non_reentrant<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, CLOBError>,
+    ) -> Result<T, CLOBError> {
+        if self.locked.get() {
+            return Err(CLOBError::Reentrancy(Reentrancy {}));
+        }
+
+        self.locked.set(true);
+        let result = f(self);
+        self.locked.set(false);
+
+        result
+    }
 }
 
-sol_storage! {
-    #[entrypoint]
-    pub struct CLOB {
-        bool placeholder;
+#[public]
+impl CLOB {
+    /// Sets the Options contract and the quote token of the book.
+    ///
+    /// The caller becomes the owner, who can set `min_order_quantity`.
+    ///
+    /// TODO: Replace with proper constructor when upgrading to stylus-sdk that supports it.
+    ///
+    /// # Parameters
+    /// - `options`: Options contract whose ERC-1155 option tokens are traded
+    /// - `quote`: ERC20 token premiums are paid in
+    /// - `quote_decimals`: Decimals of the quote token
+    ///
+    /// # Errors
+    /// - `AlreadyInitialized`: Called more than once
+    /// - `ZeroAddress`: `options` or `quote` is zero
+    /// - `InvalidDecimals`: `quote_decimals` exceeds 18
+    ///
+    /// # WARNING
+    /// The first call is unprotected: the CLOB must be deployed and initialized
+    /// atomically so it cannot be front-run.
+    pub fn initialize(
+        &mut self,
+        options: Address,
+        quote: Address,
+        quote_decimals: u8,
+    ) -> Result<(), CLOBError> {
+        if self.options.get() != Address::ZERO {
+            return Err(CLOBError::AlreadyInitialized(AlreadyInitialized {}));
+        }
+
+        if options == Address::ZERO || quote == Address::ZERO {
+            return Err(CLOBError::ZeroAddress(ZeroAddress {}));
+        }
+
+        if quote_decimals > 18 {
+            return Err(CLOBError::InvalidDecimals(InvalidDecimals {
+                decimals: quote_decimals,
+            }));
+        }
+
+        self.options.set(options);
+        self.quote.set(quote);
+        self.quote_decimals.set(U8::from(quote_decimals));
+        self.owner.set(self.vm().msg_sender());
+
+        Ok(())
+    }
+
+    /// Sets the smallest remainder a fill may leave in a maker order.
+    ///
+    /// A market order fill that would leave less than this in a maker order closes the
+    /// order and refunds the dust to the maker instead. Zero disables the check.
+    ///
+    /// # Parameters
+    /// - `quantity`: Minimum remaining quantity (option token units)
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    pub fn set_min_order_quantity(&mut self, quantity: U256) -> Result<(), CLOBError> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() || caller == Address::ZERO {
+            return Err(CLOBError::Unauthorized(Unauthorized { caller }));
+        }
+
+        self.min_order_quantity.set(quantity);
+        Ok(())
+    }
+
+    /// Returns the owner set by `initialize` (zero before initialization).
+    #[must_use]
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Returns the smallest remainder a fill may leave in a maker order (zero if disabled).
+    #[must_use]
+    pub fn min_order_quantity(&self) -> U256 {
+        self.min_order_quantity.get()
+    }
+
+    /// Sets the trading fees charged on every fill.
+    ///
+    /// Both fees are charged on the fill's premium and deducted from the quote tokens
+    /// the option seller receives, so buyers pay exactly the maker's price and locked
+    /// buy premiums are never exceeded. Fees are rounded up by less than one unit of
+    /// the quote token and paid to `recipient`.
+    ///
+    /// # Parameters
+    /// - `maker_fee_bps`: Fee for the resting order's side (at most `MAX_FEE_BPS`)
+    /// - `taker_fee_bps`: Fee for the incoming order's side (at most `MAX_FEE_BPS`)
+    /// - `recipient`: Address receiving fees
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    /// - `FeeTooHigh`: A fee exceeds `MAX_FEE_BPS`
+    /// - `ZeroAddress`: A fee is non-zero and `recipient` is the zero address
+    pub fn set_fees(
+        &mut self,
+        maker_fee_bps: U256,
+        taker_fee_bps: U256,
+        recipient: Address,
+    ) -> Result<(), CLOBError> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() || caller == Address::ZERO {
+            return Err(CLOBError::Unauthorized(Unauthorized { caller }));
+        }
+
+        for fee_bps in [maker_fee_bps, taker_fee_bps] {
+            if fee_bps > U256::from(MAX_FEE_BPS) {
+                return Err(CLOBError::FeeTooHigh(FeeTooHigh {
+                    fee_bps,
+                    max_fee_bps: U256::from(MAX_FEE_BPS),
+                }));
+            }
+        }
+
+        if !(maker_fee_bps.is_zero() && taker_fee_bps.is_zero()) && recipient == Address::ZERO {
+            return Err(CLOBError::ZeroAddress(ZeroAddress {}));
+        }
+
+        self.maker_fee_bps.set(maker_fee_bps);
+        self.taker_fee_bps.set(taker_fee_bps);
+        self.fee_recipient.set(recipient);
+
+        Ok(())
+    }
+
+    /// Returns the fee charged for the resting order's side of a fill (basis points).
+    #[must_use]
+    pub fn maker_fee_bps(&self) -> U256 {
+        self.maker_fee_bps.get()
+    }
+
+    /// Returns the fee charged for the incoming order's side of a fill (basis points).
+    #[must_use]
+    pub fn taker_fee_bps(&self) -> U256 {
+        self.taker_fee_bps.get()
+    }
+
+    /// Returns the address receiving trading fees.
+    #[must_use]
+    pub fn fee_recipient(&self) -> Address {
+        self.fee_recipient.get()
+    }
+
+    /// Replaces the taker fee discount tiers.
+    ///
+    /// A taker whose cumulative fill premium (`taker_volume_of`) has reached
+    /// `thresholds[i]` pays `fee_bps[i]` instead of `taker_fee_bps`, whichever is lower.
+    /// The highest tier reached applies; maker fees are not discounted. Passing empty
+    /// arrays removes all tiers.
+    ///
+    /// # Parameters
+    /// - `thresholds`: Minimum taker volume of each tier (quote token's native decimals),
+    ///   strictly increasing
+    /// - `fee_bps`: Taker fee of each tier (at most `MAX_FEE_BPS`)
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    /// - `InvalidFeeTiers`: Arrays differ in length, thresholds are not strictly
+    ///   increasing, or there are more than `MAX_FEE_TIERS`
+    /// - `FeeTooHigh`: A tier fee exceeds `MAX_FEE_BPS`
+    pub fn set_taker_fee_tiers(
+        &mut self,
+        thresholds: Vec<U256>,
+        fee_bps: Vec<U256>,
+    ) -> Result<(), CLOBError> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() || caller == Address::ZERO {
+            return Err(CLOBError::Unauthorized(Unauthorized { caller }));
+        }
+
+        if thresholds.len() != fee_bps.len()
+            || thresholds.len() > MAX_FEE_TIERS
+            || thresholds.windows(2).any(|pair| pair[0] >= pair[1])
+        {
+            return Err(CLOBError::InvalidFeeTiers(InvalidFeeTiers {}));
+        }
+        if let Some(&tier_fee_bps) = fee_bps.iter().find(|&&bps| bps > U256::from(MAX_FEE_BPS)) {
+            return Err(CLOBError::FeeTooHigh(FeeTooHigh {
+                fee_bps: tier_fee_bps,
+                max_fee_bps: U256::from(MAX_FEE_BPS),
+            }));
+        }
+
+        while self.taker_fee_tier_thresholds.pop().is_some() {}
+        while self.taker_fee_tier_bps.pop().is_some() {}
+        for (threshold, tier_fee_bps) in thresholds.into_iter().zip(fee_bps) {
+            self.taker_fee_tier_thresholds.push(threshold);
+            self.taker_fee_tier_bps.push(tier_fee_bps);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the taker fee discount tiers as `(thresholds, fee_bps)`.
+    #[must_use]
+    pub fn taker_fee_tiers(&self) -> (Vec<U256>, Vec<U256>) {
+        let thresholds = (0..self.taker_fee_tier_thresholds.len())
+            .filter_map(|i| self.taker_fee_tier_thresholds.get(i))
+            .collect();
+        let fee_bps = (0..self.taker_fee_tier_bps.len())
+            .filter_map(|i| self.taker_fee_tier_bps.get(i))
+            .collect();
+        (thresholds, fee_bps)
+    }
+
+    /// Returns the cumulative premium of an address's fills as taker (quote token's
+    /// native decimals), which determines its taker fee tier.
+    ///
+    /// # Parameters
+    /// - `account`: Taker address
+    #[must_use]
+    pub fn taker_volume_of(&self, account: Address) -> U256 {
+        self.taker_volume.get(account)
+    }
+
+    /// Returns an address's current taker fee tier and the taker fee it pays.
+    ///
+    /// # Parameters
+    /// - `account`: Taker address
+    ///
+    /// # Returns
+    /// `(tier, fee_bps)`, where tier 0 means no discount tier has been reached and tier
+    /// `i` means `thresholds[i - 1]` has
+    #[must_use]
+    pub fn taker_fee_tier_of(&self, account: Address) -> (U256, U256) {
+        let (tier, fee_bps) = self.taker_fee_tier(account);
+        (U256::from(tier), fee_bps)
     }
-}
 
-#[public]
-impl CLOB {
     /// Places a limit order in the orderbook.
     ///
-    /// Locks tokens from the maker:
+    /// An order that crosses the opposite side (a buy at or above the best ask, a sell
+    /// at or below the best bid) first takes liquidity like a market order limited to
+    /// `price`: it fills at the makers' prices, best price first, and emits one `Trade`
+    /// per filled maker order. With `post_only` it is rejected instead, so makers can be
+    /// sure they never take.
+    ///
+    /// The unfilled remainder rests in the book and locks tokens from the maker:
     /// - For sell orders: Locks ERC-1155 option tokens (requires approval)
     /// - For buy orders: Locks quote ERC20 tokens (price * quantity)
     ///
-    /// Orders are added to the orderbook at the specified price level and wait for takers.
-    /// Uses price-time priority: orders at the same price execute FIFO.
+    /// Resting orders are added to the tail of the FIFO queue at the specified price
+    /// level and wait for takers. Uses price-time priority: orders at the same price
+    /// execute FIFO.
+    ///
+    /// Buy orders lock `price * quantity` quote tokens, rounded up to the quote token's
+    /// decimals. Sell orders require the CLOB to be an approved operator on the Options
+    /// contract. Emits `OrderPlaced` for the resting remainder.
     ///
     /// # Parameters
     /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
     /// - `price`: Price per option token in quote token units (18 decimals normalized)
     /// - `quantity`: Quantity of option tokens to buy/sell
     /// - `side`: Order side (0 = Buy, 1 = Sell)
+    /// - `post_only`: Reject the order instead of taking when it crosses
     ///
     /// # Returns
-    /// Order ID that can be used to cancel the order later.
+    /// Order ID of the resting remainder that can be used to cancel it later, or zero if
+    /// the order was filled completely. IDs increase monotonically from 1.
     ///
     /// # Errors
-    /// Returns `CLOBError::Unimplemented` (stub implementation).
+    /// - `ZeroQuantity`: Quantity is zero
+    /// - `Unimplemented`: Side is neither buy nor sell
+    /// - `ZeroPrice`: Price is zero
+    /// - `NotInitialized`: `initialize` has not been called
+    /// - `WouldCross`: `post_only` is set and the order would match the opposite side
+    /// - `SeriesExpired`: The series is at or past its expiry
+    /// - `OptionsCallFailed`: Metadata call to the Options contract reverted
+    /// - `TransferFailed`: Maker lacks the option tokens or quote tokens, or the approval
+    /// - `Overflow`: Quote amount or order ID overflows
+    /// - `Reentrancy`: Called re-entrantly from a token callback
     pub fn place_order(
         &mut self,
         token_id: B256,
         price: U256,
         quantity: U256,
         side: u8,
+        post_only: bool,
     ) -> Result<U256, CLOBError> {
-        let _side = OrderSide::from_u8(side)?;
-        let _ = (token_id, price, quantity);
-        Err(CLOBError::Unimplemented(Unimplemented {}))
+        self.non_reentrant(|this| {
+            if quantity == U256::ZERO {
+                return Err(CLOBError::ZeroQuantity(ZeroQuantity {}));
+            }
+
+            let side = OrderSide::from_u8(side)?;
+
+            if price.is_zero() {
+                return Err(CLOBError::ZeroPrice(ZeroPrice {}));
+            }
+
+            if this.options.get() == Address::ZERO {
+                return Err(CLOBError::NotInitialized(NotInitialized {}));
+            }
+
+            let best_opposite = this.best_price(token_id, side.opposite());
+            let crosses =
+                !best_opposite.is_zero() && !Self::improves(side.opposite(), price, best_opposite);
+            if crosses && post_only {
+                return Err(CLOBError::WouldCross(WouldCross {
+                    price,
+                    best_price: best_opposite,
+                }));
+            }
+
+            this.check_tradable(token_id)?;
+
+            let maker = this.vm().msg_sender();
+            let fills = if crosses {
+                this.match_orders(maker, token_id, side.opposite(), quantity, Some(price))?
+            } else {
+                Vec::new()
+            };
+            let filled = fills
+                .iter()
+                .try_fold(U256::ZERO, |filled, fill| filled.checked_add(fill.quantity))
+                .ok_or(CLOBError::Overflow(Overflow {}))?;
+            let remaining = quantity - filled;
+
+            let order_id = if remaining.is_zero() {
+                U256::ZERO
+            } else {
+                this.insert_order(maker, token_id, price, remaining, side)?
+            };
+
+            // External calls after all state updates
+            if !fills.is_empty() {
+                this.settle_fills(maker, maker, token_id, side, &fills)?;
+            }
+            if !remaining.is_zero() {
+                this.lock_order_assets(maker, token_id, price, remaining, side)?;
+
+                log(
+                    this.vm(),
+                    OrderPlaced {
+                        orderId: order_id,
+                        maker,
+                        tokenId: token_id,
+                        side: side.to_u8(),
+                        price,
+                        quantity: remaining,
+                    },
+                );
+            }
+
+            Ok(order_id)
+        })
     }
 
     /// Cancels an existing limit order.
@@ -90,16 +1584,93 @@ impl CLOB {
     /// - For sell orders: Returns ERC-1155 option tokens
     /// - For buy orders: Returns quote ERC20 tokens
     ///
-    /// Only the order maker can cancel their own orders.
+    /// Only the order maker can cancel their own orders. Buy orders are refunded the
+    /// premium locked for the unfilled quantity, so fills and refund add up to what was
+    /// locked. Emits `OrderCancelled`.
     ///
     /// # Parameters
     /// - `order_id`: The order ID returned from `place_order`
     ///
     /// # Errors
-    /// Returns `CLOBError::Unimplemented` (stub implementation).
+    /// - `OrderNotFound`: Order does not exist, or was filled or cancelled
+    /// - `Unauthorized`: Caller is not the order maker
+    /// - `TransferFailed`: Refund transfer failed
+    /// - `Overflow`: Refund amount overflows
+    /// - `Reentrancy`: Called re-entrantly from a token callback
     pub fn cancel_order(&mut self, order_id: U256) -> Result<(), CLOBError> {
-        let _ = order_id;
-        Err(CLOBError::Unimplemented(Unimplemented {}))
+        self.non_reentrant(|this| {
+            let (maker, remaining) = {
+                let order = this.orders.get(order_id);
+                (order.maker.get(), order.remaining.get())
+            };
+            if remaining.is_zero() {
+                return Err(CLOBError::OrderNotFound(OrderNotFound { order_id }));
+            }
+
+            let caller = this.vm().msg_sender();
+            if caller != maker {
+                return Err(CLOBError::Unauthorized(Unauthorized { caller }));
+            }
+
+            this.close_order(order_id)
+        })
+    }
+
+    /// Cancels up to `max_orders` resting orders of an expired series and refunds the
+    /// makers.
+    ///
+    /// Permissionless, so anyone can clear a dead book. Bids are closed before asks,
+    /// best price first; each order is refunded like `cancel_order` and emits
+    /// `OrderCancelled`. Deep books are pruned in batches by calling again until it
+    /// returns zero.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
+    /// - `max_orders`: Maximum number of orders to cancel in this call
+    ///
+    /// # Returns
+    /// Number of orders cancelled
+    ///
+    /// # Errors
+    /// - `NotInitialized`: `initialize` has not been called
+    /// - `SeriesNotExpired`: The series has not reached its expiry
+    /// - `OptionsCallFailed`: Metadata call to the Options contract reverted
+    /// - `TransferFailed`: A refund transfer failed
+    /// - `Overflow`: A refund amount overflows
+    /// - `Reentrancy`: Called re-entrantly from a token callback
+    pub fn prune_expired(&mut self, token_id: B256, max_orders: u32) -> Result<U256, CLOBError> {
+        self.non_reentrant(|this| {
+            if this.options.get() == Address::ZERO {
+                return Err(CLOBError::NotInitialized(NotInitialized {}));
+            }
+
+            let expiry = this.series_expiry(token_id)?;
+            if U256::from(this.vm().block_timestamp()) < expiry {
+                return Err(CLOBError::SeriesNotExpired(SeriesNotExpired {
+                    token_id,
+                    expiry,
+                }));
+            }
+
+            let mut pruned = 0u32;
+            for side in [OrderSide::Buy, OrderSide::Sell] {
+                while pruned < max_orders {
+                    let price = this.best_price(token_id, side);
+                    if price.is_zero() {
+                        break;
+                    }
+                    let order_id = this
+                        .price_levels
+                        .get(Self::level_key(token_id, side, price))
+                        .head
+                        .get();
+                    this.close_order(order_id)?;
+                    pruned += 1;
+                }
+            }
+
+            Ok(U256::from(pruned))
+        })
     }
 
     /// Executes a market order against the orderbook.
@@ -116,22 +1687,362 @@ impl CLOB {
     /// - Quote ERC20 premium transfers from buyer to seller (at maker prices)
     /// - Maker orders are filled/reduced in FIFO order at each price level
     ///
+    /// Buy takers pay each maker directly and need a quote token approval; sell takers
+    /// deliver option tokens to each maker and need the CLOB approved as operator.
+    /// Emits one `Trade` per filled maker order.
+    ///
+    /// A fill that would leave a maker order with a remainder below `min_order_quantity`
+    /// closes the order: the taker still receives exactly `quantity`, the dust is
+    /// refunded to the maker and `OrderCancelled` is emitted with the dust.
+    ///
     /// # Parameters
     /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
     /// - `quantity`: Quantity of option tokens to buy/sell
     /// - `side`: Order side (0 = Buy to take liquidity from asks, 1 = Sell to take from bids)
     ///
     /// # Errors
-    /// Returns `CLOBError::Unimplemented` (stub implementation).
+    /// - `ZeroQuantity`: Quantity is zero
+    /// - `Unimplemented`: Side is neither buy nor sell
+    /// - `NotInitialized`: `initialize` has not been called
+    /// - `InsufficientLiquidity`: The opposite side holds less than `quantity`
+    /// - `SeriesExpired`: The series is at or past its expiry
+    /// - `OptionsCallFailed`: Metadata call to the Options contract reverted
+    /// - `TransferFailed`: Taker lacks the option tokens or quote tokens, or the approval
+    /// - `Overflow`: Premium overflows
+    /// - `Reentrancy`: Called re-entrantly from a token callback
     pub fn market_order(
         &mut self,
         token_id: B256,
         quantity: U256,
         side: u8,
     ) -> Result<(), CLOBError> {
-        let _side = OrderSide::from_u8(side)?;
-        let _ = (token_id, quantity);
-        Err(CLOBError::Unimplemented(Unimplemented {}))
+        self.non_reentrant(|this| {
+            if quantity == U256::ZERO {
+                return Err(CLOBError::ZeroQuantity(ZeroQuantity {}));
+            }
+
+            let side = OrderSide::from_u8(side)?;
+
+            if this.options.get() == Address::ZERO {
+                return Err(CLOBError::NotInitialized(NotInitialized {}));
+            }
+
+            let maker_side = side.opposite();
+            let available = this.available_liquidity(token_id, maker_side, quantity)?;
+            if available < quantity {
+                return Err(CLOBError::InsufficientLiquidity(InsufficientLiquidity {
+                    requested: quantity,
+                    available,
+                }));
+            }
+
+            this.check_tradable(token_id)?;
+
+            let taker = this.vm().msg_sender();
+            let fills = this.match_orders(taker, token_id, maker_side, quantity, None)?;
+
+            // External calls after all state updates
+            this.settle_fills(taker, taker, token_id, side, &fills)
+        })
+    }
+
+    /// Buys call options from the asks and exercises them in one transaction.
+    ///
+    /// Fills `quantity` option tokens like a buy `market_order` (the caller pays the
+    /// premium and fees), then pulls the strike payment quoted by the Options contract's
+    /// `getExerciseCost` from the caller and exercises the options through
+    /// `exerciseCallTo`, which delivers the underlying to the caller. The options never
+    /// leave this contract, so arbitrageurs can capture asks priced below intrinsic
+    /// value without holding inventory. Emits `Trade` for each fill and `FlashArb`.
+    ///
+    /// The caller must approve this contract for the premium (quote token) and the
+    /// strike payment (the series' quote token).
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the call option (keccak256 hash)
+    /// - `quantity`: Option tokens to buy and exercise (option token units, a whole
+    ///   number of underlying base units)
+    /// - `max_premium`: Most the caller pays for the options, fees included (quote token
+    ///   decimals)
+    /// - `min_underlying_out`: Least underlying the caller must receive (native
+    ///   decimals)
+    ///
+    /// # Returns
+    /// Underlying received by the caller (native decimals)
+    ///
+    /// # Errors
+    /// - `ZeroQuantity`: Quantity is zero
+    /// - `NotInitialized`: `initialize` has not been called
+    /// - `UnexercisableQuantity`: `quantity` is not a whole number of underlying base units
+    /// - `InsufficientLiquidity`: The asks hold less than `quantity`
+    /// - `SeriesExpired`: The series is at or past its expiry
+    /// - `OptionsCallFailed`: Metadata call to the Options contract reverted
+    /// - `PremiumTooHigh`: The fills cost more than `max_premium`
+    /// - `ExerciseFailed`: The Options contract rejected the exercise (e.g. a put, a cash
+    ///   call or a closed exercise window)
+    /// - `InsufficientUnderlyingOut`: The caller received less than `min_underlying_out`
+    /// - `TransferFailed`: The caller lacks the premium or strike payment, or the approval
+    /// - `Overflow`: Premium overflows
+    /// - `Reentrancy`: Called re-entrantly from a token callback
+    pub fn flash_arb(
+        &mut self,
+        token_id: B256,
+        quantity: U256,
+        max_premium: U256,
+        min_underlying_out: U256,
+    ) -> Result<U256, CLOBError> {
+        self.non_reentrant(|this| {
+            if quantity == U256::ZERO {
+                return Err(CLOBError::ZeroQuantity(ZeroQuantity {}));
+            }
+
+            let options = this.options.get();
+            if options == Address::ZERO {
+                return Err(CLOBError::NotInitialized(NotInitialized {}));
+            }
+
+            let (underlying, strike_token, underlying_decimals) = IOptions::new(options)
+                .option_metadata_of(Call::new_in(this), token_id)
+                .map(|metadata| (metadata.0, metadata.1, metadata.2))
+                .map_err(|_| CLOBError::OptionsCallFailed(OptionsCallFailed {}))?;
+            let scale = U256::from(10).pow(U256::from(18u8.saturating_sub(underlying_decimals)));
+            if !(quantity % scale).is_zero() {
+                return Err(CLOBError::UnexercisableQuantity(UnexercisableQuantity {
+                    quantity,
+                }));
+            }
+            let native_quantity = quantity / scale;
+
+            let available = this.available_liquidity(token_id, OrderSide::Sell, quantity)?;
+            if available < quantity {
+                return Err(CLOBError::InsufficientLiquidity(InsufficientLiquidity {
+                    requested: quantity,
+                    available,
+                }));
+            }
+
+            this.check_tradable(token_id)?;
+
+            let taker = this.vm().msg_sender();
+            let fills = this.match_orders(taker, token_id, OrderSide::Sell, quantity, None)?;
+            let premium = fills
+                .iter()
+                .try_fold(U256::ZERO, |premium, fill| premium.checked_add(fill.cost))
+                .ok_or(CLOBError::Overflow(Overflow {}))?;
+            if premium > max_premium {
+                return Err(CLOBError::PremiumTooHigh(PremiumTooHigh {
+                    premium,
+                    max_premium,
+                }));
+            }
+
+            // External calls after all state updates
+            let contract_addr = this.vm().contract_address();
+            this.settle_fills(taker, contract_addr, token_id, OrderSide::Buy, &fills)?;
+
+            let (strike_payment, _) = IOptions::new(options)
+                .get_exercise_cost(Call::new_in(this), token_id, native_quantity)
+                .map_err(|_| CLOBError::ExerciseFailed(ExerciseFailed {}))?;
+            let balance_before = this.token_balance(underlying, taker)?;
+            this.transfer_token_from(strike_token, taker, contract_addr, strike_payment)?;
+            this.approve_token(strike_token, options, strike_payment)?;
+            IOptions::new(options)
+                .exercise_call_to(Call::new_in(this), token_id, native_quantity, taker)
+                .map_err(|_| CLOBError::ExerciseFailed(ExerciseFailed {}))?;
+            this.approve_token(strike_token, options, U256::ZERO)?;
+
+            let underlying_out = this
+                .token_balance(underlying, taker)?
+                .saturating_sub(balance_before);
+            if underlying_out < min_underlying_out {
+                return Err(CLOBError::InsufficientUnderlyingOut(
+                    InsufficientUnderlyingOut {
+                        received: underlying_out,
+                        min_underlying_out,
+                    },
+                ));
+            }
+
+            log(
+                this.vm(),
+                FlashArb {
+                    taker,
+                    tokenId: token_id,
+                    quantity,
+                    premium,
+                    strikePayment: strike_payment,
+                    underlyingOut: underlying_out,
+                },
+            );
+
+            Ok(underlying_out)
+        })
+    }
+
+    /// Returns the aggregated depth of one side of a series' book.
+    ///
+    /// Each entry is `(price, total_quantity)` of one price level, best price first (highest
+    /// bids, lowest asks), for at most `max_levels` levels. An empty side returns an empty
+    /// list.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
+    /// - `side`: Book side (0 = Buy for bids, 1 = Sell for asks)
+    /// - `max_levels`: Maximum number of levels to return
+    ///
+    /// # Errors
+    /// - `Unimplemented`: Side is neither buy nor sell
+    pub fn get_levels(
+        &self,
+        token_id: B256,
+        side: u8,
+        max_levels: u32,
+    ) -> Result<Vec<(U256, U256)>, CLOBError> {
+        let side = OrderSide::from_u8(side)?;
+
+        let mut levels = Vec::new();
+        let mut price = self.best_price(token_id, side);
+        while !price.is_zero() && levels.len() < max_levels as usize {
+            let level = self
+                .price_levels
+                .get(Self::level_key(token_id, side, price));
+            levels.push((price, level.total_quantity.get()));
+            price = level.worse.get();
+        }
+
+        Ok(levels)
+    }
+
+    /// Returns a resting order.
+    ///
+    /// Orders that were cancelled, filled completely or never placed are inactive and
+    /// returned with every field zeroed.
+    ///
+    /// # Parameters
+    /// - `order_id`: The order ID returned from `place_order`
+    ///
+    /// # Returns
+    /// Tuple of (maker, token_id, price, remaining, side, active)
+    #[must_use]
+    pub fn get_order(&self, order_id: U256) -> (Address, B256, U256, U256, u8, bool) {
+        let order = self.orders.get(order_id);
+        let remaining = order.remaining.get();
+        if remaining.is_zero() {
+            return (Address::ZERO, B256::ZERO, U256::ZERO, U256::ZERO, 0, false);
+        }
+
+        (
+            order.maker.get(),
+            order.token_id.get(),
+            order.price.get(),
+            remaining,
+            order.side.get().to::<u8>(),
+            true,
+        )
+    }
+
+    /// Returns the IDs of a maker's open orders, oldest first.
+    ///
+    /// Orders that were cancelled or filled completely are left out.
+    ///
+    /// # Parameters
+    /// - `maker`: Address that placed the orders
+    #[must_use]
+    pub fn get_open_orders(&self, maker: Address) -> Vec<U256> {
+        let order_ids = self.maker_orders.getter(maker);
+        (0..order_ids.len())
+            .filter_map(|i| order_ids.get(i))
+            .filter(|&order_id| !self.orders.get(order_id).remaining.get().is_zero())
+            .collect()
+    }
+
+    /// Returns the highest price with resting buy orders for a series (zero if none).
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
+    #[must_use]
+    pub fn best_bid(&self, token_id: B256) -> U256 {
+        self.best_price(token_id, OrderSide::Buy)
+    }
+
+    /// Returns the lowest price with resting sell orders for a series (zero if none).
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
+    #[must_use]
+    pub fn best_ask(&self, token_id: B256) -> U256 {
+        self.best_price(token_id, OrderSide::Sell)
+    }
+
+    /// Returns whether a series' book is crossed or locked (`best_bid >= best_ask`).
+    ///
+    /// Matching should never leave a book in this state, so `true` indicates a bug;
+    /// keepers can monitor it. A book with an empty side is never crossed.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
+    #[must_use]
+    pub fn is_book_crossed(&self, token_id: B256) -> bool {
+        let best_bid = self.best_bid.get(token_id);
+        let best_ask = self.best_ask.get(token_id);
+        !best_bid.is_zero() && !best_ask.is_zero() && best_bid >= best_ask
+    }
+
+    /// Returns whether a series' book has no resting orders on either side.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
+    #[must_use]
+    pub fn book_is_empty(&self, token_id: B256) -> bool {
+        self.best_bid.get(token_id).is_zero() && self.best_ask.get(token_id).is_zero()
+    }
+
+    /// ERC-1155 receiver hook, called by the Options contract when option tokens are
+    /// escrowed for a sell order.
+    ///
+    /// Only accepts tokens of the book's Options contract; any other caller gets a
+    /// zero value, which makes its transfer revert.
+    ///
+    /// # Returns
+    /// `ERC1155_RECEIVED` when the caller is the Options contract
+    #[selector(name = "onERC1155Received")]
+    pub fn on_erc1155_received(
+        &self,
+        operator: Address,
+        from: Address,
+        id: U256,
+        value: U256,
+        data: Bytes,
+    ) -> FixedBytes<4> {
+        let _ = (operator, from, id, value, data);
+        if self.vm().msg_sender() == self.options.get() {
+            FixedBytes::from(ERC1155_RECEIVED)
+        } else {
+            FixedBytes::ZERO
+        }
+    }
+
+    /// ERC-1155 batch receiver hook.
+    ///
+    /// Only accepts tokens of the book's Options contract, like `onERC1155Received`.
+    ///
+    /// # Returns
+    /// `ERC1155_BATCH_RECEIVED` when the caller is the Options contract
+    #[selector(name = "onERC1155BatchReceived")]
+    pub fn on_erc1155_batch_received(
+        &self,
+        operator: Address,
+        from: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Bytes,
+    ) -> FixedBytes<4> {
+        let _ = (operator, from, ids, values, data);
+        if self.vm().msg_sender() == self.options.get() {
+            FixedBytes::from(ERC1155_BATCH_RECEIVED)
+        } else {
+            FixedBytes::ZERO
+        }
     }
 }
 
@@ -143,52 +2054,343 @@ mod tests {
     use super::*;
 
     #[motsu::test]
-    fn test_place_order_buy_returns_unimplemented(contract: Contract<CLOB>, alice: Address) {
+    fn test_place_order_zero_price_fails(contract: Contract<CLOB>, alice: Address) {
+        for side in [0, 1] {
+            let result = contract.sender(alice).place_order(
+                B256::ZERO,
+                U256::ZERO,
+                U256::from(100),
+                side,
+                false,
+            );
+
+            assert!(matches!(result, Err(CLOBError::ZeroPrice(_))));
+        }
+    }
+
+    #[motsu::test]
+    fn test_receiver_hooks_only_accept_options_contract(contract: Contract<CLOB>, alice: Address) {
+        let options = Address::repeat_byte(0x01);
+        contract
+            .sender(alice)
+            .initialize(options, Address::repeat_byte(0x02), 6)
+            .unwrap();
+
+        let accepted = contract.sender(options).on_erc1155_received(
+            alice,
+            alice,
+            U256::from(1),
+            U256::from(100),
+            Vec::<u8>::new().into(),
+        );
+        assert_eq!(accepted, FixedBytes::from(ERC1155_RECEIVED));
+        let accepted = contract.sender(options).on_erc1155_batch_received(
+            alice,
+            alice,
+            vec![U256::from(1)],
+            vec![U256::from(100)],
+            Vec::<u8>::new().into(),
+        );
+        assert_eq!(accepted, FixedBytes::from(ERC1155_BATCH_RECEIVED));
+
+        let rejected = contract.sender(alice).on_erc1155_received(
+            alice,
+            alice,
+            U256::from(1),
+            U256::from(100),
+            Vec::<u8>::new().into(),
+        );
+        assert_eq!(rejected, FixedBytes::ZERO);
+    }
+
+    #[motsu::test]
+    fn test_reentrant_calls_fail(contract: Contract<CLOB>, alice: Address) {
+        contract
+            .sender(alice)
+            .initialize(Address::repeat_byte(0x01), Address::repeat_byte(0x02), 6)
+            .unwrap();
+        contract.sender(alice).locked.set(true);
+
         let result = contract.sender(alice).place_order(
             B256::ZERO,
             U256::from(1000),
             U256::from(100),
-            0, // OrderSide::Buy
+            1,
+            false,
         );
-
-        assert!(matches!(result, Err(CLOBError::Unimplemented(_))));
+        assert!(matches!(result, Err(CLOBError::Reentrancy(_))));
+        let result = contract
+            .sender(alice)
+            .market_order(B256::ZERO, U256::from(100), 0);
+        assert!(matches!(result, Err(CLOBError::Reentrancy(_))));
+        let result = contract.sender(alice).cancel_order(U256::from(1));
+        assert!(matches!(result, Err(CLOBError::Reentrancy(_))));
     }
 
     #[motsu::test]
-    fn test_place_order_sell_returns_unimplemented(contract: Contract<CLOB>, alice: Address) {
+    fn test_place_order_before_initialize_fails(contract: Contract<CLOB>, alice: Address) {
         let result = contract.sender(alice).place_order(
             B256::ZERO,
             U256::from(1000),
             U256::from(100),
-            1, // OrderSide::Sell
+            0,
+            false,
         );
 
-        assert!(matches!(result, Err(CLOBError::Unimplemented(_))));
+        assert!(matches!(result, Err(CLOBError::NotInitialized(_))));
     }
 
     #[motsu::test]
-    fn test_cancel_order_returns_unimplemented(contract: Contract<CLOB>, alice: Address) {
+    fn test_post_only_order_rejects_crossing_prices(contract: Contract<CLOB>, alice: Address) {
+        contract
+            .sender(alice)
+            .initialize(Address::repeat_byte(0x01), Address::repeat_byte(0x02), 6)
+            .unwrap();
+        contract
+            .sender(alice)
+            .set_best_bid(B256::ZERO, U256::from(900));
+        contract
+            .sender(alice)
+            .set_best_ask(B256::ZERO, U256::from(1000));
+
+        for (price, side) in [(1000, 0), (1100, 0), (900, 1), (800, 1)] {
+            let result = contract.sender(alice).place_order(
+                B256::ZERO,
+                U256::from(price),
+                U256::from(100),
+                side,
+                true,
+            );
+
+            assert!(matches!(result, Err(CLOBError::WouldCross(_))));
+        }
+    }
+
+    #[motsu::test]
+    fn test_initialize_validates_and_runs_once(contract: Contract<CLOB>, alice: Address) {
+        let options = Address::repeat_byte(0x01);
+        let quote = Address::repeat_byte(0x02);
+
+        let result = contract.sender(alice).initialize(Address::ZERO, quote, 6);
+        assert!(matches!(result, Err(CLOBError::ZeroAddress(_))));
+        let result = contract.sender(alice).initialize(options, quote, 19);
+        assert!(matches!(result, Err(CLOBError::InvalidDecimals(_))));
+
+        contract
+            .sender(alice)
+            .initialize(options, quote, 6)
+            .unwrap();
+        let result = contract.sender(alice).initialize(options, quote, 6);
+        assert!(matches!(result, Err(CLOBError::AlreadyInitialized(_))));
+    }
+
+    #[test]
+    fn test_quote_amount_rounds_up() {
+        let one = U256::from(10).pow(U256::from(18));
+
+        // 2.5 USDC per option, 3 options
+        let price = U256::from(25) * one / U256::from(10);
+        assert_eq!(
+            quote_amount(price, U256::from(3) * one, 6).unwrap(),
+            U256::from(7_500_000)
+        );
+        // One wei of an option at 1 quote token still locks one unit
+        assert_eq!(quote_amount(one, U256::from(1), 6).unwrap(), U256::from(1));
+        assert!(matches!(
+            quote_amount(U256::MAX, U256::from(2), 6),
+            Err(CLOBError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_fill_costs_add_up_to_locked_premium() {
+        let one = U256::from(10).pow(U256::from(18));
+        // 1/3 USDC per option, 10 options filled as 3 + 3 + 4
+        let price = one / U256::from(3);
+        let quantity = U256::from(10) * one;
+        let locked = quote_amount(price, quantity, 6).unwrap();
+
+        let mut paid = U256::ZERO;
+        let mut remaining = quantity;
+        for filled in [3, 3, 4] {
+            let remaining_after = remaining - U256::from(filled) * one;
+            paid += fill_cost(price, quantity, remaining, remaining_after, 6).unwrap();
+            remaining = remaining_after;
+        }
+
+        assert_eq!(paid, locked);
+        assert!(matches!(
+            fill_cost(price, quantity, quantity + U256::from(1), U256::ZERO, 6),
+            Err(CLOBError::Overflow(_))
+        ));
+    }
+
+    #[motsu::test]
+    fn test_cancel_unknown_order_fails(contract: Contract<CLOB>, alice: Address) {
         let result = contract.sender(alice).cancel_order(U256::from(1));
 
-        assert!(matches!(result, Err(CLOBError::Unimplemented(_))));
+        assert!(matches!(
+            result,
+            Err(CLOBError::OrderNotFound(OrderNotFound { order_id })) if order_id == U256::from(1)
+        ));
     }
 
     #[motsu::test]
-    fn test_market_order_buy_returns_unimplemented(contract: Contract<CLOB>, alice: Address) {
-        let result = contract
+    fn test_market_order_before_initialize_fails(contract: Contract<CLOB>, alice: Address) {
+        for side in [0, 1] {
+            let result = contract
+                .sender(alice)
+                .market_order(B256::ZERO, U256::from(100), side);
+
+            assert!(matches!(result, Err(CLOBError::NotInitialized(_))));
+        }
+    }
+
+    #[motsu::test]
+    fn test_market_order_on_empty_book_fails(contract: Contract<CLOB>, alice: Address) {
+        contract
             .sender(alice)
-            .market_order(B256::ZERO, U256::from(100), 0); // OrderSide::Buy
+            .initialize(Address::repeat_byte(0x01), Address::repeat_byte(0x02), 6)
+            .unwrap();
+
+        for side in [0, 1] {
+            let result = contract
+                .sender(alice)
+                .market_order(B256::ZERO, U256::from(100), side);
 
-        assert!(matches!(result, Err(CLOBError::Unimplemented(_))));
+            assert!(matches!(
+                result,
+                Err(CLOBError::InsufficientLiquidity(InsufficientLiquidity { available, .. }))
+                    if available.is_zero()
+            ));
+        }
     }
 
     #[motsu::test]
-    fn test_market_order_sell_returns_unimplemented(contract: Contract<CLOB>, alice: Address) {
-        let result = contract
+    fn test_price_levels_stay_sorted(contract: Contract<CLOB>, alice: Address) {
+        let token_id = B256::ZERO;
+        for price in [300, 100, 200, 400] {
+            contract
+                .sender(alice)
+                .insert_order(
+                    alice,
+                    token_id,
+                    U256::from(price),
+                    U256::from(1),
+                    OrderSide::Sell,
+                )
+                .unwrap();
+        }
+
+        let mut prices = vec![];
+        let mut price = contract.sender(alice).best_ask(token_id);
+        while !price.is_zero() {
+            prices.push(price);
+            price = contract
+                .sender(alice)
+                .price_levels
+                .get(CLOB::level_key(token_id, OrderSide::Sell, price))
+                .worse
+                .get();
+        }
+        assert_eq!(prices, [100, 200, 300, 400].map(U256::from).to_vec());
+
+        // Order 3 is the only order at 200
+        contract.sender(alice).unlink_order(U256::from(3)).unwrap();
+        assert_eq!(
+            contract
+                .sender(alice)
+                .price_levels
+                .get(CLOB::level_key(token_id, OrderSide::Sell, U256::from(100)))
+                .worse
+                .get(),
+            U256::from(300)
+        );
+        contract.sender(alice).unlink_order(U256::from(2)).unwrap();
+        assert_eq!(contract.sender(alice).best_ask(token_id), U256::from(300));
+    }
+
+    #[motsu::test]
+    fn test_place_order_zero_quantity_fails(contract: Contract<CLOB>, alice: Address) {
+        for side in [0, 1] {
+            let result = contract.sender(alice).place_order(
+                B256::ZERO,
+                U256::from(1000),
+                U256::ZERO,
+                side,
+                false,
+            );
+
+            assert!(matches!(result, Err(CLOBError::ZeroQuantity(_))));
+        }
+    }
+
+    #[motsu::test]
+    fn test_market_order_zero_quantity_fails(contract: Contract<CLOB>, alice: Address) {
+        for side in [0, 1] {
+            let result = contract
+                .sender(alice)
+                .market_order(B256::ZERO, U256::ZERO, side);
+
+            assert!(matches!(result, Err(CLOBError::ZeroQuantity(_))));
+        }
+    }
+
+    #[motsu::test]
+    fn test_empty_book_is_not_crossed(contract: Contract<CLOB>, alice: Address) {
+        assert!(contract.sender(alice).book_is_empty(B256::ZERO));
+        assert!(!contract.sender(alice).is_book_crossed(B256::ZERO));
+    }
+
+    #[motsu::test]
+    fn test_normal_book_is_not_crossed(contract: Contract<CLOB>, alice: Address) {
+        contract
+            .sender(alice)
+            .set_best_bid(B256::ZERO, U256::from(900));
+        contract
             .sender(alice)
-            .market_order(B256::ZERO, U256::from(100), 1); // OrderSide::Sell
+            .set_best_ask(B256::ZERO, U256::from(1000));
 
-        assert!(matches!(result, Err(CLOBError::Unimplemented(_))));
+        assert!(!contract.sender(alice).book_is_empty(B256::ZERO));
+        assert!(!contract.sender(alice).is_book_crossed(B256::ZERO));
+    }
+
+    #[motsu::test]
+    fn test_one_sided_book_is_not_crossed(contract: Contract<CLOB>, alice: Address) {
+        contract
+            .sender(alice)
+            .set_best_bid(B256::ZERO, U256::from(900));
+
+        assert!(!contract.sender(alice).book_is_empty(B256::ZERO));
+        assert!(!contract.sender(alice).is_book_crossed(B256::ZERO));
+    }
+
+    #[motsu::test]
+    fn test_crossed_book_is_detected(contract: Contract<CLOB>, alice: Address) {
+        contract
+            .sender(alice)
+            .set_best_bid(B256::ZERO, U256::from(1100));
+        contract
+            .sender(alice)
+            .set_best_ask(B256::ZERO, U256::from(1000));
+
+        assert!(contract.sender(alice).is_book_crossed(B256::ZERO));
+    }
+
+    #[motsu::test]
+    fn test_locked_book_is_detected(contract: Contract<CLOB>, alice: Address) {
+        contract
+            .sender(alice)
+            .set_best_bid(B256::ZERO, U256::from(1000));
+        contract
+            .sender(alice)
+            .set_best_ask(B256::ZERO, U256::from(1000));
+
+        assert!(contract.sender(alice).is_book_crossed(B256::ZERO));
+        // Other series are unaffected
+        assert!(contract
+            .sender(alice)
+            .book_is_empty(B256::repeat_byte(0x01)));
     }
 }
 
@@ -200,62 +2402,58 @@ mod proptests {
 
     use super::*;
 
-    // Property-based tests for CLOB stub behavior
-    //
-    // These tests verify that all public functions return Unimplemented errors
-    // for arbitrary inputs. Once implementation is added, these tests will be
-    // updated to verify the actual orderbook invariants.
-
     proptest! {
-        /// Property: place_order returns Unimplemented for all inputs
+        /// Property: a buy order never locks less than its exact premium
         #[test]
-        fn prop_place_order_returns_unimplemented(
-            price in 1u64..1_000_000u64,
-            quantity in 1u64..1_000_000u64,
-            side in 0u8..2u8,
+        fn prop_quote_amount_covers_premium(
+            price in 1u64..u64::MAX,
+            quantity in 1u64..u64::MAX,
+            quote_decimals in 0u8..=18u8,
         ) {
-            let contract = Contract::<CLOB>::default();
-            let alice = Address::repeat_byte(0x01);
-
-            let result = contract.sender(alice).place_order(
-                B256::ZERO,
-                U256::from(price),
-                U256::from(quantity),
-                side,
-            );
+            let amount =
+                quote_amount(U256::from(price), U256::from(quantity), quote_decimals).unwrap();
+            let scale = U256::from(10).pow(U256::from(36 - u32::from(quote_decimals)));
 
-            prop_assert!(matches!(result, Err(CLOBError::Unimplemented(_))));
+            prop_assert!(amount * scale >= U256::from(price) * U256::from(quantity));
+            prop_assert!((amount - U256::from(1)) * scale < U256::from(price) * U256::from(quantity));
         }
 
-        /// Property: cancel_order returns Unimplemented for all order IDs
+        /// Property: however an order is split into fills, they pay exactly the premium
         #[test]
-        fn prop_cancel_order_returns_unimplemented(
-            order_id in 0u64..1_000_000u64,
+        fn prop_fill_costs_add_up_to_locked_premium(
+            price in 1u64..u64::MAX,
+            quantity in 2u64..u64::MAX,
+            split in 1u64..u64::MAX,
+            quote_decimals in 0u8..=18u8,
         ) {
-            let contract = Contract::<CLOB>::default();
-            let alice = Address::repeat_byte(0x01);
+            let (price, quantity) = (U256::from(price), U256::from(quantity));
+            let first = U256::from(split) % quantity;
 
-            let result = contract.sender(alice).cancel_order(U256::from(order_id));
+            let paid = fill_cost(price, quantity, quantity, quantity - first, quote_decimals)
+                .unwrap()
+                + fill_cost(price, quantity, quantity - first, U256::ZERO, quote_decimals)
+                    .unwrap();
 
-            prop_assert!(matches!(result, Err(CLOBError::Unimplemented(_))));
+            prop_assert_eq!(paid, quote_amount(price, quantity, quote_decimals).unwrap());
         }
 
-        /// Property: market_order returns Unimplemented for all inputs
+        /// Property: the best ask is the lowest price of any resting sell order
         #[test]
-        fn prop_market_order_returns_unimplemented(
-            quantity in 1u64..1_000_000u64,
-            side in 0u8..2u8,
+        fn prop_best_ask_is_lowest_resting_price(
+            prices in proptest::collection::vec(1u64..1_000u64, 1..20),
         ) {
             let contract = Contract::<CLOB>::default();
             let alice = Address::repeat_byte(0x01);
 
-            let result = contract.sender(alice).market_order(
-                B256::ZERO,
-                U256::from(quantity),
-                side,
-            );
+            for &price in &prices {
+                contract
+                    .sender(alice)
+                    .insert_order(alice, B256::ZERO, U256::from(price), U256::from(1), OrderSide::Sell)
+                    .unwrap();
+            }
 
-            prop_assert!(matches!(result, Err(CLOBError::Unimplemented(_))));
+            let lowest = prices.iter().copied().min().unwrap_or_default();
+            prop_assert_eq!(contract.sender(alice).best_ask(B256::ZERO), U256::from(lowest));
         }
     }
 }
@@ -0,0 +1,148 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::{CLOBError, OrderCancelled, CLOB};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const MAKER: Address = Address::new([0xAA; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(MAKER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    for account in [MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(10) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote.sender(account).mint(account, U256::from(100_000_000));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(100_000_000));
+    }
+}
+
+#[motsu::test]
+fn cancelled_sell_returns_option_tokens(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let order_id = clob
+        .sender(MAKER)
+        .place_order(
+            TOKEN_ID,
+            U256::from(2) * one(),
+            U256::from(4) * one(),
+            SELL,
+            false,
+        )
+        .unwrap();
+
+    clob.sender(MAKER).cancel_order(order_id).unwrap();
+
+    assert_eq!(
+        options.sender(MAKER).balance_of(MAKER, TOKEN_ID),
+        U256::from(10) * one()
+    );
+    assert_eq!(
+        options.sender(MAKER).balance_of(clob.address(), TOKEN_ID),
+        U256::ZERO
+    );
+    clob.assert_emitted(&OrderCancelled {
+        orderId: order_id,
+        maker: MAKER,
+        tokenId: TOKEN_ID,
+        remaining: U256::from(4) * one(),
+    });
+}
+
+#[motsu::test]
+fn cancelled_partially_filled_buy_refunds_the_rest(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    // 3 options at 1/3 USDC lock 1 USDC
+    let order_id = clob
+        .sender(MAKER)
+        .place_order(
+            TOKEN_ID,
+            one() / U256::from(3),
+            U256::from(3) * one(),
+            BUY,
+            false,
+        )
+        .unwrap();
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), SELL)
+        .unwrap();
+
+    clob.sender(MAKER).cancel_order(order_id).unwrap();
+
+    // Everything locked went either to the taker or back to the maker
+    assert_eq!(quote.sender(MAKER).balance_of(clob.address()), U256::ZERO);
+    assert_eq!(
+        quote.sender(MAKER).balance_of(MAKER) + quote.sender(TAKER).balance_of(TAKER),
+        U256::from(200_000_000)
+    );
+    assert_eq!(
+        options.sender(MAKER).balance_of(MAKER, TOKEN_ID),
+        U256::from(11) * one()
+    );
+}
+
+#[motsu::test]
+fn only_maker_can_cancel_once(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let order_id = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+
+    let result = clob.sender(TAKER).cancel_order(order_id);
+    assert!(matches!(result, Err(CLOBError::Unauthorized(_))));
+
+    clob.sender(MAKER).cancel_order(order_id).unwrap();
+    let result = clob.sender(MAKER).cancel_order(order_id);
+    assert!(matches!(result, Err(CLOBError::OrderNotFound(_))));
+}
+
+#[motsu::test]
+fn filled_order_cannot_be_cancelled(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let order_id = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), BUY)
+        .unwrap();
+
+    let result = clob.sender(MAKER).cancel_order(order_id);
+
+    assert!(matches!(result, Err(CLOBError::OrderNotFound(_))));
+}
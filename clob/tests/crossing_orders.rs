@@ -0,0 +1,212 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::{CLOBError, OrderPlaced, Trade, CLOB};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const MAKER: Address = Address::new([0xAA; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+const QUOTE_BALANCE: u64 = 100_000_000;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+/// Initializes the book and funds both accounts with 10 options and 100 USDC.
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(MAKER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    for account in [MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(10) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote
+            .sender(account)
+            .mint(account, U256::from(QUOTE_BALANCE));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(QUOTE_BALANCE));
+    }
+}
+
+#[motsu::test]
+fn crossing_buy_fills_completely_at_maker_prices(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let cheap = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+    let dear = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(3) * one(), one(), SELL, false)
+        .unwrap();
+
+    let order_id = clob
+        .sender(TAKER)
+        .place_order(
+            TOKEN_ID,
+            U256::from(3) * one(),
+            U256::from(2) * one(),
+            BUY,
+            false,
+        )
+        .unwrap();
+
+    // Fully filled: nothing rests, and the taker paid 2 + 3 USDC
+    assert_eq!(order_id, U256::ZERO);
+    assert!(clob.sender(TAKER).book_is_empty(TOKEN_ID));
+    assert_eq!(
+        options.sender(TAKER).balance_of(TAKER, TOKEN_ID),
+        U256::from(12) * one()
+    );
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(QUOTE_BALANCE - 5_000_000)
+    );
+    assert_eq!(
+        quote.sender(MAKER).balance_of(MAKER),
+        U256::from(QUOTE_BALANCE + 5_000_000)
+    );
+    for (order_id, price) in [(cheap, 2), (dear, 3)] {
+        clob.assert_emitted(&Trade {
+            makerOrderId: order_id,
+            maker: MAKER,
+            taker: TAKER,
+            tokenId: TOKEN_ID,
+            takerSide: BUY,
+            price: U256::from(price) * one(),
+            quantity: one(),
+            premium: U256::from(price * 1_000_000),
+            fee: U256::ZERO,
+        });
+    }
+}
+
+#[motsu::test]
+fn crossing_buy_rests_unfilled_remainder(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    clob.sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+    clob.sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(3) * one(), one(), SELL, false)
+        .unwrap();
+
+    // Takes the ask at 2 USDC, stops before the one at 3 and rests 2 options at 2.5
+    let price = U256::from(25) * one() / U256::from(10);
+    let order_id = clob
+        .sender(TAKER)
+        .place_order(TOKEN_ID, price, U256::from(3) * one(), BUY, false)
+        .unwrap();
+
+    assert_eq!(order_id, U256::from(3));
+    assert_eq!(clob.sender(TAKER).best_bid(TOKEN_ID), price);
+    assert_eq!(clob.sender(TAKER).best_ask(TOKEN_ID), U256::from(3) * one());
+    assert_eq!(
+        clob.sender(TAKER).get_levels(TOKEN_ID, BUY, 10).unwrap(),
+        vec![(price, U256::from(2) * one())]
+    );
+    assert_eq!(
+        options.sender(TAKER).balance_of(TAKER, TOKEN_ID),
+        U256::from(11) * one()
+    );
+    // 2 USDC paid for the fill and 5 USDC locked for the resting remainder
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(QUOTE_BALANCE - 7_000_000)
+    );
+    clob.assert_emitted(&OrderPlaced {
+        orderId: order_id,
+        maker: TAKER,
+        tokenId: TOKEN_ID,
+        side: BUY,
+        price,
+        quantity: U256::from(2) * one(),
+    });
+}
+
+#[motsu::test]
+fn crossing_sell_fills_at_bid_price(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    clob.sender(MAKER)
+        .place_order(
+            TOKEN_ID,
+            U256::from(3) * one(),
+            U256::from(2) * one(),
+            BUY,
+            false,
+        )
+        .unwrap();
+
+    let order_id = clob
+        .sender(TAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+
+    // The sell limit of 2 USDC is filled at the better bid of 3 USDC
+    assert_eq!(order_id, U256::ZERO);
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(QUOTE_BALANCE + 3_000_000)
+    );
+    assert_eq!(
+        options.sender(MAKER).balance_of(MAKER, TOKEN_ID),
+        U256::from(11) * one()
+    );
+    assert_eq!(
+        clob.sender(TAKER).get_levels(TOKEN_ID, BUY, 10).unwrap(),
+        vec![(U256::from(3) * one(), one())]
+    );
+}
+
+#[motsu::test]
+fn post_only_order_that_would_cross_is_rejected(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    clob.sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+
+    let result = clob
+        .sender(TAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), BUY, true);
+
+    assert!(matches!(result, Err(CLOBError::WouldCross(_))));
+    assert_eq!(clob.sender(TAKER).best_bid(TOKEN_ID), U256::ZERO);
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(QUOTE_BALANCE)
+    );
+
+    // A post-only order below the ask rests as usual
+    let price = U256::from(15) * one() / U256::from(10);
+    clob.sender(TAKER)
+        .place_order(TOKEN_ID, price, one(), BUY, true)
+        .unwrap();
+    assert_eq!(clob.sender(TAKER).best_bid(TOKEN_ID), price);
+}
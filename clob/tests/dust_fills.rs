@@ -0,0 +1,161 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::{CLOBError, OrderCancelled, CLOB};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const OWNER: Address = Address::new([0x0A; 20]);
+const MAKER: Address = Address::new([0xAA; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+/// Sets a minimum remainder of 0.01 options and funds both accounts.
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(OWNER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    clob.sender(OWNER)
+        .set_min_order_quantity(one() / U256::from(100))
+        .unwrap();
+    for account in [MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(10) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote.sender(account).mint(account, U256::from(100_000_000));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(100_000_000));
+    }
+}
+
+#[motsu::test]
+fn near_complete_fill_closes_sell_order(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let order_id = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+
+    // Buying 0.995 would leave 0.005 options resting
+    let quantity = one() * U256::from(995) / U256::from(1_000);
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, quantity, BUY)
+        .unwrap();
+
+    let dust = one() - quantity;
+    assert_eq!(clob.sender(TAKER).best_ask(TOKEN_ID), U256::ZERO);
+    assert!(clob.sender(TAKER).book_is_empty(TOKEN_ID));
+    assert_eq!(
+        options.sender(TAKER).balance_of(TAKER, TOKEN_ID),
+        U256::from(10) * one() + quantity
+    );
+    assert_eq!(
+        options.sender(MAKER).balance_of(MAKER, TOKEN_ID),
+        U256::from(9) * one() + dust
+    );
+    assert_eq!(
+        options.sender(MAKER).balance_of(clob.address(), TOKEN_ID),
+        U256::ZERO
+    );
+    assert_eq!(
+        quote.sender(MAKER).balance_of(MAKER),
+        U256::from(101_990_000)
+    );
+    clob.assert_emitted(&OrderCancelled {
+        orderId: order_id,
+        maker: MAKER,
+        tokenId: TOKEN_ID,
+        remaining: dust,
+    });
+
+    let result = clob.sender(MAKER).cancel_order(order_id);
+    assert!(matches!(result, Err(CLOBError::OrderNotFound(_))));
+}
+
+#[motsu::test]
+fn near_complete_fill_closes_buy_order_and_refunds_premium(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    clob.sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), BUY, false)
+        .unwrap();
+
+    let quantity = one() * U256::from(995) / U256::from(1_000);
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, quantity, SELL)
+        .unwrap();
+
+    assert_eq!(clob.sender(TAKER).best_bid(TOKEN_ID), U256::ZERO);
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(101_990_000)
+    );
+    // The premium of the 0.005 dust goes back to the maker
+    assert_eq!(
+        quote.sender(MAKER).balance_of(MAKER),
+        U256::from(98_010_000)
+    );
+    assert_eq!(quote.sender(MAKER).balance_of(clob.address()), U256::ZERO);
+}
+
+#[motsu::test]
+fn fill_leaving_minimum_keeps_order_open(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let order_id = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+
+    let quantity = one() * U256::from(99) / U256::from(100);
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, quantity, BUY)
+        .unwrap();
+
+    assert_eq!(clob.sender(TAKER).best_ask(TOKEN_ID), U256::from(2) * one());
+    clob.sender(MAKER).cancel_order(order_id).unwrap();
+    assert_eq!(
+        options.sender(MAKER).balance_of(MAKER, TOKEN_ID),
+        U256::from(9) * one() + one() / U256::from(100)
+    );
+}
+
+#[motsu::test]
+fn only_owner_sets_min_order_quantity(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+
+    let result = clob.sender(MAKER).set_min_order_quantity(U256::ZERO);
+
+    assert!(matches!(result, Err(CLOBError::Unauthorized(_))));
+    assert_eq!(clob.sender(MAKER).owner(), OWNER);
+    assert_eq!(
+        clob.sender(MAKER).min_order_quantity(),
+        one() / U256::from(100)
+    );
+}
@@ -0,0 +1,224 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::{CLOBError, Trade, CLOB};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const MAKER: Address = Address::new([0xAA; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const FEE_RECIPIENT: Address = Address::new([0xFE; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+const QUOTE_BALANCE: u64 = 100_000_000;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+/// Initializes the book (owned by `MAKER`) and funds both accounts with 10 options and
+/// 100 USDC.
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(MAKER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    for account in [MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(10) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote
+            .sender(account)
+            .mint(account, U256::from(QUOTE_BALANCE));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(QUOTE_BALANCE));
+    }
+}
+
+#[motsu::test]
+fn zero_fees_pay_the_full_premium(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let ask = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), BUY)
+        .unwrap();
+
+    assert_eq!(
+        quote.sender(MAKER).balance_of(MAKER),
+        U256::from(QUOTE_BALANCE + 2_000_000)
+    );
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(QUOTE_BALANCE - 2_000_000)
+    );
+    assert_eq!(quote.sender(MAKER).balance_of(FEE_RECIPIENT), U256::ZERO);
+    clob.assert_emitted(&Trade {
+        makerOrderId: ask,
+        maker: MAKER,
+        taker: TAKER,
+        tokenId: TOKEN_ID,
+        takerSide: BUY,
+        price: U256::from(2) * one(),
+        quantity: one(),
+        premium: U256::from(2_000_000),
+        fee: U256::ZERO,
+    });
+}
+
+#[motsu::test]
+fn asymmetric_fees_come_out_of_a_market_buy_premium(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    clob.sender(MAKER)
+        .set_fees(U256::from(10), U256::from(30), FEE_RECIPIENT)
+        .unwrap();
+    let ask = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), BUY)
+        .unwrap();
+
+    // 40 bps of 2 USDC
+    let fee = 8_000;
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(QUOTE_BALANCE - 2_000_000)
+    );
+    assert_eq!(
+        quote.sender(MAKER).balance_of(MAKER),
+        U256::from(QUOTE_BALANCE + 2_000_000 - fee)
+    );
+    assert_eq!(
+        quote.sender(MAKER).balance_of(FEE_RECIPIENT),
+        U256::from(fee)
+    );
+    clob.assert_emitted(&Trade {
+        makerOrderId: ask,
+        maker: MAKER,
+        taker: TAKER,
+        tokenId: TOKEN_ID,
+        takerSide: BUY,
+        price: U256::from(2) * one(),
+        quantity: one(),
+        premium: U256::from(2_000_000 - fee),
+        fee: U256::from(fee),
+    });
+}
+
+#[motsu::test]
+fn fees_never_exceed_a_filled_bid_lock(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    clob.sender(MAKER)
+        .set_fees(U256::from(30), U256::from(10), FEE_RECIPIENT)
+        .unwrap();
+    clob.sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), BUY, false)
+        .unwrap();
+
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), SELL)
+        .unwrap();
+
+    // The bid locked exactly 2 USDC, split between the seller and the fee recipient
+    let fee = 8_000;
+    assert_eq!(
+        quote.sender(MAKER).balance_of(MAKER),
+        U256::from(QUOTE_BALANCE - 2_000_000)
+    );
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(QUOTE_BALANCE + 2_000_000 - fee)
+    );
+    assert_eq!(
+        quote.sender(MAKER).balance_of(FEE_RECIPIENT),
+        U256::from(fee)
+    );
+    assert_eq!(quote.sender(MAKER).balance_of(clob.address()), U256::ZERO);
+}
+
+#[motsu::test]
+fn fee_on_tiny_fill_rounds_up_by_less_than_one_unit(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    clob.sender(MAKER)
+        .set_fees(U256::from(10), U256::from(30), FEE_RECIPIENT)
+        .unwrap();
+    // 0.0000011 options at 2 USDC cost 3 units (2.2 rounded up), whose 0.012 unit fee
+    // rounds up to 1
+    let tiny = one() / U256::from(1_000_000) + one() / U256::from(10_000_000);
+    clob.sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), tiny, SELL, false)
+        .unwrap();
+
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, tiny, BUY)
+        .unwrap();
+
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(QUOTE_BALANCE - 3)
+    );
+    assert_eq!(
+        quote.sender(MAKER).balance_of(MAKER),
+        U256::from(QUOTE_BALANCE + 2)
+    );
+    assert_eq!(quote.sender(MAKER).balance_of(FEE_RECIPIENT), U256::from(1));
+}
+
+#[motsu::test]
+fn set_fees_is_owner_only_and_capped(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+
+    let result = clob
+        .sender(TAKER)
+        .set_fees(U256::from(10), U256::from(10), FEE_RECIPIENT);
+    assert!(matches!(result, Err(CLOBError::Unauthorized(_))));
+
+    let result = clob
+        .sender(MAKER)
+        .set_fees(U256::from(10), U256::from(101), FEE_RECIPIENT);
+    assert!(matches!(result, Err(CLOBError::FeeTooHigh(_))));
+
+    let result = clob
+        .sender(MAKER)
+        .set_fees(U256::from(10), U256::ZERO, Address::ZERO);
+    assert!(matches!(result, Err(CLOBError::ZeroAddress(_))));
+
+    clob.sender(MAKER)
+        .set_fees(U256::from(100), U256::from(100), FEE_RECIPIENT)
+        .unwrap();
+    assert_eq!(clob.sender(TAKER).maker_fee_bps(), U256::from(100));
+    assert_eq!(clob.sender(TAKER).taker_fee_bps(), U256::from(100));
+    assert_eq!(clob.sender(TAKER).fee_recipient(), FEE_RECIPIENT);
+}
@@ -0,0 +1,108 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::CLOB;
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const MAKER: Address = Address::new([0xAA; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(MAKER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    for account in [MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(10) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote.sender(account).mint(account, U256::from(100_000_000));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(100_000_000));
+    }
+}
+
+fn inactive() -> (Address, B256, U256, U256, u8, bool) {
+    (Address::ZERO, B256::ZERO, U256::ZERO, U256::ZERO, 0, false)
+}
+
+#[motsu::test]
+fn unknown_order_is_inactive(clob: Contract<CLOB>) {
+    assert_eq!(clob.sender(MAKER).get_order(U256::from(1)), inactive());
+}
+
+#[motsu::test]
+fn resting_order_is_read_back(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let price = U256::from(2) * one();
+    let order_id = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, price, U256::from(3) * one(), BUY, false)
+        .unwrap();
+
+    assert_eq!(
+        clob.sender(TAKER).get_order(order_id),
+        (MAKER, TOKEN_ID, price, U256::from(3) * one(), BUY, true)
+    );
+}
+
+#[motsu::test]
+fn fills_update_remaining_until_inactive(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let price = U256::from(2) * one();
+    let order_id = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, price, U256::from(3) * one(), SELL, false)
+        .unwrap();
+
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), BUY)
+        .unwrap();
+    assert_eq!(
+        clob.sender(TAKER).get_order(order_id),
+        (MAKER, TOKEN_ID, price, U256::from(2) * one(), SELL, true)
+    );
+
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, U256::from(2) * one(), BUY)
+        .unwrap();
+    assert_eq!(clob.sender(TAKER).get_order(order_id), inactive());
+}
+
+#[motsu::test]
+fn cancelled_order_is_inactive(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let order_id = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+
+    clob.sender(MAKER).cancel_order(order_id).unwrap();
+
+    assert_eq!(clob.sender(MAKER).get_order(order_id), inactive());
+}
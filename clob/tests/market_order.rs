@@ -0,0 +1,175 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::{CLOBError, Trade, CLOB};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const MAKER: Address = Address::new([0xAA; 20]);
+const SECOND_MAKER: Address = Address::new([0xAB; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(MAKER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    for account in [MAKER, SECOND_MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(10) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote.sender(account).mint(account, U256::from(100_000_000));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(100_000_000));
+    }
+}
+
+#[motsu::test]
+fn market_buy_fills_asks_in_price_time_order(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let first = clob
+        .sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+    let second = clob
+        .sender(SECOND_MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+    clob.sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(3) * one(), one(), SELL, false)
+        .unwrap();
+
+    // The first maker at 2 USDC fills fully, the second one by half
+    let quantity = one() + one() / U256::from(2);
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, quantity, BUY)
+        .unwrap();
+
+    assert_eq!(
+        options.sender(TAKER).balance_of(TAKER, TOKEN_ID),
+        U256::from(10) * one() + quantity
+    );
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(97_000_000)
+    );
+    assert_eq!(
+        quote.sender(MAKER).balance_of(MAKER),
+        U256::from(102_000_000)
+    );
+    assert_eq!(
+        quote.sender(SECOND_MAKER).balance_of(SECOND_MAKER),
+        U256::from(101_000_000)
+    );
+    assert_eq!(
+        options.sender(TAKER).balance_of(clob.address(), TOKEN_ID),
+        U256::from(3) * one() - quantity
+    );
+    clob.assert_emitted(&Trade {
+        makerOrderId: first,
+        maker: MAKER,
+        taker: TAKER,
+        tokenId: TOKEN_ID,
+        takerSide: BUY,
+        price: U256::from(2) * one(),
+        quantity: one(),
+        premium: U256::from(2_000_000),
+        fee: U256::ZERO,
+    });
+    clob.assert_emitted(&Trade {
+        makerOrderId: second,
+        maker: SECOND_MAKER,
+        taker: TAKER,
+        tokenId: TOKEN_ID,
+        takerSide: BUY,
+        price: U256::from(2) * one(),
+        quantity: one() / U256::from(2),
+        premium: U256::from(1_000_000),
+        fee: U256::ZERO,
+    });
+}
+
+#[motsu::test]
+fn market_sell_is_paid_from_locked_premium(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    clob.sender(MAKER)
+        .place_order(
+            TOKEN_ID,
+            U256::from(2) * one(),
+            U256::from(2) * one(),
+            BUY,
+            false,
+        )
+        .unwrap();
+    clob.sender(SECOND_MAKER)
+        .place_order(TOKEN_ID, one(), one(), BUY, false)
+        .unwrap();
+
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, U256::from(3) * one(), SELL)
+        .unwrap();
+
+    assert_eq!(
+        options.sender(TAKER).balance_of(TAKER, TOKEN_ID),
+        U256::from(7) * one()
+    );
+    assert_eq!(
+        options.sender(MAKER).balance_of(MAKER, TOKEN_ID),
+        U256::from(12) * one()
+    );
+    assert_eq!(
+        options
+            .sender(SECOND_MAKER)
+            .balance_of(SECOND_MAKER, TOKEN_ID),
+        U256::from(11) * one()
+    );
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(105_000_000)
+    );
+    assert_eq!(quote.sender(TAKER).balance_of(clob.address()), U256::ZERO);
+    assert!(clob.sender(TAKER).book_is_empty(TOKEN_ID));
+}
+
+#[motsu::test]
+fn market_order_beyond_liquidity_fails(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    clob.sender(MAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), SELL, false)
+        .unwrap();
+
+    let result = clob
+        .sender(TAKER)
+        .market_order(TOKEN_ID, U256::from(2) * one(), BUY);
+
+    assert!(matches!(result, Err(CLOBError::InsufficientLiquidity(_))));
+    assert_eq!(clob.sender(TAKER).best_ask(TOKEN_ID), U256::from(2) * one());
+    assert_eq!(
+        quote.sender(TAKER).balance_of(TAKER),
+        U256::from(100_000_000)
+    );
+}
@@ -0,0 +1,89 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::CLOB;
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const MAKER: Address = Address::new([0xAA; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(MAKER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    for account in [MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(10) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote.sender(account).mint(account, U256::from(100_000_000));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(100_000_000));
+    }
+}
+
+fn place(clob: &Contract<CLOB>, maker: Address, price: u64, side: u8) -> U256 {
+    clob.sender(maker)
+        .place_order(TOKEN_ID, U256::from(price) * one(), one(), side, false)
+        .unwrap()
+}
+
+#[motsu::test]
+fn maker_without_orders_has_none_open(clob: Contract<CLOB>) {
+    assert!(clob.sender(MAKER).get_open_orders(MAKER).is_empty());
+}
+
+#[motsu::test]
+fn open_orders_are_listed_per_maker(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let first = place(&clob, MAKER, 3, SELL);
+    let other = place(&clob, TAKER, 4, SELL);
+    let second = place(&clob, MAKER, 1, BUY);
+
+    assert_eq!(
+        clob.sender(MAKER).get_open_orders(MAKER),
+        vec![first, second]
+    );
+    assert_eq!(clob.sender(MAKER).get_open_orders(TAKER), vec![other]);
+}
+
+#[motsu::test]
+fn cancelled_and_filled_orders_are_not_listed(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let filled = place(&clob, MAKER, 2, SELL);
+    let cancelled = place(&clob, MAKER, 3, SELL);
+    let partially_filled = place(&clob, MAKER, 4, SELL);
+    let open = place(&clob, MAKER, 1, BUY);
+
+    clob.sender(MAKER).cancel_order(cancelled).unwrap();
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one() + one() / U256::from(2), BUY)
+        .unwrap();
+
+    assert_eq!(
+        clob.sender(MAKER).get_open_orders(MAKER),
+        vec![partially_filled, open]
+    );
+    assert!(!clob.sender(MAKER).get_open_orders(MAKER).contains(&filled));
+}
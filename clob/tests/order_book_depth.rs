@@ -0,0 +1,164 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::CLOB;
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const MAKER: Address = Address::new([0xAA; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn quote_price(amount: u64) -> U256 {
+    U256::from(amount) * one()
+}
+
+/// Initializes the book and funds both accounts with option tokens and quote tokens.
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(MAKER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    for account in [MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(100) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote
+            .sender(account)
+            .mint(account, U256::from(1_000_000_000));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(1_000_000_000));
+    }
+}
+
+fn place(clob: &Contract<CLOB>, price: u64, quantity: u64, side: u8) -> U256 {
+    clob.sender(MAKER)
+        .place_order(
+            TOKEN_ID,
+            quote_price(price),
+            U256::from(quantity) * one(),
+            side,
+            false,
+        )
+        .unwrap()
+}
+
+fn levels(clob: &Contract<CLOB>, side: u8, max_levels: u32) -> Vec<(U256, U256)> {
+    clob.sender(MAKER)
+        .get_levels(TOKEN_ID, side, max_levels)
+        .unwrap()
+}
+
+fn level(price: u64, quantity: u64) -> (U256, U256) {
+    (quote_price(price), U256::from(quantity) * one())
+}
+
+#[motsu::test]
+fn empty_book_has_no_levels(clob: Contract<CLOB>) {
+    assert!(levels(&clob, BUY, 10).is_empty());
+    assert!(levels(&clob, SELL, 10).is_empty());
+}
+
+#[motsu::test]
+fn orders_at_the_same_price_are_aggregated(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+
+    place(&clob, 10, 1, SELL);
+    place(&clob, 10, 2, SELL);
+    place(&clob, 10, 4, SELL);
+    place(&clob, 5, 3, BUY);
+    place(&clob, 5, 5, BUY);
+
+    assert_eq!(levels(&clob, SELL, 10), vec![level(10, 7)]);
+    assert_eq!(levels(&clob, BUY, 10), vec![level(5, 8)]);
+}
+
+#[motsu::test]
+fn asks_are_listed_lowest_first(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+
+    place(&clob, 12, 1, SELL);
+    place(&clob, 10, 2, SELL);
+    place(&clob, 11, 3, SELL);
+    place(&clob, 10, 1, SELL);
+
+    assert_eq!(
+        levels(&clob, SELL, 10),
+        vec![level(10, 3), level(11, 3), level(12, 1)]
+    );
+}
+
+#[motsu::test]
+fn bids_are_listed_highest_first(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+
+    place(&clob, 4, 1, BUY);
+    place(&clob, 6, 2, BUY);
+    place(&clob, 5, 3, BUY);
+    place(&clob, 6, 1, BUY);
+
+    assert_eq!(
+        levels(&clob, BUY, 10),
+        vec![level(6, 3), level(5, 3), level(4, 1)]
+    );
+}
+
+#[motsu::test]
+fn depth_is_truncated_to_max_levels(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+
+    place(&clob, 10, 1, SELL);
+    place(&clob, 11, 1, SELL);
+    place(&clob, 12, 1, SELL);
+
+    assert_eq!(levels(&clob, SELL, 2), vec![level(10, 1), level(11, 1)]);
+    assert!(levels(&clob, SELL, 0).is_empty());
+}
+
+#[motsu::test]
+fn cancels_and_fills_reduce_level_totals(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+
+    let cancelled = place(&clob, 10, 2, SELL);
+    place(&clob, 10, 3, SELL);
+    place(&clob, 11, 4, SELL);
+
+    clob.sender(MAKER).cancel_order(cancelled).unwrap();
+    assert_eq!(levels(&clob, SELL, 10), vec![level(10, 3), level(11, 4)]);
+
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, U256::from(4) * one(), BUY)
+        .unwrap();
+    assert_eq!(levels(&clob, SELL, 10), vec![level(11, 3)]);
+}
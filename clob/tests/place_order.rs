@@ -0,0 +1,194 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::{CLOBError, OrderPlaced, CLOB};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const ALICE: Address = Address::new([0xAA; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(ALICE)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+}
+
+#[motsu::test]
+fn sell_order_locks_option_tokens(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    options
+        .sender(ALICE)
+        .mint(ALICE, TOKEN_ID, U256::from(10) * one());
+    options
+        .sender(ALICE)
+        .set_approval_for_all(clob.address(), true);
+
+    let quantity = U256::from(4) * one();
+    let order_id = clob
+        .sender(ALICE)
+        .place_order(TOKEN_ID, U256::from(2) * one(), quantity, SELL, false)
+        .unwrap();
+
+    assert_eq!(order_id, U256::from(1));
+    assert_eq!(
+        options.sender(ALICE).balance_of(ALICE, TOKEN_ID),
+        U256::from(6) * one()
+    );
+    assert_eq!(
+        options.sender(ALICE).balance_of(clob.address(), TOKEN_ID),
+        quantity
+    );
+    assert!(!clob.sender(ALICE).book_is_empty(TOKEN_ID));
+    clob.assert_emitted(&OrderPlaced {
+        orderId: order_id,
+        maker: ALICE,
+        tokenId: TOKEN_ID,
+        side: SELL,
+        price: U256::from(2) * one(),
+        quantity,
+    });
+}
+
+#[motsu::test]
+fn buy_order_locks_premium(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let premium = U256::from(7_500_000);
+    quote.sender(ALICE).mint(ALICE, premium);
+    quote.sender(ALICE).approve(clob.address(), premium);
+
+    // 3 options at 2.5 USDC
+    let price = U256::from(25) * one() / U256::from(10);
+    let order_id = clob
+        .sender(ALICE)
+        .place_order(TOKEN_ID, price, U256::from(3) * one(), BUY, false)
+        .unwrap();
+
+    assert_eq!(order_id, U256::from(1));
+    assert_eq!(quote.sender(ALICE).balance_of(ALICE), U256::ZERO);
+    assert_eq!(quote.sender(ALICE).balance_of(clob.address()), premium);
+    assert!(!clob.sender(ALICE).book_is_empty(TOKEN_ID));
+}
+
+#[motsu::test]
+fn order_ids_increase_across_sides(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    options.sender(ALICE).mint(ALICE, TOKEN_ID, U256::from(2));
+    options
+        .sender(ALICE)
+        .set_approval_for_all(clob.address(), true);
+    quote.sender(ALICE).mint(ALICE, U256::from(2));
+    quote.sender(ALICE).approve(clob.address(), U256::from(2));
+
+    let ids = [
+        clob.sender(ALICE)
+            .place_order(TOKEN_ID, U256::from(2) * one(), U256::from(1), SELL, false)
+            .unwrap(),
+        clob.sender(ALICE)
+            .place_order(TOKEN_ID, one(), U256::from(1), BUY, false)
+            .unwrap(),
+        clob.sender(ALICE)
+            .place_order(TOKEN_ID, U256::from(2) * one(), U256::from(1), SELL, false)
+            .unwrap(),
+    ];
+
+    assert_eq!(ids, [U256::from(1), U256::from(2), U256::from(3)]);
+    assert!(!clob.sender(ALICE).is_book_crossed(TOKEN_ID));
+}
+
+#[motsu::test]
+fn sell_order_without_approval_fails(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    options.sender(ALICE).mint(ALICE, TOKEN_ID, one());
+
+    let result = clob
+        .sender(ALICE)
+        .place_order(TOKEN_ID, one(), one(), SELL, false);
+
+    assert!(matches!(result, Err(CLOBError::TransferFailed(_))));
+    assert_eq!(options.sender(ALICE).balance_of(ALICE, TOKEN_ID), one());
+}
+
+#[motsu::test]
+fn sell_order_above_balance_fails(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    options.sender(ALICE).mint(ALICE, TOKEN_ID, one());
+    options
+        .sender(ALICE)
+        .set_approval_for_all(clob.address(), true);
+
+    let result =
+        clob.sender(ALICE)
+            .place_order(TOKEN_ID, one(), one() + U256::from(1), SELL, false);
+
+    assert!(matches!(result, Err(CLOBError::TransferFailed(_))));
+    assert_eq!(options.sender(ALICE).balance_of(ALICE, TOKEN_ID), one());
+}
+
+#[motsu::test]
+fn buy_order_above_allowance_fails(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    quote.sender(ALICE).mint(ALICE, U256::from(2_000_000));
+    quote
+        .sender(ALICE)
+        .approve(clob.address(), U256::from(1_999_999));
+
+    let result = clob
+        .sender(ALICE)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), BUY, false);
+
+    assert!(matches!(result, Err(CLOBError::TransferFailed(_))));
+    assert_eq!(quote.sender(ALICE).balance_of(ALICE), U256::from(2_000_000));
+}
+
+#[motsu::test]
+fn buy_order_above_balance_fails(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    quote.sender(ALICE).mint(ALICE, U256::from(1_999_999));
+    quote
+        .sender(ALICE)
+        .approve(clob.address(), U256::from(2_000_000));
+
+    let result = clob
+        .sender(ALICE)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), BUY, false);
+
+    assert!(matches!(result, Err(CLOBError::TransferFailed(_))));
+    assert_eq!(quote.sender(ALICE).balance_of(ALICE), U256::from(1_999_999));
+}
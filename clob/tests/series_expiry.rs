@@ -0,0 +1,251 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::{CLOBError, OrderCancelled, CLOB};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const MAKER: Address = Address::new([0xAA; 20]);
+const OTHER_MAKER: Address = Address::new([0xCC; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+const QUOTE_BALANCE: u64 = 100_000_000;
+const PAST_EXPIRY: u64 = 1_000_000_000;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+/// Initializes the book and funds every account with 10 options and 100 USDC.
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(MAKER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    for account in [MAKER, OTHER_MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(10) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote
+            .sender(account)
+            .mint(account, U256::from(QUOTE_BALANCE));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(QUOTE_BALANCE));
+    }
+}
+
+fn place(clob: &Contract<CLOB>, maker: Address, price: u64, side: u8) -> U256 {
+    clob.sender(maker)
+        .place_order(TOKEN_ID, U256::from(price) * one(), one(), side, false)
+        .unwrap()
+}
+
+#[motsu::test]
+fn trading_before_expiry_succeeds(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    options.sender(MAKER).set_expiry_in(TOKEN_ID, 60);
+
+    place(&clob, MAKER, 2, SELL);
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), BUY)
+        .unwrap();
+
+    assert_eq!(
+        options.sender(TAKER).balance_of(TAKER, TOKEN_ID),
+        U256::from(11) * one()
+    );
+}
+
+#[motsu::test]
+fn placing_at_expiry_fails(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    options.sender(MAKER).set_expiry_in(TOKEN_ID, 0);
+
+    for side in [BUY, SELL] {
+        let result =
+            clob.sender(MAKER)
+                .place_order(TOKEN_ID, U256::from(2) * one(), one(), side, false);
+
+        assert!(matches!(result, Err(CLOBError::SeriesExpired(_))));
+    }
+    assert!(clob.sender(MAKER).book_is_empty(TOKEN_ID));
+}
+
+#[motsu::test]
+fn market_order_after_expiry_fails(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    place(&clob, MAKER, 2, SELL);
+    options
+        .sender(MAKER)
+        .set_expiry(TOKEN_ID, U256::from(PAST_EXPIRY));
+
+    let result = clob.sender(TAKER).market_order(TOKEN_ID, one(), BUY);
+
+    assert!(matches!(
+        result,
+        Err(CLOBError::SeriesExpired(err))
+            if err.token_id == TOKEN_ID && err.expiry == U256::from(PAST_EXPIRY)
+    ));
+    assert_eq!(
+        options.sender(TAKER).balance_of(TAKER, TOKEN_ID),
+        U256::from(10) * one()
+    );
+}
+
+#[motsu::test]
+fn crossing_order_after_expiry_does_not_match(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let ask = place(&clob, MAKER, 2, SELL);
+    options
+        .sender(MAKER)
+        .set_expiry(TOKEN_ID, U256::from(PAST_EXPIRY));
+
+    let result = clob
+        .sender(TAKER)
+        .place_order(TOKEN_ID, U256::from(3) * one(), one(), BUY, false);
+
+    assert!(matches!(result, Err(CLOBError::SeriesExpired(_))));
+    assert!(clob.sender(TAKER).get_order(ask).5);
+}
+
+#[motsu::test]
+fn prune_expired_refunds_every_resting_order(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let asks = [
+        place(&clob, MAKER, 3, SELL),
+        place(&clob, OTHER_MAKER, 3, SELL),
+        place(&clob, MAKER, 4, SELL),
+    ];
+    let bids = [
+        place(&clob, MAKER, 1, BUY),
+        place(&clob, OTHER_MAKER, 2, BUY),
+    ];
+    options
+        .sender(MAKER)
+        .set_expiry(TOKEN_ID, U256::from(PAST_EXPIRY));
+
+    // Anyone can prune
+    let pruned = clob.sender(TAKER).prune_expired(TOKEN_ID, 100).unwrap();
+
+    assert_eq!(pruned, U256::from(5));
+    assert!(clob.sender(TAKER).book_is_empty(TOKEN_ID));
+    for maker in [MAKER, OTHER_MAKER] {
+        assert!(clob.sender(TAKER).get_open_orders(maker).is_empty());
+        assert_eq!(
+            options.sender(maker).balance_of(maker, TOKEN_ID),
+            U256::from(10) * one()
+        );
+        assert_eq!(
+            quote.sender(maker).balance_of(maker),
+            U256::from(QUOTE_BALANCE)
+        );
+    }
+    assert_eq!(
+        options.sender(MAKER).balance_of(clob.address(), TOKEN_ID),
+        U256::ZERO
+    );
+    assert_eq!(quote.sender(MAKER).balance_of(clob.address()), U256::ZERO);
+    for order_id in asks.into_iter().chain(bids) {
+        let maker = if order_id == asks[1] || order_id == bids[1] {
+            OTHER_MAKER
+        } else {
+            MAKER
+        };
+        clob.assert_emitted(&OrderCancelled {
+            orderId: order_id,
+            maker,
+            tokenId: TOKEN_ID,
+            remaining: one(),
+        });
+    }
+}
+
+#[motsu::test]
+fn prune_expired_stops_at_max_orders(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let bid = place(&clob, MAKER, 1, BUY);
+    let ask = place(&clob, OTHER_MAKER, 3, SELL);
+    options
+        .sender(MAKER)
+        .set_expiry(TOKEN_ID, U256::from(PAST_EXPIRY));
+
+    let first = clob.sender(TAKER).prune_expired(TOKEN_ID, 1).unwrap();
+
+    assert_eq!(first, U256::from(1));
+    assert!(!clob.sender(TAKER).get_order(bid).5);
+    assert!(clob.sender(TAKER).get_order(ask).5);
+
+    let second = clob.sender(TAKER).prune_expired(TOKEN_ID, 1).unwrap();
+
+    assert_eq!(second, U256::from(1));
+    assert!(!clob.sender(TAKER).get_order(ask).5);
+    assert!(clob.sender(TAKER).book_is_empty(TOKEN_ID));
+    assert_eq!(
+        clob.sender(TAKER).prune_expired(TOKEN_ID, 1).unwrap(),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn prune_expired_before_expiry_fails(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let ask = place(&clob, MAKER, 2, SELL);
+    options.sender(MAKER).set_expiry_in(TOKEN_ID, 60);
+
+    let result = clob.sender(TAKER).prune_expired(TOKEN_ID, 100);
+
+    assert!(matches!(result, Err(CLOBError::SeriesNotExpired(_))));
+    assert!(clob.sender(TAKER).get_order(ask).5);
+}
+
+#[motsu::test]
+fn prune_expired_on_empty_book_prunes_nothing(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    options
+        .sender(MAKER)
+        .set_expiry(TOKEN_ID, U256::from(PAST_EXPIRY));
+
+    assert_eq!(
+        clob.sender(TAKER).prune_expired(TOKEN_ID, 100).unwrap(),
+        U256::ZERO
+    );
+}
@@ -0,0 +1,188 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::{CLOBError, CLOB, MAX_FEE_BPS, MAX_FEE_TIERS};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const MAKER: Address = Address::new([0xAA; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const FEE_RECIPIENT: Address = Address::new([0xFE; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+const QUOTE_BALANCE: u64 = 100_000_000;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+/// Initializes the book (owned by `MAKER`) with a 0.5% taker fee and a 0.1% tier from
+/// 2 USDC of taker volume, funds both accounts and rests an ask for 5 options at 2 USDC.
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(MAKER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    clob.sender(MAKER)
+        .set_fees(U256::ZERO, U256::from(50), FEE_RECIPIENT)
+        .unwrap();
+    clob.sender(MAKER)
+        .set_taker_fee_tiers(vec![U256::from(2_000_000)], vec![U256::from(10)])
+        .unwrap();
+    for account in [MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(10) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote
+            .sender(account)
+            .mint(account, U256::from(QUOTE_BALANCE));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(QUOTE_BALANCE));
+    }
+    clob.sender(MAKER)
+        .place_order(
+            TOKEN_ID,
+            U256::from(2) * one(),
+            U256::from(5) * one(),
+            SELL,
+            false,
+        )
+        .unwrap();
+}
+
+#[motsu::test]
+fn taker_crossing_threshold_pays_discounted_fee(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    assert_eq!(
+        clob.sender(TAKER).taker_fee_tier_of(TAKER),
+        (U256::ZERO, U256::from(50))
+    );
+
+    // The fill that reaches the threshold is charged 50 bps of 2 USDC
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), BUY)
+        .unwrap();
+    assert_eq!(
+        quote.sender(TAKER).balance_of(FEE_RECIPIENT),
+        U256::from(10_000)
+    );
+    assert_eq!(
+        clob.sender(TAKER).taker_volume_of(TAKER),
+        U256::from(2_000_000)
+    );
+    assert_eq!(
+        clob.sender(TAKER).taker_fee_tier_of(TAKER),
+        (U256::from(1), U256::from(10))
+    );
+
+    // Later fills are charged 10 bps
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), BUY)
+        .unwrap();
+    assert_eq!(
+        quote.sender(TAKER).balance_of(FEE_RECIPIENT),
+        U256::from(12_000)
+    );
+}
+
+#[motsu::test]
+fn crossing_limit_order_counts_as_taker_volume(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+
+    clob.sender(TAKER)
+        .place_order(TOKEN_ID, U256::from(2) * one(), one(), BUY, false)
+        .unwrap();
+
+    assert_eq!(
+        clob.sender(TAKER).taker_volume_of(TAKER),
+        U256::from(2_000_000)
+    );
+    // Resting makers are not takers
+    assert_eq!(clob.sender(TAKER).taker_volume_of(MAKER), U256::ZERO);
+}
+
+#[motsu::test]
+fn tier_fee_never_exceeds_taker_fee(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    clob.sender(MAKER)
+        .set_taker_fee_tiers(vec![U256::ZERO], vec![U256::from(MAX_FEE_BPS)])
+        .unwrap();
+
+    assert_eq!(
+        clob.sender(TAKER).taker_fee_tier_of(TAKER),
+        (U256::from(1), U256::from(50))
+    );
+}
+
+#[motsu::test]
+fn set_taker_fee_tiers_replaces_and_validates(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+
+    let err = clob
+        .sender(TAKER)
+        .set_taker_fee_tiers(vec![], vec![])
+        .unwrap_err();
+    assert!(matches!(err, CLOBError::Unauthorized(_)));
+
+    let err = clob
+        .sender(MAKER)
+        .set_taker_fee_tiers(vec![U256::from(1)], vec![])
+        .unwrap_err();
+    assert!(matches!(err, CLOBError::InvalidFeeTiers(_)));
+
+    let err = clob
+        .sender(MAKER)
+        .set_taker_fee_tiers(
+            vec![U256::from(2), U256::from(1)],
+            vec![U256::from(10), U256::from(5)],
+        )
+        .unwrap_err();
+    assert!(matches!(err, CLOBError::InvalidFeeTiers(_)));
+
+    let count = MAX_FEE_TIERS + 1;
+    let err = clob
+        .sender(MAKER)
+        .set_taker_fee_tiers(
+            (0..count).map(U256::from).collect(),
+            vec![U256::ZERO; count],
+        )
+        .unwrap_err();
+    assert!(matches!(err, CLOBError::InvalidFeeTiers(_)));
+
+    let err = clob
+        .sender(MAKER)
+        .set_taker_fee_tiers(vec![U256::from(1)], vec![U256::from(MAX_FEE_BPS + 1)])
+        .unwrap_err();
+    assert!(matches!(err, CLOBError::FeeTooHigh(_)));
+
+    clob.sender(MAKER)
+        .set_taker_fee_tiers(vec![], vec![])
+        .unwrap();
+    assert_eq!(clob.sender(MAKER).taker_fee_tiers(), (vec![], vec![]));
+    assert_eq!(
+        clob.sender(TAKER).taker_fee_tier_of(TAKER),
+        (U256::ZERO, U256::from(50))
+    );
+}
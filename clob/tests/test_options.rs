@@ -0,0 +1,161 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, U256};
+#[allow(deprecated)]
+use stylus_sdk::call::Call;
+use stylus_sdk::{abi::Bytes, prelude::*};
+
+sol_interface! {
+    interface IERC1155Receiver {
+        function onERC1155Received(address operator, address from, uint256 id, uint256 value, bytes data) external returns (bytes4);
+    }
+}
+
+/// `onERC1155Received` return value that accepts a transfer.
+const ERC1155_RECEIVED: [u8; 4] = [0xf2, 0x3a, 0x6e, 0x61];
+
+sol_storage! {
+    /// Minimal stand-in for the Options contract's ERC-1155 transfers and series expiry.
+    #[entrypoint]
+    pub struct TestOptions {
+        mapping(address => mapping(bytes32 => uint256)) balances;
+        mapping(address => mapping(address => bool)) operator_approvals;
+        mapping(bytes32 => uint256) expiries;
+    }
+}
+
+/// Expiry of series whose expiry was never set, far in the future.
+const DEFAULT_EXPIRY: u64 = 2_000_000_000;
+
+/// `OptionMetadataView` fields of the Options contract.
+type OptionMetadataView = (
+    Address,
+    Address,
+    u8,
+    u8,
+    U256,
+    U256,
+    u8,
+    Address,
+    u8,
+    U256,
+    U256,
+    Address,
+    u8,
+);
+
+#[public]
+impl TestOptions {
+    #[must_use]
+    pub fn balance_of(&self, owner: Address, id: B256) -> U256 {
+        self.balances.getter(owner).get(id)
+    }
+
+    pub fn mint(&mut self, to: Address, id: B256, amount: U256) {
+        let current_balance = self.balances.getter(to).get(id);
+        self.balances
+            .setter(to)
+            .insert(id, current_balance + amount);
+    }
+
+    pub fn set_expiry(&mut self, id: B256, expiry: U256) {
+        self.expiries.insert(id, expiry);
+    }
+
+    /// Sets the expiry `seconds` after the current block timestamp.
+    pub fn set_expiry_in(&mut self, id: B256, seconds: u64) {
+        let now = self.vm().block_timestamp();
+        self.expiries.insert(id, U256::from(now + seconds));
+    }
+
+    #[must_use]
+    pub fn option_metadata_of(&self, id: B256) -> OptionMetadataView {
+        let mut expiry = self.expiries.get(id);
+        if expiry.is_zero() {
+            expiry = U256::from(DEFAULT_EXPIRY);
+        }
+
+        (
+            Address::ZERO,
+            Address::ZERO,
+            0,
+            0,
+            U256::ZERO,
+            expiry,
+            0,
+            Address::ZERO,
+            0,
+            U256::ZERO,
+            U256::ZERO,
+            Address::ZERO,
+            0,
+        )
+    }
+
+    pub fn set_approval_for_all(&mut self, operator: Address, approved: bool) {
+        let owner = self.vm().msg_sender();
+        self.operator_approvals
+            .setter(owner)
+            .insert(operator, approved);
+    }
+
+    pub fn safe_transfer_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: B256,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        let operator = self.vm().msg_sender();
+        if operator != from && !self.operator_approvals.getter(from).get(operator) {
+            return Err(b"not approved".to_vec());
+        }
+
+        let from_balance = self.balances.getter(from).get(id);
+        if from_balance < amount {
+            return Err(b"insufficient balance".to_vec());
+        }
+
+        self.balances.setter(from).insert(id, from_balance - amount);
+        let to_balance = self.balances.getter(to).get(id);
+        self.balances.setter(to).insert(id, to_balance + amount);
+
+        self.check_received(operator, from, to, id, amount, data)
+    }
+}
+
+impl TestOptions {
+    /// Calls `onERC1155Received` on contract recipients like the Options contract.
+    #[allow(deprecated)]
+    fn check_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        to: Address,
+        id: B256,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        if self.vm().code_size(to) == 0 {
+            return Ok(());
+        }
+
+        let response = IERC1155Receiver::new(to)
+            .on_erc_1155_received(
+                Call::new_in(self),
+                operator,
+                from,
+                U256::from_be_bytes(id.0),
+                amount,
+                data,
+            )
+            .map_err(|_| b"receiver reverted".to_vec())?;
+        if response.0 != ERC1155_RECEIVED {
+            return Err(b"receiver rejected".to_vec());
+        }
+
+        Ok(())
+    }
+}
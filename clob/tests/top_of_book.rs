@@ -0,0 +1,159 @@
+mod test_erc20;
+mod test_options;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::CLOB;
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use test_options::TestOptions;
+
+const MAKER: Address = Address::new([0xAA; 20]);
+const TAKER: Address = Address::new([0xBB; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const BUY: u8 = 0;
+const SELL: u8 = 1;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn quote_price(amount: u64) -> U256 {
+    U256::from(amount) * one()
+}
+
+/// Initializes the book and funds both accounts with option tokens and quote tokens.
+fn setup(clob: &Contract<CLOB>, options: &Contract<TestOptions>, quote: &Contract<TestERC20>) {
+    clob.sender(MAKER)
+        .initialize(options.address(), quote.address(), 6)
+        .unwrap();
+    for account in [MAKER, TAKER] {
+        options
+            .sender(account)
+            .mint(account, TOKEN_ID, U256::from(100) * one());
+        options
+            .sender(account)
+            .set_approval_for_all(clob.address(), true);
+        quote
+            .sender(account)
+            .mint(account, U256::from(1_000_000_000));
+        quote
+            .sender(account)
+            .approve(clob.address(), U256::from(1_000_000_000));
+    }
+}
+
+fn place(clob: &Contract<CLOB>, price: u64, quantity: u64, side: u8) -> U256 {
+    clob.sender(MAKER)
+        .place_order(
+            TOKEN_ID,
+            quote_price(price),
+            U256::from(quantity) * one(),
+            side,
+            false,
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn empty_book_has_no_top(clob: Contract<CLOB>) {
+    assert_eq!(clob.sender(MAKER).best_bid(TOKEN_ID), U256::ZERO);
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), U256::ZERO);
+}
+
+#[motsu::test]
+fn placing_orders_moves_top_only_when_improved(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+
+    place(&clob, 10, 1, SELL);
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), quote_price(10));
+    place(&clob, 12, 1, SELL);
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), quote_price(10));
+    place(&clob, 9, 1, SELL);
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), quote_price(9));
+
+    place(&clob, 5, 1, BUY);
+    assert_eq!(clob.sender(MAKER).best_bid(TOKEN_ID), quote_price(5));
+    place(&clob, 4, 1, BUY);
+    assert_eq!(clob.sender(MAKER).best_bid(TOKEN_ID), quote_price(5));
+    place(&clob, 7, 1, BUY);
+    assert_eq!(clob.sender(MAKER).best_bid(TOKEN_ID), quote_price(7));
+
+    // Other series are unaffected
+    assert_eq!(
+        clob.sender(MAKER).best_ask(B256::repeat_byte(0x01)),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn fills_move_top_once_level_is_exhausted(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    place(&clob, 10, 2, SELL);
+    place(&clob, 11, 2, SELL);
+    place(&clob, 5, 2, BUY);
+    place(&clob, 4, 2, BUY);
+
+    // Partial fill of the best level leaves it in place
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), BUY)
+        .unwrap();
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), quote_price(10));
+
+    // Emptying it moves to the next worse level
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, U256::from(2) * one(), BUY)
+        .unwrap();
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), quote_price(11));
+
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, one(), SELL)
+        .unwrap();
+    assert_eq!(clob.sender(MAKER).best_bid(TOKEN_ID), quote_price(5));
+    clob.sender(TAKER)
+        .market_order(TOKEN_ID, U256::from(3) * one(), SELL)
+        .unwrap();
+    assert_eq!(clob.sender(MAKER).best_bid(TOKEN_ID), U256::ZERO);
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), quote_price(11));
+}
+
+#[motsu::test]
+fn cancels_move_top_once_level_is_empty(
+    clob: Contract<CLOB>,
+    options: Contract<TestOptions>,
+    quote: Contract<TestERC20>,
+) {
+    setup(&clob, &options, &quote);
+    let best_first = place(&clob, 10, 1, SELL);
+    let best_second = place(&clob, 10, 1, SELL);
+    let middle = place(&clob, 11, 1, SELL);
+    let worst = place(&clob, 12, 1, SELL);
+
+    // Cancelling one of two orders at the best price keeps the level
+    clob.sender(MAKER).cancel_order(best_first).unwrap();
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), quote_price(10));
+
+    // Cancelling a level behind the top leaves the top untouched
+    clob.sender(MAKER).cancel_order(middle).unwrap();
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), quote_price(10));
+
+    // The next worse level after 10 is now 12
+    clob.sender(MAKER).cancel_order(best_second).unwrap();
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), quote_price(12));
+
+    clob.sender(MAKER).cancel_order(worst).unwrap();
+    assert_eq!(clob.sender(MAKER).best_ask(TOKEN_ID), U256::ZERO);
+    assert!(clob.sender(MAKER).book_is_empty(TOKEN_ID));
+
+    let bid = place(&clob, 5, 1, BUY);
+    assert_eq!(clob.sender(MAKER).best_bid(TOKEN_ID), quote_price(5));
+    clob.sender(MAKER).cancel_order(bid).unwrap();
+    assert_eq!(clob.sender(MAKER).best_bid(TOKEN_ID), U256::ZERO);
+}
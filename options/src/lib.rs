@@ -2,20 +2,110 @@
 #![cfg_attr(not(any(test, feature = "export-abi")), no_std)]
 extern crate alloc;
 
-use alloc::{vec, vec::Vec};
-use alloy_primitives::{keccak256, Address, B256, U256, U8};
+use alloc::{string::String, vec, vec::Vec};
+use alloy_primitives::{keccak256, Address, FixedBytes, B256, U256, U64, U8};
 use alloy_sol_types::sol;
 
 // Note: Using deprecated Call until sol_interface! macro is updated to use new trait paths
 #[allow(deprecated)]
 use stylus_sdk::call::Call;
-use stylus_sdk::prelude::*;
+use stylus_sdk::{abi::Bytes, prelude::*};
 
 #[cfg(test)]
 mod mock_erc20;
+mod token_uri;
+
+/// Settlement grace period after expiry, in seconds.
+///
+/// Options stay exercisable until `expiry + SETTLEMENT_GRACE_PERIOD` (exclusive) so that
+/// exercises submitted just before expiry cannot race collateral withdrawal. Writing a
+/// series is rejected from `expiry` onwards and collateral withdrawal only opens once
+/// the grace period has elapsed.
+pub const SETTLEMENT_GRACE_PERIOD: u64 = 3_600;
+
+/// Exercise window of European series, in seconds.
+///
+/// European options are only exercisable from `expiry - EUROPEAN_EXERCISE_WINDOW` until
+/// `expiry + EUROPEAN_EXERCISE_WINDOW` (exclusive); collateral withdrawal only opens
+/// once the window has closed.
+pub const EUROPEAN_EXERCISE_WINDOW: u64 = 3_600;
+
+/// Default furthest expiry a write accepts, in seconds from now (3 years of 365 days).
+///
+/// Used while the owner has not set a horizon with `set_max_expiry_horizon`.
+pub const DEFAULT_MAX_EXPIRY_HORIZON: u64 = 3 * 365 * 24 * 3_600;
+
+/// Time after expiry during which holders of a settled cash call can always exercise it
+/// (30 days).
+///
+/// Once it has passed and the owner has set a treasury cut, `collect_forfeited_value`
+/// can end the series' exercises and split the value of the options still outstanding
+/// between the treasury and the writers.
+pub const FORFEITURE_DELAY: u64 = 30 * 24 * 3_600;
+
+/// Value a contract recipient's `onERC1155Received` must return to accept a transfer
+/// (`bytes4(keccak256("onERC1155Received(address,address,uint256,uint256,bytes)"))`).
+pub const ERC1155_RECEIVED: [u8; 4] = [0xf2, 0x3a, 0x6e, 0x61];
+
+/// Value a contract recipient's `onERC1155BatchReceived` must return to accept a batch
+/// transfer
+/// (`bytes4(keccak256("onERC1155BatchReceived(address,address,uint256[],uint256[],bytes)"))`).
+pub const ERC1155_BATCH_RECEIVED: [u8; 4] = [0xbc, 0x19, 0x7c, 0x81];
+
+/// ERC-165 interface ID of `supportsInterface(bytes4)`.
+pub const ERC165_INTERFACE_ID: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+
+/// ERC-165 interface ID of standard ERC-1155 (`uint256` token IDs).
+///
+/// Not advertised by `supports_interface`: this contract's ABI takes `bytes32` token IDs.
+pub const ERC1155_INTERFACE_ID: [u8; 4] = [0xd9, 0xb6, 0x7a, 0x26];
+
+/// ERC-165 interface ID of the ERC-1155 Metadata URI extension (`uri(uint256)`).
+///
+/// Not advertised by `supports_interface`: `uri` takes a `bytes32` token ID.
+pub const ERC1155_METADATA_URI_INTERFACE_ID: [u8; 4] = [0x0e, 0x89, 0x34, 0x1c];
+
+/// Maximum number of series that can be written in one `batch_write_options` call.
+///
+/// Bounds the gas of a batch so a ladder always fits in a block.
+pub const MAX_BATCH_SIZE: usize = 32;
+
+/// Maximum protocol fee, in basis points (5%).
+pub const MAX_FEE_BPS: u64 = 500;
+
+/// Basis points denominator (100%).
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum number of write fee discount tiers set with `set_fee_tiers`.
+pub const MAX_FEE_TIERS: usize = 8;
+
+/// Maximum number of token IDs returned by one `get_series_by_pair` page.
+pub const MAX_SERIES_PAGE_SIZE: usize = 100;
+
+/// Maximum keeper incentive on `auto_exercise`, in basis points of the payout (1%).
+pub const MAX_KEEPER_FEE_BPS: u64 = 100;
+
+/// Maximum strike price (18 decimals normalized): 10^36, i.e. 10^18 quote tokens per
+/// underlying token.
+pub const MAX_STRIKE: U256 = U256::from_limbs([0xb34b_9f10_0000_0000, 0x00c0_97ce_7bc9_0715, 0, 0]);
+
+/// Scale of the per-unit strike index of a series' assignment pool (10^36).
+pub const ASSIGNMENT_INDEX_PRECISION: U256 =
+    U256::from_limbs([0xb34b_9f10_0000_0000, 0x00c0_97ce_7bc9_0715, 0, 0]);
+
+/// Assignment pool units per option left in a series at which the next write starts a
+/// new pool epoch.
+///
+/// Exercises shrink the quantity behind each unit, so without a restart writes would mint
+/// ever more units. Positions more than one epoch behind are settled as fully assigned,
+/// which loses them at most a billionth of what they wrote.
+pub const MAX_UNITS_PER_QUANTITY: u64 = 1_000_000_000;
 
 sol! {
     /// Represents a token with its address and decimal precision.
+    ///
+    /// `decimals` is caller-supplied and verified against the token's `decimals()` on
+    /// the first write of a series.
     #[derive(Copy)]
     struct Token {
         address address;
@@ -32,24 +122,241 @@ sol! {
         uint256 strike;
         uint256 expiry;
         uint8 option_type;
+        address oracle;
+        uint8 settlement;
+        uint256 cap;
+        uint256 settlement_price;
+        address vault;
+        uint8 exercise_style;
     }
 
     /// Emitted when an option is written.
+    ///
+    /// `writer` receives the option tokens and owns the position; `payer` supplied
+    /// the collateral (the same address unless written via `write_call_option_for`).
+    /// `optionType`, `strike` and `expiry` repeat the series terms so indexers need no
+    /// metadata call. They were appended to the original five fields, which changed the
+    /// event signature (topic 0): indexers of the old ABI must update their topic filter.
     event OptionWritten(
         address indexed writer,
         bytes32 indexed tokenId,
+        address payer,
         uint256 quantity,
-        uint256 collateral
+        uint256 collateral,
+        uint8 optionType,
+        uint256 strike,
+        uint256 expiry
     );
 
+    /// Emitted when a call option is exercised.
+    ///
+    /// `recipient` receives the underlying, `quantity` is in the underlying token's
+    /// native decimals and `normalizedQuantity` is the same amount in 18 decimals
+    /// (option token units).
     event ExerciseCall(
         address indexed holder,
         address indexed writer,
         bytes32 indexed tokenId,
+        address recipient,
         uint256 quantity,
+        uint256 normalizedQuantity,
         uint256 strikePayment,
         uint256 underlyingReceived
     );
+
+    /// Emitted when a cash-settled call option is exercised.
+    ///
+    /// `quantity` is in the underlying token's native decimals, `settlementPrice` and
+    /// `intrinsicValue` are in quote terms (18 decimals normalized) and `underlyingPaid`
+    /// is the underlying worth `intrinsicValue` at `settlementPrice` (native decimals).
+    event CashExerciseCall(
+        address indexed holder,
+        bytes32 indexed tokenId,
+        uint256 quantity,
+        uint256 settlementPrice,
+        uint256 intrinsicValue,
+        uint256 underlyingPaid
+    );
+
+    /// Emitted when a writer closes a position by burning their own option tokens.
+    event PositionClosed(
+        address indexed writer,
+        bytes32 indexed tokenId,
+        uint256 quantity,
+        uint256 normalizedQuantity,
+        uint256 collateralReturned
+    );
+
+    /// Emitted when a writer rolls a position into a new series with `roll_position`.
+    ///
+    /// `collateralPaid` was pulled from the writer and `collateralRefunded` returned to
+    /// them on top of the collateral carried over (native decimals).
+    event PositionRolled(
+        address indexed writer,
+        bytes32 indexed oldTokenId,
+        bytes32 indexed newTokenId,
+        uint256 quantity,
+        uint256 collateralPaid,
+        uint256 collateralRefunded
+    );
+
+    /// Emitted when a protocol fee is paid to the fee recipient.
+    ///
+    /// `amount` is in `token`'s native decimals.
+    event FeeCollected(
+        address indexed payer,
+        bytes32 indexed tokenId,
+        address token,
+        uint256 amount
+    );
+
+    /// Emitted once per `batch_exercise`, after the per-leg `ExerciseCall` events.
+    event BatchExercised(
+        address indexed holder,
+        uint256 legs,
+        uint256 totalNormalizedQuantity
+    );
+
+    /// Emitted when the owner drains surplus tokens not backing any position.
+    event SurplusDrained(address indexed token, address indexed to, uint256 amount);
+
+    /// Emitted when a call spread is written against an escrowed long call.
+    ///
+    /// `spreadTokenId` is the series of the minted spread tokens (see
+    /// `write_call_spread`). `quantity` is in 18 decimals (option token units) and
+    /// `collateral` is the quote amount locked for the max loss, in the quote token's
+    /// native decimals.
+    event SpreadWritten(
+        address indexed writer,
+        bytes32 indexed shortTokenId,
+        bytes32 indexed longTokenId,
+        bytes32 spreadTokenId,
+        uint256 quantity,
+        uint256 collateral
+    );
+
+    /// Emitted when the settlement price of a cash call series is recorded.
+    ///
+    /// `settlementPrice` is the oracle price of one underlying token in quote tokens
+    /// (18 decimals normalized).
+    event SeriesSettled(bytes32 indexed tokenId, uint256 settlementPrice);
+
+    /// Emitted when a cash call is exercised against its recorded settlement price.
+    ///
+    /// `quantity` is in the underlying token's native decimals and `payout` in the quote
+    /// token's native decimals.
+    event SettledCallExercised(
+        address indexed holder,
+        bytes32 indexed tokenId,
+        uint256 quantity,
+        uint256 settlementPrice,
+        uint256 payout
+    );
+
+    /// Emitted when a holder exercises a vault-backed call by swapping part of the
+    /// underlying for the strike with `exercise_call_via_swap`.
+    ///
+    /// `underlyingSwapped` went to `router` for `quoteReceived` quote tokens, of which the
+    /// strike went to the vault; `underlyingReturned` was sent to the holder. All amounts
+    /// are in native decimals.
+    event ExercisedViaSwap(
+        address indexed holder,
+        bytes32 indexed tokenId,
+        address router,
+        uint256 underlyingSwapped,
+        uint256 quoteReceived,
+        uint256 underlyingReturned
+    );
+
+    /// Emitted when the value of a cash call series' unexercised options is forfeited.
+    ///
+    /// `quantity` is the forfeited option quantity (18 decimals normalized);
+    /// `treasuryAmount` and `writersAmount` are in the quote token's native decimals and
+    /// sum to its settlement value.
+    event ForfeitedValueCollected(
+        bytes32 indexed tokenId,
+        address indexed treasury,
+        uint256 quantity,
+        uint256 treasuryAmount,
+        uint256 writersAmount
+    );
+
+    /// Emitted for each holder whose cash calls a keeper exercised with `auto_exercise`.
+    ///
+    /// `quantity` is the holder's whole balance (18 decimals normalized); `payout` and
+    /// `keeperFee` are in the quote token's native decimals and sum to the holder's
+    /// settlement value. `payout` is credited to the holder's `claimable_cash`.
+    event AutoExercised(
+        address indexed holder,
+        bytes32 indexed tokenId,
+        address keeper,
+        uint256 quantity,
+        uint256 payout,
+        uint256 keeperFee
+    );
+
+    /// Emitted when a holder withdraws the cash settlement credited by `auto_exercise`.
+    ///
+    /// `amount` is in the quote token's native decimals.
+    event CashClaimed(
+        address indexed holder,
+        bytes32 indexed tokenId,
+        uint256 amount
+    );
+
+    /// Emitted when call exercises assigned to a writer are settled into their position.
+    ///
+    /// `quantity` is the writer's share of every exercise since their last settlement
+    /// (18 decimals normalized), `strikeCredited` the strike payment credited to them
+    /// for `claim_assignment` (quote token's native decimals, excluding exercises of
+    /// their own) and `collateralCredited` the collateral of cash-settled exercises left
+    /// over after the payout and added back to their position (18 decimals normalized).
+    event ExerciseAssigned(
+        address indexed writer,
+        bytes32 indexed tokenId,
+        uint256 quantity,
+        uint256 strikeCredited,
+        uint256 collateralCredited
+    );
+
+    /// Emitted when a writer claims the strike payments assigned to them.
+    ///
+    /// `amount` is in the quote token's native decimals.
+    event AssignmentClaimed(address indexed writer, bytes32 indexed tokenId, uint256 amount);
+
+    /// Emitted when a writer withdraws the collateral left over after settlement.
+    ///
+    /// `quantity` is the position size in 18 decimals and `collateralReturned` is in the
+    /// collateral token's native decimals.
+    event CollateralWithdrawn(
+        address indexed writer,
+        bytes32 indexed tokenId,
+        uint256 quantity,
+        uint256 collateralReturned
+    );
+
+    /// Standard ERC-1155 transfer event.
+    ///
+    /// `id` is the token ID as a `uint256` and `value` is in 18 decimals.
+    event TransferSingle(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256 id,
+        uint256 value
+    );
+
+    /// Standard ERC-1155 event for multi-token transfers, mints and burns.
+    event TransferBatch(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256[] ids,
+        uint256[] values
+    );
+
+    /// Standard ERC-1155 event emitted when `account` approves or revokes `operator`.
+    event ApprovalForAll(address indexed account, address indexed operator, bool approved);
 }
 
 // Implement AbiType for Token to make it usable in #[public] functions
@@ -58,12 +365,51 @@ impl stylus_sdk::abi::AbiType for Token {
     const ABI: stylus_sdk::abi::ConstString = stylus_sdk::abi::ConstString::new("(address,uint8)");
 }
 
+// Implement AbiType for OptionMetadataView so `option_metadata_of` can return it
+impl stylus_sdk::abi::AbiType for OptionMetadataView {
+    type SolType = Self;
+    const ABI: stylus_sdk::abi::ConstString = stylus_sdk::abi::ConstString::new(
+        "(address,address,uint8,uint8,uint256,uint256,uint8,address,uint8,uint256,uint256,address,uint8)",
+    );
+}
+
 sol_interface! {
     /// ERC20 interface for interacting with external token contracts.
     interface IERC20 {
         function balanceOf(address account) external view returns (uint256);
         function transfer(address to, uint256 value) external returns (bool);
         function transferFrom(address from, address to, uint256 value) external returns (bool);
+        function approve(address spender, uint256 value) external;
+        function decimals() external view returns (uint8);
+    }
+
+    /// ERC-1155 receiver hooks called on contract recipients of option tokens.
+    interface IERC1155Receiver {
+        function onERC1155Received(address operator, address from, uint256 id, uint256 value, bytes data) external returns (bytes4);
+        function onERC1155BatchReceived(address operator, address from, uint256[] ids, uint256[] values, bytes data) external returns (bytes4);
+    }
+
+    /// Per-series collateral vault (see the `vault` crate).
+    ///
+    /// `deposit` pulls `assets` from the caller and records a FIFO checkpoint for
+    /// `receiver`; `exerciseWithdraw` releases exercised collateral and is restricted to
+    /// the options contract and series the vault was initialized with, which `getSeries`
+    /// returns as (options contract, token ID, asset, strike asset, expiry).
+    interface IOptionVault {
+        function deposit(uint256 assets, address receiver) external returns (uint256);
+        function exerciseWithdraw(bytes32 tokenId, uint256 assets, address recipient) external returns (uint256);
+        function getSeries() external view returns (address, bytes32, address, address, uint256);
+    }
+
+    /// Price oracle interface for cash-settled option series.
+    ///
+    /// `latestPrice` returns the price of one `base` token in `quote` tokens with
+    /// `decimals` decimals (at most 18); `latestTimestamp` returns when it was last
+    /// updated (Unix seconds).
+    interface IPriceOracle {
+        function latestPrice(address base, address quote) external view returns (uint256);
+        function latestTimestamp(address base, address quote) external view returns (uint256);
+        function decimals() external view returns (uint8);
     }
 }
 
@@ -92,6 +438,115 @@ impl OptionType {
     }
 }
 
+/// How an option series is collateralized and settled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SettlementType {
+    /// Collateralized in the underlying and settled by delivery (or, for oracle-bound
+    /// series, by `exercise_call_cash` paying intrinsic value out of the underlying).
+    #[default]
+    Physical,
+    /// Collateralized in quote tokens up to a per-option cap and settled in quote
+    /// tokens against a settlement price recorded after expiry.
+    Cash,
+}
+
+impl SettlementType {
+    /// Converts settlement type to u8 for encoding.
+    ///
+    /// # Returns
+    /// - `0` for Physical
+    /// - `1` for Cash
+    #[must_use]
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            Self::Physical => 0,
+            Self::Cash => 1,
+        }
+    }
+}
+
+/// When the holder of an option series may exercise it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExerciseStyle {
+    /// Exercisable at any time until the settlement grace period after expiry elapses.
+    #[default]
+    American,
+    /// Exercisable only within `EUROPEAN_EXERCISE_WINDOW` of expiry.
+    European,
+}
+
+impl ExerciseStyle {
+    /// Converts exercise style to u8 for encoding.
+    ///
+    /// # Returns
+    /// - `0` for American
+    /// - `1` for European
+    #[must_use]
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            Self::American => 0,
+            Self::European => 1,
+        }
+    }
+}
+
+/// Which series token a write locks as collateral.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CollateralToken {
+    /// The underlying token (calls).
+    #[default]
+    Underlying,
+    /// The quote token (puts).
+    Quote,
+}
+
+impl CollateralToken {
+    /// Converts collateral token to u8 for encoding.
+    ///
+    /// # Returns
+    /// - `0` for Underlying
+    /// - `1` for Quote
+    #[must_use]
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            Self::Underlying => 0,
+            Self::Quote => 1,
+        }
+    }
+}
+
+/// Lifecycle state of an option series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeriesStatus {
+    /// No option of this series has ever been written.
+    NotWritten,
+    /// Before expiry with options outstanding.
+    Active,
+    /// At or after expiry with options still outstanding.
+    Expired,
+    /// Every option of the series has been exercised or closed.
+    Settled,
+}
+
+impl SeriesStatus {
+    /// Converts series status to u8 for encoding.
+    ///
+    /// # Returns
+    /// - `0` for NotWritten
+    /// - `1` for Active
+    /// - `2` for Expired
+    /// - `3` for Settled
+    #[must_use]
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            Self::NotWritten => 0,
+            Self::Active => 1,
+            Self::Expired => 2,
+            Self::Settled => 3,
+        }
+    }
+}
+
 sol! {
     /// Errors that can occur in the Options contract.
     #[derive(Debug)]
@@ -107,12 +562,16 @@ sol! {
     #[derive(Debug)]
     error InvalidStrike();
     #[derive(Debug)]
+    error StrikeTooLarge(uint256 strike, uint256 max);
+    #[derive(Debug)]
     error ExpiredOption(uint256 expiry, uint256 current);
     #[derive(Debug)]
     error InvalidQuantity();
     #[derive(Debug)]
     error SameToken();
     #[derive(Debug)]
+    error ZeroAddress();
+    #[derive(Debug)]
     error FeeOnTransferDetected(uint256 expected, uint256 received);
     #[derive(Debug)]
     error TransferFailed();
@@ -123,7 +582,85 @@ sol! {
     #[derive(Debug)]
     error ExerciseAfterExpiry(uint256 expiry, uint256 current);
     #[derive(Debug)]
+    error ExerciseWindowClosed(uint256 opens_at, uint256 closes_at, uint256 current);
+    #[derive(Debug)]
     error WrongOptionType(uint8 expected, uint8 actual);
+    #[derive(Debug)]
+    error MetadataMismatch();
+    #[derive(Debug)]
+    error InvalidOracle();
+    #[derive(Debug)]
+    error NotCashSettled();
+    #[derive(Debug)]
+    error NotInTheMoney(uint256 spot, uint256 strike);
+    #[derive(Debug)]
+    error OracleCallFailed();
+    #[derive(Debug)]
+    error WithdrawalBeforeSettlement(uint256 available_at, uint256 current);
+    #[derive(Debug)]
+    error Unauthorized(address caller);
+    #[derive(Debug)]
+    error AlreadyInitialized();
+    #[derive(Debug)]
+    error Paused();
+    #[derive(Debug)]
+    error InvalidRecipient();
+    #[derive(Debug)]
+    error Reentrancy();
+    #[derive(Debug)]
+    error InvalidSpread();
+    #[derive(Debug)]
+    error BatchLengthMismatch();
+    #[derive(Debug)]
+    error InvalidBatchSize(uint256 size, uint256 max);
+    #[derive(Debug)]
+    error InsufficientSurplus(uint256 balance, uint256 locked);
+    #[derive(Debug)]
+    error FeeTooHigh(uint256 fee_bps, uint256 max_fee_bps);
+    #[derive(Debug)]
+    error StaleSettlementPrice(uint256 updated_at, uint256 max_staleness);
+    #[derive(Debug)]
+    error DecimalsChanged(uint8 stored, uint8 current);
+    #[derive(Debug)]
+    error DecimalsUnavailable(address token);
+    #[derive(Debug)]
+    error DecimalsMismatch(address token, uint8 declared, uint8 actual);
+    #[derive(Debug)]
+    error WrongSettlementType(uint8 expected, uint8 actual);
+    #[derive(Debug)]
+    error InvalidCap();
+    #[derive(Debug)]
+    error SettlementPriceRequired();
+    #[derive(Debug)]
+    error SettlementBeforeExpiry(uint256 expiry, uint256 current);
+    #[derive(Debug)]
+    error AlreadySettled(uint256 settlement_price);
+    #[derive(Debug)]
+    error InvalidVault(address vault);
+    #[derive(Debug)]
+    error BelowAutoExerciseThreshold(uint256 intrinsic_value, uint256 threshold);
+    #[derive(Debug)]
+    error RouterNotApproved(address router);
+    #[derive(Debug)]
+    error SwapFailed(address router);
+    #[derive(Debug)]
+    error InsufficientSwapOutput(uint256 required, uint256 received);
+    #[derive(Debug)]
+    error SlippageExceeded(uint256 min_out, uint256 received);
+    #[derive(Debug)]
+    error ERC1155ReceiverRejected(address receiver);
+    #[derive(Debug)]
+    error VaultCallFailed(address vault);
+    #[derive(Debug)]
+    error InvalidFeeTiers();
+    #[derive(Debug)]
+    error SeriesIndexOutOfBounds(uint256 index, uint256 count);
+    #[derive(Debug)]
+    error ExpiryTooFar(uint256 expiry, uint256 max_expiry);
+    #[derive(Debug)]
+    error InvalidStrikeTick(uint256 strike, uint256 tick);
+    #[derive(Debug)]
+    error NoTreasuryPolicy();
 }
 
 #[derive(SolidityError, Debug)]
@@ -140,12 +677,16 @@ pub enum OptionsError {
     Overflow(Overflow),
     /// Strike price must be greater than zero.
     InvalidStrike(InvalidStrike),
+    /// Strike exceeds `MAX_STRIKE`, or `strike * quantity` would overflow.
+    StrikeTooLarge(StrikeTooLarge),
     /// Option expiry must be in the future.
     ExpiredOption(ExpiredOption),
     /// Quantity must be greater than zero.
     InvalidQuantity(InvalidQuantity),
     /// Underlying and quote tokens must be different.
     SameToken(SameToken),
+    /// Underlying and quote tokens must not be the zero address.
+    ZeroAddress(ZeroAddress),
     /// Fee-on-transfer token detected.
     FeeOnTransferDetected(FeeOnTransferDetected),
     /// ERC20 transfer failed.
@@ -156,8 +697,86 @@ pub enum OptionsError {
     OptionNotFound(OptionNotFound),
     /// Cannot exercise option after expiry.
     ExerciseAfterExpiry(ExerciseAfterExpiry),
+    /// European option exercised outside its exercise window.
+    ExerciseWindowClosed(ExerciseWindowClosed),
     /// Wrong option type for this exercise function.
     WrongOptionType(WrongOptionType),
+    /// Token decimals differ from those stored for an existing series.
+    MetadataMismatch(MetadataMismatch),
+    /// Oracle address must not be zero.
+    InvalidOracle(InvalidOracle),
+    /// Option series is physically settled (no oracle).
+    NotCashSettled(NotCashSettled),
+    /// Oracle spot price is not above the strike price.
+    NotInTheMoney(NotInTheMoney),
+    /// Price oracle call failed.
+    OracleCallFailed(OracleCallFailed),
+    /// Collateral withdrawal before the settlement grace period has elapsed.
+    WithdrawalBeforeSettlement(WithdrawalBeforeSettlement),
+    /// Caller is not the contract owner.
+    Unauthorized(Unauthorized),
+    /// Contract owner has already been set.
+    AlreadyInitialized(AlreadyInitialized),
+    /// Contract is paused.
+    Paused(Paused),
+    /// Recipient must not be the zero address.
+    InvalidRecipient(InvalidRecipient),
+    /// Function re-entered while an external call was in progress.
+    Reentrancy(Reentrancy),
+    /// Short and long legs do not form a valid call spread.
+    InvalidSpread(InvalidSpread),
+    /// Batch parameter arrays have different lengths.
+    BatchLengthMismatch(BatchLengthMismatch),
+    /// Batch is empty or exceeds `MAX_BATCH_SIZE`.
+    InvalidBatchSize(InvalidBatchSize),
+    /// Token balance does not exceed the collateral locked in it.
+    InsufficientSurplus(InsufficientSurplus),
+    /// Protocol fee exceeds `MAX_FEE_BPS`.
+    FeeTooHigh(FeeTooHigh),
+    /// Oracle price is older than the configured maximum staleness.
+    StaleSettlementPrice(StaleSettlementPrice),
+    /// Token decimals differ from those stored in the series metadata.
+    DecimalsChanged(DecimalsChanged),
+    /// Token `decimals()` query failed.
+    DecimalsUnavailable(DecimalsUnavailable),
+    /// Declared token decimals differ from the token's `decimals()`.
+    DecimalsMismatch(DecimalsMismatch),
+    /// Wrong settlement type for this function.
+    WrongSettlementType(WrongSettlementType),
+    /// Cash call cap must be greater than zero.
+    InvalidCap(InvalidCap),
+    /// Cash call series has no settlement price yet.
+    SettlementPriceRequired(SettlementPriceRequired),
+    /// Cash call series cannot be settled before expiry.
+    SettlementBeforeExpiry(SettlementBeforeExpiry),
+    /// Cash call series already has a settlement price.
+    AlreadySettled(AlreadySettled),
+    /// Vault address is zero or its expiry differs from the series.
+    InvalidVault(InvalidVault),
+    /// Call to a series vault failed.
+    VaultCallFailed(VaultCallFailed),
+    /// Settled intrinsic value does not exceed the auto-exercise threshold.
+    BelowAutoExerciseThreshold(BelowAutoExerciseThreshold),
+    /// Swap router has not been approved by the owner.
+    RouterNotApproved(RouterNotApproved),
+    /// Call to the swap router reverted.
+    SwapFailed(SwapFailed),
+    /// Swap returned less quote than the strike payment.
+    InsufficientSwapOutput(InsufficientSwapOutput),
+    /// Holder would receive less underlying than their minimum.
+    SlippageExceeded(SlippageExceeded),
+    /// Contract recipient did not accept an option token transfer.
+    ERC1155ReceiverRejected(ERC1155ReceiverRejected),
+    /// Fee tier thresholds are not strictly increasing or exceed `MAX_FEE_TIERS`.
+    InvalidFeeTiers(InvalidFeeTiers),
+    /// Series registry index is past the last written series.
+    SeriesIndexOutOfBounds(SeriesIndexOutOfBounds),
+    /// Expiry is beyond the current time plus the maximum expiry horizon.
+    ExpiryTooFar(ExpiryTooFar),
+    /// Strike is not a multiple of the configured strike tick.
+    InvalidStrikeTick(InvalidStrikeTick),
+    /// No treasury cut of forfeited value is set.
+    NoTreasuryPolicy(NoTreasuryPolicy),
 }
 
 sol_storage! {
@@ -177,6 +796,18 @@ sol_storage! {
         uint256 expiry;
         /// Option type (0=Call, 1=Put)
         uint8 option_type;
+        /// Price oracle for cash settlement (zero for physically settled series)
+        address oracle;
+        /// Settlement type (0=Physical, 1=Cash)
+        uint8 settlement;
+        /// Maximum payout per cash call (18 decimals normalized, zero for physical series)
+        uint256 cap;
+        /// Settlement price of a cash call series (18 decimals normalized, zero until set)
+        uint256 settlement_price;
+        /// Vault holding the collateral (zero when this contract holds it)
+        address vault;
+        /// Exercise style (0=American, 1=European)
+        uint8 exercise_style;
     }
 
     /// Writer position for an option series.
@@ -185,6 +816,58 @@ sol_storage! {
         uint256 quantity_written;
         /// Collateral locked (18 decimals normalized)
         uint256 collateral_locked;
+        /// Cumulative quantity exercised against this position (18 decimals normalized)
+        uint256 quantity_exercised;
+        /// Quantity reduced since the position was last written to (18 decimals normalized)
+        uint256 quantity_reduced;
+        /// Collateral released since the position was last written to (18 decimals
+        /// normalized)
+        uint256 collateral_released;
+        /// Units held in the series' assignment pool (see `assign_exercise`)
+        uint256 assignment_units;
+        /// Assignment pool epoch the units belong to
+        uint256 assignment_epoch;
+        /// Pool strike index when the position was last settled
+        uint256 strike_index;
+        /// Pool's cumulative quantity assigned when the position was last settled (18
+        /// decimals normalized)
+        uint256 assigned_index;
+        /// Pool collateral index when the position was last settled
+        uint256 collateral_index;
+    }
+
+    /// Sum of every writer position of an option series.
+    pub struct GlobalPosition {
+        /// Quantity of options written across all writers (18 decimals normalized)
+        uint256 quantity_written;
+        /// Collateral locked across all writers (18 decimals normalized)
+        uint256 collateral_locked;
+        /// Units of the current assignment pool epoch held across all writers
+        uint256 assignment_units;
+        /// Current assignment pool epoch
+        uint256 assignment_epoch;
+        /// Strike payment credited per pool unit this epoch (quote token's native decimals,
+        /// scaled by `ASSIGNMENT_INDEX_PRECISION`)
+        uint256 strike_per_unit;
+        /// Cumulative quantity assigned to writers (18 decimals normalized)
+        uint256 quantity_assigned;
+        /// Collateral of cash-settled exercises credited back per pool unit this epoch (18
+        /// decimals normalized, scaled by `ASSIGNMENT_INDEX_PRECISION`)
+        uint256 collateral_per_unit;
+    }
+
+    /// Final state of a closed assignment pool epoch of a series.
+    pub struct AssignmentEpoch {
+        /// Quantity left in the pool (18 decimals normalized)
+        uint256 quantity;
+        /// Pool units outstanding
+        uint256 units;
+        /// Final strike index (see `GlobalPosition`)
+        uint256 strike_per_unit;
+        /// Cumulative quantity assigned when the epoch closed (18 decimals normalized)
+        uint256 quantity_assigned;
+        /// Final collateral index (see `GlobalPosition`)
+        uint256 collateral_per_unit;
     }
 
     #[entrypoint]
@@ -197,14 +880,89 @@ sol_storage! {
         mapping(bytes32 => OptionMetadata) option_metadata;
         /// Mapping from position_key(writer, token_id) to position
         mapping(bytes32 => Position) positions;
+        /// Mapping from token_id to cumulative quantity written
+        mapping(bytes32 => uint256) total_written;
+        /// Mapping from token_id to cumulative quantity exercised
+        mapping(bytes32 => uint256) total_exercised;
+        /// Number of distinct option series ever written
+        uint256 series_count;
+        /// Operator allowed to pause and unpause the contract
+        address owner;
+        /// Account that sent the deployment transaction, the only one allowed to call
+        /// `initialize`
+        address deployer;
+        /// Whether writing and exercising are paused
+        bool paused;
+        /// Reentrancy lock held while a state-changing call with external calls runs
+        bool locked;
+        /// Mapping from call spread token_id to the token_id of the long calls it escrows
+        mapping(bytes32 => bytes32) spread_long_token_id;
+        /// Mapping from token to collateral locked in it across all series (native decimals)
+        mapping(address => uint256) locked_collateral;
+        /// Protocol fee on writing and exercise, in basis points
+        uint256 fee_bps;
+        /// Address receiving protocol fees
+        address fee_recipient;
+        /// Maximum age of an oracle price used for cash settlement (seconds, 0 disables)
+        uint64 max_settlement_staleness;
+        /// Whether exercise re-checks live token decimals against the series metadata
+        bool strict_decimals;
+        /// Mapping from token_id to every writer that has held a position, in order
+        mapping(bytes32 => address[]) series_writers;
+        /// Mapping from position_key(writer, token_id) to whether the writer is listed
+        mapping(bytes32 => bool) is_series_writer;
+        /// Keeper incentive on `auto_exercise`, in basis points of the payout
+        uint256 keeper_fee_bps;
+        /// Minimum intrinsic value per option for `auto_exercise` (18 decimals normalized)
+        uint256 auto_exercise_threshold;
+        /// Mapping from swap router to whether `exercise_call_via_swap` may call it
+        mapping(address => bool) approved_routers;
+        /// Mapping from token holder to operators allowed to transfer all their tokens
+        mapping(address => mapping(address => bool)) operator_approvals;
+        /// Mapping from payer to cumulative quantity written (18 decimals normalized)
+        mapping(address => uint256) write_volume;
+        /// Minimum write volume of each fee tier, strictly increasing
+        uint256[] fee_tier_thresholds;
+        /// Write fee of each fee tier, in basis points
+        uint256[] fee_tier_bps;
+        /// Token ID of every series ever written, in order of creation
+        bytes32[] series_ids;
+        /// Mapping from pair_key(underlying, quote) to the token IDs of the pair's series
+        mapping(bytes32 => bytes32[]) pair_series;
+        /// Mapping from token_id to the sum of all writer positions
+        mapping(bytes32 => GlobalPosition) global_positions;
+        /// Mapping from position_key(writer, token_id) to strike payments assigned to the
+        /// writer and not yet claimed (quote token's native decimals)
+        mapping(bytes32 => uint256) claimable_assignments;
+        /// Furthest expiry a write accepts, in seconds from now (0 uses
+        /// `DEFAULT_MAX_EXPIRY_HORIZON`)
+        uint64 max_expiry_horizon;
+        /// Granularity written strikes must be a multiple of (18 decimals, 0 disables)
+        uint256 strike_tick;
+        /// Mapping from epoch_key(token_id, epoch) to the final state of a closed
+        /// assignment pool epoch
+        mapping(bytes32 => AssignmentEpoch) assignment_epochs;
+        /// Share of forfeited cash call value paid to the treasury, in basis points
+        uint256 treasury_bps;
+        /// Address receiving the treasury share of forfeited value
+        address treasury;
+        /// Mapping from token_id to whether its unexercised value has been forfeited
+        mapping(bytes32 => bool) forfeiture_collected;
+        /// Mapping from token_id to the forfeited value credited to writers per option of
+        /// their positions (18 decimals normalized quote)
+        mapping(bytes32 => uint256) forfeited_per_option;
+        /// Mapping from holder to token_id to the `auto_exercise` payout they have yet to
+        /// claim (quote token's native decimals, still counted in `locked_collateral`)
+        mapping(address => mapping(bytes32 => uint256)) claimable_cash;
     }
 }
 
-/// Generates a deterministic token ID for an option series.
+/// Returns the bytes hashed by `generate_token_id`.
 ///
-/// Token ID is computed as `keccak256(underlying, quote, strike, expiry, option_type)`.
-/// All writers of the same option parameters share the same token ID, enabling
-/// fungibility and secondary market trading.
+/// The preimage is the 81-byte concatenation
+/// `underlying (20) || quote (20) || strike (32, big-endian) || expiry (8, big-endian) ||
+/// option_type (1)`, without ABI padding, so that off-chain clients can check their
+/// encoding before hashing.
 ///
 /// # Parameters
 /// - `underlying`: Address of the underlying token
@@ -214,42 +972,231 @@ sol_storage! {
 /// - `option_type`: Call or Put
 ///
 /// # Returns
-/// Deterministic `B256` hash as token ID
+/// Token ID preimage bytes
 #[must_use]
-pub(crate) fn generate_token_id(
+pub fn token_id_preimage(
     underlying: Address,
     quote: Address,
     strike: U256,
     expiry: u64,
     option_type: OptionType,
-) -> B256 {
-    let encoded = [
+) -> Vec<u8> {
+    [
         underlying.as_slice(),
         quote.as_slice(),
         strike.to_be_bytes::<32>().as_slice(),
         &expiry.to_be_bytes(),
         &[option_type.to_u8()],
     ]
-    .concat();
-
-    keccak256(encoded)
+    .concat()
 }
 
-/// Normalizes an amount from native token decimals to 18 decimals.
+/// Generates a deterministic token ID for an option series.
 ///
-/// All internal calculations use 18-decimal precision. This function converts
-/// amounts from their native decimal representation to the internal 18-decimal format.
+/// Token ID is computed as `keccak256(token_id_preimage(...))`, the packed encoding of
+/// `underlying, quote, strike, expiry, option_type`.
+/// All writers of the same option parameters share the same token ID, enabling
+/// fungibility and secondary market trading.
 ///
 /// # Parameters
-/// - `amount`: Amount in native decimals
-/// - `from_decimals`: Number of decimals in the native token (must be <= 18)
+/// - `underlying`: Address of the underlying token
+/// - `quote`: Address of the quote token
+/// - `strike`: Strike price (18 decimals normalized)
+/// - `expiry`: Expiration timestamp (Unix seconds)
+/// - `option_type`: Call or Put
 ///
 /// # Returns
-/// Amount normalized to 18 decimals
+/// Deterministic `B256` hash as token ID
+#[must_use]
+pub(crate) fn generate_token_id(
+    underlying: Address,
+    quote: Address,
+    strike: U256,
+    expiry: u64,
+    option_type: OptionType,
+) -> B256 {
+    keccak256(token_id_preimage(
+        underlying,
+        quote,
+        strike,
+        expiry,
+        option_type,
+    ))
+}
+
+/// Generates a token ID for a cash-settled option series.
 ///
-/// # Errors
-/// - `InvalidDecimals`: If `from_decimals > 18`
-/// - `NormalizationOverflow`: If multiplication would overflow U256
+/// Token ID is computed as `keccak256(generate_token_id(...), oracle)`, so cash-settled
+/// series never share a token ID with the physically settled series of the same
+/// parameters or with series settled against a different oracle.
+///
+/// # Parameters
+/// - `underlying`: Address of the underlying token
+/// - `quote`: Address of the quote token
+/// - `strike`: Strike price (18 decimals normalized)
+/// - `expiry`: Expiration timestamp (Unix seconds)
+/// - `option_type`: Call or Put
+/// - `oracle`: Address of the price oracle used for settlement
+///
+/// # Returns
+/// Deterministic `B256` hash as token ID
+#[must_use]
+pub(crate) fn generate_cash_settled_token_id(
+    underlying: Address,
+    quote: Address,
+    strike: U256,
+    expiry: u64,
+    option_type: OptionType,
+    oracle: Address,
+) -> B256 {
+    let token_id = generate_token_id(underlying, quote, strike, expiry, option_type);
+    keccak256([token_id.as_slice(), oracle.as_slice()].concat())
+}
+
+/// Generates a token ID for a cash call series.
+///
+/// Token ID is computed as
+/// `keccak256(generate_token_id(...), SettlementType::Cash, cap, oracle)`, so cash calls
+/// never share a token ID with physically settled or oracle-bound series of the same
+/// parameters, nor with cash calls of a different cap or oracle.
+///
+/// # Parameters
+/// - `underlying`: Address of the underlying token
+/// - `quote`: Address of the quote token
+/// - `strike`: Strike price (18 decimals normalized)
+/// - `expiry`: Expiration timestamp (Unix seconds)
+/// - `cap`: Maximum payout per option (18 decimals normalized)
+/// - `oracle`: Address of the price oracle used for settlement
+///
+/// # Returns
+/// Deterministic `B256` hash as token ID
+#[must_use]
+pub(crate) fn generate_cash_call_token_id(
+    underlying: Address,
+    quote: Address,
+    strike: U256,
+    expiry: u64,
+    cap: U256,
+    oracle: Address,
+) -> B256 {
+    let token_id = generate_token_id(underlying, quote, strike, expiry, OptionType::Call);
+    let encoded = [
+        token_id.as_slice(),
+        &[SettlementType::Cash.to_u8()],
+        cap.to_be_bytes::<32>().as_slice(),
+        oracle.as_slice(),
+    ]
+    .concat();
+
+    keccak256(encoded)
+}
+
+/// Generates a token ID for a vault-backed call series.
+///
+/// Token ID is computed as `keccak256(generate_token_id(...), "vault", vault)`, so
+/// vault-backed series never share a token ID with series whose collateral this
+/// contract holds, nor with series backed by a different vault.
+///
+/// # Parameters
+/// - `underlying`: Address of the underlying token
+/// - `quote`: Address of the quote token
+/// - `strike`: Strike price (18 decimals normalized)
+/// - `expiry`: Expiration timestamp (Unix seconds)
+/// - `vault`: Address of the vault holding the collateral
+///
+/// # Returns
+/// Deterministic `B256` hash as token ID
+#[must_use]
+pub(crate) fn generate_vault_token_id(
+    underlying: Address,
+    quote: Address,
+    strike: U256,
+    expiry: u64,
+    vault: Address,
+) -> B256 {
+    let token_id = generate_token_id(underlying, quote, strike, expiry, OptionType::Call);
+    keccak256([token_id.as_slice(), b"vault", vault.as_slice()].concat())
+}
+
+/// Generates a token ID for a European option series.
+///
+/// Token ID is computed as `keccak256(token_id, "european")` over the token ID the
+/// series would have as an American option, so the two exercise styles of otherwise
+/// identical options are distinct instruments.
+///
+/// # Parameters
+/// - `american_token_id`: Token ID of the American series with the same parameters
+///
+/// # Returns
+/// Deterministic `B256` hash as token ID
+#[must_use]
+pub(crate) fn generate_european_token_id(american_token_id: B256) -> B256 {
+    keccak256([american_token_id.as_slice(), b"european"].concat())
+}
+
+/// Generates a token ID for a call spread series.
+///
+/// Token ID is computed as `keccak256(short_token_id, "spread", long_token_id)`, so
+/// calls written against an escrowed long never share a token ID with the fully
+/// collateralized calls of the short series, nor with spreads against another long.
+///
+/// # Parameters
+/// - `short_token_id`: Token ID of the call series written (lower strike)
+/// - `long_token_id`: Token ID of the escrowed long call series (higher strike)
+///
+/// # Returns
+/// Deterministic `B256` hash as token ID
+#[must_use]
+pub(crate) fn generate_spread_token_id(short_token_id: B256, long_token_id: B256) -> B256 {
+    keccak256(
+        [
+            short_token_id.as_slice(),
+            b"spread",
+            long_token_id.as_slice(),
+        ]
+        .concat(),
+    )
+}
+
+/// Validates a token as the underlying or quote of a series.
+///
+/// Runs the token checks a write would, so integrators can reject a bad `Token` before
+/// sending a transaction.
+///
+/// # Parameters
+/// - `token`: Token address and declared decimals
+///
+/// # Errors
+/// - `ZeroAddress`: Token address is zero
+/// - `InvalidDecimals`: Decimals exceed 18
+pub fn validate_token(token: Token) -> Result<(), OptionsError> {
+    if token.address == Address::ZERO {
+        return Err(OptionsError::ZeroAddress(ZeroAddress {}));
+    }
+    if token.decimals > 18 {
+        return Err(OptionsError::InvalidDecimals(InvalidDecimals {
+            decimals: token.decimals,
+        }));
+    }
+
+    Ok(())
+}
+
+/// Normalizes an amount from native token decimals to 18 decimals.
+///
+/// All internal calculations use 18-decimal precision. This function converts
+/// amounts from their native decimal representation to the internal 18-decimal format.
+///
+/// # Parameters
+/// - `amount`: Amount in native decimals
+/// - `from_decimals`: Number of decimals in the native token (must be <= 18)
+///
+/// # Returns
+/// Amount normalized to 18 decimals
+///
+/// # Errors
+/// - `InvalidDecimals`: If `from_decimals > 18`
+/// - `NormalizationOverflow`: If multiplication would overflow U256
 pub(crate) fn normalize_amount(amount: U256, from_decimals: u8) -> Result<U256, OptionsError> {
     if from_decimals > 18 {
         return Err(OptionsError::InvalidDecimals(InvalidDecimals {
@@ -272,7 +1219,8 @@ pub(crate) fn normalize_amount(amount: U256, from_decimals: u8) -> Result<U256,
 /// Denormalizes an amount from 18 decimals to native token decimals.
 ///
 /// Converts amounts from the internal 18-decimal representation back to
-/// native token decimals for ERC20 transfers.
+/// native token decimals for ERC20 transfers. Rounds down; see
+/// `denormalize_amount_rounding`.
 ///
 /// # Parameters
 /// - `amount`: Amount in 18 decimals
@@ -285,6 +1233,42 @@ pub(crate) fn normalize_amount(amount: U256, from_decimals: u8) -> Result<U256,
 /// - `InvalidDecimals`: If `to_decimals > 18`
 /// - `NormalizationOverflow`: If scale factor calculation would overflow
 pub(crate) fn denormalize_amount(amount: U256, to_decimals: u8) -> Result<U256, OptionsError> {
+    denormalize_amount_rounding(amount, to_decimals, false)
+}
+
+/// Denormalizes an amount from 18 decimals to native token decimals, rounding up.
+///
+/// Shorthand for `denormalize_amount_rounding(amount, to_decimals, true)`.
+///
+/// # Errors
+/// See `denormalize_amount_rounding`
+pub(crate) fn denormalize_amount_ceil(amount: U256, to_decimals: u8) -> Result<U256, OptionsError> {
+    denormalize_amount_rounding(amount, to_decimals, true)
+}
+
+/// Denormalizes an amount from 18 decimals to native token decimals in a chosen
+/// rounding direction.
+///
+/// Amounts owed to the contract (e.g. the strike payment of an exercise) round up and
+/// amounts paid out by the contract round down, so rounding never shortchanges the
+/// protocol. Rounding up uses `div_ceil`, which cannot overflow for any `amount`.
+///
+/// # Parameters
+/// - `amount`: Amount in 18 decimals
+/// - `to_decimals`: Number of decimals in the target token (must be <= 18)
+/// - `round_up`: Whether to round a remainder up instead of truncating it
+///
+/// # Returns
+/// Amount in native token decimals
+///
+/// # Errors
+/// - `InvalidDecimals`: If `to_decimals > 18`
+/// - `NormalizationOverflow`: If scale factor calculation would overflow
+pub(crate) fn denormalize_amount_rounding(
+    amount: U256,
+    to_decimals: u8,
+    round_up: bool,
+) -> Result<U256, OptionsError> {
     if to_decimals > 18 {
         return Err(OptionsError::InvalidDecimals(InvalidDecimals {
             decimals: to_decimals,
@@ -296,1357 +1280,9992 @@ pub(crate) fn denormalize_amount(amount: U256, to_decimals: u8) -> Result<U256,
         OptionsError::NormalizationOverflow(NormalizationOverflow {}),
     )?;
 
-    Ok(amount / scale_factor)
+    if round_up {
+        Ok(amount.div_ceil(scale_factor))
+    } else {
+        Ok(amount / scale_factor)
+    }
 }
 
-/// Validates parameters for writing an option.
+/// Computes the strike payment owed for exercising calls, rounded up.
 ///
-/// Performs comprehensive validation of all option parameters at the contract boundary.
-/// All external input is treated as untrusted.
+/// The strike value is `strike * quantity` in quote terms; it is owed to the writer,
+/// so both the 18-decimal product and the conversion to quote decimals round up.
 ///
 /// # Parameters
-/// - `strike`: Strike price (must be > 0)
-/// - `expiry`: Expiration timestamp (must be > current_timestamp)
-/// - `quantity`: Quantity of options (must be > 0)
-/// - `underlying`: Underlying token
-/// - `quote`: Quote token
-/// - `current_timestamp`: Current block timestamp
+/// - `strike`: Strike price (18 decimals normalized)
+/// - `quantity`: Quantity of options (18 decimals normalized)
+/// - `quote_decimals`: Quote token decimals (must be <= 18)
+///
+/// # Returns
+/// Strike payment in the quote token's native decimals
 ///
 /// # Errors
-/// - `InvalidStrike`: Strike price is zero
-/// - `ExpiredOption`: Expiry is not in the future
-/// - `InvalidQuantity`: Quantity is zero
-/// - `SameToken`: Underlying and quote addresses are identical
-pub(crate) fn validate_write_params(
+/// - `Overflow`: `strike * quantity` overflows
+/// - `InvalidDecimals`: `quote_decimals > 18`
+pub(crate) fn strike_payment(
     strike: U256,
-    expiry: u64,
     quantity: U256,
-    underlying: Token,
-    quote: Token,
-    current_timestamp: u64,
-) -> Result<(), OptionsError> {
-    // Validate strike > 0
-    if strike.is_zero() {
-        return Err(OptionsError::InvalidStrike(InvalidStrike {}));
+    quote_decimals: u8,
+) -> Result<U256, OptionsError> {
+    let one = U256::from(10).pow(U256::from(18));
+    let strike_value = strike
+        .checked_mul(quantity)
+        .ok_or(OptionsError::Overflow(Overflow {}))?
+        .div_ceil(one);
+
+    denormalize_amount_ceil(strike_value, quote_decimals)
+}
+
+/// Computes the cash settlement of an in-the-money call.
+///
+/// The intrinsic value is `(spot - strike) * quantity` in quote terms. It is paid out
+/// in underlying tokens, so the payout is the underlying amount worth the intrinsic
+/// value at the spot price: `(spot - strike) * quantity / spot`, rounded down.
+///
+/// # Parameters
+/// - `spot`: Oracle price of one underlying token in quote tokens (18 decimals normalized)
+/// - `strike`: Strike price (18 decimals normalized, must be below `spot`)
+/// - `quantity`: Quantity of options (18 decimals normalized)
+///
+/// # Returns
+/// Tuple of (intrinsic_value in quote terms, payout in underlying), both 18 decimals normalized
+///
+/// # Errors
+/// - `NotInTheMoney`: `spot` is not above `strike`
+/// - `Overflow`: Arithmetic overflow during calculation
+pub(crate) fn cash_settlement_amounts(
+    spot: U256,
+    strike: U256,
+    quantity: U256,
+) -> Result<(U256, U256), OptionsError> {
+    if spot <= strike {
+        return Err(OptionsError::NotInTheMoney(NotInTheMoney { spot, strike }));
     }
 
-    // Validate expiry > current_timestamp
-    if expiry <= current_timestamp {
-        return Err(OptionsError::ExpiredOption(ExpiredOption {
-            expiry: U256::from(expiry),
-            current: U256::from(current_timestamp),
-        }));
+    let one = U256::from(10).pow(U256::from(18));
+    let price_difference = spot - strike;
+    let value_scaled = price_difference
+        .checked_mul(quantity)
+        .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+    let intrinsic_value = value_scaled / one;
+    let payout = value_scaled / spot;
+
+    Ok((intrinsic_value, payout))
+}
+
+/// Returns `a - b`, or zero when `b > a`.
+///
+/// # Parameters
+/// - `a`: Minuend
+/// - `b`: Subtrahend
+#[must_use]
+pub(crate) fn checked_sub_or_zero(a: U256, b: U256) -> U256 {
+    if a > b {
+        a - b
+    } else {
+        U256::ZERO
     }
+}
 
-    // Validate quantity > 0
-    if quantity.is_zero() {
-        return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+/// Returns the intrinsic value of one option, clamped at zero.
+///
+/// `spot - strike` for calls and `strike - spot` for puts; out-of-the-money options are
+/// worth zero rather than underflowing.
+///
+/// # Parameters
+/// - `spot`: Price of one underlying token in quote tokens (18 decimals normalized)
+/// - `strike`: Strike price (18 decimals normalized)
+/// - `is_call`: Whether the option is a call (otherwise a put)
+///
+/// # Returns
+/// Intrinsic value per option in quote terms (18 decimals normalized)
+#[must_use]
+pub(crate) fn saturating_intrinsic(spot: U256, strike: U256, is_call: bool) -> U256 {
+    if is_call {
+        checked_sub_or_zero(spot, strike)
+    } else {
+        checked_sub_or_zero(strike, spot)
     }
+}
 
-    // Validate underlying != quote
-    if underlying.address == quote.address {
-        return Err(OptionsError::SameToken(SameToken {}));
+/// Validates that an oracle price is recent enough to settle against.
+///
+/// Timestamps in the future count as fresh. A `max_staleness` of zero disables the check.
+///
+/// # Parameters
+/// - `updated_at`: When the oracle price was last updated (Unix seconds)
+/// - `current_time`: Current block timestamp (Unix seconds)
+/// - `max_staleness`: Maximum allowed price age (seconds)
+///
+/// # Errors
+/// - `StaleSettlementPrice`: Price is older than `max_staleness`
+pub(crate) fn validate_price_freshness(
+    updated_at: U256,
+    current_time: u64,
+    max_staleness: u64,
+) -> Result<(), OptionsError> {
+    if max_staleness == 0 {
+        return Ok(());
+    }
+
+    if checked_sub_or_zero(U256::from(current_time), updated_at) > U256::from(max_staleness) {
+        return Err(OptionsError::StaleSettlementPrice(StaleSettlementPrice {
+            updated_at,
+            max_staleness: U256::from(max_staleness),
+        }));
     }
 
     Ok(())
 }
 
-#[public]
-impl Options {
-    /// Writes a call option by locking underlying tokens as collateral (1:1).
-    ///
-    /// Mints ERC-1155 tokens representing the call option and returns a deterministic token ID
-    /// based on the option parameters (keccak256 hash).
-    ///
-    /// # Parameters
-    /// - `strike`: Strike price (18 decimals normalized)
-    /// - `expiry`: Expiration timestamp (Unix seconds)
-    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
-    /// - `underlying`: Underlying token (address and decimals)
-    /// - `quote`: Quote token (address and decimals)
-    ///
-    /// # Returns
-    /// Token ID (B256) representing this option series
-    ///
-    /// # Errors
-    /// - `InvalidStrike`: Strike price is zero
-    /// - `ExpiredOption`: Expiry is not in the future
-    /// - `InvalidQuantity`: Quantity is zero
-    /// - `SameToken`: Underlying and quote addresses are identical
-    /// - `InvalidDecimals`: Token decimals exceed 18
-    /// - `NormalizationOverflow`: Amount normalization would overflow
-    /// - `Overflow`: Position or balance accumulation would overflow
-    /// - `FeeOnTransferDetected`: Underlying token deducts fees during transfer
-    /// - `TransferFailed`: ERC20 transfer failed
-    pub fn write_call_option(
-        &mut self,
-        strike: U256,
-        expiry: u64,
-        quantity: U256,
-        underlying: Token,
-        quote: Token,
-    ) -> Result<B256, OptionsError> {
-        let (current_timestamp, sender, contract_addr) = {
-            let vm = self.vm();
-            (vm.block_timestamp(), vm.msg_sender(), vm.contract_address())
-        };
-
-        validate_write_params(
-            strike,
-            expiry,
-            quantity,
-            underlying,
-            quote,
-            current_timestamp,
-        )?;
+/// Splits the collateral of settled cash calls between holders and writers.
+///
+/// Each option pays `min(max(settlement_price - strike, 0), cap)` to its holder; the
+/// rest of the `cap` backing it goes back to the writer. Both amounts round down, so
+/// together they never exceed the collateral locked at write time.
+///
+/// # Parameters
+/// - `settlement_price`: Settlement price of one underlying token in quote tokens
+///   (18 decimals normalized)
+/// - `strike`: Strike price (18 decimals normalized)
+/// - `cap`: Maximum payout per option (18 decimals normalized)
+/// - `quantity`: Quantity of options (18 decimals normalized)
+///
+/// # Returns
+/// Tuple of (holder payout, writer remainder) in quote terms, both 18 decimals normalized
+///
+/// # Errors
+/// - `Overflow`: Arithmetic overflow during calculation
+pub(crate) fn cash_call_settlement(
+    settlement_price: U256,
+    strike: U256,
+    cap: U256,
+    quantity: U256,
+) -> Result<(U256, U256), OptionsError> {
+    let one = U256::from(10).pow(U256::from(18));
+    let payoff = saturating_intrinsic(settlement_price, strike, true).min(cap);
+
+    let holder_value = payoff
+        .checked_mul(quantity)
+        .ok_or(OptionsError::Overflow(Overflow {}))?
+        / one;
+    let writer_value = (cap - payoff)
+        .checked_mul(quantity)
+        .ok_or(OptionsError::Overflow(Overflow {}))?
+        / one;
+
+    Ok((holder_value, writer_value))
+}
 
-        let token_id = generate_token_id(
-            underlying.address,
-            quote.address,
-            strike,
-            expiry,
-            OptionType::Call,
-        );
+/// Validates that a cash call series can be settled.
+///
+/// Settlement opens at expiry (inclusive).
+///
+/// # Parameters
+/// - `expiry`: Expiration timestamp of the series
+/// - `current_time`: Current block timestamp
+///
+/// # Errors
+/// - `SettlementBeforeExpiry`: Current time < expiry
+pub(crate) fn validate_settlement_time(
+    expiry: U256,
+    current_time: u64,
+) -> Result<(), OptionsError> {
+    if U256::from(current_time) < expiry {
+        return Err(OptionsError::SettlementBeforeExpiry(
+            SettlementBeforeExpiry {
+                expiry,
+                current: U256::from(current_time),
+            },
+        ));
+    }
 
-        let normalized_quantity = normalize_amount(quantity, underlying.decimals)?;
+    Ok(())
+}
 
-        self.store_option_metadata(
-            token_id,
-            underlying,
-            quote,
-            strike,
-            expiry,
-            OptionType::Call,
-        );
+/// Amounts to pay out for a recorded call exercise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CallExercise {
+    /// Token ID of the exercised series
+    pub token_id: B256,
+    /// Underlying token delivered
+    pub underlying: Address,
+    /// Quote token of the series
+    pub quote: Address,
+    /// Quantity exercised (underlying token's native decimals)
+    pub quantity: U256,
+    /// Quantity exercised (18 decimals normalized)
+    pub normalized_quantity: U256,
+    /// Strike value of the exercise (quote token's native decimals)
+    pub strike_payment: U256,
+    /// Protocol fee deducted from the delivered underlying (native decimals)
+    pub fee: U256,
+    /// Strike payment the holder owes other writers of the series (quote token's native
+    /// decimals, zero for vault-backed series)
+    pub strike_owed: U256,
+    /// Vault delivering the underlying (zero when this contract holds the collateral)
+    pub vault: Address,
+}
 
-        self.create_or_update_position(sender, token_id, normalized_quantity, normalized_quantity)?;
+/// Amounts of a writer position, as tracked by `reduce_position`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct PositionAmounts {
+    /// Quantity written (18 decimals normalized)
+    pub quantity_written: U256,
+    /// Collateral locked (18 decimals normalized)
+    pub collateral_locked: U256,
+    /// Quantity reduced since the position was last written to (18 decimals normalized)
+    pub quantity_reduced: U256,
+    /// Collateral released since the position was last written to (18 decimals
+    /// normalized)
+    pub collateral_released: U256,
+}
 
-        self._mint(sender, token_id, normalized_quantity)?;
+/// Call exercises assigned to a writer position since it was last settled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct PendingAssignment {
+    /// Quantity assigned (18 decimals normalized)
+    pub quantity: U256,
+    /// Strike payment credited (quote token's native decimals)
+    pub strike_credited: U256,
+    /// Collateral of cash-settled exercises credited back (18 decimals normalized)
+    pub collateral_credited: U256,
+    /// Units the position holds in the series' current assignment pool epoch once settled
+    pub units: U256,
+}
 
-        // External call after all state updates (reentrancy protection)
-        self.safe_transfer_from(underlying.address, sender, contract_addr, quantity)?;
+/// Reduces a writer position by `quantity`, releasing collateral proportionally.
+///
+/// Releases are computed from the cumulative reduction since the position was last
+/// written to: the collateral released so far is always
+/// `floor(total_collateral * total_reduced / total_quantity)`, so rounding never
+/// accumulates over many small reductions and a reduction to zero quantity
+/// releases exactly the collateral that is left.
+///
+/// # Parameters
+/// - `amounts`: Current position amounts
+/// - `quantity`: Amount to reduce the position by (18 decimals normalized)
+///
+/// # Returns
+/// The reduced position amounts
+///
+/// # Errors
+/// - `InsufficientBalance`: Position quantity less than requested reduction
+/// - `Overflow`: Arithmetic overflow during calculation (should never occur with valid inputs)
+pub(crate) fn reduced_position(
+    amounts: PositionAmounts,
+    quantity: U256,
+) -> Result<PositionAmounts, OptionsError> {
+    if amounts.quantity_written < quantity {
+        return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+            available: amounts.quantity_written,
+            requested: quantity,
+        }));
+    }
 
-        log(
-            self.vm(),
-            OptionWritten {
-                writer: sender,
-                tokenId: token_id,
-                quantity: normalized_quantity,
-                collateral: normalized_quantity,
-            },
-        );
+    let new_quantity = amounts
+        .quantity_written
+        .checked_sub(quantity)
+        .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+    // Totals as of the last write, reconstructed from what is left plus what was
+    // already reduced
+    let total_quantity = amounts
+        .quantity_written
+        .checked_add(amounts.quantity_reduced)
+        .ok_or(OptionsError::Overflow(Overflow {}))?;
+    let total_collateral = amounts
+        .collateral_locked
+        .checked_add(amounts.collateral_released)
+        .ok_or(OptionsError::Overflow(Overflow {}))?;
+    let new_quantity_reduced = amounts
+        .quantity_reduced
+        .checked_add(quantity)
+        .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+    let new_collateral_released = if new_quantity.is_zero() {
+        total_collateral
+    } else {
+        total_collateral
+            .checked_mul(new_quantity_reduced)
+            .ok_or(OptionsError::Overflow(Overflow {}))?
+            .checked_div(total_quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?
+    };
+    let collateral_to_reduce = new_collateral_released
+        .checked_sub(amounts.collateral_released)
+        .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+    let new_collateral = amounts
+        .collateral_locked
+        .checked_sub(collateral_to_reduce)
+        .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+    Ok(PositionAmounts {
+        quantity_written: new_quantity,
+        collateral_locked: new_collateral,
+        quantity_reduced: new_quantity_reduced,
+        collateral_released: new_collateral_released,
+    })
+}
 
-        Ok(token_id)
+/// Applies a pending assignment to writer position amounts.
+///
+/// The assigned quantity is reduced as in `reduced_position`. Credited collateral is
+/// added back and, like a write, restarts the reduction tracking so later releases are
+/// proportional to the new totals.
+///
+/// # Parameters
+/// - `amounts`: Position amounts as of the last settlement
+/// - `pending`: Pending assignment of the position
+///
+/// # Returns
+/// The settled position amounts
+///
+/// # Errors
+/// - `Overflow`: Arithmetic overflow during calculation
+pub(crate) fn settled_amounts(
+    amounts: PositionAmounts,
+    pending: PendingAssignment,
+) -> Result<PositionAmounts, OptionsError> {
+    let reduced = if pending.quantity.is_zero() {
+        amounts
+    } else {
+        reduced_position(amounts, pending.quantity)?
+    };
+    if pending.collateral_credited.is_zero() {
+        return Ok(reduced);
     }
 
-    /// Writes a put option by locking quote tokens as collateral (strike * quantity).
-    ///
-    /// Mints ERC-1155 tokens representing the put option and returns a deterministic token ID
-    /// based on the option parameters (keccak256 hash).
-    ///
-    /// # Parameters
-    /// - `strike`: Strike price (18 decimals normalized)
-    /// - `expiry`: Expiration timestamp (Unix seconds)
-    /// - `quantity`: Quantity of options to write
-    /// - `underlying`: Underlying token (address and decimals)
-    /// - `quote`: Quote token (address and decimals)
-    ///
-    /// # Errors
-    /// Returns `OptionsError::Unimplemented` (stub implementation).
-    pub fn write_put_option(
-        &mut self,
-        strike: U256,
-        expiry: u64,
-        quantity: U256,
-        underlying: Token,
-        quote: Token,
-    ) -> Result<B256, OptionsError> {
-        let _ = (strike, expiry, quantity, underlying, quote);
-        Err(OptionsError::Unimplemented(Unimplemented {}))
-    }
+    Ok(PositionAmounts {
+        quantity_written: reduced.quantity_written,
+        collateral_locked: reduced
+            .collateral_locked
+            .checked_add(pending.collateral_credited)
+            .ok_or(OptionsError::Overflow(Overflow {}))?,
+        quantity_reduced: U256::ZERO,
+        collateral_released: U256::ZERO,
+    })
+}
 
-    /// Exercises a call option.
-    ///
-    /// Immediate atomic settlement following checks-effects-interactions pattern:
-    /// 1. Validates exercise conditions (holder balance, expiry, option type)
-    /// 2. Burns option tokens from holder
-    /// 3. Reduces writer's position (if holder is writer in PoC model)
-    /// 4. Transfers underlying tokens from contract to holder
-    ///
-    /// PoC Note: holder must be writer (single-writer model). Strike payment
-    /// transfer omitted since holder pays themselves.
-    ///
-    /// Fee-on-transfer behavior: If underlying token becomes fee-on-transfer
-    /// after writing, holder receives less tokens on exercise. This doesn't
-    /// revert - holder accepts the loss rather than being unable to exercise.
-    ///
-    /// # Parameters
-    /// - `token_id`: The ERC-1155 token ID of the call option (keccak256 hash)
-    /// - `quantity`: Quantity of options to exercise (18-decimal normalized)
-    ///
-    /// # Returns
-    /// - `Ok(())` on successful exercise
-    ///
-    /// # Errors
-    /// - `OptionNotFound`: Option metadata not found for token_id
-    /// - `ExerciseAfterExpiry`: Current time >= option expiry
-    /// - `WrongOptionType`: Token ID represents a put option, not call
-    /// - `InvalidQuantity`: Quantity is zero
-    /// - `InsufficientBalance`: Holder doesn't have enough option tokens
-    /// - `TransferFailed`: ERC20 transfer failed
-    /// - `Overflow`: Arithmetic overflow during calculation
-    ///
-    /// # Example
-    /// ```ignore
-    /// // Writer exercises own call option
-    /// let token_id = contract.write_call_option(strike, expiry, quantity, underlying, quote)?;
-    /// let exercise_qty = U256::from(50) * U256::from(10).pow(U256::from(18));
-    /// contract.exercise_call(token_id, exercise_qty)?;
-    /// ```
-    #[allow(deprecated)]
-    pub fn exercise_call(&mut self, token_id: B256, quantity: U256) -> Result<(), OptionsError> {
-        let holder = self.vm().msg_sender();
-        let current_time = self.vm().block_timestamp();
+/// Returns the quantity behind units of a series' assignment pool.
+///
+/// Rounds down, so writers are assigned rounded up and the pool never promises more
+/// than it holds.
+///
+/// # Parameters
+/// - `units`: Pool units
+/// - `pool_quantity`: Quantity left in the pool (18 decimals normalized)
+/// - `pool_units`: Pool units outstanding
+///
+/// # Returns
+/// `floor(units * pool_quantity / pool_units)`, all of the pool from `pool_units` units
+///
+/// # Errors
+/// - `Overflow`: Arithmetic overflow during calculation
+pub(crate) fn quantity_of_units(
+    units: U256,
+    pool_quantity: U256,
+    pool_units: U256,
+) -> Result<U256, OptionsError> {
+    if units.is_zero() {
+        return Ok(U256::ZERO);
+    }
+    if units >= pool_units {
+        return Ok(pool_quantity);
+    }
+    Ok(units
+        .checked_mul(pool_quantity)
+        .ok_or(OptionsError::Overflow(Overflow {}))?
+        / pool_units)
+}
 
-        self.validate_call_exercise(holder, token_id, quantity, current_time)?;
+/// Adds `amount` of `token` owed to `to` to a list of aggregated payouts.
+///
+/// Zero amounts are skipped so no empty transfers are made.
+///
+/// # Errors
+/// - `Overflow`: Aggregated payout would overflow
+pub(crate) fn add_payout(
+    payouts: &mut Vec<(Address, Address, U256)>,
+    token: Address,
+    to: Address,
+    amount: U256,
+) -> Result<(), OptionsError> {
+    if amount.is_zero() {
+        return Ok(());
+    }
 
-        let metadata = self.get_option_metadata(token_id);
-        let underlying_token = metadata.underlying;
-        let underlying_decimals = metadata.underlying_decimals;
-        let strike = metadata.strike;
-        let quote_decimals = metadata.quote_decimals;
-
-        let underlying_denorm = denormalize_amount(quantity, underlying_decimals)?;
-        let strike_total = strike
-            .checked_mul(quantity)
+    if let Some(payout) = payouts
+        .iter_mut()
+        .find(|(payout_token, payout_to, _)| *payout_token == token && *payout_to == to)
+    {
+        payout.2 = payout
+            .2
+            .checked_add(amount)
             .ok_or(OptionsError::Overflow(Overflow {}))?;
-        let strike_payment = denormalize_amount(strike_total, quote_decimals)?;
-
-        self._burn(holder, token_id, quantity)?;
-
-        self.reduce_position(holder, token_id, quantity)?;
-
-        let erc20 = IERC20::new(underlying_token);
-        let success = erc20
-            .transfer(Call::new_in(self), holder, underlying_denorm)
-            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+    } else {
+        payouts.push((token, to, amount));
+    }
 
-        if !success {
-            return Err(OptionsError::TransferFailed(TransferFailed {}));
-        }
+    Ok(())
+}
 
-        log(
-            self.vm(),
-            ExerciseCall {
-                holder,
-                writer: holder,
-                tokenId: token_id,
-                quantity,
-                strikePayment: strike_payment,
-                underlyingReceived: underlying_denorm,
-            },
-        );
+/// Computes the protocol fee on an amount, rounded down.
+///
+/// # Parameters
+/// - `amount`: Amount the fee applies to (any decimals)
+/// - `fee_bps`: Fee in basis points
+///
+/// # Returns
+/// Fee in the same units as `amount`
+///
+/// # Errors
+/// - `Overflow`: Arithmetic overflow during calculation
+pub(crate) fn protocol_fee(amount: U256, fee_bps: U256) -> Result<U256, OptionsError> {
+    Ok(amount
+        .checked_mul(fee_bps)
+        .ok_or(OptionsError::Overflow(Overflow {}))?
+        / U256::from(BPS_DENOMINATOR))
+}
 
-        Ok(())
+/// Computes the quote collateral for a call spread: the max loss
+/// `(buy_strike - sell_strike) * quantity`.
+///
+/// Rounded up to the quote token's native precision so a spread is never
+/// under-collateralized.
+///
+/// # Parameters
+/// - `sell_strike`: Strike of the short call (18 decimals normalized)
+/// - `buy_strike`: Strike of the long call (18 decimals normalized, must be above `sell_strike`)
+/// - `quantity`: Quantity of options (18 decimals normalized)
+/// - `quote_decimals`: Decimals of the quote token (must be <= 18)
+///
+/// # Returns
+/// Collateral in the quote token's native decimals
+///
+/// # Errors
+/// - `InvalidSpread`: `buy_strike` is not above `sell_strike`
+/// - `InvalidDecimals`: `quote_decimals > 18`
+/// - `Overflow`: Arithmetic overflow during calculation
+pub(crate) fn spread_collateral(
+    sell_strike: U256,
+    buy_strike: U256,
+    quantity: U256,
+    quote_decimals: u8,
+) -> Result<U256, OptionsError> {
+    if buy_strike <= sell_strike {
+        return Err(OptionsError::InvalidSpread(InvalidSpread {}));
     }
-
-    /// Exercises a put option
-    ///
-    /// Immediate atomic settlement: holder delivers underlying tokens to writer,
-    /// receives strike (quote tokens) from collateral, burns option tokens.
-    /// Can only be called before option expiry.
-    ///
-    /// # Parameters
-    /// - `token_id`: The ERC-1155 token ID of the put option (keccak256 hash)
-    /// - `quantity`: Quantity of options to exercise
-    ///
-    /// # Errors
-    /// Returns `OptionsError::Unimplemented` (stub implementation).
-    pub fn exercise_put(&mut self, token_id: B256, quantity: U256) -> Result<(), OptionsError> {
-        let _ = (token_id, quantity);
-        Err(OptionsError::Unimplemented(Unimplemented {}))
+    if quote_decimals > 18 {
+        return Err(OptionsError::InvalidDecimals(InvalidDecimals {
+            decimals: quote_decimals,
+        }));
     }
 
-    /// Withdraws collateral for expired unexercised options.
-    ///
-    /// Writers can reclaim their locked collateral after option expiry.
-    /// Returns underlying tokens for calls, quote tokens for puts.
-    /// Reduces or closes the writer's position. Only callable after expiry.
-    ///
-    /// # Parameters
-    /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
-    /// - `quantity`: Quantity of collateral to withdraw
-    ///
-    /// # Errors
-    /// Returns `OptionsError::Unimplemented` (stub implementation).
-    pub fn withdraw_expired_collateral(
-        &mut self,
-        token_id: B256,
-        quantity: U256,
-    ) -> Result<(), OptionsError> {
-        let _ = (token_id, quantity);
-        Err(OptionsError::Unimplemented(Unimplemented {}))
-    }
-}
+    let max_loss_scaled = (buy_strike - sell_strike)
+        .checked_mul(quantity)
+        .ok_or(OptionsError::Overflow(Overflow {}))?;
+    let divisor = U256::from(10).pow(U256::from(36 - u32::from(quote_decimals)));
 
-/// Test-only helper methods (accessible through motsu deref)
-impl Options {
-    /// Test wrapper for _mint - accessible in motsu tests through deref
-    ///
-    /// # Errors
-    /// Returns `OptionsError::Overflow` if balance or total supply would overflow
-    #[cfg(test)]
-    pub fn test_mint(
-        &mut self,
-        to: Address,
-        token_id: B256,
-        quantity: U256,
-    ) -> Result<(), OptionsError> {
-        self._mint(to, token_id, quantity)
-    }
+    Ok(max_loss_scaled.div_ceil(divisor))
+}
 
-    /// Test wrapper for _burn - accessible in motsu tests through deref
-    ///
-    /// # Errors
-    /// Returns `OptionsError::InsufficientBalance` if balance is less than quantity
-    #[cfg(test)]
-    pub fn test_burn(
-        &mut self,
-        from: Address,
-        token_id: B256,
-        quantity: U256,
-    ) -> Result<(), OptionsError> {
-        self._burn(from, token_id, quantity)
-    }
+/// Returns the timestamp from which a series can no longer be exercised.
+///
+/// `expiry + SETTLEMENT_GRACE_PERIOD` for American series and
+/// `expiry + EUROPEAN_EXERCISE_WINDOW` for European series. Saturates instead of
+/// overflowing on out-of-range stored expiries.
+///
+/// # Parameters
+/// - `expiry`: Expiration timestamp of the series
+/// - `exercise_style`: Exercise style of the series (0=American, 1=European)
+#[must_use]
+pub(crate) fn exercise_closes_at(expiry: U256, exercise_style: u8) -> U256 {
+    let period = if exercise_style == ExerciseStyle::European.to_u8() {
+        EUROPEAN_EXERCISE_WINDOW
+    } else {
+        SETTLEMENT_GRACE_PERIOD
+    };
+    expiry.saturating_add(U256::from(period))
+}
 
-    /// Test wrapper for balance_of - accessible in motsu tests through deref
-    #[cfg(test)]
-    #[must_use]
-    pub fn test_balance_of(&self, owner: Address, token_id: B256) -> U256 {
-        self.balance_of(owner, token_id)
+/// Validates that the settlement grace period of a series has elapsed.
+///
+/// Collateral can only be withdrawn once options can no longer be exercised, i.e.
+/// from `exercise_closes_at(expiry, exercise_style)` onwards.
+///
+/// # Parameters
+/// - `expiry`: Expiration timestamp of the series
+/// - `exercise_style`: Exercise style of the series (0=American, 1=European)
+/// - `current_time`: Current block timestamp
+///
+/// # Errors
+/// - `WithdrawalBeforeSettlement`: Exercise is still open at `current_time`
+pub(crate) fn validate_withdrawal_time(
+    expiry: U256,
+    exercise_style: u8,
+    current_time: u64,
+) -> Result<(), OptionsError> {
+    let available_at = exercise_closes_at(expiry, exercise_style);
+    if U256::from(current_time) < available_at {
+        return Err(OptionsError::WithdrawalBeforeSettlement(
+            WithdrawalBeforeSettlement {
+                available_at,
+                current: U256::from(current_time),
+            },
+        ));
     }
 
-    /// Test wrapper for total_supply_of - accessible in motsu tests through deref
-    #[cfg(test)]
-    #[must_use]
-    pub fn test_total_supply_of(&self, token_id: B256) -> U256 {
-        self.total_supply_of(token_id)
-    }
+    Ok(())
 }
 
-/// Internal helper functions for Options contract
-impl Options {
-    /// Generates a composite key for balance lookups.
-    ///
-    /// Combines owner address and token ID into a single key for storage mapping.
+/// Validates parameters for writing an option.
+///
+/// Performs comprehensive validation of all option parameters at the contract boundary.
+/// All external input is treated as untrusted.
+///
+/// # Parameters
+/// - `strike`: Strike price (must be > 0 and <= `MAX_STRIKE`)
+/// - `expiry`: Expiration timestamp (must be > current_timestamp)
+/// - `quantity`: Quantity of options (must be > 0, native decimals)
+/// - `underlying`: Underlying token
+/// - `quote`: Quote token
+/// - `current_timestamp`: Current block timestamp
+/// - `max_expiry_horizon`: Furthest accepted expiry, in seconds from `current_timestamp`
+/// - `strike_tick`: Granularity the strike must be a multiple of (0 disables the check)
+///
+/// # Errors
+/// - `InvalidStrike`: Strike price is zero
+/// - `StrikeTooLarge`: Strike exceeds `MAX_STRIKE`, or `strike * quantity` (normalized)
+///   overflows, which would make the strike payment or put collateral uncomputable
+/// - `InvalidStrikeTick`: Strike is not a multiple of a non-zero `strike_tick`
+/// - `ExpiredOption`: Expiry is not in the future
+/// - `ExpiryTooFar`: Expiry is after `current_timestamp + max_expiry_horizon`
+/// - `InvalidQuantity`: Quantity is zero
+/// - `ZeroAddress`: Underlying or quote address is zero
+/// - `SameToken`: Underlying and quote addresses are identical
+/// - `InvalidDecimals`: Underlying decimals exceed 18
+/// - `NormalizationOverflow`: Quantity normalization would overflow
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn validate_write_params(
+    strike: U256,
+    expiry: u64,
+    quantity: U256,
+    underlying: Token,
+    quote: Token,
+    current_timestamp: u64,
+    max_expiry_horizon: u64,
+    strike_tick: U256,
+) -> Result<(), OptionsError> {
+    // Validate strike > 0
+    if strike.is_zero() {
+        return Err(OptionsError::InvalidStrike(InvalidStrike {}));
+    }
+
+    // Validate strike <= MAX_STRIKE
+    if strike > MAX_STRIKE {
+        return Err(OptionsError::StrikeTooLarge(StrikeTooLarge {
+            strike,
+            max: MAX_STRIKE,
+        }));
+    }
+
+    // Validate strike is a multiple of the tick, when one is set
+    if !strike_tick.is_zero() && !(strike % strike_tick).is_zero() {
+        return Err(OptionsError::InvalidStrikeTick(InvalidStrikeTick {
+            strike,
+            tick: strike_tick,
+        }));
+    }
+
+    // Validate expiry > current_timestamp
+    if expiry <= current_timestamp {
+        return Err(OptionsError::ExpiredOption(ExpiredOption {
+            expiry: U256::from(expiry),
+            current: U256::from(current_timestamp),
+        }));
+    }
+
+    // Validate expiry <= current_timestamp + max_expiry_horizon
+    let max_expiry = current_timestamp.saturating_add(max_expiry_horizon);
+    if expiry > max_expiry {
+        return Err(OptionsError::ExpiryTooFar(ExpiryTooFar {
+            expiry: U256::from(expiry),
+            max_expiry: U256::from(max_expiry),
+        }));
+    }
+
+    // Validate quantity > 0
+    if quantity.is_zero() {
+        return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+    }
+
+    // Validate neither token is the zero address
+    if underlying.address == Address::ZERO || quote.address == Address::ZERO {
+        return Err(OptionsError::ZeroAddress(ZeroAddress {}));
+    }
+
+    // Validate underlying != quote
+    if underlying.address == quote.address {
+        return Err(OptionsError::SameToken(SameToken {}));
+    }
+
+    // Validate strike * quantity fits, so the write can be exercised
+    let normalized_quantity = normalize_amount(quantity, underlying.decimals)?;
+    validate_strike_notional(strike, normalized_quantity)?;
+
+    Ok(())
+}
+
+/// Validates that `strike * quantity` fits in a U256.
+///
+/// Exercising (strike payment) and writing puts (collateral) both compute this product,
+/// so a series whose total written quantity fails this check could never be settled.
+///
+/// # Parameters
+/// - `strike`: Strike price (18 decimals normalized)
+/// - `quantity`: Quantity of options (18 decimals normalized)
+///
+/// # Errors
+/// - `StrikeTooLarge`: `strike * quantity` overflows
+pub(crate) fn validate_strike_notional(strike: U256, quantity: U256) -> Result<(), OptionsError> {
+    if strike.checked_mul(quantity).is_none() {
+        return Err(OptionsError::StrikeTooLarge(StrikeTooLarge {
+            strike,
+            max: U256::MAX / quantity,
+        }));
+    }
+    Ok(())
+}
+
+/// Computes the collateral a write pulls from the caller, in native decimals.
+///
+/// Calls lock `quantity` of the underlying; puts lock `strike * quantity` of the quote,
+/// rounded up like the strike payment. Runs the write's normalization and notional
+/// checks, so any parameters `write_call` accepts succeed here with the amount it pulls
+/// (before the protocol fee).
+///
+/// # Parameters
+/// - `strike`: Strike price (18 decimals normalized)
+/// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+/// - `underlying_decimals`: Decimals of the underlying token
+/// - `quote_decimals`: Decimals of the quote token
+/// - `option_type`: Option type (0 = Call, 1 = Put)
+///
+/// # Returns
+/// Tuple of (collateral amount in native decimals, token it is paid in)
+///
+/// # Errors
+/// - `WrongOptionType`: Option type is neither call nor put
+/// - `InvalidDecimals`: Underlying decimals, or quote decimals for puts, exceed 18
+/// - `NormalizationOverflow`: Quantity normalization would overflow
+/// - `StrikeTooLarge`: `strike * quantity` (normalized) overflows
+pub(crate) fn required_collateral(
+    strike: U256,
+    quantity: U256,
+    underlying_decimals: u8,
+    quote_decimals: u8,
+    option_type: u8,
+) -> Result<(U256, CollateralToken), OptionsError> {
+    if option_type > OptionType::Put.to_u8() {
+        return Err(OptionsError::WrongOptionType(WrongOptionType {
+            expected: OptionType::Call.to_u8(),
+            actual: option_type,
+        }));
+    }
+
+    let normalized_quantity = normalize_amount(quantity, underlying_decimals)?;
+    validate_strike_notional(strike, normalized_quantity)?;
+
+    if option_type == OptionType::Call.to_u8() {
+        Ok((quantity, CollateralToken::Underlying))
+    } else {
+        let collateral = strike_payment(strike, normalized_quantity, quote_decimals)?;
+        Ok((collateral, CollateralToken::Quote))
+    }
+}
+
+#[public]
+impl Options {
+    /// Records the account deploying the contract as the only one allowed to call
+    /// `initialize`.
+    ///
+    /// Uses the transaction origin rather than the caller, which is the deployer
+    /// contract when deploying through `cargo stylus deploy`.
+    #[constructor]
+    pub fn constructor(&mut self) {
+        let deployer = self.vm().tx_origin();
+        self.deployer.set(deployer);
+    }
+
+    /// Sets the contract owner who can pause and unpause the contract.
+    ///
+    /// Only the deployer recorded by the constructor can call it, so the owner cannot
+    /// be claimed by front-running the first call.
     ///
     /// # Parameters
-    /// - `owner`: Token holder address
-    /// - `token_id`: ERC-1155 token ID
+    /// - `owner`: Address of the protocol operator
     ///
-    /// # Returns
-    /// `keccak256(owner || token_id)` as composite key
-    fn balance_key(owner: Address, token_id: B256) -> B256 {
-        let encoded = [owner.as_slice(), token_id.as_slice()].concat();
-        keccak256(encoded)
+    /// # Errors
+    /// - `AlreadyInitialized`: Owner has already been set
+    /// - `Unauthorized`: Caller is not the deployer, or owner address is zero
+    pub fn initialize(&mut self, owner: Address) -> Result<(), OptionsError> {
+        if self.owner.get() != Address::ZERO {
+            return Err(OptionsError::AlreadyInitialized(AlreadyInitialized {}));
+        }
+
+        let caller = self.vm().msg_sender();
+        if caller != self.deployer.get() {
+            return Err(OptionsError::Unauthorized(Unauthorized { caller }));
+        }
+
+        if owner == Address::ZERO {
+            return Err(OptionsError::Unauthorized(Unauthorized { caller: owner }));
+        }
+
+        self.owner.set(owner);
+
+        Ok(())
     }
 
-    /// Mints option tokens to an address.
+    /// Pauses writing and exercising options.
     ///
-    /// Increases both the recipient's balance and the token's total supply.
-    /// Uses checked arithmetic to prevent overflow.
+    /// Emergency switch for the protocol operator. Closing positions and withdrawing
+    /// expired collateral stay available so funds are never trapped.
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    pub fn pause(&mut self) -> Result<(), OptionsError> {
+        self.only_owner()?;
+        self.paused.set(true);
+        Ok(())
+    }
+
+    /// Unpauses writing and exercising options.
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    pub fn unpause(&mut self) -> Result<(), OptionsError> {
+        self.only_owner()?;
+        self.paused.set(false);
+        Ok(())
+    }
+
+    /// Sets the protocol fee charged on writing and exercising calls.
+    ///
+    /// Writers pay `collateral * fee_bps / 10_000` on top of the collateral (or their
+    /// discount tier's rate, see `set_fee_tiers`), and exercises deliver the underlying
+    /// minus the same fraction. Both go to `recipient`.
     ///
     /// # Parameters
-    /// - `to`: Recipient address
-    /// - `token_id`: ERC-1155 token ID
-    /// - `quantity`: Amount to mint
+    /// - `fee_bps`: Fee in basis points (at most `MAX_FEE_BPS`)
+    /// - `recipient`: Address receiving fees
     ///
     /// # Errors
-    /// - `OptionsError::Overflow` if balance or total supply would overflow
-    pub(crate) fn _mint(
+    /// - `Unauthorized`: Caller is not the owner
+    /// - `FeeTooHigh`: Fee exceeds `MAX_FEE_BPS`
+    /// - `InvalidRecipient`: Fee is non-zero and recipient is the zero address
+    pub fn set_fee(&mut self, fee_bps: U256, recipient: Address) -> Result<(), OptionsError> {
+        self.only_owner()?;
+
+        if fee_bps > U256::from(MAX_FEE_BPS) {
+            return Err(OptionsError::FeeTooHigh(FeeTooHigh {
+                fee_bps,
+                max_fee_bps: U256::from(MAX_FEE_BPS),
+            }));
+        }
+
+        if !fee_bps.is_zero() && recipient == Address::ZERO {
+            return Err(OptionsError::InvalidRecipient(InvalidRecipient {}));
+        }
+
+        self.fee_bps.set(fee_bps);
+        self.fee_recipient.set(recipient);
+
+        Ok(())
+    }
+
+    /// Replaces the write fee discount tiers.
+    ///
+    /// A payer whose cumulative written quantity (`write_volume_of`) has reached
+    /// `thresholds[i]` pays `fee_bps[i]` on writes instead of the protocol fee, whichever
+    /// is lower. The highest tier reached applies. Exercise fees are not discounted.
+    /// Passing empty arrays removes all tiers.
+    ///
+    /// # Parameters
+    /// - `thresholds`: Minimum write volume of each tier (18 decimals normalized),
+    ///   strictly increasing
+    /// - `fee_bps`: Write fee of each tier, in basis points (at most `MAX_FEE_BPS`)
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    /// - `BatchLengthMismatch`: `thresholds` and `fee_bps` have different lengths
+    /// - `InvalidFeeTiers`: Thresholds are not strictly increasing or there are more than
+    ///   `MAX_FEE_TIERS`
+    /// - `FeeTooHigh`: A tier fee exceeds `MAX_FEE_BPS`
+    pub fn set_fee_tiers(
         &mut self,
-        to: Address,
-        token_id: B256,
-        quantity: U256,
+        thresholds: Vec<U256>,
+        fee_bps: Vec<U256>,
     ) -> Result<(), OptionsError> {
-        let key = Self::balance_key(to, token_id);
-        let current_balance = self.balances.get(key);
-        let new_balance = current_balance
-            .checked_add(quantity)
-            .ok_or(OptionsError::Overflow(Overflow {}))?;
-        self.balances.insert(key, new_balance);
+        self.only_owner()?;
 
-        let current_supply = self.total_supply.get(token_id);
-        let new_supply = current_supply
-            .checked_add(quantity)
-            .ok_or(OptionsError::Overflow(Overflow {}))?;
-        self.total_supply.insert(token_id, new_supply);
+        if thresholds.len() != fee_bps.len() {
+            return Err(OptionsError::BatchLengthMismatch(BatchLengthMismatch {}));
+        }
+        if thresholds.len() > MAX_FEE_TIERS || thresholds.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(OptionsError::InvalidFeeTiers(InvalidFeeTiers {}));
+        }
+        if let Some(&tier_fee_bps) = fee_bps.iter().find(|&&bps| bps > U256::from(MAX_FEE_BPS)) {
+            return Err(OptionsError::FeeTooHigh(FeeTooHigh {
+                fee_bps: tier_fee_bps,
+                max_fee_bps: U256::from(MAX_FEE_BPS),
+            }));
+        }
+
+        while self.fee_tier_thresholds.pop().is_some() {}
+        while self.fee_tier_bps.pop().is_some() {}
+        for (threshold, tier_fee_bps) in thresholds.into_iter().zip(fee_bps) {
+            self.fee_tier_thresholds.push(threshold);
+            self.fee_tier_bps.push(tier_fee_bps);
+        }
 
         Ok(())
     }
 
-    /// Burns option tokens from an address.
+    /// Approves or revokes a swap router for `exercise_call_via_swap`.
     ///
-    /// Decreases both the holder's balance and the token's total supply.
-    /// Uses checked arithmetic to prevent underflow.
+    /// Routers are called with arbitrary calldata from this contract, so only routers
+    /// that cannot be made to move this contract's tokens may be approved.
     ///
     /// # Parameters
-    /// - `from`: Token holder address
-    /// - `token_id`: ERC-1155 token ID
-    /// - `quantity`: Amount to burn
+    /// - `router`: Swap router address
+    /// - `approved`: Whether the router may be used
     ///
     /// # Errors
-    /// - `OptionsError::InsufficientBalance` if balance < quantity
-    pub(crate) fn _burn(
+    /// - `Unauthorized`: Caller is not the owner
+    pub fn set_router_approval(
         &mut self,
-        from: Address,
-        token_id: B256,
-        quantity: U256,
+        router: Address,
+        approved: bool,
     ) -> Result<(), OptionsError> {
-        let key = Self::balance_key(from, token_id);
-        let current_balance = self.balances.get(key);
+        self.only_owner()?;
+        self.approved_routers.insert(router, approved);
+        Ok(())
+    }
 
-        if current_balance < quantity {
-            return Err(OptionsError::InsufficientBalance(InsufficientBalance {
-                available: current_balance,
-                requested: quantity,
+    /// Sets the keeper incentive and intrinsic value threshold of `auto_exercise`.
+    ///
+    /// # Parameters
+    /// - `keeper_fee_bps`: Share of each payout paid to the keeper (at most
+    ///   `MAX_KEEPER_FEE_BPS`)
+    /// - `threshold`: Intrinsic value per option that settlement must exceed (18 decimals
+    ///   normalized quote)
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    /// - `FeeTooHigh`: Keeper fee exceeds `MAX_KEEPER_FEE_BPS`
+    pub fn set_auto_exercise_params(
+        &mut self,
+        keeper_fee_bps: U256,
+        threshold: U256,
+    ) -> Result<(), OptionsError> {
+        self.only_owner()?;
+
+        if keeper_fee_bps > U256::from(MAX_KEEPER_FEE_BPS) {
+            return Err(OptionsError::FeeTooHigh(FeeTooHigh {
+                fee_bps: keeper_fee_bps,
+                max_fee_bps: U256::from(MAX_KEEPER_FEE_BPS),
             }));
         }
 
-        let new_balance = current_balance
-            .checked_sub(quantity)
-            .ok_or(OptionsError::Overflow(Overflow {}))?;
-        self.balances.insert(key, new_balance);
+        self.keeper_fee_bps.set(keeper_fee_bps);
+        self.auto_exercise_threshold.set(threshold);
 
-        let current_supply = self.total_supply.get(token_id);
-        let new_supply = current_supply
-            .checked_sub(quantity)
-            .ok_or(OptionsError::Overflow(Overflow {}))?;
-        self.total_supply.insert(token_id, new_supply);
+        Ok(())
+    }
+
+    /// Sets the treasury cut of forfeited cash call value.
+    ///
+    /// Holders of a settled cash call can exercise it for `FORFEITURE_DELAY` after
+    /// expiry. While the cut is non-zero, `collect_forfeited_value` can afterwards end
+    /// the series' exercises and pay `treasury_bps` of the outstanding options' value to
+    /// `treasury`; the rest goes to the writers. A zero cut disables forfeiture, so
+    /// holders can exercise indefinitely.
+    ///
+    /// # Parameters
+    /// - `treasury_bps`: Treasury cut in basis points (at most `BPS_DENOMINATOR`)
+    /// - `treasury`: Address receiving the cut
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    /// - `FeeTooHigh`: Cut exceeds `BPS_DENOMINATOR`
+    /// - `InvalidRecipient`: Cut is non-zero and treasury is the zero address
+    pub fn set_treasury_policy(
+        &mut self,
+        treasury_bps: U256,
+        treasury: Address,
+    ) -> Result<(), OptionsError> {
+        self.only_owner()?;
+
+        if treasury_bps > U256::from(BPS_DENOMINATOR) {
+            return Err(OptionsError::FeeTooHigh(FeeTooHigh {
+                fee_bps: treasury_bps,
+                max_fee_bps: U256::from(BPS_DENOMINATOR),
+            }));
+        }
+
+        if !treasury_bps.is_zero() && treasury == Address::ZERO {
+            return Err(OptionsError::InvalidRecipient(InvalidRecipient {}));
+        }
+
+        self.treasury_bps.set(treasury_bps);
+        self.treasury.set(treasury);
 
         Ok(())
     }
 
-    /// Returns the balance of an account for a specific token.
+    /// Sets the maximum age of an oracle price accepted for cash settlement.
     ///
     /// # Parameters
-    /// - `owner`: Token holder address
-    /// - `token_id`: ERC-1155 token ID
+    /// - `max_staleness`: Maximum price age in seconds (0 disables the check)
     ///
-    /// # Returns
-    /// Token balance (0 if no balance exists)
-    #[must_use]
-    pub fn balance_of(&self, owner: Address, token_id: B256) -> U256 {
-        let key = Self::balance_key(owner, token_id);
-        self.balances.get(key)
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    pub fn set_max_settlement_staleness(&mut self, max_staleness: u64) -> Result<(), OptionsError> {
+        self.only_owner()?;
+        self.max_settlement_staleness.set(U64::from(max_staleness));
+        Ok(())
     }
 
-    /// Returns the total supply of a token.
+    /// Sets how far in the future a written series may expire.
+    ///
+    /// Writes (including rolls) with an expiry after `block.timestamp + horizon` revert
+    /// with `ExpiryTooFar`. Existing series are unaffected.
     ///
     /// # Parameters
-    /// - `token_id`: ERC-1155 token ID
+    /// - `horizon`: Maximum expiry in seconds from now (0 restores
+    ///   `DEFAULT_MAX_EXPIRY_HORIZON`)
     ///
-    /// # Returns
-    /// Total supply (0 if no tokens minted)
-    #[allow(dead_code)] // TODO: Remove when used in Issue #11 (Full ERC-1155)
-    pub(crate) fn total_supply_of(&self, token_id: B256) -> U256 {
-        self.total_supply.get(token_id)
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    pub fn set_max_expiry_horizon(&mut self, horizon: u64) -> Result<(), OptionsError> {
+        self.only_owner()?;
+        self.max_expiry_horizon.set(U64::from(horizon));
+        Ok(())
     }
 
-    /// Safely transfers ERC20 tokens with fee-on-transfer detection.
+    /// Sets the granularity written strikes must be a multiple of.
     ///
-    /// Checks the recipient's balance before and after transfer to ensure the full
-    /// amount was received. This prevents fee-on-transfer tokens from breaking
-    /// collateral accounting.
+    /// Writes (including rolls) with a strike that is not a multiple of a non-zero tick
+    /// revert with `InvalidStrikeTick`, so series of one pair aggregate on fewer strikes.
+    /// Existing series are unaffected.
     ///
     /// # Parameters
-    /// - `token`: ERC20 token contract address
-    /// - `from`: Address to transfer from (requires prior approval)
-    /// - `to`: Recipient address
-    /// - `amount`: Amount to transfer
+    /// - `tick`: Strike granularity (18 decimals normalized, 0 disables the check)
     ///
     /// # Errors
-    /// - `TransferFailed`: ERC20 transferFrom call failed
-    /// - `FeeOnTransferDetected`: Received amount doesn't match requested amount
-    /// - `UnexpectedBalanceDecrease`: Balance decreased instead of increased
-    #[allow(deprecated)]
-    pub(crate) fn safe_transfer_from(
-        &mut self,
-        token: Address,
-        from: Address,
-        to: Address,
-        amount: U256,
-    ) -> Result<(), OptionsError> {
-        let erc20 = IERC20::new(token);
+    /// - `Unauthorized`: Caller is not the owner
+    pub fn set_strike_tick(&mut self, tick: U256) -> Result<(), OptionsError> {
+        self.only_owner()?;
+        self.strike_tick.set(tick);
+        Ok(())
+    }
+
+    /// Enables or disables strict decimals checking on exercise.
+    ///
+    /// When enabled, exercise re-reads `decimals()` from the underlying and quote tokens
+    /// and reverts if either differs from the decimals stored when the series was
+    /// written, instead of settling with stale normalization. Disabled by default.
+    ///
+    /// # Parameters
+    /// - `enabled`: Whether to check decimals on exercise
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    pub fn set_strict_decimals(&mut self, enabled: bool) -> Result<(), OptionsError> {
+        self.only_owner()?;
+        self.strict_decimals.set(enabled);
+        Ok(())
+    }
+
+    /// Transfers a token's surplus balance, above all collateral locked in it, to `to`.
+    ///
+    /// Lets the operator recover tokens sent to the contract by mistake without being
+    /// able to touch collateral backing outstanding options.
+    ///
+    /// # Parameters
+    /// - `token`: ERC20 token to drain
+    /// - `to`: Address receiving the surplus
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner
+    /// - `InvalidRecipient`: `to` is the zero address
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `InsufficientSurplus`: Balance does not exceed the locked collateral
+    /// - `TransferFailed`: ERC20 call failed
+    /// - `FeeOnTransferDetected`: Token deducts fees during transfer
+    #[allow(deprecated)]
+    pub fn owner_drain(&mut self, token: Address, to: Address) -> Result<(), OptionsError> {
+        self.only_owner()?;
+
+        if to == Address::ZERO {
+            return Err(OptionsError::InvalidRecipient(InvalidRecipient {}));
+        }
+
+        self.non_reentrant(|this| {
+            let contract_addr = this.vm().contract_address();
+            let balance = IERC20::new(token)
+                .balance_of(Call::new_in(this), contract_addr)
+                .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+            let locked = this.locked_collateral.get(token);
+
+            if balance <= locked {
+                return Err(OptionsError::InsufficientSurplus(InsufficientSurplus {
+                    balance,
+                    locked,
+                }));
+            }
+
+            let surplus = balance - locked;
+            this.safe_transfer(token, to, surplus)?;
+
+            log(
+                this.vm(),
+                SurplusDrained {
+                    token,
+                    to,
+                    amount: surplus,
+                },
+            );
+
+            Ok(())
+        })
+    }
+
+    /// Writes a call option by locking underlying tokens as collateral (1:1).
+    ///
+    /// Mints ERC-1155 tokens representing the call option and returns a deterministic token ID
+    /// based on the option parameters (keccak256 hash). If a protocol fee is set, the writer
+    /// additionally pays `quantity * fee_bps / 10_000` underlying to the fee recipient.
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp (Unix seconds)
+    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    ///
+    /// # Returns
+    /// Token ID (B256) representing this option series
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `Paused`: Contract is paused
+    /// - `InvalidStrike`: Strike price is zero
+    /// - `StrikeTooLarge`: Strike exceeds `MAX_STRIKE` or the series notional would overflow
+    /// - `ExpiredOption`: Expiry is not in the future
+    /// - `InvalidQuantity`: Quantity is zero
+    /// - `ZeroAddress`: Underlying or quote address is zero
+    /// - `SameToken`: Underlying and quote addresses are identical
+    /// - `InvalidDecimals`: Token decimals exceed 18
+    /// - `DecimalsMismatch`: First write of a series and declared decimals differ from
+    ///   the token's `decimals()`
+    /// - `DecimalsUnavailable`: First write of a series and a token has no `decimals()`
+    /// - `NormalizationOverflow`: Amount normalization would overflow
+    /// - `Overflow`: Position or balance accumulation would overflow
+    /// - `FeeOnTransferDetected`: Underlying token deducts fees during transfer
+    /// - `TransferFailed`: ERC20 transfer failed
+    /// - `ERC1155ReceiverRejected`: `writer` is a contract that does not accept the minted
+    ///   option tokens
+    pub fn write_call_option(
+        &mut self,
+        strike: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+    ) -> Result<B256, OptionsError> {
+        let writer = self.vm().msg_sender();
+        self.non_reentrant(|this| {
+            this.write_call(
+                strike,
+                expiry,
+                quantity,
+                underlying,
+                quote,
+                Address::ZERO,
+                writer,
+                ExerciseStyle::American,
+            )
+        })
+    }
+
+    /// Writes a European call option by locking underlying tokens as collateral (1:1).
+    ///
+    /// Behaves like `write_call_option`, but the options can only be exercised within
+    /// `EUROPEAN_EXERCISE_WINDOW` of expiry. The exercise style is part of the token ID,
+    /// so European series are distinct from American series with the same parameters.
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp (Unix seconds)
+    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    ///
+    /// # Returns
+    /// Token ID (B256) representing this option series
+    ///
+    /// # Errors
+    /// All errors of `write_call_option`
+    pub fn write_european_call_option(
+        &mut self,
+        strike: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+    ) -> Result<B256, OptionsError> {
+        let writer = self.vm().msg_sender();
+        self.non_reentrant(|this| {
+            this.write_call(
+                strike,
+                expiry,
+                quantity,
+                underlying,
+                quote,
+                Address::ZERO,
+                writer,
+                ExerciseStyle::European,
+            )
+        })
+    }
+
+    /// Writes a call option on behalf of `receiver`, paying the collateral from the caller.
+    ///
+    /// Lets integrators (e.g. a treasury contract building structured products) deposit
+    /// collateral while the option tokens and the writer position belong to an end user.
+    /// Only `receiver` can later exercise against, close or withdraw the position.
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp (Unix seconds)
+    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    /// - `receiver`: Address receiving the option tokens and owning the position
+    ///
+    /// # Returns
+    /// Token ID (B256) representing this option series
+    ///
+    /// # Errors
+    /// - `InvalidRecipient`: Receiver is the zero address
+    /// - All errors of `write_call_option`
+    pub fn write_call_option_for(
+        &mut self,
+        strike: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+        receiver: Address,
+    ) -> Result<B256, OptionsError> {
+        if receiver == Address::ZERO {
+            return Err(OptionsError::InvalidRecipient(InvalidRecipient {}));
+        }
+
+        self.non_reentrant(|this| {
+            this.write_call(
+                strike,
+                expiry,
+                quantity,
+                underlying,
+                quote,
+                Address::ZERO,
+                receiver,
+                ExerciseStyle::American,
+            )
+        })
+    }
+
+    /// Writes a cash-settled call option by locking underlying tokens as collateral (1:1).
+    ///
+    /// Behaves like `write_call_option` but binds the series to a price oracle, which
+    /// enables `exercise_call_cash`. The oracle is part of the token ID, so cash-settled
+    /// series are distinct from physically settled series with the same parameters.
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp (Unix seconds)
+    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    /// - `oracle`: Price oracle used for cash settlement
+    ///
+    /// # Returns
+    /// Token ID (B256) representing this option series
+    ///
+    /// # Errors
+    /// - `InvalidOracle`: Oracle address is zero
+    /// - All errors of `write_call_option`
+    pub fn write_cash_settled_call_option(
+        &mut self,
+        strike: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+        oracle: Address,
+    ) -> Result<B256, OptionsError> {
+        if oracle == Address::ZERO {
+            return Err(OptionsError::InvalidOracle(InvalidOracle {}));
+        }
+
+        let writer = self.vm().msg_sender();
+        self.non_reentrant(|this| {
+            this.write_call(
+                strike,
+                expiry,
+                quantity,
+                underlying,
+                quote,
+                oracle,
+                writer,
+                ExerciseStyle::American,
+            )
+        })
+    }
+
+    /// Writes a cash call: a European call paying its intrinsic value in quote tokens.
+    ///
+    /// The payout per option is capped at `cap`, so the writer locks `cap * quantity` of
+    /// quote tokens (rounded up) instead of the underlying. After expiry anyone can
+    /// record the settlement price with `settle_series`; holders then call
+    /// `exercise_settled_call` and writers reclaim the remainder with
+    /// `withdraw_settled_collateral`. The settlement type, cap and oracle are part of the
+    /// token ID. No protocol fee is charged.
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `cap`: Maximum payout per option (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp (Unix seconds)
+    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    /// - `oracle`: Price oracle used for settlement
+    ///
+    /// # Returns
+    /// Token ID (B256) representing this option series
+    ///
+    /// # Errors
+    /// - `InvalidOracle`: Oracle address is zero
+    /// - `InvalidCap`: Cap is zero
+    /// - `StrikeTooLarge`: Cap exceeds `MAX_STRIKE` or the capped notional would overflow
+    /// - All errors of `write_call_option`
+    #[allow(clippy::too_many_arguments)]
+    pub fn write_cash_call_option(
+        &mut self,
+        strike: U256,
+        cap: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+        oracle: Address,
+    ) -> Result<B256, OptionsError> {
+        if oracle == Address::ZERO {
+            return Err(OptionsError::InvalidOracle(InvalidOracle {}));
+        }
+
+        self.non_reentrant(|this| {
+            this.write_cash_call(strike, cap, expiry, quantity, underlying, quote, oracle)
+        })
+    }
+
+    /// Writes a call option whose collateral is deposited into a per-series vault.
+    ///
+    /// Pulls `quantity` of underlying from the caller and deposits it into `vault` on
+    /// their behalf, so the writer receives vault shares and a FIFO deposit checkpoint
+    /// instead of a position in this contract. The vault must be initialized with this
+    /// contract as its options contract, the series token ID (see
+    /// `generate_vault_token_id`), the series underlying and quote as its asset and
+    /// strike asset, and the series expiry, so each vault backs exactly one series. The
+    /// vault is part of the token ID.
+    ///
+    /// Exercising a vault-backed series pays the strike into the vault, which then
+    /// delivers the underlying; writers collect strike payments and unassigned
+    /// collateral from the vault after expiry with `claim`. The vault stops exercises at
+    /// expiry, so the settlement grace period does not apply.
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp (Unix seconds)
+    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    /// - `vault`: Vault holding the series collateral
+    ///
+    /// # Returns
+    /// Token ID (B256) representing this option series
+    ///
+    /// # Errors
+    /// - `InvalidVault`: Vault address is zero or it was not initialized for this series
+    ///   (options contract, token ID, asset, strike asset or expiry differ)
+    /// - `VaultCallFailed`: Vault series query or deposit failed
+    /// - All errors of `write_call_option`
+    pub fn write_vault_call_option(
+        &mut self,
+        strike: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+        vault: Address,
+    ) -> Result<B256, OptionsError> {
+        if vault == Address::ZERO {
+            return Err(OptionsError::InvalidVault(InvalidVault { vault }));
+        }
+
+        self.non_reentrant(|this| {
+            this.write_vault_call(strike, expiry, quantity, underlying, quote, vault)
+        })
+    }
+
+    /// Writes a put option by locking quote tokens as collateral (strike * quantity).
+    ///
+    /// Mints ERC-1155 tokens representing the put option and returns a deterministic token ID
+    /// based on the option parameters (keccak256 hash).
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp (Unix seconds)
+    /// - `quantity`: Quantity of options to write
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    ///
+    /// # Errors
+    /// Returns `OptionsError::Unimplemented` (stub implementation).
+    pub fn write_put_option(
+        &mut self,
+        strike: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+    ) -> Result<B256, OptionsError> {
+        let _ = (strike, expiry, quantity, underlying, quote);
+        Err(OptionsError::Unimplemented(Unimplemented {}))
+    }
+
+    /// Writes a put option on behalf of `receiver`, paying the collateral from the caller.
+    ///
+    /// Put counterpart of `write_call_option_for`.
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp (Unix seconds)
+    /// - `quantity`: Quantity of options to write
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    /// - `receiver`: Address receiving the option tokens and owning the position
+    ///
+    /// # Errors
+    /// - `InvalidRecipient`: Receiver is the zero address
+    /// - `Unimplemented`: Stub implementation
+    pub fn write_put_option_for(
+        &mut self,
+        strike: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+        receiver: Address,
+    ) -> Result<B256, OptionsError> {
+        if receiver == Address::ZERO {
+            return Err(OptionsError::InvalidRecipient(InvalidRecipient {}));
+        }
+
+        let _ = (strike, expiry, quantity, underlying, quote);
+        Err(OptionsError::Unimplemented(Unimplemented {}))
+    }
+
+    /// Writes several option series in one transaction.
+    ///
+    /// Entry `i` writes `quantities[i]` options with `strikes[i]`, `expiries[i]` and
+    /// `option_types[i]` on the shared underlying/quote pair, exactly as
+    /// `write_call_option` would. Collateral for all entries is pulled with a single
+    /// transfer of the aggregate amount, and one `OptionWritten` is emitted per entry.
+    /// The batch is atomic: any invalid entry reverts the whole batch.
+    ///
+    /// # Parameters
+    /// - `strikes`: Strike prices (18 decimals normalized)
+    /// - `expiries`: Expiration timestamps (Unix seconds)
+    /// - `quantities`: Quantities to write (in underlying token's native decimals)
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    /// - `option_types`: Option types (0 = Call, 1 = Put)
+    ///
+    /// # Returns
+    /// Token IDs of the written series, in entry order
+    ///
+    /// # Errors
+    /// - `BatchLengthMismatch`: Parameter arrays have different lengths
+    /// - `InvalidBatchSize`: Batch is empty or larger than `MAX_BATCH_SIZE`
+    /// - `WrongOptionType`: Option type is neither call nor put
+    /// - `Unimplemented`: Entry is a put (put writing is not implemented)
+    /// - All errors of `write_call_option`
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn batch_write_options(
+        &mut self,
+        strikes: Vec<U256>,
+        expiries: Vec<u64>,
+        quantities: Vec<U256>,
+        underlying: Token,
+        quote: Token,
+        option_types: Vec<u8>,
+    ) -> Result<Vec<B256>, OptionsError> {
+        self.non_reentrant(|this| {
+            this.write_batch(
+                &strikes,
+                &expiries,
+                &quantities,
+                underlying,
+                quote,
+                &option_types,
+            )
+        })
+    }
+
+    /// Writes calls of one series covered by long calls of a higher strike (a call spread).
+    ///
+    /// Instead of locking underlying 1:1, the caller escrows `quantity` of their
+    /// `buy_token_id` long calls and locks only the max loss
+    /// `(buy_strike - sell_strike) * quantity` in quote tokens. The calls are minted as
+    /// a series of their own, with the terms of `sell_token_id` and the token ID
+    /// `keccak256(sell_token_id, "spread", buy_token_id)`, so they are never exercised
+    /// against the underlying of fully collateralized writers, nor the other way round.
+    /// Writers of the same spread share its assignment pool like writers of any series.
+    ///
+    /// Exercising a spread token exercises one escrowed long call against the long
+    /// series' writers to deliver the underlying; the spread writers' quote collateral
+    /// pays the long strike down to the short strike (see `exercise_call`). Unexercised
+    /// spreads return the quote collateral and the escrowed long calls through
+    /// `close_position` or `withdraw_expired_collateral`.
+    ///
+    /// Both series must already exist and share underlying, quote, expiry, settlement and
+    /// exercise style.
+    ///
+    /// # Parameters
+    /// - `sell_token_id`: Token ID of the call series to write (lower strike)
+    /// - `buy_token_id`: Token ID of the long call series held by the caller (higher strike)
+    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+    ///
+    /// # Returns
+    /// Token ID of the spread series
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `Paused`: Contract is paused
+    /// - `OptionNotFound`: Either series has not been written
+    /// - `WrongOptionType`: Either series is not a call
+    /// - `InvalidSpread`: Series differ in underlying, quote, expiry, oracle or exercise
+    ///   style, either is vault-backed or itself a spread, or the long strike is not above
+    ///   the short strike
+    /// - `ExpiredOption`: Series has expired
+    /// - `InvalidQuantity`: Quantity is zero
+    /// - `InsufficientBalance`: Caller holds fewer long calls than `quantity`
+    /// - `NormalizationOverflow`: Quantity normalization would overflow
+    /// - `Overflow`: Arithmetic overflow during calculation
+    /// - `FeeOnTransferDetected`: Quote token deducts fees during transfer
+    /// - `TransferFailed`: ERC20 transfer failed
+    pub fn write_call_spread(
+        &mut self,
+        sell_token_id: B256,
+        buy_token_id: B256,
+        quantity: U256,
+    ) -> Result<B256, OptionsError> {
+        self.non_reentrant(|this| this.write_spread(sell_token_id, buy_token_id, quantity))
+    }
+
+    /// Exercises a call option.
+    ///
+    /// Immediate atomic settlement following checks-effects-interactions pattern:
+    /// 1. Validates exercise conditions (holder balance, expiry, option type)
+    /// 2. Burns option tokens from holder
+    /// 3. Assigns the exercise pro rata across all writers of the series through its
+    ///    assignment pool: each position is reduced by its share and the matching strike
+    ///    payment credited to the writer when the position is next settled (see
+    ///    `settle_assignment`), claimable after expiry with `claim_assignment`
+    /// 4. Pulls the strike owed to other writers from the holder and transfers the
+    ///    underlying to the holder
+    ///
+    /// Use `exercise_call_to` to deliver the underlying to another address.
+    ///
+    /// A holder who is also a writer pays no strike for their own share.
+    ///
+    /// Call spread series (`write_call_spread`) are assigned across the spread's writers,
+    /// whose share of the quote collateral is released, and the same quantity of
+    /// escrowed long calls is exercised against the long series' writers to deliver the
+    /// underlying. The holder pays the long strike less the released collateral, which
+    /// is the spread's strike up to rounding.
+    ///
+    /// Vault-backed series (`write_vault_call_option`) can be exercised by any holder:
+    /// the strike payment is pulled from the holder into the series vault and the vault
+    /// delivers the underlying via `exerciseWithdraw`.
+    ///
+    /// Protocol fee: `quantity * fee_bps / 10_000` of the released underlying goes to the
+    /// fee recipient and the rest to the holder.
+    ///
+    /// Fee-on-transfer behavior: If underlying token becomes fee-on-transfer
+    /// after writing, holder receives less tokens on exercise. This doesn't
+    /// revert - holder accepts the loss rather than being unable to exercise.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the call option (keccak256 hash)
+    /// - `quantity`: Quantity of options to exercise (in underlying token's native decimals,
+    ///   the same units as `write_call_option`)
+    ///
+    /// # Returns
+    /// - `Ok(())` on successful exercise
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `Paused`: Contract is paused
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `ExerciseAfterExpiry`: Current time >= option expiry + `SETTLEMENT_GRACE_PERIOD`
+    /// - `ExerciseWindowClosed`: European option outside its exercise window
+    /// - `WrongOptionType`: Token ID represents a put option, not call
+    /// - `InvalidQuantity`: Quantity is zero
+    /// - `InsufficientBalance`: Holder doesn't have enough option tokens
+    /// - `DecimalsChanged`: Strict decimals is on and a token's decimals changed since writing
+    /// - `DecimalsUnavailable`: Strict decimals is on and a token's `decimals()` query failed
+    /// - `NormalizationOverflow`: Quantity normalization would overflow
+    /// - `TransferFailed`: ERC20 transfer failed, including the strike payment
+    /// - `FeeOnTransferDetected`: Quote token deducts fees from the strike payment
+    /// - `VaultCallFailed`: The series vault rejected the withdrawal (e.g. after expiry)
+    /// - `Overflow`: Arithmetic overflow during calculation
+    ///
+    /// # Example
+    /// ```ignore
+    /// // Writer exercises half of their own 1 WBTC (8 decimals) call option
+    /// let token_id = contract.write_call_option(strike, expiry, U256::from(100_000_000), underlying, quote)?;
+    /// contract.exercise_call(token_id, U256::from(50_000_000))?;
+    /// ```
+    pub fn exercise_call(&mut self, token_id: B256, quantity: U256) -> Result<(), OptionsError> {
+        let holder = self.vm().msg_sender();
+        self.non_reentrant(|this| this.settle_call_exercise(token_id, quantity, holder, U256::ZERO))
+    }
+
+    /// Exercises a call option, reverting if the caller receives too little underlying.
+    ///
+    /// Behaves like `exercise_call` but measures the underlying the caller actually
+    /// receives, so holders can refuse an exercise made lossy by an underlying that
+    /// became fee-on-transfer after writing.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the call option (keccak256 hash)
+    /// - `quantity`: Quantity of options to exercise (in underlying token's native decimals)
+    /// - `min_received`: Minimum underlying the caller must receive (native decimals)
+    ///
+    /// # Errors
+    /// - `FeeOnTransferDetected`: Caller would receive less than `min_received`
+    /// - `UnexpectedBalanceDecrease`: Caller's underlying balance decreased
+    /// - All errors of `exercise_call`
+    pub fn exercise_call_strict(
+        &mut self,
+        token_id: B256,
+        quantity: U256,
+        min_received: U256,
+    ) -> Result<(), OptionsError> {
+        let holder = self.vm().msg_sender();
+        self.non_reentrant(|this| {
+            this.settle_call_exercise(token_id, quantity, holder, min_received)
+        })
+    }
+
+    /// Exercises a vault-backed call, paying the strike out of the underlying received.
+    ///
+    /// For holders without quote capital: the vault releases the underlying to this
+    /// contract, `router` is approved for exactly that amount and called with
+    /// `swap_calldata`, which must swap enough of it into quote tokens sent back to this
+    /// contract. The strike payment goes to the vault, and any surplus quote plus the
+    /// underlying left over go to the caller.
+    ///
+    /// Only owner-approved routers can be used. Balances of this contract are measured
+    /// around the swap, so the router can spend at most the released underlying and
+    /// cannot take quote tokens. The router's approval is reset afterwards.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the vault-backed call
+    /// - `quantity`: Quantity of options to exercise (in underlying token's native decimals)
+    /// - `router`: Approved swap router
+    /// - `swap_calldata`: Calldata of the router swap
+    /// - `min_underlying_out`: Minimum underlying the caller must keep after the swap
+    ///   (native decimals)
+    ///
+    /// # Returns
+    /// Underlying sent to the caller (native decimals)
+    ///
+    /// # Errors
+    /// - `RouterNotApproved`: `router` is not approved by the owner
+    /// - `InvalidVault`: Series is not vault-backed
+    /// - `SwapFailed`: The router call reverted
+    /// - `InsufficientSwapOutput`: The swap returned less quote than the strike payment
+    /// - `SlippageExceeded`: Less than `min_underlying_out` underlying is left
+    /// - `UnexpectedBalanceDecrease`: The router took more than the released underlying
+    ///   or took quote tokens
+    /// - All errors of `exercise_call`
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn exercise_call_via_swap(
+        &mut self,
+        token_id: B256,
+        quantity: U256,
+        router: Address,
+        swap_calldata: Bytes,
+        min_underlying_out: U256,
+    ) -> Result<U256, OptionsError> {
+        self.non_reentrant(|this| {
+            this.settle_swap_exercise(
+                token_id,
+                quantity,
+                router,
+                &swap_calldata,
+                min_underlying_out,
+            )
+        })
+    }
+
+    /// Exercises a call option, delivering the underlying to `recipient`.
+    ///
+    /// Behaves exactly like `exercise_call` (option tokens are burned from and the
+    /// position reduced for the caller) except that the underlying tokens are sent
+    /// to `recipient`, e.g. when exercising through a smart-contract wallet.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the call option (keccak256 hash)
+    /// - `quantity`: Quantity of options to exercise (in underlying token's native decimals)
+    /// - `recipient`: Address receiving the underlying tokens
+    ///
+    /// # Errors
+    /// - `InvalidRecipient`: Recipient is the zero address
+    /// - All errors of `exercise_call`
+    pub fn exercise_call_to(
+        &mut self,
+        token_id: B256,
+        quantity: U256,
+        recipient: Address,
+    ) -> Result<(), OptionsError> {
+        if recipient == Address::ZERO {
+            return Err(OptionsError::InvalidRecipient(InvalidRecipient {}));
+        }
+
+        self.non_reentrant(|this| {
+            this.settle_call_exercise(token_id, quantity, recipient, U256::ZERO)
+        })
+    }
+
+    /// Exercises options across several series in one transaction.
+    ///
+    /// Each leg is validated and settled exactly like `exercise_call`, in order, so
+    /// duplicate token IDs accumulate. Underlying payouts are aggregated into a single
+    /// transfer per distinct token and recipient. The batch is atomic: any failing
+    /// leg reverts the whole batch. Emits one `ExerciseCall` per leg and a
+    /// `BatchExercised` summary.
+    ///
+    /// As with `exercise_call`, each leg is assigned pro rata across its writers; the
+    /// strike owed to other writers is pulled once per leg.
+    ///
+    /// # Parameters
+    /// - `token_ids`: Token IDs of the series to exercise
+    /// - `quantities`: Quantities to exercise (in each underlying token's native decimals)
+    ///
+    /// # Errors
+    /// - `BatchLengthMismatch`: Parameter arrays have different lengths
+    /// - `InvalidBatchSize`: Batch is empty or larger than `MAX_BATCH_SIZE`
+    /// - `Unimplemented`: A leg is a put (put exercise is not implemented)
+    /// - All errors of `exercise_call`
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn batch_exercise(
+        &mut self,
+        token_ids: Vec<B256>,
+        quantities: Vec<U256>,
+    ) -> Result<(), OptionsError> {
+        self.non_reentrant(|this| this.exercise_batch(&token_ids, &quantities))
+    }
+
+    /// Exercises a cash-settled call option against the series' price oracle.
+    ///
+    /// Instead of paying the strike for the full underlying amount, the holder
+    /// receives only the intrinsic value `(spot - strike) * quantity`, paid out of the
+    /// locked collateral as the underlying amount worth that value at the spot price.
+    ///
+    /// As with `exercise_call`, the exercise is assigned pro rata across all writers of
+    /// the series (see `settle_assignment`). The rest of the assigned collateral stays
+    /// with the writers: it is added back to their positions when they are next settled,
+    /// and a holder who is also a writer receives their own share right away.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the cash-settled call option
+    /// - `quantity`: Quantity of options to exercise (in underlying token's native decimals)
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `Paused`: Contract is paused
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `NotCashSettled`: Series has no settlement oracle
+    /// - `InvalidSpread`: Series is a call spread
+    /// - `ExerciseAfterExpiry`: Current time >= option expiry + `SETTLEMENT_GRACE_PERIOD`
+    /// - `ExerciseWindowClosed`: European option outside its exercise window
+    /// - `WrongOptionType`: Token ID represents a put option, not call
+    /// - `InvalidQuantity`: Quantity is zero
+    /// - `InsufficientBalance`: Holder doesn't have enough option tokens
+    /// - `DecimalsChanged`: Strict decimals is on and a token's decimals changed since writing
+    /// - `DecimalsUnavailable`: Strict decimals is on and a token's `decimals()` query failed
+    /// - `OracleCallFailed`: Oracle query failed
+    /// - `InvalidDecimals`: Oracle reports more than 18 decimals
+    /// - `StaleSettlementPrice`: Oracle price is older than `max_settlement_staleness`
+    /// - `NotInTheMoney`: Spot price is at or below the strike price
+    /// - `NormalizationOverflow`: Quantity normalization would overflow
+    /// - `TransferFailed`: ERC20 transfer failed
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub fn exercise_call_cash(
+        &mut self,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        self.non_reentrant(|this| {
+            this.when_not_paused()?;
+
+            let holder = this.vm().msg_sender();
+            let current_time = this.vm().block_timestamp();
+
+            let metadata = this.get_option_metadata(token_id);
+            if metadata.expiry.is_zero() {
+                return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+            }
+            if metadata.oracle == Address::ZERO {
+                return Err(OptionsError::NotCashSettled(NotCashSettled {}));
+            }
+            // A spread's collateral is quote, not the underlying paid out here
+            if this.spread_long_token_id.get(token_id) != B256::ZERO {
+                return Err(OptionsError::InvalidSpread(InvalidSpread {}));
+            }
+
+            let normalized_quantity = normalize_amount(quantity, metadata.underlying_decimals)?;
+
+            this.validate_call_exercise(holder, token_id, normalized_quantity, current_time)?;
+            this.check_decimals_unchanged(&metadata)?;
+
+            let spot = this.oracle_price(&metadata, current_time)?;
+
+            let (intrinsic_value, payout) =
+                cash_settlement_amounts(spot, metadata.strike, normalized_quantity)?;
+
+            this._burn(holder, token_id, normalized_quantity)?;
+
+            let (_, collateral_returned) = this.assign_exercise(
+                holder,
+                token_id,
+                normalized_quantity,
+                U256::ZERO,
+                Some(payout),
+            )?;
+
+            let new_total_exercised = this
+                .total_exercised
+                .get(token_id)
+                .checked_add(normalized_quantity)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+            this.total_exercised.insert(token_id, new_total_exercised);
+
+            let underlying_paid = denormalize_amount(payout, metadata.underlying_decimals)?;
+            let underlying_returned =
+                denormalize_amount(collateral_returned, metadata.underlying_decimals)?;
+            let holder_amount = underlying_paid
+                .checked_add(underlying_returned)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+            this.release_collateral(metadata.underlying, holder_amount)?;
+            if !holder_amount.is_zero() {
+                this.transfer_token(metadata.underlying, holder, holder_amount)?;
+            }
+
+            log(
+                this.vm(),
+                CashExerciseCall {
+                    holder,
+                    tokenId: token_id,
+                    quantity,
+                    settlementPrice: spot,
+                    intrinsicValue: intrinsic_value,
+                    underlyingPaid: underlying_paid,
+                },
+            );
+
+            Ok(())
+        })
+    }
+
+    /// Exercises the whole cash call balance of each listed holder on their behalf.
+    ///
+    /// Callable by anyone once the settlement price is recorded, so holders who forget to
+    /// exercise still receive their payout. Only runs if the settled intrinsic value per
+    /// option, `min(settlement_price - strike, cap)`, exceeds `auto_exercise_threshold`.
+    /// Each holder is credited their settlement value minus `keeper_fee_bps` of it, to
+    /// withdraw with `claim_cash`, and the caller receives the fees in one transfer.
+    /// Nothing is sent to holders, so the gas does not grow with token transfers to them
+    /// and a holder the quote token refuses to pay cannot block the batch. Holders with
+    /// no balance (including repeated entries) are skipped. Emits `AutoExercised` per
+    /// exercised holder.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the cash call series
+    /// - `holders`: Holders to exercise for (at most `MAX_BATCH_SIZE`)
+    ///
+    /// # Returns
+    /// Total keeper fee paid to the caller (quote token's native decimals)
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `Paused`: Contract is paused
+    /// - `InvalidBatchSize`: `holders` is empty or longer than `MAX_BATCH_SIZE`
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `WrongSettlementType`: Series is not a cash call
+    /// - `SettlementPriceRequired`: `settle_series` has not been called yet
+    /// - `ExerciseAfterExpiry`: The series' unexercised value was forfeited (see
+    ///   `collect_forfeited_value`)
+    /// - `DecimalsChanged`: Strict decimals is on and a token's decimals changed since writing
+    /// - `DecimalsUnavailable`: Strict decimals is on and a token's `decimals()` query failed
+    /// - `BelowAutoExerciseThreshold`: Intrinsic value does not exceed the threshold
+    /// - `TransferFailed`: ERC20 transfer failed
+    /// - `Overflow`: Arithmetic overflow during calculation
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn auto_exercise(
+        &mut self,
+        token_id: B256,
+        holders: Vec<Address>,
+    ) -> Result<U256, OptionsError> {
+        self.non_reentrant(|this| this.auto_exercise_holders(token_id, &holders))
+    }
+
+    /// Withdraws the caller's cash settlement credited by `auto_exercise`.
+    ///
+    /// Never paused and unaffected by `collect_forfeited_value`: the options were already
+    /// exercised, so the credit belongs to the holder. Emits `CashClaimed`.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the cash call series
+    ///
+    /// # Returns
+    /// Amount paid to the caller (quote token's native decimals)
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `InvalidQuantity`: The caller has nothing to claim for the series
+    /// - `TransferFailed`: ERC20 transfer failed
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub fn claim_cash(&mut self, token_id: B256) -> Result<U256, OptionsError> {
+        self.non_reentrant(|this| {
+            let holder = this.vm().msg_sender();
+            let amount = this.claimable_cash.getter(holder).get(token_id);
+            if amount.is_zero() {
+                return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+            }
+
+            let quote = this.get_option_metadata(token_id).quote;
+            this.claimable_cash
+                .setter(holder)
+                .insert(token_id, U256::ZERO);
+            this.release_collateral(quote, amount)?;
+            this.transfer_token(quote, holder, amount)?;
+
+            log(
+                this.vm(),
+                CashClaimed {
+                    holder,
+                    tokenId: token_id,
+                    amount,
+                },
+            );
+
+            Ok(amount)
+        })
+    }
+
+    /// Records the settlement price of an oracle-bound series.
+    ///
+    /// Callable by anyone from expiry on. Reads the series oracle once and stores the
+    /// normalized price immutably; for cash calls it is then fixed for every
+    /// `exercise_settled_call` and `withdraw_settled_collateral`.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of a cash call or cash-settled series
+    ///
+    /// # Returns
+    /// The recorded settlement price (18 decimals normalized)
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `NotCashSettled`: Series has no settlement oracle
+    /// - `AlreadySettled`: Settlement price has already been recorded
+    /// - `SettlementBeforeExpiry`: Current time < expiry
+    /// - `OracleCallFailed`: Oracle query failed or returned a zero price
+    /// - `InvalidDecimals`: Oracle reports more than 18 decimals
+    /// - `StaleSettlementPrice`: Oracle price is older than `max_settlement_staleness`
+    pub fn settle_series(&mut self, token_id: B256) -> Result<U256, OptionsError> {
+        self.non_reentrant(|this| {
+            let current_time = this.vm().block_timestamp();
+
+            let metadata = this.get_option_metadata(token_id);
+            if metadata.expiry.is_zero() {
+                return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+            }
+            if metadata.oracle == Address::ZERO {
+                return Err(OptionsError::NotCashSettled(NotCashSettled {}));
+            }
+            if !metadata.settlement_price.is_zero() {
+                return Err(OptionsError::AlreadySettled(AlreadySettled {
+                    settlement_price: metadata.settlement_price,
+                }));
+            }
+            validate_settlement_time(metadata.expiry, current_time)?;
+
+            let settlement_price = this.oracle_price(&metadata, current_time)?;
+            if settlement_price.is_zero() {
+                return Err(OptionsError::OracleCallFailed(OracleCallFailed {}));
+            }
+
+            this.option_metadata
+                .setter(token_id)
+                .settlement_price
+                .set(settlement_price);
+
+            log(
+                this.vm(),
+                SeriesSettled {
+                    tokenId: token_id,
+                    settlementPrice: settlement_price,
+                },
+            );
+
+            Ok(settlement_price)
+        })
+    }
+
+    /// Exercises cash calls against the series' recorded settlement price.
+    ///
+    /// Burns `quantity` option tokens from the caller and pays
+    /// `min(settlement_price - strike, cap) * quantity` in quote tokens (rounded down)
+    /// out of the series collateral. Writer positions are untouched: writers reclaim what
+    /// is left with `withdraw_settled_collateral`.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the cash call series
+    /// - `quantity`: Quantity of options to exercise (in underlying token's native decimals)
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `Paused`: Contract is paused
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `WrongSettlementType`: Series is not a cash call
+    /// - `SettlementPriceRequired`: `settle_series` has not been called yet
+    /// - `ExerciseAfterExpiry`: The series' unexercised value was forfeited (see
+    ///   `collect_forfeited_value`)
+    /// - `InvalidQuantity`: Quantity is zero
+    /// - `NormalizationOverflow`: Quantity normalization would overflow
+    /// - `InsufficientBalance`: Holder doesn't have enough option tokens
+    /// - `DecimalsChanged`: Strict decimals is on and a token's decimals changed since writing
+    /// - `DecimalsUnavailable`: Strict decimals is on and a token's `decimals()` query failed
+    /// - `NotInTheMoney`: Settlement price is at or below the strike price
+    /// - `TransferFailed`: ERC20 transfer failed
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub fn exercise_settled_call(
+        &mut self,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        self.non_reentrant(|this| {
+            this.when_not_paused()?;
+
+            let holder = this.vm().msg_sender();
+
+            let metadata = this.get_cash_call_metadata(token_id)?;
+            if metadata.settlement_price.is_zero() {
+                return Err(OptionsError::SettlementPriceRequired(
+                    SettlementPriceRequired {},
+                ));
+            }
+            this.ensure_not_forfeited(token_id, metadata.expiry)?;
+
+            if quantity.is_zero() {
+                return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+            }
+
+            let normalized_quantity = normalize_amount(quantity, metadata.underlying_decimals)?;
+
+            let holder_balance = this.balance_of(holder, token_id);
+            if holder_balance < normalized_quantity {
+                return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+                    available: holder_balance,
+                    requested: normalized_quantity,
+                }));
+            }
+
+            this.check_decimals_unchanged(&metadata)?;
+
+            if metadata.settlement_price <= metadata.strike {
+                return Err(OptionsError::NotInTheMoney(NotInTheMoney {
+                    spot: metadata.settlement_price,
+                    strike: metadata.strike,
+                }));
+            }
+
+            let (holder_value, _) = cash_call_settlement(
+                metadata.settlement_price,
+                metadata.strike,
+                metadata.cap,
+                normalized_quantity,
+            )?;
+            let payout = denormalize_amount(holder_value, metadata.quote_decimals)?;
+
+            this._burn(holder, token_id, normalized_quantity)?;
+
+            let new_total_exercised = this
+                .total_exercised
+                .get(token_id)
+                .checked_add(normalized_quantity)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+            this.total_exercised.insert(token_id, new_total_exercised);
+
+            this.release_collateral(metadata.quote, payout)?;
+            if !payout.is_zero() {
+                this.transfer_token(metadata.quote, holder, payout)?;
+            }
+
+            log(
+                this.vm(),
+                SettledCallExercised {
+                    holder,
+                    tokenId: token_id,
+                    quantity,
+                    settlementPrice: metadata.settlement_price,
+                    payout,
+                },
+            );
+
+            Ok(())
+        })
+    }
+
+    /// Withdraws a writer's collateral left over after a cash call series settled.
+    ///
+    /// Closes the caller's whole position and returns
+    /// `(cap - min(settlement_price - strike, cap)) * quantity_written` in quote tokens
+    /// (rounded down). The holders' share stays in the contract for
+    /// `exercise_settled_call`, whether or not it has been exercised yet, unless it was
+    /// forfeited: a writer withdrawing after `collect_forfeited_value` also receives
+    /// their pro rata share of the forfeited value left after the treasury cut.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the cash call series
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `WrongSettlementType`: Series is not a cash call
+    /// - `SettlementPriceRequired`: `settle_series` has not been called yet
+    /// - `InvalidQuantity`: Caller has no position in the series
+    /// - `TransferFailed`: ERC20 transfer failed
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub fn withdraw_settled_collateral(&mut self, token_id: B256) -> Result<(), OptionsError> {
+        self.non_reentrant(|this| {
+            let writer = this.vm().msg_sender();
+
+            let metadata = this.get_cash_call_metadata(token_id)?;
+            if metadata.settlement_price.is_zero() {
+                return Err(OptionsError::SettlementPriceRequired(
+                    SettlementPriceRequired {},
+                ));
+            }
+
+            let (quantity_written, _) = this.get_position(writer, token_id);
+            if quantity_written.is_zero() {
+                return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+            }
+
+            let (_, writer_value) = cash_call_settlement(
+                metadata.settlement_price,
+                metadata.strike,
+                metadata.cap,
+                quantity_written,
+            )?;
+            let forfeited_value = quantity_written
+                .checked_mul(this.forfeited_per_option.get(token_id))
+                .ok_or(OptionsError::Overflow(Overflow {}))?
+                / U256::from(10).pow(U256::from(18));
+            let writer_value = writer_value
+                .checked_add(forfeited_value)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+            let collateral_returned = denormalize_amount(writer_value, metadata.quote_decimals)?;
+
+            this.reduce_position(writer, token_id, quantity_written, false)?;
+
+            this.release_collateral(metadata.quote, collateral_returned)?;
+            if !collateral_returned.is_zero() {
+                this.transfer_token(metadata.quote, writer, collateral_returned)?;
+            }
+
+            log(
+                this.vm(),
+                CollateralWithdrawn {
+                    writer,
+                    tokenId: token_id,
+                    quantity: quantity_written,
+                    collateralReturned: collateral_returned,
+                },
+            );
+
+            Ok(())
+        })
+    }
+
+    /// Collects the value of a settled cash call series' options left unexercised.
+    ///
+    /// Only while the owner has set a treasury cut (see `set_treasury_policy`), and only
+    /// once holders have had `FORFEITURE_DELAY` after expiry to exercise. Ends the
+    /// series' exercises and splits the settlement value of its outstanding options:
+    /// `treasury_bps` of it goes to the treasury and the rest is credited to the writers
+    /// still holding positions, pro rata to their quantity written, paid out by
+    /// `withdraw_settled_collateral`. If every writer has already withdrawn, all of it goes
+    /// to the treasury. Callable by anyone.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the cash call series
+    ///
+    /// # Returns
+    /// Amount paid to the treasury (quote token's native decimals)
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `NoTreasuryPolicy`: The treasury cut is zero
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `WrongSettlementType`: Series is not a cash call
+    /// - `SettlementPriceRequired`: `settle_series` has not been called yet
+    /// - `WithdrawalBeforeSettlement`: Current time < expiry + `FORFEITURE_DELAY`
+    /// - `NotInTheMoney`: Settlement price is at or below the strike price
+    /// - `InvalidQuantity`: No options are outstanding, or their value was already
+    ///   collected
+    /// - `TransferFailed`: ERC20 transfer failed
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub fn collect_forfeited_value(&mut self, token_id: B256) -> Result<U256, OptionsError> {
+        self.non_reentrant(|this| {
+            let current_time = this.vm().block_timestamp();
+
+            let treasury_bps = this.treasury_bps.get();
+            if treasury_bps.is_zero() {
+                return Err(OptionsError::NoTreasuryPolicy(NoTreasuryPolicy {}));
+            }
+
+            let metadata = this.get_cash_call_metadata(token_id)?;
+            if metadata.settlement_price.is_zero() {
+                return Err(OptionsError::SettlementPriceRequired(
+                    SettlementPriceRequired {},
+                ));
+            }
+
+            let available_at = metadata.expiry.saturating_add(U256::from(FORFEITURE_DELAY));
+            if U256::from(current_time) < available_at {
+                return Err(OptionsError::WithdrawalBeforeSettlement(
+                    WithdrawalBeforeSettlement {
+                        available_at,
+                        current: U256::from(current_time),
+                    },
+                ));
+            }
+
+            if metadata.settlement_price <= metadata.strike {
+                return Err(OptionsError::NotInTheMoney(NotInTheMoney {
+                    spot: metadata.settlement_price,
+                    strike: metadata.strike,
+                }));
+            }
+
+            let quantity = this.total_supply.get(token_id);
+            if quantity.is_zero() || this.forfeiture_collected.get(token_id) {
+                return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+            }
+
+            let (holder_value, _) = cash_call_settlement(
+                metadata.settlement_price,
+                metadata.strike,
+                metadata.cap,
+                quantity,
+            )?;
+            let value = denormalize_amount(holder_value, metadata.quote_decimals)?;
+
+            let writers_quantity = this.global_positions.get(token_id).quantity_written.get();
+            let treasury_amount = if writers_quantity.is_zero() {
+                value
+            } else {
+                protocol_fee(value, treasury_bps)?
+            };
+            let writers_amount = value - treasury_amount;
+
+            if !writers_amount.is_zero() {
+                let per_option = normalize_amount(writers_amount, metadata.quote_decimals)?
+                    .checked_mul(U256::from(10).pow(U256::from(18)))
+                    .ok_or(OptionsError::Overflow(Overflow {}))?
+                    / writers_quantity;
+                this.forfeited_per_option.insert(token_id, per_option);
+            }
+            this.forfeiture_collected.insert(token_id, true);
+
+            let treasury = this.treasury.get();
+            this.release_collateral(metadata.quote, treasury_amount)?;
+            if !treasury_amount.is_zero() {
+                this.transfer_token(metadata.quote, treasury, treasury_amount)?;
+            }
+
+            log(
+                this.vm(),
+                ForfeitedValueCollected {
+                    tokenId: token_id,
+                    treasury,
+                    quantity,
+                    treasuryAmount: treasury_amount,
+                    writersAmount: writers_amount,
+                },
+            );
+
+            Ok(treasury_amount)
+        })
+    }
+
+    /// Settles the call exercises assigned to a writer's position since it was last
+    /// settled.
+    ///
+    /// Exercises only update the series' assignment pool (see `exercise_call`); each
+    /// writer's share is applied to their position and claimable balance lazily, whenever
+    /// they write, close, withdraw or claim. Anyone may settle a position earlier, e.g. to
+    /// emit its `ExerciseAssigned` event; position views already include pending
+    /// assignments.
+    ///
+    /// # Parameters
+    /// - `writer`: Writer address
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Errors
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub fn settle_assignment(
+        &mut self,
+        writer: Address,
+        token_id: B256,
+    ) -> Result<(), OptionsError> {
+        self.settle_position_assignment(writer, token_id)
+    }
+
+    /// Claims the strike payments assigned to the caller's position in a series.
+    ///
+    /// Exercises of a non-vault call credit each writer's share of the strike payment
+    /// (see `exercise_call`); this settles the caller's position (see `settle_assignment`)
+    /// and pays out everything credited so far. Exercises during the settlement grace
+    /// period credit more, which can be claimed again.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID of the call option
+    ///
+    /// # Returns
+    /// Amount paid out (quote token's native decimals)
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `OptionNotFound`: Series has never been written
+    /// - `WithdrawalBeforeSettlement`: Series has not expired yet
+    /// - `InvalidQuantity`: Nothing is claimable
+    /// - `TransferFailed`: ERC20 transfer failed
+    pub fn claim_assignment(&mut self, token_id: B256) -> Result<U256, OptionsError> {
+        self.non_reentrant(|this| {
+            let (writer, current) = {
+                let vm = this.vm();
+                (vm.msg_sender(), U256::from(vm.block_timestamp()))
+            };
+
+            let metadata = this.get_option_metadata(token_id);
+            if metadata.expiry.is_zero() {
+                return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+            }
+            if current < metadata.expiry {
+                return Err(OptionsError::WithdrawalBeforeSettlement(
+                    WithdrawalBeforeSettlement {
+                        available_at: metadata.expiry,
+                        current,
+                    },
+                ));
+            }
+
+            this.settle_position_assignment(writer, token_id)?;
+
+            let key = Self::position_key(writer, token_id);
+            let amount = this.claimable_assignments.get(key);
+            if amount.is_zero() {
+                return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+            }
+
+            this.claimable_assignments.insert(key, U256::ZERO);
+            this.release_collateral(metadata.quote, amount)?;
+            this.transfer_token(metadata.quote, writer, amount)?;
+
+            log(
+                this.vm(),
+                AssignmentClaimed {
+                    writer,
+                    tokenId: token_id,
+                    amount,
+                },
+            );
+
+            Ok(amount)
+        })
+    }
+
+    /// Exercises a put option
+    ///
+    /// Immediate atomic settlement: holder delivers underlying tokens to writer,
+    /// receives strike (quote tokens) from collateral, burns option tokens.
+    /// Can only be called before option expiry plus the settlement grace period.
+    ///
+    /// The strike payment is measured on receipt when `min_quote_received` is non-zero,
+    /// so holders can refuse an exercise made lossy by a quote token that became
+    /// fee-on-transfer after writing.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the put option (keccak256 hash)
+    /// - `quantity`: Quantity of options to exercise
+    /// - `min_quote_received`: Minimum quote the caller must receive (native decimals, 0
+    ///   skips the check)
+    ///
+    /// # Errors
+    /// Returns `OptionsError::Unimplemented` (stub implementation). Once implemented,
+    /// `FeeOnTransferDetected` if the caller would receive less than `min_quote_received`.
+    pub fn exercise_put(
+        &mut self,
+        token_id: B256,
+        quantity: U256,
+        min_quote_received: U256,
+    ) -> Result<(), OptionsError> {
+        let _ = (token_id, quantity, min_quote_received);
+        Err(OptionsError::Unimplemented(Unimplemented {}))
+    }
+
+    /// Closes (part of) a writer's position by burning their own option tokens.
+    ///
+    /// A writer who still holds option tokens of a series they wrote can unwind
+    /// before expiry instead of waiting for it. Burns `quantity` option tokens
+    /// from the caller, reduces their position by the same amount and returns the
+    /// proportional collateral: underlying tokens for calls, quote tokens for puts and
+    /// cash calls. Closing a call spread also returns the same quantity of escrowed long
+    /// calls.
+    ///
+    /// The ERC-1155 balance and the position quantity are checked independently:
+    /// holding tokens bought from another writer does not entitle the caller to
+    /// that writer's collateral, and a position whose tokens were transferred away
+    /// cannot be closed because the outstanding options are still exercisable.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
+    /// - `quantity`: Quantity of options to close (in underlying token's native decimals)
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `InvalidQuantity`: Quantity is zero
+    /// - `InsufficientBalance`: Caller holds fewer option tokens, or has a smaller
+    ///   position, than `quantity`
+    /// - `InvalidDecimals`: Stored token decimals exceed 18
+    /// - `NormalizationOverflow`: Quantity normalization would overflow
+    /// - `TransferFailed`: ERC20 transfer failed
+    /// - `Overflow`: Arithmetic overflow during calculation
+    #[allow(deprecated)]
+    pub fn close_position(&mut self, token_id: B256, quantity: U256) -> Result<(), OptionsError> {
+        self.non_reentrant(|this| {
+            let writer = this.vm().msg_sender();
+
+            let metadata = this.get_option_metadata(token_id);
+            if metadata.expiry.is_zero() {
+                return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+            }
+
+            if quantity.is_zero() {
+                return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+            }
+
+            let normalized_quantity = normalize_amount(quantity, metadata.underlying_decimals)?;
+
+            let writer_balance = this.balance_of(writer, token_id);
+            if writer_balance < normalized_quantity {
+                return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+                    available: writer_balance,
+                    requested: normalized_quantity,
+                }));
+            }
+
+            let (quantity_written, _) = this.get_position(writer, token_id);
+            if quantity_written < normalized_quantity {
+                return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+                    available: quantity_written,
+                    requested: normalized_quantity,
+                }));
+            }
+
+            let (collateral_token, collateral_decimals) =
+                this.collateral_token(token_id, &metadata);
+
+            this._burn(writer, token_id, normalized_quantity)?;
+
+            let collateral_released =
+                this.reduce_position(writer, token_id, normalized_quantity, false)?;
+            let collateral_returned = denormalize_amount(collateral_released, collateral_decimals)?;
+            this.release_collateral(collateral_token, collateral_returned)?;
+            this.return_spread_long(writer, token_id, normalized_quantity)?;
+
+            let erc20 = IERC20::new(collateral_token);
+            let success = erc20
+                .transfer(Call::new_in(this), writer, collateral_returned)
+                .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+            if !success {
+                return Err(OptionsError::TransferFailed(TransferFailed {}));
+            }
+
+            log(
+                this.vm(),
+                PositionClosed {
+                    writer,
+                    tokenId: token_id,
+                    quantity,
+                    normalizedQuantity: normalized_quantity,
+                    collateralReturned: collateral_returned,
+                },
+            );
+
+            Ok(())
+        })
+    }
+
+    /// Rolls (part of) a writer's position into a series with a new expiry and strike.
+    ///
+    /// Burns `quantity` of the caller's option tokens of `old_token_id` and writes the
+    /// same quantity of the series with `new_strike` and `new_expiry` (same underlying,
+    /// quote, option type, oracle and exercise style) in one transaction. The collateral released
+    /// from the old position is locked for the new one, so only the difference is
+    /// transferred: pulled from the caller if the new series needs more, refunded if it
+    /// needs less. The protocol fee is charged as on any write.
+    ///
+    /// As with `close_position`, the caller must hold both the option tokens and the
+    /// position. Only physically settled calls written directly into this contract can
+    /// be rolled.
+    ///
+    /// # Parameters
+    /// - `old_token_id`: The ERC-1155 token ID of the series to roll out of
+    /// - `new_expiry`: Expiration timestamp of the new series (Unix seconds)
+    /// - `new_strike`: Strike price of the new series (18 decimals normalized)
+    /// - `quantity`: Quantity of options to roll (in underlying token's native decimals)
+    ///
+    /// # Returns
+    /// Token ID of the new series
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `Paused`: Contract is paused
+    /// - `OptionNotFound`: Option metadata not found for `old_token_id`
+    /// - `WrongOptionType`: The old series is not a call
+    /// - `WrongSettlementType`: The old series is cash settled
+    /// - `InvalidVault`: The old series is vault-backed
+    /// - `InvalidSpread`: The old series is a call spread
+    /// - `InvalidQuantity`: Quantity is zero
+    /// - `InsufficientBalance`: Caller holds fewer option tokens, or has a smaller
+    ///   position, than `quantity`
+    /// - `TransferFailed`: ERC20 transfer failed
+    /// - All validation errors of `write_call_option` for the new series
+    pub fn roll_position(
+        &mut self,
+        old_token_id: B256,
+        new_expiry: u64,
+        new_strike: U256,
+        quantity: U256,
+    ) -> Result<B256, OptionsError> {
+        self.non_reentrant(|this| this.roll_call(old_token_id, new_expiry, new_strike, quantity))
+    }
+
+    /// Withdraws collateral for expired unexercised options.
+    ///
+    /// Writers can reclaim their locked collateral once the series can no longer be
+    /// exercised. Settles the caller's pending assignments (see `settle_assignment`),
+    /// reduces their position by `quantity` and returns the proportional collateral:
+    /// underlying tokens for calls, quote tokens for puts. Call spreads return their
+    /// quote collateral together with the same quantity of escrowed long calls.
+    ///
+    /// Cash calls are settled with `withdraw_settled_collateral` instead, and vault-backed
+    /// series by their vault.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the option (keccak256 hash)
+    /// - `quantity`: Quantity of the position to withdraw collateral for (in underlying
+    ///   token's native decimals)
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `WithdrawalBeforeSettlement`: Current time < expiry + `SETTLEMENT_GRACE_PERIOD`
+    ///   (`EUROPEAN_EXERCISE_WINDOW` for European series)
+    /// - `WrongSettlementType`: Series is a cash call
+    /// - `InvalidVault`: Series is vault-backed
+    /// - `InvalidQuantity`: Quantity is zero
+    /// - `InsufficientBalance`: Caller's position is smaller than `quantity`
+    /// - `NormalizationOverflow`: Quantity normalization would overflow
+    /// - `TransferFailed`: ERC20 transfer failed
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub fn withdraw_expired_collateral(
+        &mut self,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        self.non_reentrant(|this| {
+            let (writer, current_time) = {
+                let vm = this.vm();
+                (vm.msg_sender(), vm.block_timestamp())
+            };
+
+            let metadata = this.get_option_metadata(token_id);
+            if metadata.expiry.is_zero() {
+                return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+            }
+            validate_withdrawal_time(metadata.expiry, metadata.exercise_style, current_time)?;
+            if metadata.settlement != SettlementType::Physical.to_u8() {
+                return Err(OptionsError::WrongSettlementType(WrongSettlementType {
+                    expected: SettlementType::Physical.to_u8(),
+                    actual: metadata.settlement,
+                }));
+            }
+            if metadata.vault != Address::ZERO {
+                return Err(OptionsError::InvalidVault(InvalidVault {
+                    vault: metadata.vault,
+                }));
+            }
+
+            if quantity.is_zero() {
+                return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+            }
+
+            let normalized_quantity = normalize_amount(quantity, metadata.underlying_decimals)?;
+
+            let (quantity_written, _) = this.get_position(writer, token_id);
+            if quantity_written < normalized_quantity {
+                return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+                    available: quantity_written,
+                    requested: normalized_quantity,
+                }));
+            }
+
+            let (collateral_token, collateral_decimals) =
+                this.collateral_token(token_id, &metadata);
+
+            let collateral_released =
+                this.reduce_position(writer, token_id, normalized_quantity, false)?;
+            let collateral_returned = denormalize_amount(collateral_released, collateral_decimals)?;
+            this.release_collateral(collateral_token, collateral_returned)?;
+            this.return_spread_long(writer, token_id, normalized_quantity)?;
+
+            if !collateral_returned.is_zero() {
+                this.transfer_token(collateral_token, writer, collateral_returned)?;
+            }
+
+            log(
+                this.vm(),
+                CollateralWithdrawn {
+                    writer,
+                    tokenId: token_id,
+                    quantity: normalized_quantity,
+                    collateralReturned: collateral_returned,
+                },
+            );
+
+            Ok(())
+        })
+    }
+
+    /// Transfers option tokens between accounts (ERC-1155 `safeTransferFrom`).
+    ///
+    /// The caller must be `from` or an operator approved by `from`. When `to` is a
+    /// contract, its `onERC1155Received` hook is called after the balances are updated
+    /// and must return `ERC1155_RECEIVED`, so tokens cannot be stranded in contracts that
+    /// don't handle them.
+    ///
+    /// # Parameters
+    /// - `from`: Token holder
+    /// - `to`: Recipient
+    /// - `token_id`: ERC-1155 token ID
+    /// - `amount`: Quantity of option tokens to transfer (18 decimals normalized)
+    /// - `data`: Passed through to the recipient's `onERC1155Received`
+    ///
+    /// # Errors
+    /// - `Paused`: Contract is paused
+    /// - `Unauthorized`: Caller is neither `from` nor an approved operator
+    /// - `InvalidRecipient`: `to` is the zero address
+    /// - `InsufficientBalance`: `from` holds fewer than `amount` tokens
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `ERC1155ReceiverRejected`: Contract recipient reverted or returned another value
+    pub fn safe_transfer_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: B256,
+        amount: U256,
+        data: Bytes,
+    ) -> Result<(), OptionsError> {
+        self.when_not_paused()?;
+
+        let operator = self.vm().msg_sender();
+        if operator != from && !self.is_approved_for_all(from, operator) {
+            return Err(OptionsError::Unauthorized(Unauthorized {
+                caller: operator,
+            }));
+        }
+
+        if to == Address::ZERO {
+            return Err(OptionsError::InvalidRecipient(InvalidRecipient {}));
+        }
+
+        self.non_reentrant(|this| {
+            this._transfer(operator, from, to, token_id, amount)?;
+            this.check_erc1155_received(operator, from, to, token_id, amount, data)
+        })
+    }
+
+    /// Transfers several option series between accounts (ERC-1155
+    /// `safeBatchTransferFrom`).
+    ///
+    /// Authorized like `safe_transfer_from`. Emits one `TransferBatch`, and when `to` is a
+    /// contract calls its `onERC1155BatchReceived` hook, which must return
+    /// `ERC1155_BATCH_RECEIVED`.
+    ///
+    /// # Parameters
+    /// - `from`: Token holder
+    /// - `to`: Recipient
+    /// - `token_ids`: ERC-1155 token IDs
+    /// - `amounts`: Quantity of each series to transfer (18 decimals normalized)
+    /// - `data`: Passed through to the recipient's `onERC1155BatchReceived`
+    ///
+    /// # Errors
+    /// - `Paused`: Contract is paused
+    /// - `Unauthorized`: Caller is neither `from` nor an approved operator
+    /// - `InvalidRecipient`: `to` is the zero address
+    /// - `BatchLengthMismatch`: `token_ids` and `amounts` have different lengths
+    /// - `InsufficientBalance`: `from` holds fewer tokens of a series than requested
+    /// - `Reentrancy`: Called re-entrantly from an external call
+    /// - `ERC1155ReceiverRejected`: Contract recipient reverted or returned another value
+    pub fn safe_batch_transfer_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_ids: Vec<B256>,
+        amounts: Vec<U256>,
+        data: Bytes,
+    ) -> Result<(), OptionsError> {
+        self.when_not_paused()?;
+
+        let operator = self.vm().msg_sender();
+        if operator != from && !self.is_approved_for_all(from, operator) {
+            return Err(OptionsError::Unauthorized(Unauthorized {
+                caller: operator,
+            }));
+        }
+
+        if to == Address::ZERO {
+            return Err(OptionsError::InvalidRecipient(InvalidRecipient {}));
+        }
+
+        if token_ids.len() != amounts.len() {
+            return Err(OptionsError::BatchLengthMismatch(BatchLengthMismatch {}));
+        }
+
+        self.non_reentrant(|this| {
+            for (&token_id, &amount) in token_ids.iter().zip(&amounts) {
+                this.move_balance(from, to, token_id, amount)?;
+            }
+
+            let ids: Vec<U256> = token_ids
+                .iter()
+                .map(|token_id| U256::from_be_bytes(token_id.0))
+                .collect();
+            log(
+                this.vm(),
+                TransferBatch {
+                    operator,
+                    from,
+                    to,
+                    ids: ids.clone(),
+                    values: amounts.clone(),
+                },
+            );
+
+            this.check_erc1155_batch_received(operator, from, to, ids, amounts, data)
+        })
+    }
+
+    /// Approves or revokes `operator` to transfer all of the caller's option tokens.
+    ///
+    /// Follows ERC-1155: approving oneself is allowed and has no effect on transfers.
+    /// Emits `ApprovalForAll`.
+    ///
+    /// # Parameters
+    /// - `operator`: Address allowed to call `safe_transfer_from` for the caller
+    /// - `approved`: Whether to approve or revoke
+    pub fn set_approval_for_all(&mut self, operator: Address, approved: bool) {
+        let account = self.vm().msg_sender();
+        self.operator_approvals
+            .setter(account)
+            .insert(operator, approved);
+
+        log(
+            self.vm(),
+            ApprovalForAll {
+                account,
+                operator,
+                approved,
+            },
+        );
+    }
+
+    /// Returns whether `operator` may transfer all of `account`'s option tokens.
+    #[must_use]
+    pub fn is_approved_for_all(&self, account: Address, operator: Address) -> bool {
+        self.operator_approvals.getter(account).get(operator)
+    }
+
+    /// Returns whether the contract implements an interface (ERC-165).
+    ///
+    /// True for ERC-165 only. The ERC-1155 and Metadata URI interface IDs are not
+    /// advertised: their selectors take `uint256` token IDs, while this contract's ABI
+    /// takes `bytes32` series hashes, so standard ERC-1155 callers cannot use it.
+    ///
+    /// # Parameters
+    /// - `interface_id`: ERC-165 interface ID
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        interface_id.0 == ERC165_INTERFACE_ID
+    }
+
+    /// Transfers the caller's entire balance of a series to `to`.
+    ///
+    /// Convenience for full exits: behaves like `safe_transfer_from` from the caller with
+    /// their current balance as the amount and empty `data`.
+    ///
+    /// # Parameters
+    /// - `to`: Recipient
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Quantity transferred (18 decimals normalized)
+    ///
+    /// # Errors
+    /// - All errors of `safe_transfer_from`
+    pub fn transfer_all(&mut self, to: Address, token_id: B256) -> Result<U256, OptionsError> {
+        let from = self.vm().msg_sender();
+        let amount = self.balance_of(from, token_id);
+
+        self.safe_transfer_from(from, to, token_id, amount, Vec::new().into())?;
+
+        Ok(amount)
+    }
+
+    /// Returns the total supply of an option series.
+    ///
+    /// Total supply is the amount of option tokens currently outstanding: it grows
+    /// when options are written and shrinks when they are exercised or burned.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Total supply (0 if no tokens minted)
+    #[must_use]
+    pub fn total_supply(&self, token_id: B256) -> U256 {
+        self.total_supply.get(token_id)
+    }
+
+    /// Returns whether an option series has ever been written.
+    ///
+    /// Stays true after every token of the series is exercised or burned, so a zero
+    /// `total_supply` with `exists` distinguishes a fully burned series from one that
+    /// was never created.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    #[must_use]
+    pub fn exists(&self, token_id: B256) -> bool {
+        !self.option_metadata.get(token_id).expiry.get().is_zero()
+    }
+
+    /// Returns the cumulative quantity of options ever written for a series.
+    ///
+    /// Unlike total supply, this never decreases when options are exercised.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Cumulative quantity written (18 decimals normalized, 0 if never written)
+    #[must_use]
+    pub fn total_written(&self, token_id: B256) -> U256 {
+        self.total_written.get(token_id)
+    }
+
+    /// Returns the cumulative quantity of options exercised for a series.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Cumulative quantity exercised (18 decimals normalized, 0 if never exercised)
+    #[must_use]
+    pub fn total_exercised(&self, token_id: B256) -> U256 {
+        self.total_exercised.get(token_id)
+    }
+
+    /// Returns aggregate risk figures of an option series.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Tuple of (total_supply, total_collateral, total_exercised), all 18 decimals
+    /// normalized: outstanding options, collateral locked across every writer position
+    /// (the sum of `get_collateral_locked`) and the
+    /// cumulative quantity exercised
+    #[must_use]
+    pub fn get_series_stats(&self, token_id: B256) -> (U256, U256, U256) {
+        (
+            self.total_supply.get(token_id),
+            self.global_positions.get(token_id).collateral_locked.get(),
+            self.total_exercised.get(token_id),
+        )
+    }
+
+    /// Returns every writer that has held a position in a series.
+    ///
+    /// Writers are listed once, in the order of their first write, and stay listed
+    /// after their position is closed or exercised.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    #[must_use]
+    pub fn get_series_writers(&self, token_id: B256) -> Vec<Address> {
+        let writers = self.series_writers.getter(token_id);
+        (0..writers.len()).filter_map(|i| writers.get(i)).collect()
+    }
+
+    /// Retrieves a writer's position for an option series.
+    ///
+    /// # Parameters
+    /// - `writer`: Writer address
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Tuple of (quantity_written, collateral_locked), both 18 decimals normalized and
+    /// zero if the writer has no position, with pending assignments applied (see
+    /// `settle_assignment`)
+    #[must_use]
+    pub fn get_position(&self, writer: Address, token_id: B256) -> (U256, U256) {
+        let (amounts, _) = self.settled_position(writer, token_id).unwrap_or_default();
+        (amounts.quantity_written, amounts.collateral_locked)
+    }
+
+    /// Returns the collateral a writer has locked in an option series.
+    ///
+    /// Same as the second value of `get_position`.
+    ///
+    /// # Parameters
+    /// - `writer`: Writer address
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Collateral locked (18 decimals normalized, 0 if the writer has no position)
+    #[must_use]
+    pub fn get_collateral_locked(&self, writer: Address, token_id: B256) -> U256 {
+        let (_, collateral_locked) = self.get_position(writer, token_id);
+        collateral_locked
+    }
+
+    /// Returns the strike payments assigned to a writer and not yet claimed.
+    ///
+    /// # Parameters
+    /// - `writer`: Writer address
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Claimable amount (quote token's native decimals) including pending assignments,
+    /// paid out by `claim_assignment`
+    #[must_use]
+    pub fn claimable_assignment(&self, writer: Address, token_id: B256) -> U256 {
+        let key = Self::position_key(writer, token_id);
+        let (_, pending) = self.settled_position(writer, token_id).unwrap_or_default();
+        self.claimable_assignments
+            .get(key)
+            .saturating_add(pending.strike_credited)
+    }
+
+    /// Returns the cumulative quantity exercised against a writer's position.
+    ///
+    /// Only counts exercises; quantity unwound via `close_position` is excluded.
+    ///
+    /// # Parameters
+    /// - `writer`: Writer address
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Cumulative quantity exercised including pending assignments (18 decimals
+    /// normalized, 0 if never exercised)
+    #[must_use]
+    pub fn writer_exercised(&self, writer: Address, token_id: B256) -> U256 {
+        let key = Self::position_key(writer, token_id);
+        let (_, pending) = self.settled_position(writer, token_id).unwrap_or_default();
+        self.positions
+            .get(key)
+            .quantity_exercised
+            .get()
+            .saturating_add(pending.quantity)
+    }
+
+    /// Returns the most a writer can owe on a series if every option they wrote is exercised.
+    ///
+    /// For calls this is the underlying deliverable against the writer's position; for
+    /// puts, cash calls and call spreads, the collateral locked in quote tokens.
+    ///
+    /// # Parameters
+    /// - `writer`: Writer address
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Worst-case obligation in the underlying (calls) or quote (puts, cash calls, call
+    /// spreads) token's native decimals, 0 if the writer has no position
+    ///
+    /// # Errors
+    /// - `InvalidDecimals`: Stored decimals exceed 18
+    /// - `Overflow`: Position quantities overflow when combined
+    pub fn max_writer_obligation(
+        &self,
+        writer: Address,
+        token_id: B256,
+    ) -> Result<U256, OptionsError> {
+        let metadata = self.get_option_metadata(token_id);
+        let (position, _) = self.settled_position(writer, token_id)?;
+
+        if metadata.option_type == OptionType::Put.to_u8()
+            || metadata.settlement == SettlementType::Cash.to_u8()
+            || self.spread_long_token_id.get(token_id) != B256::ZERO
+        {
+            return denormalize_amount(position.collateral_locked, metadata.quote_decimals);
+        }
+
+        denormalize_amount(position.quantity_written, metadata.underlying_decimals)
+    }
+
+    /// Returns the protocol fee in basis points.
+    #[must_use]
+    pub fn fee_bps(&self) -> U256 {
+        self.fee_bps.get()
+    }
+
+    /// Returns the address receiving protocol fees.
+    #[must_use]
+    pub fn fee_recipient(&self) -> Address {
+        self.fee_recipient.get()
+    }
+
+    /// Returns the write fee discount tiers as `(thresholds, fee_bps)`.
+    #[must_use]
+    pub fn fee_tiers(&self) -> (Vec<U256>, Vec<U256>) {
+        let thresholds = (0..self.fee_tier_thresholds.len())
+            .filter_map(|i| self.fee_tier_thresholds.get(i))
+            .collect();
+        let fee_bps = (0..self.fee_tier_bps.len())
+            .filter_map(|i| self.fee_tier_bps.get(i))
+            .collect();
+        (thresholds, fee_bps)
+    }
+
+    /// Returns the cumulative quantity an address has paid to write (18 decimals
+    /// normalized), which determines its fee tier.
+    ///
+    /// # Parameters
+    /// - `account`: Address paying for writes
+    #[must_use]
+    pub fn write_volume_of(&self, account: Address) -> U256 {
+        self.write_volume.get(account)
+    }
+
+    /// Returns an address's current fee tier and the write fee it pays.
+    ///
+    /// # Parameters
+    /// - `account`: Address paying for writes
+    ///
+    /// # Returns
+    /// `(tier, fee_bps)`, where tier 0 means no discount tier has been reached and tier
+    /// `i` means `thresholds[i - 1]` has
+    #[must_use]
+    pub fn fee_tier_of(&self, account: Address) -> (U256, U256) {
+        let (tier, fee_bps) = self.write_fee_tier(account);
+        (U256::from(tier), fee_bps)
+    }
+
+    /// Returns the keeper incentive on `auto_exercise`, in basis points.
+    #[must_use]
+    pub fn keeper_fee_bps(&self) -> U256 {
+        self.keeper_fee_bps.get()
+    }
+
+    /// Returns the intrinsic value per option `auto_exercise` requires settlement to exceed.
+    #[must_use]
+    pub fn auto_exercise_threshold(&self) -> U256 {
+        self.auto_exercise_threshold.get()
+    }
+
+    /// Returns the treasury cut of forfeited cash call value, in basis points.
+    #[must_use]
+    pub fn treasury_bps(&self) -> U256 {
+        self.treasury_bps.get()
+    }
+
+    /// Returns the address receiving the treasury cut of forfeited value.
+    #[must_use]
+    pub fn treasury(&self) -> Address {
+        self.treasury.get()
+    }
+
+    /// Returns whether `router` may be used by `exercise_call_via_swap`.
+    #[must_use]
+    pub fn is_router_approved(&self, router: Address) -> bool {
+        self.approved_routers.get(router)
+    }
+
+    /// Returns the maximum age of an oracle price accepted for cash settlement.
+    ///
+    /// # Returns
+    /// Maximum price age in seconds (0 if the check is disabled)
+    #[must_use]
+    pub fn max_settlement_staleness(&self) -> u64 {
+        self.max_settlement_staleness.get().to::<u64>()
+    }
+
+    /// Returns how far in the future a written series may expire.
+    ///
+    /// # Returns
+    /// Maximum expiry in seconds from now (`DEFAULT_MAX_EXPIRY_HORIZON` until the owner
+    /// sets one)
+    #[must_use]
+    pub fn max_expiry_horizon(&self) -> u64 {
+        match self.max_expiry_horizon.get().to::<u64>() {
+            0 => DEFAULT_MAX_EXPIRY_HORIZON,
+            horizon => horizon,
+        }
+    }
+
+    /// Returns the granularity written strikes must be a multiple of.
+    ///
+    /// # Returns
+    /// Strike tick (18 decimals normalized, 0 if the check is disabled)
+    #[must_use]
+    pub fn strike_tick(&self) -> U256 {
+        self.strike_tick.get()
+    }
+
+    /// Returns whether exercise checks live token decimals against the series metadata.
+    #[must_use]
+    pub fn strict_decimals(&self) -> bool {
+        self.strict_decimals.get()
+    }
+
+    /// Returns the collateral locked in a token across all series.
+    ///
+    /// # Parameters
+    /// - `token`: ERC20 token address
+    ///
+    /// # Returns
+    /// Locked collateral (in the token's native decimals)
+    #[must_use]
+    pub fn locked_collateral(&self, token: Address) -> U256 {
+        self.locked_collateral.get(token)
+    }
+
+    /// Returns the long call series escrowed by a call spread series.
+    ///
+    /// Spread writers' positions are read with `get_position` on the spread's token ID;
+    /// each option left in a position is backed by one escrowed long call.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID of the call spread series
+    ///
+    /// # Returns
+    /// Token ID of the long call series, zero if `token_id` is not a call spread
+    #[must_use]
+    pub fn get_spread_long_token_id(&self, token_id: B256) -> B256 {
+        self.spread_long_token_id.get(token_id)
+    }
+
+    /// Returns the contract owner (zero if not initialized).
+    #[must_use]
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Returns whether writing and exercising are paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    /// Returns the number of distinct option series ever written.
+    ///
+    /// # Returns
+    /// Series count (repeat writes of an existing series are not counted)
+    #[must_use]
+    pub fn series_count(&self) -> U256 {
+        self.series_count.get()
+    }
+
+    /// Returns the number of series in the registry.
+    ///
+    /// Same as `series_count`; valid `get_series_at` indices are below it.
+    #[must_use]
+    pub fn get_series_count(&self) -> U256 {
+        U256::from(self.series_ids.len())
+    }
+
+    /// Returns the token ID of the series at a registry index.
+    ///
+    /// Series are registered in order of first write and never removed, so an index
+    /// always resolves to the same series.
+    ///
+    /// # Parameters
+    /// - `index`: Registry index, below `get_series_count`
+    ///
+    /// # Errors
+    /// - `SeriesIndexOutOfBounds`: No series at `index`
+    pub fn get_series_at(&self, index: U256) -> Result<B256, OptionsError> {
+        self.series_ids.get(index).ok_or_else(|| {
+            OptionsError::SeriesIndexOutOfBounds(SeriesIndexOutOfBounds {
+                index,
+                count: U256::from(self.series_ids.len()),
+            })
+        })
+    }
+
+    /// Returns a page of the series written for a token pair.
+    ///
+    /// Series are listed in order of first write. Page through with `offset` until
+    /// fewer than `limit` token IDs come back.
+    ///
+    /// # Parameters
+    /// - `underlying`: Underlying token address
+    /// - `quote`: Quote token address
+    /// - `offset`: Position in the pair's series list to start from
+    /// - `limit`: Maximum number of token IDs, capped at `MAX_SERIES_PAGE_SIZE`
+    #[must_use]
+    pub fn get_series_by_pair(
+        &self,
+        underlying: Address,
+        quote: Address,
+        offset: U256,
+        limit: U256,
+    ) -> Vec<B256> {
+        let series = self.pair_series.getter(Self::pair_key(underlying, quote));
+        let start = offset.saturating_to::<usize>().min(series.len());
+        let end = start
+            .saturating_add(limit.saturating_to::<usize>().min(MAX_SERIES_PAGE_SIZE))
+            .min(series.len());
+
+        (start..end).filter_map(|i| series.get(i)).collect()
+    }
+
+    /// Returns the lifecycle state of an option series.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// - `0`: Not written
+    /// - `1`: Active (before expiry, options outstanding)
+    /// - `2`: Expired (at or after expiry, options outstanding)
+    /// - `3`: Settled (no options outstanding)
+    #[must_use]
+    pub fn series_status(&self, token_id: B256) -> u8 {
+        let current_time = self.vm().block_timestamp();
+        self.series_status_at(token_id, current_time).to_u8()
+    }
+
+    /// Returns the recorded settlement price of an oracle-bound series.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Settlement price (18 decimals normalized), 0 until `settle_series` is called
+    #[must_use]
+    pub fn settlement_price(&self, token_id: B256) -> U256 {
+        self.option_metadata.get(token_id).settlement_price.get()
+    }
+
+    /// Returns the `auto_exercise` payout a holder has yet to withdraw with `claim_cash`.
+    ///
+    /// # Parameters
+    /// - `holder`: Holder of the exercised cash calls
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Claimable amount (quote token's native decimals)
+    #[must_use]
+    pub fn claimable_cash(&self, holder: Address, token_id: B256) -> U256 {
+        self.claimable_cash.getter(holder).get(token_id)
+    }
+
+    /// Returns the metadata of an option series.
+    ///
+    /// Lets other contracts (e.g. the CLOB checking expiry) read a series' terms.
+    /// Series that have never been written are returned zeroed (`expiry == 0`).
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    #[must_use]
+    pub fn option_metadata_of(&self, token_id: B256) -> OptionMetadataView {
+        self.get_option_metadata(token_id)
+    }
+
+    /// Resolves a token ID to the terms of its series.
+    ///
+    /// Token IDs are hashes, so this is how off-chain callers recover what a balance
+    /// represents. Unlike `option_metadata_of`, unwritten series revert rather than
+    /// returning zeroes.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// `(underlying, quote, underlying_decimals, quote_decimals, strike, expiry,
+    /// option_type)`, with the strike 18 decimals normalized and `option_type` 0 for
+    /// calls and 1 for puts
+    ///
+    /// # Errors
+    /// - `OptionNotFound`: Series has never been written
+    pub fn get_series(
+        &self,
+        token_id: B256,
+    ) -> Result<(Address, Address, u8, u8, U256, U256, u8), OptionsError> {
+        let metadata = self.get_option_metadata(token_id);
+        if metadata.expiry.is_zero() {
+            return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+        }
+
+        Ok((
+            metadata.underlying,
+            metadata.quote,
+            metadata.underlying_decimals,
+            metadata.quote_decimals,
+            metadata.strike,
+            metadata.expiry,
+            metadata.option_type,
+        ))
+    }
+
+    /// Returns the ERC-1155 metadata URI of an option series.
+    ///
+    /// The URI is `data:application/json;base64,` followed by JSON built on-chain from
+    /// the series metadata: a name like `CALL <underlying> @ <strike> exp <expiry>` and
+    /// attributes for the option type, strike, expiry and both tokens with their
+    /// decimals. The strike is formatted in quote tokens per underlying token.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Errors
+    /// - `OptionNotFound`: Series has never been written
+    pub fn uri(&self, token_id: B256) -> Result<String, OptionsError> {
+        let metadata = self.get_option_metadata(token_id);
+        if metadata.expiry.is_zero() {
+            return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+        }
+
+        Ok(token_uri::token_uri(&metadata))
+    }
+}
+
+/// Test-only helper methods (accessible through motsu deref)
+impl Options {
+    /// Test wrapper for _mint - accessible in motsu tests through deref
+    ///
+    /// # Errors
+    /// Returns `OptionsError::Overflow` if balance or total supply would overflow
+    #[cfg(test)]
+    pub fn test_mint(
+        &mut self,
+        to: Address,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        self._mint(to, token_id, quantity)
+    }
+
+    /// Test wrapper for _burn - accessible in motsu tests through deref
+    ///
+    /// # Errors
+    /// Returns `OptionsError::InsufficientBalance` if balance is less than quantity
+    #[cfg(test)]
+    pub fn test_burn(
+        &mut self,
+        from: Address,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        self._burn(from, token_id, quantity)
+    }
+
+    /// Test wrapper for balance_of - accessible in motsu tests through deref
+    #[cfg(test)]
+    #[must_use]
+    pub fn test_balance_of(&self, owner: Address, token_id: B256) -> U256 {
+        self.balance_of(owner, token_id)
+    }
+
+    /// Test wrapper for total_supply - accessible in motsu tests through deref
+    #[cfg(test)]
+    #[must_use]
+    pub fn test_total_supply(&self, token_id: B256) -> U256 {
+        self.total_supply(token_id)
+    }
+}
+
+/// Internal helper functions for Options contract
+impl Options {
+    /// Generates a composite key for balance lookups.
+    ///
+    /// Combines owner address and token ID into a single key for storage mapping.
+    ///
+    /// # Parameters
+    /// - `owner`: Token holder address
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// `keccak256(owner || token_id)` as composite key
+    fn balance_key(owner: Address, token_id: B256) -> B256 {
+        let encoded = [owner.as_slice(), token_id.as_slice()].concat();
+        keccak256(encoded)
+    }
+
+    /// Mints option tokens to an address.
+    ///
+    /// Increases both the recipient's balance and the token's total supply.
+    /// Uses checked arithmetic to prevent overflow. Emits `TransferSingle` from
+    /// the zero address with the caller as operator.
+    ///
+    /// # Parameters
+    /// - `to`: Recipient address
+    /// - `token_id`: ERC-1155 token ID
+    /// - `quantity`: Amount to mint
+    ///
+    /// # Errors
+    /// - `OptionsError::Overflow` if balance or total supply would overflow
+    pub(crate) fn _mint(
+        &mut self,
+        to: Address,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        let key = Self::balance_key(to, token_id);
+        let current_balance = self.balances.get(key);
+        let new_balance = current_balance
+            .checked_add(quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.balances.insert(key, new_balance);
+
+        let current_supply = self.total_supply.get(token_id);
+        let new_supply = current_supply
+            .checked_add(quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.total_supply.insert(token_id, new_supply);
+
+        let operator = self.vm().msg_sender();
+        log(
+            self.vm(),
+            TransferSingle {
+                operator,
+                from: Address::ZERO,
+                to,
+                id: U256::from_be_bytes(token_id.0),
+                value: quantity,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Burns option tokens from an address.
+    ///
+    /// Decreases both the holder's balance and the token's total supply.
+    /// Uses checked arithmetic to prevent underflow. Emits `TransferSingle` to
+    /// the zero address with the caller as operator.
+    ///
+    /// # Parameters
+    /// - `from`: Token holder address
+    /// - `token_id`: ERC-1155 token ID
+    /// - `quantity`: Amount to burn
+    ///
+    /// # Errors
+    /// - `OptionsError::InsufficientBalance` if balance < quantity
+    pub(crate) fn _burn(
+        &mut self,
+        from: Address,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        let key = Self::balance_key(from, token_id);
+        let current_balance = self.balances.get(key);
+
+        if current_balance < quantity {
+            return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+                available: current_balance,
+                requested: quantity,
+            }));
+        }
+
+        let new_balance = current_balance
+            .checked_sub(quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.balances.insert(key, new_balance);
+
+        let current_supply = self.total_supply.get(token_id);
+        let new_supply = current_supply
+            .checked_sub(quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.total_supply.insert(token_id, new_supply);
+
+        let operator = self.vm().msg_sender();
+        log(
+            self.vm(),
+            TransferSingle {
+                operator,
+                from,
+                to: Address::ZERO,
+                id: U256::from_be_bytes(token_id.0),
+                value: quantity,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Moves option tokens between accounts and emits `TransferSingle`.
+    ///
+    /// Total supply is unchanged. Transfers to self leave the balance as is.
+    ///
+    /// # Parameters
+    /// - `operator`: Address performing the transfer
+    /// - `from`: Token holder
+    /// - `to`: Recipient
+    /// - `token_id`: ERC-1155 token ID
+    /// - `quantity`: Amount to transfer
+    ///
+    /// # Errors
+    /// - `OptionsError::InsufficientBalance` if `from`'s balance < quantity
+    /// - `OptionsError::Overflow` if `to`'s balance would overflow
+    pub(crate) fn _transfer(
+        &mut self,
+        operator: Address,
+        from: Address,
+        to: Address,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        self.move_balance(from, to, token_id, quantity)?;
+
+        log(
+            self.vm(),
+            TransferSingle {
+                operator,
+                from,
+                to,
+                id: U256::from_be_bytes(token_id.0),
+                value: quantity,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Moves option tokens between accounts without emitting an event.
+    ///
+    /// # Errors
+    /// - `OptionsError::InsufficientBalance` if `from`'s balance < quantity
+    /// - `OptionsError::Overflow` if `to`'s balance would overflow
+    pub(crate) fn move_balance(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        let from_key = Self::balance_key(from, token_id);
+        let from_balance = self.balances.get(from_key);
+
+        if from_balance < quantity {
+            return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+                available: from_balance,
+                requested: quantity,
+            }));
+        }
+
+        self.balances.insert(from_key, from_balance - quantity);
+
+        let to_key = Self::balance_key(to, token_id);
+        let to_balance = self
+            .balances
+            .get(to_key)
+            .checked_add(quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.balances.insert(to_key, to_balance);
+
+        Ok(())
+    }
+
+    /// Calls `onERC1155Received` on a contract recipient and checks it accepts.
+    ///
+    /// Recipients without code (EOAs) are not called.
+    ///
+    /// # Errors
+    /// - `ERC1155ReceiverRejected`: The hook reverted or returned another value than
+    ///   `ERC1155_RECEIVED`
+    #[allow(deprecated)]
+    pub(crate) fn check_erc1155_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        to: Address,
+        token_id: B256,
+        quantity: U256,
+        data: Bytes,
+    ) -> Result<(), OptionsError> {
+        if self.vm().code_size(to) == 0 {
+            return Ok(());
+        }
+
+        let response = IERC1155Receiver::new(to)
+            .on_erc_1155_received(
+                Call::new_in(self),
+                operator,
+                from,
+                U256::from_be_bytes(token_id.0),
+                quantity,
+                data,
+            )
+            .map_err(|_| {
+                OptionsError::ERC1155ReceiverRejected(ERC1155ReceiverRejected { receiver: to })
+            })?;
+
+        if response.0 != ERC1155_RECEIVED {
+            return Err(OptionsError::ERC1155ReceiverRejected(
+                ERC1155ReceiverRejected { receiver: to },
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Calls `onERC1155BatchReceived` on a contract recipient and checks it accepts.
+    ///
+    /// Recipients without code (EOAs) are not called.
+    ///
+    /// # Errors
+    /// - `ERC1155ReceiverRejected`: The hook reverted or returned another value than
+    ///   `ERC1155_BATCH_RECEIVED`
+    #[allow(deprecated)]
+    pub(crate) fn check_erc1155_batch_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        to: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Bytes,
+    ) -> Result<(), OptionsError> {
+        if self.vm().code_size(to) == 0 {
+            return Ok(());
+        }
+
+        let response = IERC1155Receiver::new(to)
+            .on_erc_1155_batch_received(Call::new_in(self), operator, from, ids, values, data)
+            .map_err(|_| {
+                OptionsError::ERC1155ReceiverRejected(ERC1155ReceiverRejected { receiver: to })
+            })?;
+
+        if response.0 != ERC1155_BATCH_RECEIVED {
+            return Err(OptionsError::ERC1155ReceiverRejected(
+                ERC1155ReceiverRejected { receiver: to },
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Runs the receiver check for option tokens freshly minted to `writer`.
+    ///
+    /// # Errors
+    /// - `ERC1155ReceiverRejected`: `writer` is a contract that does not accept them
+    pub(crate) fn check_minted(
+        &mut self,
+        writer: Address,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        let operator = self.vm().msg_sender();
+        self.check_erc1155_received(
+            operator,
+            Address::ZERO,
+            writer,
+            token_id,
+            quantity,
+            Vec::<u8>::new().into(),
+        )
+    }
+
+    /// Returns the balance of an account for a specific token.
+    ///
+    /// # Parameters
+    /// - `owner`: Token holder address
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Token balance (0 if no balance exists)
+    #[must_use]
+    pub fn balance_of(&self, owner: Address, token_id: B256) -> U256 {
+        let key = Self::balance_key(owner, token_id);
+        self.balances.get(key)
+    }
+
+    /// Returns the balances of several (owner, token) pairs.
+    ///
+    /// Results are positional: `balances[i]` is the balance of `owners[i]` for
+    /// `token_ids[i]`. Duplicate pairs are allowed and are not de-duplicated.
+    ///
+    /// # Parameters
+    /// - `owners`: Token holder addresses
+    /// - `token_ids`: ERC-1155 token IDs
+    ///
+    /// # Returns
+    /// Token balances in input order
+    ///
+    /// # Errors
+    /// - `BatchLengthMismatch`: `owners` and `token_ids` have different lengths
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn balance_of_batch(
+        &self,
+        owners: Vec<Address>,
+        token_ids: Vec<B256>,
+    ) -> Result<Vec<U256>, OptionsError> {
+        if owners.len() != token_ids.len() {
+            return Err(OptionsError::BatchLengthMismatch(BatchLengthMismatch {}));
+        }
+
+        Ok(owners
+            .iter()
+            .zip(&token_ids)
+            .map(|(&owner, &token_id)| self.balance_of(owner, token_id))
+            .collect())
+    }
+
+    /// Returns the metadata of several option series.
+    ///
+    /// Results are positional: `metadata[i]` describes `token_ids[i]`. Series that have
+    /// never been written are returned zeroed (`expiry == 0`), so option-chain UIs can
+    /// fetch a whole chain in one call.
+    ///
+    /// # Parameters
+    /// - `token_ids`: ERC-1155 token IDs
+    ///
+    /// # Returns
+    /// Series metadata in input order
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn metadata_batch(&self, token_ids: Vec<B256>) -> Vec<OptionMetadataView> {
+        token_ids
+            .iter()
+            .map(|&token_id| self.get_option_metadata(token_id))
+            .collect()
+    }
+
+    /// Returns the exact bytes hashed into the token ID of a physically settled series.
+    ///
+    /// `keccak256` of the result is the token ID `write_call_option` assigns, so
+    /// off-chain clients can verify their packed encoding (see `token_id_preimage`).
+    ///
+    /// # Parameters
+    /// - `underlying`: Address of the underlying token
+    /// - `quote`: Address of the quote token
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp (Unix seconds)
+    /// - `option_type`: Option type (0 = Call, 1 = Put)
+    ///
+    /// # Errors
+    /// - `WrongOptionType`: Option type is neither call nor put
+    pub fn token_id_preimage(
+        &self,
+        underlying: Address,
+        quote: Address,
+        strike: U256,
+        expiry: u64,
+        option_type: u8,
+    ) -> Result<Bytes, OptionsError> {
+        let option_type = match option_type {
+            0 => OptionType::Call,
+            1 => OptionType::Put,
+            _ => {
+                return Err(OptionsError::WrongOptionType(WrongOptionType {
+                    expected: OptionType::Call.to_u8(),
+                    actual: option_type,
+                }))
+            }
+        };
+
+        Ok(token_id_preimage(underlying, quote, strike, expiry, option_type).into())
+    }
+
+    /// Returns the collateral a write will pull from the caller, in native decimals.
+    ///
+    /// Calls lock `quantity` of the underlying; puts lock `strike * quantity` of the
+    /// quote, rounded up like the strike payment. The protocol fee is charged on top
+    /// (see `fee_tier_of`). Runs the write's decimal and overflow checks, but not its
+    /// expiry, token or decimals-against-token checks.
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    /// - `option_type`: Option type (0 = Call, 1 = Put)
+    ///
+    /// # Returns
+    /// Collateral in the underlying (calls) or quote (puts) token's native decimals
+    ///
+    /// # Errors
+    /// - `WrongOptionType`: Option type is neither call nor put
+    /// - `InvalidDecimals`: Underlying decimals, or quote decimals for puts, exceed 18
+    /// - `NormalizationOverflow`: Quantity normalization would overflow
+    /// - `StrikeTooLarge`: `strike * quantity` (normalized) overflows
+    #[allow(clippy::unused_self)]
+    pub fn required_collateral(
+        &self,
+        strike: U256,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+        option_type: u8,
+    ) -> Result<U256, OptionsError> {
+        let (collateral, _) = required_collateral(
+            strike,
+            quantity,
+            underlying.decimals,
+            quote.decimals,
+            option_type,
+        )?;
+        Ok(collateral)
+    }
+
+    /// Previews the collateral a write will pull, from token decimals alone.
+    ///
+    /// Like `required_collateral`, but needs no token addresses, so it can be quoted
+    /// before either token or the series exists. The protocol fee is charged on top.
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+    /// - `underlying_decimals`: Decimals of the underlying token
+    /// - `quote_decimals`: Decimals of the quote token
+    /// - `option_type`: Option type (0 = Call, 1 = Put)
+    ///
+    /// # Returns
+    /// Tuple of (collateral in native decimals, collateral token: 0 = underlying,
+    /// 1 = quote)
+    ///
+    /// # Errors
+    /// See `required_collateral`
+    #[allow(clippy::unused_self)]
+    pub fn preview_required_collateral(
+        &self,
+        strike: U256,
+        quantity: U256,
+        underlying_decimals: u8,
+        quote_decimals: u8,
+        option_type: u8,
+    ) -> Result<(U256, u8), OptionsError> {
+        let (collateral, token) = required_collateral(
+            strike,
+            quantity,
+            underlying_decimals,
+            quote_decimals,
+            option_type,
+        )?;
+        Ok((collateral, token.to_u8()))
+    }
+
+    /// Previews the token amounts of a call exercise.
+    ///
+    /// Uses the stored series terms and the exercise path's rounding: the strike payment
+    /// is rounded up and the protocol fee is deducted from the underlying. The strike
+    /// payment is what a holder who did not write into the series must approve; writers
+    /// pay nothing for their own share (see `exercise_call`), so they pay at most this
+    /// much. Holders of a call spread series pay it up to rounding.
+    /// Puts cannot be exercised yet and fail with `WrongOptionType`.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the call option (keccak256 hash)
+    /// - `quantity`: Quantity of options to exercise (in underlying token's native decimals)
+    ///
+    /// # Returns
+    /// Tuple of (strike payment in quote native decimals, underlying received in
+    /// underlying native decimals)
+    ///
+    /// # Errors
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `ExerciseAfterExpiry`: Current time >= option expiry + `SETTLEMENT_GRACE_PERIOD`
+    /// - `ExerciseWindowClosed`: European option outside its exercise window
+    /// - `WrongOptionType`: Token ID represents a put option, not call
+    /// - `WrongSettlementType`: Option is a cash call
+    /// - `InvalidQuantity`: Quantity is zero
+    /// - `NormalizationOverflow`: Quantity normalization would overflow
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub fn get_exercise_cost(
+        &self,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(U256, U256), OptionsError> {
+        let current_time = self.vm().block_timestamp();
+        let metadata = self.get_option_metadata(token_id);
+        let normalized_quantity = normalize_amount(quantity, metadata.underlying_decimals)?;
+        self.validate_call_exercisable(token_id, normalized_quantity, current_time)?;
+
+        let strike_payment = strike_payment(
+            metadata.strike,
+            normalized_quantity,
+            metadata.quote_decimals,
+        )?;
+        let fee = protocol_fee(quantity, self.fee_bps.get())?;
+
+        Ok((strike_payment, quantity - fee))
+    }
+
+    /// Checks that a token can be used as the underlying or quote of a series.
+    ///
+    /// See `validate_token`. Does not call the token, so declared decimals are not
+    /// compared with the token's own `decimals()`.
+    ///
+    /// # Parameters
+    /// - `token`: Token address and declared decimals
+    ///
+    /// # Errors
+    /// - `ZeroAddress`: Token address is zero
+    /// - `InvalidDecimals`: Decimals exceed 18
+    pub fn validate_token(&self, token: Token) -> Result<(), OptionsError> {
+        validate_token(token)
+    }
+
+    /// Safely transfers ERC20 tokens with fee-on-transfer detection.
+    ///
+    /// Checks the recipient's balance before and after transfer to ensure the full
+    /// amount was received. This prevents fee-on-transfer tokens from breaking
+    /// collateral accounting.
+    ///
+    /// # Parameters
+    /// - `token`: ERC20 token contract address
+    /// - `from`: Address to transfer from (requires prior approval)
+    /// - `to`: Recipient address
+    /// - `amount`: Amount to transfer
+    ///
+    /// # Errors
+    /// - `TransferFailed`: ERC20 transferFrom call failed
+    /// - `FeeOnTransferDetected`: Received amount doesn't match requested amount
+    /// - `UnexpectedBalanceDecrease`: Balance decreased instead of increased
+    #[allow(deprecated)]
+    pub(crate) fn safe_transfer_erc20_from(
+        &mut self,
+        token: Address,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), OptionsError> {
+        let erc20 = IERC20::new(token);
+
+        let balance_before = erc20
+            .balance_of(Call::new_in(self), to)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+        let success = erc20
+            .transfer_from(Call::new_in(self), from, to, amount)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+        if !success {
+            return Err(OptionsError::TransferFailed(TransferFailed {}));
+        }
+
+        let balance_after = erc20
+            .balance_of(Call::new_in(self), to)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+        let received = balance_after.checked_sub(balance_before).ok_or(
+            OptionsError::UnexpectedBalanceDecrease(UnexpectedBalanceDecrease {}),
+        )?;
+
+        if received != amount {
+            return Err(OptionsError::FeeOnTransferDetected(FeeOnTransferDetected {
+                expected: amount,
+                received,
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Safely transfers ERC20 tokens from contract to recipient with fee-on-transfer detection.
+    ///
+    /// Checks the recipient's balance before and after transfer to ensure the full
+    /// amount was received. This prevents fee-on-transfer tokens from breaking
+    /// settlement accounting.
+    ///
+    /// # Parameters
+    /// - `token`: ERC20 token contract address
+    /// - `to`: Recipient address
+    /// - `amount`: Amount to transfer
+    ///
+    /// # Errors
+    /// - `TransferFailed`: ERC20 transfer call failed
+    /// - `FeeOnTransferDetected`: Received amount doesn't match requested amount
+    /// - `UnexpectedBalanceDecrease`: Balance decreased instead of increased
+    #[allow(deprecated)]
+    pub fn safe_transfer(
+        &mut self,
+        token: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), OptionsError> {
+        let erc20 = IERC20::new(token);
+
+        let balance_before = erc20
+            .balance_of(Call::new_in(self), to)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+        let success = erc20
+            .transfer(Call::new_in(self), to, amount)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+        if !success {
+            return Err(OptionsError::TransferFailed(TransferFailed {}));
+        }
+
+        let balance_after = erc20
+            .balance_of(Call::new_in(self), to)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+        let received = balance_after.checked_sub(balance_before).ok_or(
+            OptionsError::UnexpectedBalanceDecrease(UnexpectedBalanceDecrease {}),
+        )?;
+
+        if received != amount {
+            return Err(OptionsError::FeeOnTransferDetected(FeeOnTransferDetected {
+                expected: amount,
+                received,
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Exercises a call option held by the caller.
+    ///
+    /// Shared implementation of `exercise_call`, `exercise_call_to` and
+    /// `exercise_call_strict`: burns the caller's option tokens, assigns the exercise to
+    /// the writers, pulls the strike owed to other writers from the caller and sends the
+    /// underlying to `recipient`.
+    ///
+    /// # Parameters
+    /// - `token_id`: The ERC-1155 token ID of the call option (keccak256 hash)
+    /// - `quantity`: Quantity of options to exercise (in underlying token's native decimals)
+    /// - `recipient`: Address receiving the underlying tokens
+    /// - `min_received`: Minimum underlying `recipient` must receive (0 skips the check)
+    ///
+    /// # Errors
+    /// See `exercise_call` and `exercise_call_strict`
+    pub(crate) fn settle_call_exercise(
+        &mut self,
+        token_id: B256,
+        quantity: U256,
+        recipient: Address,
+        min_received: U256,
+    ) -> Result<(), OptionsError> {
+        self.when_not_paused()?;
+
+        let (holder, contract_addr) = {
+            let vm = self.vm();
+            (vm.msg_sender(), vm.contract_address())
+        };
+        let exercise = self.record_call_exercise(holder, token_id, quantity)?;
+        let underlying_received = exercise.quantity - exercise.fee;
+
+        if !exercise.strike_owed.is_zero() {
+            self.safe_transfer_erc20_from(
+                exercise.quote,
+                holder,
+                contract_addr,
+                exercise.strike_owed,
+            )?;
+        }
+        if exercise.vault != Address::ZERO {
+            self.settle_vault_exercise(holder, recipient, &exercise, min_received)?;
+        } else if min_received.is_zero() {
+            self.transfer_token(exercise.underlying, recipient, underlying_received)?;
+        } else {
+            let received =
+                self.transfer_token_measured(exercise.underlying, recipient, underlying_received)?;
+            if received < min_received {
+                return Err(OptionsError::FeeOnTransferDetected(FeeOnTransferDetected {
+                    expected: min_received,
+                    received,
+                }));
+            }
+        }
+        if !exercise.fee.is_zero() {
+            let fee_recipient = self.fee_recipient.get();
+            self.transfer_token(exercise.underlying, fee_recipient, exercise.fee)?;
+        }
+
+        self.log_call_exercise(holder, recipient, &exercise);
+
+        Ok(())
+    }
+
+    /// Records a call exercise without moving tokens.
+    ///
+    /// Validates the exercise, burns the holder's option tokens, assigns it pro rata
+    /// across all writers (see `assign_exercise`) and releases the collateral. Call
+    /// spread series are settled against their escrow (see `exercise_spread`). The
+    /// caller is responsible for paying out the amounts in the returned settlement and
+    /// logging it.
+    ///
+    /// # Parameters
+    /// - `holder`: Address exercising
+    /// - `token_id`: The ERC-1155 token ID of the call option (keccak256 hash)
+    /// - `quantity`: Quantity of options to exercise (in underlying token's native decimals)
+    ///
+    /// # Returns
+    /// Amounts to pay out for the exercise
+    ///
+    /// # Errors
+    /// See `exercise_call`
+    pub(crate) fn record_call_exercise(
+        &mut self,
+        holder: Address,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<CallExercise, OptionsError> {
+        let current_time = self.vm().block_timestamp();
+
+        let metadata = self.get_option_metadata(token_id);
+        let normalized_quantity = normalize_amount(quantity, metadata.underlying_decimals)?;
+
+        self.validate_call_exercise(holder, token_id, normalized_quantity, current_time)?;
+        self.check_decimals_unchanged(&metadata)?;
+
+        let strike_payment = strike_payment(
+            metadata.strike,
+            normalized_quantity,
+            metadata.quote_decimals,
+        )?;
+
+        self._burn(holder, token_id, normalized_quantity)?;
+
+        let new_total_exercised = self
+            .total_exercised
+            .get(token_id)
+            .checked_add(normalized_quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.total_exercised.insert(token_id, new_total_exercised);
+
+        let long_token_id = self.spread_long_token_id.get(token_id);
+        // Vault-backed series hold no positions or collateral here
+        let strike_owed = if metadata.vault != Address::ZERO {
+            U256::ZERO
+        } else if long_token_id != B256::ZERO {
+            self.exercise_spread(holder, token_id, long_token_id, quantity)?
+        } else {
+            let (strike_owed, _) =
+                self.assign_exercise(holder, token_id, normalized_quantity, strike_payment, None)?;
+            self.release_collateral(metadata.underlying, quantity)?;
+            self.lock_collateral(metadata.quote, strike_owed)?;
+            strike_owed
+        };
+
+        let fee = protocol_fee(quantity, self.fee_bps.get())?;
+
+        Ok(CallExercise {
+            token_id,
+            underlying: metadata.underlying,
+            quote: metadata.quote,
+            quantity,
+            normalized_quantity,
+            strike_payment,
+            fee,
+            strike_owed,
+            vault: metadata.vault,
+        })
+    }
+
+    /// Assigns the covered part of a call exercise pro rata across all writers.
+    ///
+    /// Writers of a series share an assignment pool: each position holds units in
+    /// proportion to the quantity it has left, and an exercise only updates the pool, in
+    /// constant time however many writers the series has. The pool's quantity written and
+    /// collateral shrink by the exercised share, its cumulative quantity assigned grows by
+    /// `quantity` and `strike_value` is added to its strike index per unit. For a
+    /// cash-settled exercise, the assigned collateral left over after `cash_payout` is
+    /// added to its collateral index per unit instead of leaving the pool. Each writer's
+    /// share is settled into their position and claimable balance lazily (see
+    /// `settle_assignment`).
+    ///
+    /// The holder's own share is settled on the spot: they are not credited their share
+    /// of the strike, as they would be paying themselves, and their share of the
+    /// leftover collateral is returned to them. Credits round down, leaving dust in the
+    /// contract.
+    ///
+    /// # Parameters
+    /// - `holder`: Address exercising
+    /// - `token_id`: ERC-1155 token ID of the call option
+    /// - `quantity`: Quantity to assign, at most the global quantity written (18 decimals
+    ///   normalized)
+    /// - `strike_value`: Strike payment for `quantity` (quote token's native decimals)
+    /// - `cash_payout`: Collateral paid to the holder of a cash-settled exercise (18
+    ///   decimals normalized), `None` when all of the assigned collateral is delivered
+    ///
+    /// # Returns
+    /// Tuple of (strike payment the holder owes the other writers in the quote token's
+    /// native decimals, leftover collateral returned to the holder 18 decimals
+    /// normalized)
+    ///
+    /// # Errors
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub(crate) fn assign_exercise(
+        &mut self,
+        holder: Address,
+        token_id: B256,
+        quantity: U256,
+        strike_value: U256,
+        cash_payout: Option<U256>,
+    ) -> Result<(U256, U256), OptionsError> {
+        // Settled first so that skipping the holder's credit below only skips this exercise
+        self.settle_position_assignment(holder, token_id)?;
+
+        let global = self.global_positions.get(token_id);
+        let global_quantity = global.quantity_written.get();
+        let global_collateral = global.collateral_locked.get();
+        let global_units = global.assignment_units.get();
+
+        let collateral_assigned = if quantity == global_quantity {
+            global_collateral
+        } else {
+            global_collateral
+                .checked_mul(quantity)
+                .ok_or(OptionsError::Overflow(Overflow {}))?
+                .checked_div(global_quantity)
+                .ok_or(OptionsError::Overflow(Overflow {}))?
+        };
+        let collateral_left = cash_payout.map_or(U256::ZERO, |payout| {
+            collateral_assigned.saturating_sub(payout)
+        });
+        let per_unit = |amount: U256| {
+            if global_units.is_zero() {
+                return Ok(U256::ZERO);
+            }
+            amount
+                .checked_mul(ASSIGNMENT_INDEX_PRECISION)
+                .map(|scaled| scaled / global_units)
+                .ok_or(OptionsError::Overflow(Overflow {}))
+        };
+        let strike_increase = per_unit(strike_value)?;
+        let collateral_increase = per_unit(collateral_left)?;
+
+        let new_strike_per_unit = global
+            .strike_per_unit
+            .get()
+            .checked_add(strike_increase)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        let new_collateral_per_unit = global
+            .collateral_per_unit
+            .get()
+            .checked_add(collateral_increase)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        let new_quantity_assigned = global
+            .quantity_assigned
+            .get()
+            .checked_add(quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        let new_global_quantity = global_quantity
+            .checked_sub(quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+        let key = Self::position_key(holder, token_id);
+        let mut position = self.positions.setter(key);
+        let holder_units = position.assignment_units.get();
+        let holder_share = |increase: U256| {
+            holder_units
+                .checked_mul(increase)
+                .map(|share| share / ASSIGNMENT_INDEX_PRECISION)
+                .ok_or(OptionsError::Overflow(Overflow {}))
+        };
+        let holder_strike = holder_share(strike_increase)?;
+        let holder_collateral = holder_share(collateral_increase)?;
+        position.strike_index.set(new_strike_per_unit);
+        position.collateral_index.set(new_collateral_per_unit);
+
+        // What is left of the assigned collateral stays in the pool, less the holder's
+        // share returned to them
+        let new_global_collateral = (global_collateral - collateral_assigned)
+            .checked_add(collateral_left - holder_collateral)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        let mut global = self.global_positions.setter(token_id);
+        global.quantity_written.set(new_global_quantity);
+        global.collateral_locked.set(new_global_collateral);
+        global.strike_per_unit.set(new_strike_per_unit);
+        global.collateral_per_unit.set(new_collateral_per_unit);
+        global.quantity_assigned.set(new_quantity_assigned);
+
+        let strike_owed = if global_units.is_zero() {
+            U256::ZERO
+        } else {
+            strike_value.saturating_sub(holder_strike)
+        };
+        Ok((strike_owed, holder_collateral))
+    }
+
+    /// Closes a series' assignment pool epoch and starts a new one.
+    ///
+    /// The closed epoch's final state is recorded for positions settling later, and the
+    /// new pool holds one unit per option left.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID of the option
+    ///
+    /// # Errors
+    /// - `Overflow`: Epoch counter would overflow
+    fn start_assignment_epoch(&mut self, token_id: B256) -> Result<(), OptionsError> {
+        let global = self.global_positions.get(token_id);
+        let epoch = global.assignment_epoch.get();
+        let global_quantity = global.quantity_written.get();
+        let global_units = global.assignment_units.get();
+        let strike_per_unit = global.strike_per_unit.get();
+        let collateral_per_unit = global.collateral_per_unit.get();
+        let quantity_assigned = global.quantity_assigned.get();
+
+        let mut record = self
+            .assignment_epochs
+            .setter(Self::epoch_key(token_id, epoch));
+        record.quantity.set(global_quantity);
+        record.units.set(global_units);
+        record.strike_per_unit.set(strike_per_unit);
+        record.collateral_per_unit.set(collateral_per_unit);
+        record.quantity_assigned.set(quantity_assigned);
+
+        let new_epoch = epoch
+            .checked_add(U256::from(1))
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        let mut global = self.global_positions.setter(token_id);
+        global.assignment_epoch.set(new_epoch);
+        global.assignment_units.set(global_quantity);
+        global.strike_per_unit.set(U256::ZERO);
+        global.collateral_per_unit.set(U256::ZERO);
+
+        Ok(())
+    }
+
+    /// Computes the call exercises assigned to a writer position since it was last
+    /// settled.
+    ///
+    /// A position untouched by any exercise since its last settlement keeps its quantity;
+    /// otherwise it keeps what its units are worth in the pool (see
+    /// `quantity_of_units`), at most its quantity. Strike credits are the growth of the
+    /// pool's strike index times the units. A position one epoch behind carries its
+    /// quantity at the close of its epoch into the current one as units; a position
+    /// further behind is fully assigned and credited for its own epoch and the next.
+    ///
+    /// # Parameters
+    /// - `writer`: Writer address
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// The pending assignment
+    ///
+    /// # Errors
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub(crate) fn pending_assignment(
+        &self,
+        writer: Address,
+        token_id: B256,
+    ) -> Result<PendingAssignment, OptionsError> {
+        let position = self.positions.get(Self::position_key(writer, token_id));
+        let quantity = position.quantity_written.get();
+        let units = position.assignment_units.get();
+        let epoch = position.assignment_epoch.get();
+        let strike_index = position.strike_index.get();
+        let collateral_index = position.collateral_index.get();
+        let assigned_index = position.assigned_index.get();
+
+        let global = self.global_positions.get(token_id);
+        let global_epoch = global.assignment_epoch.get();
+        let global_quantity = global.quantity_written.get();
+        let global_units = global.assignment_units.get();
+        let global_strike_per_unit = global.strike_per_unit.get();
+        let global_collateral_per_unit = global.collateral_per_unit.get();
+        let global_quantity_assigned = global.quantity_assigned.get();
+
+        let credit = |units: U256, index_increase: U256| {
+            units
+                .checked_mul(index_increase)
+                .map(|credit| credit / ASSIGNMENT_INDEX_PRECISION)
+                .ok_or(OptionsError::Overflow(Overflow {}))
+        };
+
+        let (remaining, strike_credited, collateral_credited, units) = if epoch == global_epoch {
+            let remaining = if assigned_index == global_quantity_assigned {
+                quantity
+            } else {
+                quantity.min(quantity_of_units(units, global_quantity, global_units)?)
+            };
+            (
+                remaining,
+                credit(units, global_strike_per_unit.saturating_sub(strike_index))?,
+                credit(
+                    units,
+                    global_collateral_per_unit.saturating_sub(collateral_index),
+                )?,
+                units,
+            )
+        } else {
+            let closed = self.assignment_epochs.get(Self::epoch_key(token_id, epoch));
+            let closed_assigned = closed.quantity_assigned.get();
+            let carried = if assigned_index == closed_assigned {
+                quantity
+            } else {
+                quantity.min(quantity_of_units(
+                    units,
+                    closed.quantity.get(),
+                    closed.units.get(),
+                )?)
+            };
+            let strike_credited = credit(
+                units,
+                closed.strike_per_unit.get().saturating_sub(strike_index),
+            )?;
+            let collateral_credited = credit(
+                units,
+                closed
+                    .collateral_per_unit
+                    .get()
+                    .saturating_sub(collateral_index),
+            )?;
+
+            let next_epoch = epoch
+                .checked_add(U256::from(1))
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+            let (remaining, next_strike_per_unit, next_collateral_per_unit, units) =
+                if next_epoch == global_epoch {
+                    let remaining = if closed_assigned == global_quantity_assigned {
+                        carried
+                    } else {
+                        carried.min(quantity_of_units(carried, global_quantity, global_units)?)
+                    };
+                    (
+                        remaining,
+                        global_strike_per_unit,
+                        global_collateral_per_unit,
+                        carried,
+                    )
+                } else {
+                    let next = self
+                        .assignment_epochs
+                        .get(Self::epoch_key(token_id, next_epoch));
+                    (
+                        U256::ZERO,
+                        next.strike_per_unit.get(),
+                        next.collateral_per_unit.get(),
+                        U256::ZERO,
+                    )
+                };
+            (
+                remaining,
+                strike_credited.saturating_add(credit(carried, next_strike_per_unit)?),
+                collateral_credited.saturating_add(credit(carried, next_collateral_per_unit)?),
+                units,
+            )
+        };
+
+        Ok(PendingAssignment {
+            quantity: quantity - remaining,
+            strike_credited,
+            collateral_credited,
+            units,
+        })
+    }
+
+    /// Settles the call exercises assigned to a writer position since it was last
+    /// settled.
+    ///
+    /// Reduces the position by the assigned quantity as exercised and adds back credited
+    /// collateral (see `settled_amounts`), leaving the global position as is
+    /// (`assign_exercise` already updated it), credits the strike payment to the writer's
+    /// claimable balance, moves the position to the current pool epoch and emits
+    /// `ExerciseAssigned` if anything was assigned.
+    ///
+    /// # Parameters
+    /// - `writer`: Writer address
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Errors
+    /// - `Overflow`: Arithmetic overflow during calculation
+    pub(crate) fn settle_position_assignment(
+        &mut self,
+        writer: Address,
+        token_id: B256,
+    ) -> Result<(), OptionsError> {
+        let pending = self.pending_assignment(writer, token_id)?;
+        let key = Self::position_key(writer, token_id);
+
+        if !pending.quantity.is_zero() || !pending.collateral_credited.is_zero() {
+            let settled = settled_amounts(self.position_amounts(key), pending)?;
+            self.apply_position_reduction(key, settled, pending.quantity, true)?;
+        }
+        if !pending.strike_credited.is_zero() {
+            let claimable = self
+                .claimable_assignments
+                .get(key)
+                .checked_add(pending.strike_credited)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+            self.claimable_assignments.insert(key, claimable);
+        }
+
+        let global = self.global_positions.get(token_id);
+        let epoch = global.assignment_epoch.get();
+        let strike_per_unit = global.strike_per_unit.get();
+        let collateral_per_unit = global.collateral_per_unit.get();
+        let quantity_assigned = global.quantity_assigned.get();
+
+        let mut position = self.positions.setter(key);
+        position.assignment_units.set(pending.units);
+        position.assignment_epoch.set(epoch);
+        position.strike_index.set(strike_per_unit);
+        position.collateral_index.set(collateral_per_unit);
+        position.assigned_index.set(quantity_assigned);
+
+        if !pending.quantity.is_zero()
+            || !pending.strike_credited.is_zero()
+            || !pending.collateral_credited.is_zero()
+        {
+            log(
+                self.vm(),
+                ExerciseAssigned {
+                    writer,
+                    tokenId: token_id,
+                    quantity: pending.quantity,
+                    strikeCredited: pending.strike_credited,
+                    collateralCredited: pending.collateral_credited,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns a writer position's amounts and pending assignment as a settlement would
+    /// leave them.
+    ///
+    /// # Errors
+    /// - `Overflow`: Arithmetic overflow during calculation
+    fn settled_position(
+        &self,
+        writer: Address,
+        token_id: B256,
+    ) -> Result<(PositionAmounts, PendingAssignment), OptionsError> {
+        let amounts = self.position_amounts(Self::position_key(writer, token_id));
+        let pending = self.pending_assignment(writer, token_id)?;
+        Ok((settled_amounts(amounts, pending)?, pending))
+    }
+
+    /// Settles a vault-backed call exercise.
+    ///
+    /// Pulls the strike payment from `holder` into the series vault, then has the vault
+    /// deliver the underlying to `recipient` and the protocol fee to the fee recipient.
+    ///
+    /// # Parameters
+    /// - `holder`: Address that exercised and pays the strike
+    /// - `recipient`: Address receiving the underlying
+    /// - `exercise`: Recorded exercise amounts
+    /// - `min_received`: Minimum underlying `recipient` must receive (zero skips the check)
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Strike payment transfer or balance query failed
+    /// - `FeeOnTransferDetected`: Quote token deducts fees, or `recipient` received less
+    ///   than `min_received`
+    /// - `UnexpectedBalanceDecrease`: A balance decreased instead of increased
+    /// - `VaultCallFailed`: The vault rejected a withdrawal
+    #[allow(deprecated)]
+    pub(crate) fn settle_vault_exercise(
+        &mut self,
+        holder: Address,
+        recipient: Address,
+        exercise: &CallExercise,
+        min_received: U256,
+    ) -> Result<(), OptionsError> {
+        self.safe_transfer_erc20_from(
+            exercise.quote,
+            holder,
+            exercise.vault,
+            exercise.strike_payment,
+        )?;
+
+        let underlying = IERC20::new(exercise.underlying);
+        let balance_before = if min_received.is_zero() {
+            U256::ZERO
+        } else {
+            underlying
+                .balance_of(Call::new_in(self), recipient)
+                .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?
+        };
+
+        self.vault_withdraw(
+            exercise.vault,
+            exercise.token_id,
+            exercise.quantity - exercise.fee,
+            recipient,
+        )?;
+        if !min_received.is_zero() {
+            let balance_after = underlying
+                .balance_of(Call::new_in(self), recipient)
+                .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+            let received = balance_after.checked_sub(balance_before).ok_or(
+                OptionsError::UnexpectedBalanceDecrease(UnexpectedBalanceDecrease {}),
+            )?;
+            if received < min_received {
+                return Err(OptionsError::FeeOnTransferDetected(FeeOnTransferDetected {
+                    expected: min_received,
+                    received,
+                }));
+            }
+        }
+
+        let fee_recipient = self.fee_recipient.get();
+        self.vault_withdraw(
+            exercise.vault,
+            exercise.token_id,
+            exercise.fee,
+            fee_recipient,
+        )?;
+
+        Ok(())
+    }
+
+    /// Withdraws exercised collateral of series `token_id` from its vault to `recipient`.
+    ///
+    /// No-op for a zero amount, which the vault would reject.
+    ///
+    /// # Errors
+    /// - `VaultCallFailed`: The vault call reverted
+    #[allow(deprecated)]
+    pub(crate) fn vault_withdraw(
+        &mut self,
+        vault: Address,
+        token_id: B256,
+        assets: U256,
+        recipient: Address,
+    ) -> Result<(), OptionsError> {
+        if assets.is_zero() {
+            return Ok(());
+        }
+
+        IOptionVault::new(vault)
+            .exercise_withdraw(Call::new_in(self), token_id, assets, recipient)
+            .map_err(|_| OptionsError::VaultCallFailed(VaultCallFailed { vault }))?;
+
+        Ok(())
+    }
+
+    /// Exercises a vault-backed call for the caller, swapping underlying for the strike.
+    ///
+    /// Implementation of `exercise_call_via_swap`.
+    ///
+    /// # Errors
+    /// See `exercise_call_via_swap`
+    #[allow(deprecated)]
+    pub(crate) fn settle_swap_exercise(
+        &mut self,
+        token_id: B256,
+        quantity: U256,
+        router: Address,
+        swap_calldata: &[u8],
+        min_underlying_out: U256,
+    ) -> Result<U256, OptionsError> {
+        self.when_not_paused()?;
+
+        if !self.approved_routers.get(router) {
+            return Err(OptionsError::RouterNotApproved(RouterNotApproved {
+                router,
+            }));
+        }
+
+        let (holder, contract_addr) = {
+            let vm = self.vm();
+            (vm.msg_sender(), vm.contract_address())
+        };
+
+        let exercise = self.record_call_exercise(holder, token_id, quantity)?;
+        if exercise.vault == Address::ZERO {
+            return Err(OptionsError::InvalidVault(InvalidVault {
+                vault: Address::ZERO,
+            }));
+        }
+
+        let underlying_before = self.token_balance(exercise.underlying, contract_addr)?;
+        let quote_before = self.token_balance(exercise.quote, contract_addr)?;
+
+        self.vault_withdraw(
+            exercise.vault,
+            exercise.token_id,
+            exercise.quantity - exercise.fee,
+            contract_addr,
+        )?;
+        let fee_recipient = self.fee_recipient.get();
+        self.vault_withdraw(
+            exercise.vault,
+            exercise.token_id,
+            exercise.fee,
+            fee_recipient,
+        )?;
+        let released = self
+            .token_balance(exercise.underlying, contract_addr)?
+            .checked_sub(underlying_before)
+            .ok_or(OptionsError::UnexpectedBalanceDecrease(
+                UnexpectedBalanceDecrease {},
+            ))?;
+
+        let underlying = IERC20::new(exercise.underlying);
+        underlying
+            .approve(Call::new_in(self), router, released)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+        stylus_sdk::call::call(Call::new_in(self), router, swap_calldata)
+            .map_err(|_| OptionsError::SwapFailed(SwapFailed { router }))?;
+        underlying
+            .approve(Call::new_in(self), router, U256::ZERO)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+        // Anything below the pre-exercise balances means the router took collateral
+        // beyond the released underlying
+        let underlying_left = self
+            .token_balance(exercise.underlying, contract_addr)?
+            .checked_sub(underlying_before)
+            .ok_or(OptionsError::UnexpectedBalanceDecrease(
+                UnexpectedBalanceDecrease {},
+            ))?;
+        let quote_received = self
+            .token_balance(exercise.quote, contract_addr)?
+            .checked_sub(quote_before)
+            .ok_or(OptionsError::UnexpectedBalanceDecrease(
+                UnexpectedBalanceDecrease {},
+            ))?;
+
+        if quote_received < exercise.strike_payment {
+            return Err(OptionsError::InsufficientSwapOutput(
+                InsufficientSwapOutput {
+                    required: exercise.strike_payment,
+                    received: quote_received,
+                },
+            ));
+        }
+        if underlying_left < min_underlying_out {
+            return Err(OptionsError::SlippageExceeded(SlippageExceeded {
+                min_out: min_underlying_out,
+                received: underlying_left,
+            }));
+        }
+
+        if !exercise.strike_payment.is_zero() {
+            self.transfer_token(exercise.quote, exercise.vault, exercise.strike_payment)?;
+        }
+        let quote_surplus = quote_received - exercise.strike_payment;
+        if !quote_surplus.is_zero() {
+            self.transfer_token(exercise.quote, holder, quote_surplus)?;
+        }
+        if !underlying_left.is_zero() {
+            self.transfer_token(exercise.underlying, holder, underlying_left)?;
+        }
+
+        self.log_call_exercise(holder, holder, &exercise);
+        log(
+            self.vm(),
+            ExercisedViaSwap {
+                holder,
+                tokenId: token_id,
+                router,
+                underlyingSwapped: released - underlying_left,
+                quoteReceived: quote_received,
+                underlyingReturned: underlying_left,
+            },
+        );
+
+        Ok(underlying_left)
+    }
+
+    /// Returns `account`'s balance of an ERC20 token.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: Balance query failed
+    #[allow(deprecated)]
+    pub(crate) fn token_balance(
+        &mut self,
+        token: Address,
+        account: Address,
+    ) -> Result<U256, OptionsError> {
+        IERC20::new(token)
+            .balance_of(Call::new_in(self), account)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))
+    }
+
+    /// Emits the events for a settled call exercise.
+    ///
+    /// # Parameters
+    /// - `holder`: Address that exercised
+    /// - `recipient`: Address that received the underlying
+    /// - `exercise`: Settled exercise amounts
+    pub(crate) fn log_call_exercise(
+        &self,
+        holder: Address,
+        recipient: Address,
+        exercise: &CallExercise,
+    ) {
+        if !exercise.fee.is_zero() {
+            log(
+                self.vm(),
+                FeeCollected {
+                    payer: holder,
+                    tokenId: exercise.token_id,
+                    token: exercise.underlying,
+                    amount: exercise.fee,
+                },
+            );
+        }
+
+        log(
+            self.vm(),
+            ExerciseCall {
+                holder,
+                writer: holder,
+                tokenId: exercise.token_id,
+                recipient,
+                quantity: exercise.quantity,
+                normalizedQuantity: exercise.normalized_quantity,
+                strikePayment: exercise.strike_payment,
+                underlyingReceived: exercise.quantity - exercise.fee,
+            },
+        );
+    }
+
+    /// Transfers ERC20 tokens from the contract to `to`.
+    ///
+    /// # Errors
+    /// - `TransferFailed`: ERC20 transfer call failed or returned false
+    #[allow(deprecated)]
+    pub(crate) fn transfer_token(
+        &mut self,
+        token: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), OptionsError> {
+        let success = IERC20::new(token)
+            .transfer(Call::new_in(self), to, amount)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+        if !success {
+            return Err(OptionsError::TransferFailed(TransferFailed {}));
+        }
+
+        Ok(())
+    }
+
+    /// Transfers ERC20 tokens from the contract to `to` and measures what arrived.
+    ///
+    /// # Returns
+    /// Increase of `to`'s token balance
+    ///
+    /// # Errors
+    /// - `TransferFailed`: ERC20 call failed or transfer returned false
+    /// - `UnexpectedBalanceDecrease`: Recipient balance decreased
+    #[allow(deprecated)]
+    pub(crate) fn transfer_token_measured(
+        &mut self,
+        token: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<U256, OptionsError> {
+        let erc20 = IERC20::new(token);
+
+        let balance_before = erc20
+            .balance_of(Call::new_in(self), to)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+        self.transfer_token(token, to, amount)?;
+
+        let balance_after = erc20
+            .balance_of(Call::new_in(self), to)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+
+        balance_after
+            .checked_sub(balance_before)
+            .ok_or(OptionsError::UnexpectedBalanceDecrease(
+                UnexpectedBalanceDecrease {},
+            ))
+    }
+
+    /// Reads a token's decimals from the token contract.
+    ///
+    /// # Errors
+    /// - `DecimalsUnavailable`: The `decimals()` call failed
+    #[allow(deprecated)]
+    pub(crate) fn token_decimals(&mut self, token: Address) -> Result<u8, OptionsError> {
+        IERC20::new(token)
+            .decimals(Call::new_in(self))
+            .map_err(|_| OptionsError::DecimalsUnavailable(DecimalsUnavailable { token }))
+    }
+
+    /// Checks that a token's declared decimals match its on-chain `decimals()`.
+    ///
+    /// # Errors
+    /// - `DecimalsMismatch`: Declared decimals differ from the token's
+    /// - `DecimalsUnavailable`: The token does not implement `decimals()`
+    pub(crate) fn verify_token_decimals(&mut self, token: Token) -> Result<(), OptionsError> {
+        let actual = self.token_decimals(token.address)?;
+        if actual != token.decimals {
+            return Err(OptionsError::DecimalsMismatch(DecimalsMismatch {
+                token: token.address,
+                declared: token.decimals,
+                actual,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Checks that a series' tokens still report the decimals stored at write time.
+    ///
+    /// No-op unless strict decimals is enabled.
+    ///
+    /// # Errors
+    /// - `DecimalsChanged`: Underlying or quote decimals differ from the metadata
+    /// - `DecimalsUnavailable`: A `decimals()` call failed
+    pub(crate) fn check_decimals_unchanged(
+        &mut self,
+        metadata: &OptionMetadataView,
+    ) -> Result<(), OptionsError> {
+        if !self.strict_decimals.get() {
+            return Ok(());
+        }
+
+        for (token, stored) in [
+            (metadata.underlying, metadata.underlying_decimals),
+            (metadata.quote, metadata.quote_decimals),
+        ] {
+            let current = self.token_decimals(token)?;
+            if current != stored {
+                return Err(OptionsError::DecimalsChanged(DecimalsChanged {
+                    stored,
+                    current,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads a series' oracle price, enforcing `max_settlement_staleness`.
+    ///
+    /// The oracle answer is normalized from the oracle's `decimals` to 18 decimals.
+    ///
+    /// # Returns
+    /// Price of one underlying token in quote tokens (18 decimals normalized)
+    ///
+    /// # Errors
+    /// - `OracleCallFailed`: Oracle price, decimals or timestamp query failed
+    /// - `InvalidDecimals`: Oracle reports more than 18 decimals
+    /// - `NormalizationOverflow`: Normalized price overflows U256
+    /// - `StaleSettlementPrice`: Oracle price is older than `max_settlement_staleness`
+    #[allow(deprecated)]
+    pub(crate) fn oracle_price(
+        &mut self,
+        metadata: &OptionMetadataView,
+        current_time: u64,
+    ) -> Result<U256, OptionsError> {
+        let oracle = IPriceOracle::new(metadata.oracle);
+        let answer = oracle
+            .latest_price(Call::new_in(self), metadata.underlying, metadata.quote)
+            .map_err(|_| OptionsError::OracleCallFailed(OracleCallFailed {}))?;
+        let decimals = oracle
+            .decimals(Call::new_in(self))
+            .map_err(|_| OptionsError::OracleCallFailed(OracleCallFailed {}))?;
+        let price = normalize_amount(answer, decimals)?;
+
+        let max_staleness = self.max_settlement_staleness.get().to::<u64>();
+        if max_staleness != 0 {
+            let updated_at = oracle
+                .latest_timestamp(Call::new_in(self), metadata.underlying, metadata.quote)
+                .map_err(|_| OptionsError::OracleCallFailed(OracleCallFailed {}))?;
+            validate_price_freshness(updated_at, current_time, max_staleness)?;
+        }
+
+        Ok(price)
+    }
+
+    /// Exercises a batch of options for the caller.
+    ///
+    /// Implementation of `batch_exercise`.
+    ///
+    /// # Errors
+    /// See `batch_exercise`
+    pub(crate) fn exercise_batch(
+        &mut self,
+        token_ids: &[B256],
+        quantities: &[U256],
+    ) -> Result<(), OptionsError> {
+        self.when_not_paused()?;
+
+        let size = token_ids.len();
+        if quantities.len() != size {
+            return Err(OptionsError::BatchLengthMismatch(BatchLengthMismatch {}));
+        }
+        if size == 0 || size > MAX_BATCH_SIZE {
+            return Err(OptionsError::InvalidBatchSize(InvalidBatchSize {
+                size: U256::from(size),
+                max: U256::from(MAX_BATCH_SIZE),
+            }));
+        }
+
+        let holder = self.vm().msg_sender();
+        let fee_recipient = self.fee_recipient.get();
+
+        let mut exercises = Vec::with_capacity(size);
+        let mut payouts = Vec::new();
+        let mut total_quantity = U256::ZERO;
+        for (&token_id, &quantity) in token_ids.iter().zip(quantities) {
+            if self.get_option_metadata(token_id).option_type == OptionType::Put.to_u8() {
+                // Put exercise is not implemented yet
+                return Err(OptionsError::Unimplemented(Unimplemented {}));
+            }
+
+            let exercise = self.record_call_exercise(holder, token_id, quantity)?;
+            total_quantity = total_quantity
+                .checked_add(exercise.normalized_quantity)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+            // Vault-backed legs are paid out by their vault below
+            if exercise.vault != Address::ZERO {
+                exercises.push(exercise);
+                continue;
+            }
+
+            add_payout(
+                &mut payouts,
+                exercise.underlying,
+                holder,
+                exercise.quantity - exercise.fee,
+            )?;
+            add_payout(
+                &mut payouts,
+                exercise.underlying,
+                fee_recipient,
+                exercise.fee,
+            )?;
+
+            exercises.push(exercise);
+        }
+
+        let contract_addr = self.vm().contract_address();
+        for exercise in &exercises {
+            if !exercise.strike_owed.is_zero() {
+                self.safe_transfer_erc20_from(
+                    exercise.quote,
+                    holder,
+                    contract_addr,
+                    exercise.strike_owed,
+                )?;
+            }
+        }
+
+        // One transfer per distinct (token, recipient) after all state updates
+        for (token, to, amount) in payouts {
+            self.transfer_token(token, to, amount)?;
+        }
+        for exercise in exercises
+            .iter()
+            .filter(|exercise| exercise.vault != Address::ZERO)
+        {
+            self.settle_vault_exercise(holder, holder, exercise, U256::ZERO)?;
+        }
+
+        for exercise in &exercises {
+            self.log_call_exercise(holder, holder, exercise);
+        }
+        log(
+            self.vm(),
+            BatchExercised {
+                holder,
+                legs: U256::from(size),
+                totalNormalizedQuantity: total_quantity,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Exercises the cash call balances of a list of holders for the caller.
+    ///
+    /// Implementation of `auto_exercise`.
+    ///
+    /// # Errors
+    /// See `auto_exercise`
+    pub(crate) fn auto_exercise_holders(
+        &mut self,
+        token_id: B256,
+        holders: &[Address],
+    ) -> Result<U256, OptionsError> {
+        self.when_not_paused()?;
+
+        let size = holders.len();
+        if size == 0 || size > MAX_BATCH_SIZE {
+            return Err(OptionsError::InvalidBatchSize(InvalidBatchSize {
+                size: U256::from(size),
+                max: U256::from(MAX_BATCH_SIZE),
+            }));
+        }
+
+        let metadata = self.get_cash_call_metadata(token_id)?;
+        if metadata.settlement_price.is_zero() {
+            return Err(OptionsError::SettlementPriceRequired(
+                SettlementPriceRequired {},
+            ));
+        }
+        self.ensure_not_forfeited(token_id, metadata.expiry)?;
+        self.check_decimals_unchanged(&metadata)?;
+
+        let one = U256::from(10).pow(U256::from(18));
+        let (intrinsic_value, _) = cash_call_settlement(
+            metadata.settlement_price,
+            metadata.strike,
+            metadata.cap,
+            one,
+        )?;
+        let threshold = self.auto_exercise_threshold.get();
+        if intrinsic_value <= threshold {
+            return Err(OptionsError::BelowAutoExerciseThreshold(
+                BelowAutoExerciseThreshold {
+                    intrinsic_value,
+                    threshold,
+                },
+            ));
+        }
+
+        let keeper = self.vm().msg_sender();
+        let keeper_fee_bps = self.keeper_fee_bps.get();
+
+        // (holder, quantity, payout, keeper fee)
+        let mut exercises = Vec::with_capacity(size);
+        let mut total_keeper_fee = U256::ZERO;
+        let mut total_quantity = U256::ZERO;
+        for &holder in holders {
+            let quantity = self.balance_of(holder, token_id);
+            if quantity.is_zero() {
+                continue;
+            }
+
+            let (holder_value, _) = cash_call_settlement(
+                metadata.settlement_price,
+                metadata.strike,
+                metadata.cap,
+                quantity,
+            )?;
+            let value = denormalize_amount(holder_value, metadata.quote_decimals)?;
+            let keeper_fee = protocol_fee(value, keeper_fee_bps)?;
+
+            self._burn(holder, token_id, quantity)?;
+            // The payout stays locked until the holder claims it
+            self.release_collateral(metadata.quote, keeper_fee)?;
+            let payout = value - keeper_fee;
+            let claimable = self
+                .claimable_cash
+                .getter(holder)
+                .get(token_id)
+                .checked_add(payout)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+            self.claimable_cash
+                .setter(holder)
+                .insert(token_id, claimable);
+
+            total_quantity = total_quantity
+                .checked_add(quantity)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+            total_keeper_fee = total_keeper_fee
+                .checked_add(keeper_fee)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+            exercises.push((holder, quantity, payout, keeper_fee));
+        }
+
+        let new_total_exercised = self
+            .total_exercised
+            .get(token_id)
+            .checked_add(total_quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.total_exercised.insert(token_id, new_total_exercised);
+
+        // External call after all state updates (reentrancy protection)
+        if !total_keeper_fee.is_zero() {
+            self.transfer_token(metadata.quote, keeper, total_keeper_fee)?;
+        }
+
+        for (holder, quantity, payout, keeper_fee) in exercises {
+            log(
+                self.vm(),
+                AutoExercised {
+                    holder,
+                    tokenId: token_id,
+                    keeper,
+                    quantity,
+                    payout,
+                    keeperFee: keeper_fee,
+                },
+            );
+        }
+
+        Ok(total_keeper_fee)
+    }
+
+    /// Settles an exercise of a call spread series against the spread's escrow.
+    ///
+    /// Assigns the exercise pro rata across the spread's writers (see
+    /// `assign_exercise`), whose share of the quote collateral is released, and exercises
+    /// the same quantity of escrowed long calls against the long series' writers, which
+    /// releases the underlying delivered to the holder. The released collateral pays the
+    /// long strike down to the spread's strike; the holder owes the rest.
+    ///
+    /// # Parameters
+    /// - `holder`: Address exercising
+    /// - `token_id`: ERC-1155 token ID of the call spread series
+    /// - `long_token_id`: ERC-1155 token ID of the escrowed long call series
+    /// - `quantity`: Quantity exercised (in underlying token's native decimals)
+    ///
+    /// # Returns
+    /// Strike payment the holder owes (quote token's native decimals)
+    ///
+    /// # Errors
+    /// - All errors of `exercise_call` for the long series
+    /// - `Overflow`: Arithmetic overflow during calculation
+    fn exercise_spread(
+        &mut self,
+        holder: Address,
+        token_id: B256,
+        long_token_id: B256,
+        quantity: U256,
+    ) -> Result<U256, OptionsError> {
+        let metadata = self.get_option_metadata(token_id);
+        let normalized_quantity = normalize_amount(quantity, metadata.underlying_decimals)?;
+
+        let collateral_before = self.global_positions.get(token_id).collateral_locked.get();
+        self.assign_exercise(holder, token_id, normalized_quantity, U256::ZERO, None)?;
+        let collateral_assigned =
+            collateral_before - self.global_positions.get(token_id).collateral_locked.get();
+        let collateral_released = denormalize_amount(collateral_assigned, metadata.quote_decimals)?;
+        self.release_collateral(metadata.quote, collateral_released)?;
+
+        // The long strike owed is locked for the long writers out of the released
+        // collateral and the holder's payment
+        let contract_addr = self.vm().contract_address();
+        let long = self.record_call_exercise(contract_addr, long_token_id, quantity)?;
+
+        Ok(long.strike_owed.saturating_sub(collateral_released))
+    }
+
+    /// Writes a call spread for the caller.
+    ///
+    /// Implementation of `write_call_spread`.
+    ///
+    /// # Errors
+    /// See `write_call_spread`
+    pub(crate) fn write_spread(
+        &mut self,
+        sell_token_id: B256,
+        buy_token_id: B256,
+        quantity: U256,
+    ) -> Result<B256, OptionsError> {
+        self.when_not_paused()?;
+
+        let (current_timestamp, writer, contract_addr) = {
+            let vm = self.vm();
+            (vm.block_timestamp(), vm.msg_sender(), vm.contract_address())
+        };
+
+        let short = self.get_option_metadata(sell_token_id);
+        let long = self.get_option_metadata(buy_token_id);
+        if short.expiry.is_zero() || long.expiry.is_zero() {
+            return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+        }
+
+        for leg in [short, long] {
+            if leg.option_type != OptionType::Call.to_u8() {
+                return Err(OptionsError::WrongOptionType(WrongOptionType {
+                    expected: OptionType::Call.to_u8(),
+                    actual: leg.option_type,
+                }));
+            }
+            if leg.settlement != SettlementType::Physical.to_u8() {
+                return Err(OptionsError::WrongSettlementType(WrongSettlementType {
+                    expected: SettlementType::Physical.to_u8(),
+                    actual: leg.settlement,
+                }));
+            }
+        }
+
+        if short.underlying != long.underlying
+            || short.quote != long.quote
+            || short.underlying_decimals != long.underlying_decimals
+            || short.quote_decimals != long.quote_decimals
+            || short.expiry != long.expiry
+            || short.oracle != long.oracle
+            || short.exercise_style != long.exercise_style
+            || short.vault != Address::ZERO
+            || long.vault != Address::ZERO
+            || self.spread_long_token_id.get(sell_token_id) != B256::ZERO
+            || self.spread_long_token_id.get(buy_token_id) != B256::ZERO
+        {
+            return Err(OptionsError::InvalidSpread(InvalidSpread {}));
+        }
+
+        if short.expiry <= U256::from(current_timestamp) {
+            return Err(OptionsError::ExpiredOption(ExpiredOption {
+                expiry: short.expiry,
+                current: U256::from(current_timestamp),
+            }));
+        }
+
+        if quantity.is_zero() {
+            return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+        }
+
+        let normalized_quantity = normalize_amount(quantity, short.underlying_decimals)?;
+
+        let long_balance = self.balance_of(writer, buy_token_id);
+        if long_balance < normalized_quantity {
+            return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+                available: long_balance,
+                requested: normalized_quantity,
+            }));
+        }
+
+        let collateral = spread_collateral(
+            short.strike,
+            long.strike,
+            normalized_quantity,
+            short.quote_decimals,
+        )?;
+        let normalized_collateral = normalize_amount(collateral, short.quote_decimals)?;
+
+        let token_id = generate_spread_token_id(sell_token_id, buy_token_id);
+        let new_total_written = self
+            .total_written
+            .get(token_id)
+            .checked_add(normalized_quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+        if self.spread_long_token_id.get(token_id) == B256::ZERO {
+            self.store_option_metadata(
+                token_id,
+                Token {
+                    address: short.underlying,
+                    decimals: short.underlying_decimals,
+                },
+                Token {
+                    address: short.quote,
+                    decimals: short.quote_decimals,
+                },
+                short.strike,
+                u64::try_from(short.expiry).map_err(|_| OptionsError::Overflow(Overflow {}))?,
+                OptionType::Call,
+            )?;
+            let mut metadata = self.option_metadata.setter(token_id);
+            metadata.oracle.set(short.oracle);
+            metadata.exercise_style.set(U8::from(short.exercise_style));
+            self.spread_long_token_id.insert(token_id, buy_token_id);
+        }
+
+        // Escrow the long calls so they cannot be exercised or closed independently
+        self._transfer(
+            writer,
+            writer,
+            contract_addr,
+            buy_token_id,
+            normalized_quantity,
+        )?;
+
+        self.create_or_update_position(
+            writer,
+            token_id,
+            normalized_quantity,
+            normalized_collateral,
+        )?;
+
+        self._mint(writer, token_id, normalized_quantity)?;
+
+        self.total_written.insert(token_id, new_total_written);
+
+        self.lock_collateral(short.quote, collateral)?;
+
+        // External call after all state updates (reentrancy protection)
+        self.safe_transfer_erc20_from(short.quote, writer, contract_addr, collateral)?;
+
+        log(
+            self.vm(),
+            SpreadWritten {
+                writer,
+                shortTokenId: sell_token_id,
+                longTokenId: buy_token_id,
+                spreadTokenId: token_id,
+                quantity: normalized_quantity,
+                collateral,
+            },
+        );
+
+        self.check_minted(writer, token_id, normalized_quantity)?;
+
+        Ok(token_id)
+    }
+
+    /// Adds to the collateral locked in a token.
+    ///
+    /// # Errors
+    /// - `Overflow`: Locked collateral would overflow
+    pub(crate) fn lock_collateral(
+        &mut self,
+        token: Address,
+        amount: U256,
+    ) -> Result<(), OptionsError> {
+        let locked = self
+            .locked_collateral
+            .get(token)
+            .checked_add(amount)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.locked_collateral.insert(token, locked);
+        Ok(())
+    }
+
+    /// Subtracts from the collateral locked in a token when it leaves the contract.
+    ///
+    /// # Errors
+    /// - `Overflow`: Amount exceeds the locked collateral
+    pub(crate) fn release_collateral(
+        &mut self,
+        token: Address,
+        amount: U256,
+    ) -> Result<(), OptionsError> {
+        let locked = self
+            .locked_collateral
+            .get(token)
+            .checked_sub(amount)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.locked_collateral.insert(token, locked);
+        Ok(())
+    }
+
+    /// Ensures the caller is the contract owner.
+    ///
+    /// # Errors
+    /// - `Unauthorized`: Caller is not the owner (or no owner is set)
+    fn only_owner(&self) -> Result<(), OptionsError> {
+        let caller = self.vm().msg_sender();
+        if self.owner.get() == Address::ZERO || caller != self.owner.get() {
+            return Err(OptionsError::Unauthorized(Unauthorized { caller }));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the highest fee tier `payer`'s write volume has reached and its write fee.
+    ///
+    /// Tier 0 is the protocol fee; a tier's fee never exceeds it.
+    pub(crate) fn write_fee_tier(&self, payer: Address) -> (usize, U256) {
+        let volume = self.write_volume.get(payer);
+        let base_fee_bps = self.fee_bps.get();
+        (0..self.fee_tier_thresholds.len())
+            .rev()
+            .find(|&i| self.fee_tier_thresholds.get(i).is_some_and(|t| volume >= t))
+            .map_or((0, base_fee_bps), |i| {
+                let tier_fee_bps = self.fee_tier_bps.get(i).unwrap_or(base_fee_bps);
+                (i + 1, tier_fee_bps.min(base_fee_bps))
+            })
+    }
+
+    /// Adds a write to `payer`'s cumulative write volume.
+    ///
+    /// Called after the write's fee is computed, so a write that crosses a threshold
+    /// is still charged at the previous tier.
+    ///
+    /// # Errors
+    /// - `Overflow`: Volume accumulation would overflow
+    pub(crate) fn record_write_volume(
+        &mut self,
+        payer: Address,
+        normalized_quantity: U256,
+    ) -> Result<(), OptionsError> {
+        let volume = self
+            .write_volume
+            .get(payer)
+            .checked_add(normalized_quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.write_volume.insert(payer, volume);
+        Ok(())
+    }
+
+    /// Runs `f` while holding the reentrancy lock.
+    ///
+    /// Wraps every state-changing entry point that makes external token or oracle
+    /// calls, so a malicious token cannot call back into the contract mid-operation.
+    /// The lock is released on both success and error.
+    ///
+    /// # Errors
+    /// - `Reentrancy`: Lock is already held
+    /// - Any error returned by `f`
+    pub(crate) fn non_reentrant<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, OptionsError>,
+    ) -> Result<T, OptionsError> {
+        if self.locked.get() {
+            return Err(OptionsError::Reentrancy(Reentrancy {}));
+        }
+
+        self.locked.set(true);
+        let result = f(self);
+        self.locked.set(false);
+
+        result
+    }
+
+    /// Ensures the contract is not paused.
+    ///
+    /// # Errors
+    /// - `Paused`: Contract is paused
+    pub(crate) fn when_not_paused(&self) -> Result<(), OptionsError> {
+        if self.paused.get() {
+            return Err(OptionsError::Paused(Paused {}));
+        }
+
+        Ok(())
+    }
+
+    /// Writes a call option paid by the caller, optionally bound to a settlement oracle.
+    ///
+    /// Shared implementation of `write_call_option`, `write_call_option_for` and
+    /// `write_cash_settled_call_option`. Collateral is pulled from the caller while the
+    /// option tokens and position go to `writer`.
+    ///
+    /// # Parameters
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp (Unix seconds)
+    /// - `quantity`: Quantity of options to write (in underlying token's native decimals)
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    /// - `oracle`: Price oracle for cash settlement (zero for physical settlement)
+    /// - `writer`: Address receiving the option tokens and owning the position
+    ///
+    /// # Returns
+    /// Token ID (B256) representing this option series
+    ///
+    /// # Errors
+    /// See `write_call_option`
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn write_call(
+        &mut self,
+        strike: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+        oracle: Address,
+        writer: Address,
+        style: ExerciseStyle,
+    ) -> Result<B256, OptionsError> {
+        self.when_not_paused()?;
+
+        let (payer, contract_addr) = {
+            let vm = self.vm();
+            (vm.msg_sender(), vm.contract_address())
+        };
+
+        let (token_id, normalized_quantity) = self.record_call_write(
+            strike, expiry, quantity, underlying, quote, oracle, writer, style,
+        )?;
+        self.lock_collateral(underlying.address, quantity)?;
+        let fee = protocol_fee(quantity, self.write_fee_tier(payer).1)?;
+        self.record_write_volume(payer, normalized_quantity)?;
+
+        // External call after all state updates (reentrancy protection)
+        self.safe_transfer_erc20_from(underlying.address, payer, contract_addr, quantity)?;
+
+        if !fee.is_zero() {
+            let fee_recipient = self.fee_recipient.get();
+            self.safe_transfer_erc20_from(underlying.address, payer, fee_recipient, fee)?;
+            log(
+                self.vm(),
+                FeeCollected {
+                    payer,
+                    tokenId: token_id,
+                    token: underlying.address,
+                    amount: fee,
+                },
+            );
+        }
+
+        log(
+            self.vm(),
+            OptionWritten {
+                writer,
+                tokenId: token_id,
+                payer,
+                quantity: normalized_quantity,
+                collateral: normalized_quantity,
+                optionType: OptionType::Call.to_u8(),
+                strike,
+                expiry: U256::from(expiry),
+            },
+        );
+
+        self.check_minted(writer, token_id, normalized_quantity)?;
+
+        Ok(token_id)
+    }
+
+    /// Rolls the caller's call position into a new series.
+    ///
+    /// Implementation of `roll_position`.
+    ///
+    /// # Errors
+    /// See `roll_position`
+    pub(crate) fn roll_call(
+        &mut self,
+        old_token_id: B256,
+        new_expiry: u64,
+        new_strike: U256,
+        quantity: U256,
+    ) -> Result<B256, OptionsError> {
+        self.when_not_paused()?;
+
+        let (writer, contract_addr) = {
+            let vm = self.vm();
+            (vm.msg_sender(), vm.contract_address())
+        };
+
+        let metadata = self.get_option_metadata(old_token_id);
+        if metadata.expiry.is_zero() {
+            return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+        }
+        if metadata.option_type != OptionType::Call.to_u8() {
+            return Err(OptionsError::WrongOptionType(WrongOptionType {
+                expected: OptionType::Call.to_u8(),
+                actual: metadata.option_type,
+            }));
+        }
+        if metadata.settlement != SettlementType::Physical.to_u8() {
+            return Err(OptionsError::WrongSettlementType(WrongSettlementType {
+                expected: SettlementType::Physical.to_u8(),
+                actual: metadata.settlement,
+            }));
+        }
+        if metadata.vault != Address::ZERO {
+            return Err(OptionsError::InvalidVault(InvalidVault {
+                vault: metadata.vault,
+            }));
+        }
+        if self.spread_long_token_id.get(old_token_id) != B256::ZERO {
+            return Err(OptionsError::InvalidSpread(InvalidSpread {}));
+        }
+
+        if quantity.is_zero() {
+            return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+        }
+
+        let normalized_quantity = normalize_amount(quantity, metadata.underlying_decimals)?;
+
+        let writer_balance = self.balance_of(writer, old_token_id);
+        if writer_balance < normalized_quantity {
+            return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+                available: writer_balance,
+                requested: normalized_quantity,
+            }));
+        }
+
+        let (quantity_written, _) = self.get_position(writer, old_token_id);
+        if quantity_written < normalized_quantity {
+            return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+                available: quantity_written,
+                requested: normalized_quantity,
+            }));
+        }
+
+        self._burn(writer, old_token_id, normalized_quantity)?;
+        let collateral_released =
+            self.reduce_position(writer, old_token_id, normalized_quantity, false)?;
+        let collateral_carried =
+            denormalize_amount(collateral_released, metadata.underlying_decimals)?;
+        self.release_collateral(metadata.underlying, collateral_carried)?;
+
+        let underlying = Token {
+            address: metadata.underlying,
+            decimals: metadata.underlying_decimals,
+        };
+        let quote = Token {
+            address: metadata.quote,
+            decimals: metadata.quote_decimals,
+        };
+        let style = if metadata.exercise_style == ExerciseStyle::European.to_u8() {
+            ExerciseStyle::European
+        } else {
+            ExerciseStyle::American
+        };
+        let (new_token_id, new_normalized_quantity) = self.record_call_write(
+            new_strike,
+            new_expiry,
+            quantity,
+            underlying,
+            quote,
+            metadata.oracle,
+            writer,
+            style,
+        )?;
+        // Calls lock `quantity` of underlying in every series
+        let collateral_required = quantity;
+        self.lock_collateral(metadata.underlying, collateral_required)?;
+        let fee = protocol_fee(quantity, self.write_fee_tier(writer).1)?;
+        self.record_write_volume(writer, new_normalized_quantity)?;
+
+        // External calls after all state updates (reentrancy protection)
+        let collateral_paid = checked_sub_or_zero(collateral_required, collateral_carried);
+        let collateral_refunded = checked_sub_or_zero(collateral_carried, collateral_required);
+        if !collateral_paid.is_zero() {
+            self.safe_transfer_erc20_from(
+                metadata.underlying,
+                writer,
+                contract_addr,
+                collateral_paid,
+            )?;
+        }
+        if !collateral_refunded.is_zero() {
+            self.transfer_token(metadata.underlying, writer, collateral_refunded)?;
+        }
+
+        if !fee.is_zero() {
+            let fee_recipient = self.fee_recipient.get();
+            self.safe_transfer_erc20_from(metadata.underlying, writer, fee_recipient, fee)?;
+            log(
+                self.vm(),
+                FeeCollected {
+                    payer: writer,
+                    tokenId: new_token_id,
+                    token: metadata.underlying,
+                    amount: fee,
+                },
+            );
+        }
+
+        log(
+            self.vm(),
+            OptionWritten {
+                writer,
+                tokenId: new_token_id,
+                payer: writer,
+                quantity: new_normalized_quantity,
+                collateral: new_normalized_quantity,
+                optionType: OptionType::Call.to_u8(),
+                strike: new_strike,
+                expiry: U256::from(new_expiry),
+            },
+        );
+        log(
+            self.vm(),
+            PositionRolled {
+                writer,
+                oldTokenId: old_token_id,
+                newTokenId: new_token_id,
+                quantity,
+                collateralPaid: collateral_paid,
+                collateralRefunded: collateral_refunded,
+            },
+        );
+
+        Ok(new_token_id)
+    }
+
+    /// Records a call write without moving collateral.
+    ///
+    /// Validates the parameters, stores the series metadata and mints option tokens and
+    /// the writer position. The caller is responsible for pulling the collateral
+    /// (`quantity` of underlying) and emitting `OptionWritten`.
+    ///
+    /// # Parameters
+    /// See `write_call`
+    ///
+    /// # Returns
+    /// Tuple of (token_id, normalized quantity)
+    ///
+    /// # Errors
+    /// See `write_call_option`
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn record_call_write(
+        &mut self,
+        strike: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+        oracle: Address,
+        writer: Address,
+        style: ExerciseStyle,
+    ) -> Result<(B256, U256), OptionsError> {
+        let current_timestamp = self.vm().block_timestamp();
+
+        validate_write_params(
+            strike,
+            expiry,
+            quantity,
+            underlying,
+            quote,
+            current_timestamp,
+            self.max_expiry_horizon(),
+            self.strike_tick.get(),
+        )?;
+
+        let american_token_id = if oracle == Address::ZERO {
+            generate_token_id(
+                underlying.address,
+                quote.address,
+                strike,
+                expiry,
+                OptionType::Call,
+            )
+        } else {
+            generate_cash_settled_token_id(
+                underlying.address,
+                quote.address,
+                strike,
+                expiry,
+                OptionType::Call,
+                oracle,
+            )
+        };
+        let token_id = match style {
+            ExerciseStyle::American => american_token_id,
+            ExerciseStyle::European => generate_european_token_id(american_token_id),
+        };
+        self.ensure_series_not_expired(token_id, current_timestamp)?;
+
+        let normalized_quantity = normalize_amount(quantity, underlying.decimals)?;
+        let new_total_written = self
+            .total_written
+            .get(token_id)
+            .checked_add(normalized_quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        validate_strike_notional(strike, new_total_written)?;
+
+        // Later writes are checked against the stored metadata instead
+        if self.option_metadata.get(token_id).expiry.get().is_zero() {
+            self.verify_token_decimals(underlying)?;
+            self.verify_token_decimals(quote)?;
+        }
+
+        self.store_option_metadata(
+            token_id,
+            underlying,
+            quote,
+            strike,
+            expiry,
+            OptionType::Call,
+        )?;
+        if oracle != Address::ZERO {
+            self.option_metadata.setter(token_id).oracle.set(oracle);
+        }
+        if style == ExerciseStyle::European {
+            self.option_metadata
+                .setter(token_id)
+                .exercise_style
+                .set(U8::from(style.to_u8()));
+        }
+
+        self.create_or_update_position(writer, token_id, normalized_quantity, normalized_quantity)?;
+
+        self._mint(writer, token_id, normalized_quantity)?;
+
+        self.total_written.insert(token_id, new_total_written);
+
+        Ok((token_id, normalized_quantity))
+    }
+
+    /// Writes a cash call for the caller, locking `cap * quantity` of quote tokens.
+    ///
+    /// Implementation of `write_cash_call_option`.
+    ///
+    /// # Errors
+    /// See `write_cash_call_option`
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn write_cash_call(
+        &mut self,
+        strike: U256,
+        cap: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+        oracle: Address,
+    ) -> Result<B256, OptionsError> {
+        self.when_not_paused()?;
+
+        let (writer, contract_addr, current_timestamp) = {
+            let vm = self.vm();
+            (vm.msg_sender(), vm.contract_address(), vm.block_timestamp())
+        };
+
+        validate_write_params(
+            strike,
+            expiry,
+            quantity,
+            underlying,
+            quote,
+            current_timestamp,
+            self.max_expiry_horizon(),
+            self.strike_tick.get(),
+        )?;
+
+        if cap.is_zero() {
+            return Err(OptionsError::InvalidCap(InvalidCap {}));
+        }
+        if cap > MAX_STRIKE {
+            return Err(OptionsError::StrikeTooLarge(StrikeTooLarge {
+                strike: cap,
+                max: MAX_STRIKE,
+            }));
+        }
+
+        let token_id = generate_cash_call_token_id(
+            underlying.address,
+            quote.address,
+            strike,
+            expiry,
+            cap,
+            oracle,
+        );
+        self.ensure_series_not_expired(token_id, current_timestamp)?;
+
+        let normalized_quantity = normalize_amount(quantity, underlying.decimals)?;
+        let new_total_written = self
+            .total_written
+            .get(token_id)
+            .checked_add(normalized_quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        validate_strike_notional(strike, new_total_written)?;
+        validate_strike_notional(cap, new_total_written)?;
+
+        // Later writes are checked against the stored metadata instead
+        if self.option_metadata.get(token_id).expiry.get().is_zero() {
+            self.verify_token_decimals(underlying)?;
+            self.verify_token_decimals(quote)?;
+        }
+
+        self.store_option_metadata(
+            token_id,
+            underlying,
+            quote,
+            strike,
+            expiry,
+            OptionType::Call,
+        )?;
+        {
+            let mut metadata = self.option_metadata.setter(token_id);
+            metadata.oracle.set(oracle);
+            metadata
+                .settlement
+                .set(U8::from(SettlementType::Cash.to_u8()));
+            metadata.cap.set(cap);
+        }
+
+        let collateral = strike_payment(cap, normalized_quantity, quote.decimals)?;
+        let normalized_collateral = normalize_amount(collateral, quote.decimals)?;
+
+        self.create_or_update_position(
+            writer,
+            token_id,
+            normalized_quantity,
+            normalized_collateral,
+        )?;
+        self._mint(writer, token_id, normalized_quantity)?;
+        self.total_written.insert(token_id, new_total_written);
+        self.lock_collateral(quote.address, collateral)?;
+
+        // External call after all state updates (reentrancy protection)
+        self.safe_transfer_erc20_from(quote.address, writer, contract_addr, collateral)?;
+
+        log(
+            self.vm(),
+            OptionWritten {
+                writer,
+                tokenId: token_id,
+                payer: writer,
+                quantity: normalized_quantity,
+                collateral: normalized_collateral,
+                optionType: OptionType::Call.to_u8(),
+                strike,
+                expiry: U256::from(expiry),
+            },
+        );
+
+        self.check_minted(writer, token_id, normalized_quantity)?;
+
+        Ok(token_id)
+    }
+
+    /// Writes a vault-backed call for the caller, depositing the collateral into `vault`.
+    ///
+    /// Implementation of `write_vault_call_option`.
+    ///
+    /// # Errors
+    /// See `write_vault_call_option`
+    #[allow(deprecated)]
+    pub(crate) fn write_vault_call(
+        &mut self,
+        strike: U256,
+        expiry: u64,
+        quantity: U256,
+        underlying: Token,
+        quote: Token,
+        vault: Address,
+    ) -> Result<B256, OptionsError> {
+        self.when_not_paused()?;
+
+        let (writer, contract_addr, current_timestamp) = {
+            let vm = self.vm();
+            (vm.msg_sender(), vm.contract_address(), vm.block_timestamp())
+        };
+
+        validate_write_params(
+            strike,
+            expiry,
+            quantity,
+            underlying,
+            quote,
+            current_timestamp,
+            self.max_expiry_horizon(),
+            self.strike_tick.get(),
+        )?;
+
+        let token_id =
+            generate_vault_token_id(underlying.address, quote.address, strike, expiry, vault);
+        self.ensure_series_not_expired(token_id, current_timestamp)?;
+
+        let normalized_quantity = normalize_amount(quantity, underlying.decimals)?;
+        let new_total_written = self
+            .total_written
+            .get(token_id)
+            .checked_add(normalized_quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        validate_strike_notional(strike, new_total_written)?;
+
+        // Later writes are checked against the stored metadata instead
+        if self.option_metadata.get(token_id).expiry.get().is_zero() {
+            self.verify_token_decimals(underlying)?;
+            self.verify_token_decimals(quote)?;
+
+            // The vault pays out any exercise of its series, so it must back this one
+            // series only
+            let (options_contract, vault_token_id, asset, strike_asset, vault_expiry) =
+                IOptionVault::new(vault)
+                    .get_series(Call::new_in(self))
+                    .map_err(|_| OptionsError::VaultCallFailed(VaultCallFailed { vault }))?;
+            if options_contract != contract_addr
+                || vault_token_id != token_id
+                || asset != underlying.address
+                || strike_asset != quote.address
+                || vault_expiry != U256::from(expiry)
+            {
+                return Err(OptionsError::InvalidVault(InvalidVault { vault }));
+            }
+        }
+
+        self.store_option_metadata(
+            token_id,
+            underlying,
+            quote,
+            strike,
+            expiry,
+            OptionType::Call,
+        )?;
+        self.option_metadata.setter(token_id).vault.set(vault);
+
+        self._mint(writer, token_id, normalized_quantity)?;
+        self.total_written.insert(token_id, new_total_written);
+        let fee = protocol_fee(quantity, self.write_fee_tier(writer).1)?;
+        self.record_write_volume(writer, normalized_quantity)?;
+
+        // External calls after all state updates (reentrancy protection). The collateral
+        // passes through this contract because the vault pulls deposits from its caller.
+        self.safe_transfer_erc20_from(underlying.address, writer, contract_addr, quantity)?;
+        IERC20::new(underlying.address)
+            .approve(Call::new_in(self), vault, quantity)
+            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+        IOptionVault::new(vault)
+            .deposit(Call::new_in(self), quantity, writer)
+            .map_err(|_| OptionsError::VaultCallFailed(VaultCallFailed { vault }))?;
+
+        if !fee.is_zero() {
+            let fee_recipient = self.fee_recipient.get();
+            self.safe_transfer_erc20_from(underlying.address, writer, fee_recipient, fee)?;
+            log(
+                self.vm(),
+                FeeCollected {
+                    payer: writer,
+                    tokenId: token_id,
+                    token: underlying.address,
+                    amount: fee,
+                },
+            );
+        }
+
+        log(
+            self.vm(),
+            OptionWritten {
+                writer,
+                tokenId: token_id,
+                payer: writer,
+                quantity: normalized_quantity,
+                collateral: normalized_quantity,
+                optionType: OptionType::Call.to_u8(),
+                strike,
+                expiry: U256::from(expiry),
+            },
+        );
+
+        self.check_minted(writer, token_id, normalized_quantity)?;
+
+        Ok(token_id)
+    }
+
+    /// Retrieves the metadata of a cash call series.
+    ///
+    /// # Errors
+    /// - `OptionNotFound`: Option metadata not found for token_id
+    /// - `WrongSettlementType`: Series is not a cash call
+    pub(crate) fn get_cash_call_metadata(
+        &self,
+        token_id: B256,
+    ) -> Result<OptionMetadataView, OptionsError> {
+        let metadata = self.get_option_metadata(token_id);
+        if metadata.expiry.is_zero() {
+            return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+        }
+        if metadata.settlement != SettlementType::Cash.to_u8() {
+            return Err(OptionsError::WrongSettlementType(WrongSettlementType {
+                expected: SettlementType::Cash.to_u8(),
+                actual: metadata.settlement,
+            }));
+        }
+        Ok(metadata)
+    }
+
+    /// Rejects exercises of a cash call series whose unexercised value was forfeited.
+    ///
+    /// # Errors
+    /// - `ExerciseAfterExpiry`: `collect_forfeited_value` has been called for the series
+    fn ensure_not_forfeited(&self, token_id: B256, expiry: U256) -> Result<(), OptionsError> {
+        if self.forfeiture_collected.get(token_id) {
+            return Err(OptionsError::ExerciseAfterExpiry(ExerciseAfterExpiry {
+                expiry,
+                current: U256::from(self.vm().block_timestamp()),
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the token a series' writers lock as collateral and its decimals.
+    ///
+    /// Underlying for physically settled calls; quote for puts, cash calls and call
+    /// spreads.
+    fn collateral_token(&self, token_id: B256, metadata: &OptionMetadataView) -> (Address, u8) {
+        if metadata.option_type == OptionType::Call.to_u8()
+            && metadata.settlement == SettlementType::Physical.to_u8()
+            && self.spread_long_token_id.get(token_id) == B256::ZERO
+        {
+            (metadata.underlying, metadata.underlying_decimals)
+        } else {
+            (metadata.quote, metadata.quote_decimals)
+        }
+    }
+
+    /// Returns escrowed long calls of a call spread series to a writer.
+    ///
+    /// Does nothing for other series.
+    ///
+    /// # Parameters
+    /// - `writer`: Writer whose spread position was reduced
+    /// - `token_id`: ERC-1155 token ID of the series
+    /// - `quantity`: Quantity the position was reduced by (18 decimals normalized)
+    ///
+    /// # Errors
+    /// - `InsufficientBalance`: Fewer long calls are escrowed than `quantity`
+    fn return_spread_long(
+        &mut self,
+        writer: Address,
+        token_id: B256,
+        quantity: U256,
+    ) -> Result<(), OptionsError> {
+        let long_token_id = self.spread_long_token_id.get(token_id);
+        if long_token_id == B256::ZERO {
+            return Ok(());
+        }
+
+        let contract_addr = self.vm().contract_address();
+        self._transfer(
+            contract_addr,
+            contract_addr,
+            writer,
+            long_token_id,
+            quantity,
+        )
+    }
+
+    /// Writes a batch of option series for the caller.
+    ///
+    /// Implementation of `batch_write_options`.
+    ///
+    /// # Errors
+    /// See `batch_write_options`
+    pub(crate) fn write_batch(
+        &mut self,
+        strikes: &[U256],
+        expiries: &[u64],
+        quantities: &[U256],
+        underlying: Token,
+        quote: Token,
+        option_types: &[u8],
+    ) -> Result<Vec<B256>, OptionsError> {
+        self.when_not_paused()?;
+
+        let size = strikes.len();
+        if expiries.len() != size || quantities.len() != size || option_types.len() != size {
+            return Err(OptionsError::BatchLengthMismatch(BatchLengthMismatch {}));
+        }
+        if size == 0 || size > MAX_BATCH_SIZE {
+            return Err(OptionsError::InvalidBatchSize(InvalidBatchSize {
+                size: U256::from(size),
+                max: U256::from(MAX_BATCH_SIZE),
+            }));
+        }
+
+        let (writer, contract_addr) = {
+            let vm = self.vm();
+            (vm.msg_sender(), vm.contract_address())
+        };
+
+        // The whole batch is charged at the tier reached before it
+        let fee_bps = self.write_fee_tier(writer).1;
+        let mut token_ids = Vec::with_capacity(size);
+        let mut normalized_quantities = Vec::with_capacity(size);
+        let mut fees = Vec::with_capacity(size);
+        let mut total_collateral = U256::ZERO;
+        let mut total_fee = U256::ZERO;
+        let entries = strikes
+            .iter()
+            .zip(expiries)
+            .zip(quantities)
+            .zip(option_types);
+        for (((&strike, &expiry), &quantity), &option_type) in entries {
+            match option_type {
+                0 => {}
+                // Put writing is not implemented yet
+                1 => return Err(OptionsError::Unimplemented(Unimplemented {})),
+                actual => {
+                    return Err(OptionsError::WrongOptionType(WrongOptionType {
+                        expected: OptionType::Call.to_u8(),
+                        actual,
+                    }))
+                }
+            }
+
+            let (token_id, normalized_quantity) = self.record_call_write(
+                strike,
+                expiry,
+                quantity,
+                underlying,
+                quote,
+                Address::ZERO,
+                writer,
+                ExerciseStyle::American,
+            )?;
+            let fee = protocol_fee(quantity, fee_bps)?;
+            self.record_write_volume(writer, normalized_quantity)?;
+            token_ids.push(token_id);
+            normalized_quantities.push(normalized_quantity);
+            fees.push(fee);
+
+            total_collateral = total_collateral
+                .checked_add(quantity)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+            total_fee = total_fee
+                .checked_add(fee)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+        }
+
+        self.lock_collateral(underlying.address, total_collateral)?;
+
+        // Single external call for the aggregate collateral after all state updates
+        self.safe_transfer_erc20_from(underlying.address, writer, contract_addr, total_collateral)?;
+        if !total_fee.is_zero() {
+            let fee_recipient = self.fee_recipient.get();
+            self.safe_transfer_erc20_from(underlying.address, writer, fee_recipient, total_fee)?;
+        }
+
+        let written = token_ids
+            .iter()
+            .zip(normalized_quantities)
+            .zip(fees)
+            .zip(strikes.iter().zip(expiries));
+        for (((token_id, normalized_quantity), fee), (&strike, &expiry)) in written {
+            if !fee.is_zero() {
+                log(
+                    self.vm(),
+                    FeeCollected {
+                        payer: writer,
+                        tokenId: *token_id,
+                        token: underlying.address,
+                        amount: fee,
+                    },
+                );
+            }
+            log(
+                self.vm(),
+                OptionWritten {
+                    writer,
+                    tokenId: *token_id,
+                    payer: writer,
+                    quantity: normalized_quantity,
+                    collateral: normalized_quantity,
+                    optionType: OptionType::Call.to_u8(),
+                    strike,
+                    expiry: U256::from(expiry),
+                },
+            );
+            self.check_minted(writer, *token_id, normalized_quantity)?;
+        }
+
+        Ok(token_ids)
+    }
+
+    /// Rejects writes into a series whose stored expiry has passed.
+    ///
+    /// The token ID commits to the expiry, so `validate_write_params` already rejects
+    /// every dead series. This checks the stored metadata as well, so a write can never
+    /// add supply to an expired series even if its metadata disagrees with the token ID.
+    ///
+    /// # Errors
+    /// - `ExpiredOption`: Series exists and its stored expiry is not in the future
+    pub(crate) fn ensure_series_not_expired(
+        &self,
+        token_id: B256,
+        current_timestamp: u64,
+    ) -> Result<(), OptionsError> {
+        let expiry = self.option_metadata.get(token_id).expiry.get();
+        if !expiry.is_zero() && expiry <= U256::from(current_timestamp) {
+            return Err(OptionsError::ExpiredOption(ExpiredOption {
+                expiry,
+                current: U256::from(current_timestamp),
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Stores option metadata for a token ID.
+    ///
+    /// Metadata is stored once per option series on first write, which also counts
+    /// the new series and appends it to the series registry. Subsequent writes of the same option parameters reuse the
+    /// existing metadata. The token ID does
+    /// not commit to token decimals, so repeat writes must supply the decimals
+    /// stored on creation.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID (deterministic hash of option parameters)
+    /// - `underlying`: Underlying token (address and decimals)
+    /// - `quote`: Quote token (address and decimals)
+    /// - `strike`: Strike price (18 decimals normalized)
+    /// - `expiry`: Expiration timestamp
+    /// - `option_type`: Call or Put
+    ///
+    /// # Errors
+    /// - `MetadataMismatch`: Series exists with different underlying or quote decimals
+    /// - `Overflow`: Series count would overflow
+    pub(crate) fn store_option_metadata(
+        &mut self,
+        token_id: B256,
+        underlying: Token,
+        quote: Token,
+        strike: U256,
+        expiry: u64,
+        option_type: OptionType,
+    ) -> Result<(), OptionsError> {
+        let existing = self.get_option_metadata(token_id);
+        if !existing.expiry.is_zero() {
+            if existing.underlying_decimals != underlying.decimals
+                || existing.quote_decimals != quote.decimals
+            {
+                return Err(OptionsError::MetadataMismatch(MetadataMismatch {}));
+            }
+            return Ok(());
+        }
+
+        let new_series_count = self
+            .series_count
+            .get()
+            .checked_add(U256::from(1))
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        self.series_count.set(new_series_count);
+        self.series_ids.push(token_id);
+        self.pair_series
+            .setter(Self::pair_key(underlying.address, quote.address))
+            .push(token_id);
+
+        let mut metadata = self.option_metadata.setter(token_id);
+        metadata.underlying.set(underlying.address);
+        metadata.quote.set(quote.address);
+        metadata
+            .underlying_decimals
+            .set(U8::from(underlying.decimals));
+        metadata.quote_decimals.set(U8::from(quote.decimals));
+        metadata.strike.set(strike);
+        metadata.expiry.set(U256::from(expiry));
+        metadata.option_type.set(U8::from(option_type.to_u8()));
+
+        Ok(())
+    }
+
+    /// Retrieves option metadata for a token ID.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    ///
+    /// # Returns
+    /// Option metadata struct with all option parameters
+    pub(crate) fn get_option_metadata(&self, token_id: B256) -> OptionMetadataView {
+        let metadata = self.option_metadata.get(token_id);
+        OptionMetadataView {
+            underlying: metadata.underlying.get(),
+            quote: metadata.quote.get(),
+            underlying_decimals: metadata.underlying_decimals.get().to::<u8>(),
+            quote_decimals: metadata.quote_decimals.get().to::<u8>(),
+            strike: metadata.strike.get(),
+            expiry: metadata.expiry.get(),
+            option_type: metadata.option_type.get().to::<u8>(),
+            oracle: metadata.oracle.get(),
+            settlement: metadata.settlement.get().to::<u8>(),
+            cap: metadata.cap.get(),
+            settlement_price: metadata.settlement_price.get(),
+            vault: metadata.vault.get(),
+            exercise_style: metadata.exercise_style.get().to::<u8>(),
+        }
+    }
+
+    /// Determines the lifecycle state of an option series at a given time.
+    ///
+    /// Option tokens are only minted together with a position of equal size and
+    /// only burned together with an equal position reduction, so a series with
+    /// zero total supply also has no open writer positions. Vault-backed series have
+    /// no positions here; their vault settles writers.
+    ///
+    /// Reads only the stored expiry and the total supply, so keepers and the order book
+    /// can poll it cheaply.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID
+    /// - `current_time`: Timestamp to evaluate expiry against
+    ///
+    /// # Returns
+    /// Lifecycle state of the series
+    pub(crate) fn series_status_at(&self, token_id: B256, current_time: u64) -> SeriesStatus {
+        let expiry = self.option_metadata.get(token_id).expiry.get();
+        if expiry.is_zero() {
+            return SeriesStatus::NotWritten;
+        }
+
+        if self.total_supply.get(token_id).is_zero() {
+            return SeriesStatus::Settled;
+        }
+
+        if U256::from(current_time) < expiry {
+            SeriesStatus::Active
+        } else {
+            SeriesStatus::Expired
+        }
+    }
+
+    /// Generates a composite key for position lookups.
+    ///
+    /// Position key = keccak256(writer, token_id)
+    ///
+    /// Each writer has independent positions per option series.
+    fn position_key(writer: Address, token_id: B256) -> B256 {
+        keccak256([writer.as_slice(), token_id.as_slice()].concat())
+    }
+
+    /// Generates the key of a token pair's series list.
+    ///
+    /// Pair key = keccak256(underlying, quote)
+    fn pair_key(underlying: Address, quote: Address) -> B256 {
+        keccak256([underlying.as_slice(), quote.as_slice()].concat())
+    }
+
+    /// Generates the key of a closed assignment pool epoch.
+    ///
+    /// Epoch key = keccak256(token_id, epoch)
+    fn epoch_key(token_id: B256, epoch: U256) -> B256 {
+        keccak256([token_id.as_slice(), &epoch.to_be_bytes::<32>()].concat())
+    }
+
+    /// Creates or updates a writer's position for an option series.
+    ///
+    /// If position exists, accumulates quantity and collateral using checked arithmetic.
+    /// If position is new, creates it with provided values. The writer is appended to
+    /// `series_writers` the first time they write the series, and the quantity and
+    /// collateral are added to the series' global position.
+    ///
+    /// The position's pending assignments are settled first, and it is given the
+    /// assignment pool units `quantity` is worth. A write to a pool that has been fully
+    /// exercised, or whose units reach `MAX_UNITS_PER_QUANTITY` per option left, starts a
+    /// new pool epoch first.
+    ///
+    /// Resets the reduction tracking of `reduce_position`, so later releases are
+    /// proportional to the new totals.
+    ///
+    /// # Parameters
+    /// - `writer`: Writer address
+    /// - `token_id`: ERC-1155 token ID
+    /// - `quantity`: Quantity to add (18 decimals normalized)
+    /// - `collateral`: Collateral to add (18 decimals normalized)
+    ///
+    /// # Errors
+    /// Returns `OptionsError::Overflow` if accumulation would overflow
+    pub(crate) fn create_or_update_position(
+        &mut self,
+        writer: Address,
+        token_id: B256,
+        quantity: U256,
+        collateral: U256,
+    ) -> Result<(), OptionsError> {
+        let global = self.global_positions.get(token_id);
+        let global_quantity = global.quantity_written.get();
+        let global_units = global.assignment_units.get();
+        if !global_units.is_zero()
+            && (global_quantity.is_zero()
+                || global_units / global_quantity >= U256::from(MAX_UNITS_PER_QUANTITY))
+        {
+            self.start_assignment_epoch(token_id)?;
+        }
+        self.settle_position_assignment(writer, token_id)?;
+
+        let global = self.global_positions.get(token_id);
+        let global_quantity = global.quantity_written.get();
+        let global_units = global.assignment_units.get();
+        let units = if global_units.is_zero() {
+            quantity
+        } else {
+            quantity
+                .checked_mul(global_units)
+                .ok_or(OptionsError::Overflow(Overflow {}))?
+                / global_quantity
+        };
+
+        let key = Self::position_key(writer, token_id);
+        let mut position = self.positions.setter(key);
+
+        let current_quantity = position.quantity_written.get();
+        let current_collateral = position.collateral_locked.get();
+
+        let new_quantity = current_quantity
+            .checked_add(quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        let new_collateral = current_collateral
+            .checked_add(collateral)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+        let new_units = position
+            .assignment_units
+            .get()
+            .checked_add(units)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+
+        position.quantity_written.set(new_quantity);
+        position.collateral_locked.set(new_collateral);
+        position.quantity_reduced.set(U256::ZERO);
+        position.collateral_released.set(U256::ZERO);
+        position.assignment_units.set(new_units);
+
+        let mut global = self.global_positions.setter(token_id);
+        let new_global_quantity = global
+            .quantity_written
+            .get()
+            .checked_add(quantity)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        let new_global_collateral = global
+            .collateral_locked
+            .get()
+            .checked_add(collateral)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        let new_global_units = global_units
+            .checked_add(units)
+            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        global.quantity_written.set(new_global_quantity);
+        global.collateral_locked.set(new_global_collateral);
+        global.assignment_units.set(new_global_units);
+
+        if !self.is_series_writer.get(key) {
+            self.is_series_writer.insert(key, true);
+            self.series_writers.setter(token_id).push(writer);
+        }
+
+        Ok(())
+    }
+
+    /// Validates preconditions for exercising a call option.
+    ///
+    /// Performs comprehensive validation before exercise execution:
+    /// - Option exists (has been written with a non-zero underlying token)
+    /// - Not expired
+    /// - Is a physically collateralized call option
+    /// - Non-zero quantity
+    /// - Holder has sufficient option tokens
+    ///
+    /// # Parameters
+    /// - `holder`: Address attempting to exercise
+    /// - `token_id`: ERC-1155 token ID of the option
+    /// - `quantity`: Amount to exercise
+    /// - `current_time`: Current block timestamp
+    ///
+    /// # Errors
+    /// - `OptionNotFound`: Token ID has no metadata (never written) or a zero underlying
+    /// - `ExerciseAfterExpiry`: Current time >= expiry + `SETTLEMENT_GRACE_PERIOD`
+    /// - `ExerciseWindowClosed`: European option outside
+    ///   `[expiry - EUROPEAN_EXERCISE_WINDOW, expiry + EUROPEAN_EXERCISE_WINDOW)`
+    /// - `WrongOptionType`: Option is not a call (is a put)
+    /// - `WrongSettlementType`: Option is a cash call (see `exercise_settled_call`)
+    /// - `InvalidQuantity`: Quantity is zero
+    /// - `InsufficientBalance`: Holder doesn't have enough option tokens
+    pub(crate) fn validate_call_exercise(
+        &self,
+        holder: Address,
+        token_id: B256,
+        quantity: U256,
+        current_time: u64,
+    ) -> Result<(), OptionsError> {
+        self.validate_call_exercisable(token_id, quantity, current_time)?;
+
+        let holder_balance = self.balance_of(holder, token_id);
+        if holder_balance < quantity {
+            return Err(OptionsError::InsufficientBalance(InsufficientBalance {
+                available: holder_balance,
+                requested: quantity,
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Validates that a call series can be exercised now, regardless of who holds it.
+    ///
+    /// The holder-independent part of `validate_call_exercise`, shared with
+    /// `get_exercise_cost`.
+    ///
+    /// # Parameters
+    /// - `token_id`: ERC-1155 token ID of the option
+    /// - `quantity`: Amount to exercise
+    /// - `current_time`: Current block timestamp
+    ///
+    /// # Errors
+    /// All errors of `validate_call_exercise` except `InsufficientBalance`
+    pub(crate) fn validate_call_exercisable(
+        &self,
+        token_id: B256,
+        quantity: U256,
+        current_time: u64,
+    ) -> Result<(), OptionsError> {
+        let metadata = self.get_option_metadata(token_id);
+        if metadata.expiry.is_zero() || metadata.underlying == Address::ZERO {
+            return Err(OptionsError::OptionNotFound(OptionNotFound {}));
+        }
+
+        // Compared in U256 so an out-of-range stored expiry can't panic or truncate
+        let current = U256::from(current_time);
+        let exercisable_until = exercise_closes_at(metadata.expiry, metadata.exercise_style);
+        if metadata.exercise_style == ExerciseStyle::European.to_u8() {
+            let opens_at = metadata
+                .expiry
+                .saturating_sub(U256::from(EUROPEAN_EXERCISE_WINDOW));
+            if current < opens_at || current >= exercisable_until {
+                return Err(OptionsError::ExerciseWindowClosed(ExerciseWindowClosed {
+                    opens_at,
+                    closes_at: exercisable_until,
+                    current,
+                }));
+            }
+        } else if current >= exercisable_until {
+            return Err(OptionsError::ExerciseAfterExpiry(ExerciseAfterExpiry {
+                expiry: metadata.expiry,
+                current,
+            }));
+        }
+
+        if metadata.option_type != 0 {
+            return Err(OptionsError::WrongOptionType(WrongOptionType {
+                expected: 0,
+                actual: metadata.option_type,
+            }));
+        }
+
+        if metadata.settlement != SettlementType::Physical.to_u8() {
+            return Err(OptionsError::WrongSettlementType(WrongSettlementType {
+                expected: SettlementType::Physical.to_u8(),
+                actual: metadata.settlement,
+            }));
+        }
+
+        if quantity.is_zero() {
+            return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+        }
+
+        Ok(())
+    }
+
+    /// Reduces a writer's position for an option series.
+    ///
+    /// Settles the position's pending assignments first (see `settle_assignment`), then
+    /// decreases both the quantity written and collateral locked proportionally (see
+    /// `reduced_position`), the series' global position by the same amounts and the
+    /// position's assignment pool units by the share the quantity stands for.
+    /// Used when options are exercised or burned. Exercises also accumulate into the
+    /// position's cumulative exercised quantity; voluntary closes do not.
+    ///
+    /// # Parameters
+    /// - `writer`: Address of the position owner
+    /// - `token_id`: ERC-1155 token ID of the option
+    /// - `quantity`: Amount to reduce the position by
+    /// - `exercised`: Whether the reduction is due to exercise (vs. closing the position)
+    ///
+    /// # Returns
+    /// Collateral released from the position (18 decimals normalized)
+    ///
+    /// # Errors
+    /// - `InsufficientBalance`: Position quantity less than requested reduction
+    /// - `Overflow`: Arithmetic overflow during calculation (should never occur with valid inputs)
+    pub(crate) fn reduce_position(
+        &mut self,
+        writer: Address,
+        token_id: B256,
+        quantity: U256,
+        exercised: bool,
+    ) -> Result<U256, OptionsError> {
+        self.settle_position_assignment(writer, token_id)?;
+
+        let key = Self::position_key(writer, token_id);
+        let amounts = self.position_amounts(key);
+        let reduced = reduced_position(amounts, quantity)?;
+        let collateral_to_reduce = amounts.collateral_locked - reduced.collateral_locked;
+        self.apply_position_reduction(key, reduced, quantity, exercised)?;
+
+        let global = self.global_positions.get(token_id);
+        let global_quantity = global.quantity_written.get();
+        let global_units = global.assignment_units.get();
+        let units = self.positions.get(key).assignment_units.get();
+        let units_burned = if reduced.quantity_written.is_zero() || quantity >= global_quantity {
+            units
+        } else {
+            units.min(
+                quantity
+                    .checked_mul(global_units)
+                    .ok_or(OptionsError::Overflow(Overflow {}))?
+                    / global_quantity,
+            )
+        };
+
+        // Pooled assignment rounds each writer's share, so the global position can drift a
+        // few wei from the sum of positions
+        let mut global = self.global_positions.setter(token_id);
+        let new_global_quantity = global.quantity_written.get().saturating_sub(quantity);
+        let new_global_collateral = global
+            .collateral_locked
+            .get()
+            .saturating_sub(collateral_to_reduce);
+        global.quantity_written.set(new_global_quantity);
+        global.collateral_locked.set(new_global_collateral);
+        global
+            .assignment_units
+            .set(global_units.saturating_sub(units_burned));
+
+        self.positions
+            .setter(key)
+            .assignment_units
+            .set(units - units_burned);
+
+        Ok(collateral_to_reduce)
+    }
+
+    /// Returns the amounts of a writer position tracked by `reduce_position`.
+    fn position_amounts(&self, key: B256) -> PositionAmounts {
+        let position = self.positions.get(key);
+        PositionAmounts {
+            quantity_written: position.quantity_written.get(),
+            collateral_locked: position.collateral_locked.get(),
+            quantity_reduced: position.quantity_reduced.get(),
+            collateral_released: position.collateral_released.get(),
+        }
+    }
+
+    /// Stores reduced amounts of a writer position, leaving the global position as is.
+    ///
+    /// # Parameters
+    /// - `key`: Position key
+    /// - `reduced`: Position amounts after the reduction (see `reduced_position`)
+    /// - `quantity`: Quantity the position was reduced by (18 decimals normalized)
+    /// - `exercised`: Whether to add `quantity` to the cumulative exercised quantity
+    ///
+    /// # Errors
+    /// - `Overflow`: Cumulative exercised quantity would overflow
+    fn apply_position_reduction(
+        &mut self,
+        key: B256,
+        reduced: PositionAmounts,
+        quantity: U256,
+        exercised: bool,
+    ) -> Result<(), OptionsError> {
+        let mut position = self.positions.setter(key);
+        position.quantity_written.set(reduced.quantity_written);
+        position.collateral_locked.set(reduced.collateral_locked);
+        position.quantity_reduced.set(reduced.quantity_reduced);
+        position
+            .collateral_released
+            .set(reduced.collateral_released);
+
+        if exercised {
+            let new_exercised = position
+                .quantity_exercised
+                .get()
+                .checked_add(quantity)
+                .ok_or(OptionsError::Overflow(Overflow {}))?;
+            position.quantity_exercised.set(new_exercised);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Address;
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::mock_erc20::{FeeOnTransferERC20, MockERC20, MockOracle, MockRouter, StandardERC20};
+    use vault::OptionVault;
+
+    #[test]
+    fn test_mock_erc20_mint_increases_balance() {
+        let mut token = MockERC20::default();
+        let alice = Address::from([1u8; 20]);
+        let amount = U256::from(1000);
+
+        token.mint(alice, amount);
+
+        assert_eq!(token.balance_of(alice), amount);
+    }
+
+    #[test]
+    fn test_mock_erc20_transfer_moves_tokens() {
+        let mut token = MockERC20::default();
+        let alice = Address::from([1u8; 20]);
+        let bob = Address::from([2u8; 20]);
+        let amount = U256::from(1000);
+
+        token.mint(alice, amount);
+
+        let transfer_amount = U256::from(600);
+        let success = token.transfer(alice, bob, transfer_amount);
+
+        assert!(success);
+        assert_eq!(token.balance_of(alice), U256::from(400));
+        assert_eq!(token.balance_of(bob), transfer_amount);
+    }
+
+    #[test]
+    fn test_mock_erc20_transfer_from_with_approval() {
+        let mut token = MockERC20::default();
+        let alice = Address::from([1u8; 20]);
+        let bob = Address::from([2u8; 20]);
+        let charlie = Address::from([3u8; 20]);
+        let amount = U256::from(1000);
+
+        token.mint(alice, amount);
+
+        let approval_amount = U256::from(600);
+        token.approve(alice, bob, approval_amount);
+
+        let transfer_amount = U256::from(400);
+        let success = token.transfer_from(bob, alice, charlie, transfer_amount);
+
+        assert!(success);
+        assert_eq!(token.balance_of(alice), U256::from(600));
+        assert_eq!(token.balance_of(charlie), transfer_amount);
+    }
+
+    #[test]
+    fn test_mock_erc20_transfer_from_without_approval_fails() {
+        let mut token = MockERC20::default();
+        let alice = Address::from([1u8; 20]);
+        let bob = Address::from([2u8; 20]);
+        let charlie = Address::from([3u8; 20]);
+        let amount = U256::from(1000);
+
+        token.mint(alice, amount);
+
+        let transfer_amount = U256::from(400);
+        let success = token.transfer_from(bob, alice, charlie, transfer_amount);
+
+        assert!(!success);
+    }
+
+    #[test]
+    fn test_mock_erc20_decimals_returns_configured_value() {
+        let mut token = MockERC20::default();
+        let decimals = 6u8;
+
+        token.set_decimals(decimals);
+
+        assert_eq!(token.decimals(), decimals);
+    }
+
+    // Decimal Normalization Tests
+    #[test]
+    fn test_normalize_amount_usdc_6_decimals() {
+        let amount = U256::from(1_000_000); // 1 USDC
+        let result = normalize_amount(amount, 6);
+        assert_eq!(result.unwrap(), U256::from(1_000_000_000_000_000_000u128)); // 10^18
+    }
+
+    #[test]
+    fn test_normalize_amount_wbtc_8_decimals() {
+        let amount = U256::from(100_000_000); // 1 WBTC
+        let result = normalize_amount(amount, 8);
+        assert_eq!(result.unwrap(), U256::from(1_000_000_000_000_000_000u128)); // 10^18
+    }
+
+    #[test]
+    fn test_normalize_amount_18_decimals_no_change() {
+        let amount = U256::from(1_000_000_000_000_000_000u128); // 1 ether
+        let result = normalize_amount(amount, 18);
+        assert_eq!(result.unwrap(), U256::from(1_000_000_000_000_000_000u128));
+    }
+
+    #[test]
+    fn test_normalize_amount_0_decimals() {
+        let amount = U256::from(1);
+        let result = normalize_amount(amount, 0);
+        assert_eq!(result.unwrap(), U256::from(1_000_000_000_000_000_000u128));
+    }
+
+    #[test]
+    fn test_normalize_amount_invalid_decimals_24() {
+        let amount = U256::from(1000);
+        let result = normalize_amount(amount, 24);
+        assert!(matches!(result, Err(OptionsError::InvalidDecimals(_))));
+    }
+
+    #[test]
+    fn test_normalize_amount_overflow() {
+        let result = normalize_amount(U256::MAX, 0);
+        assert!(matches!(
+            result,
+            Err(OptionsError::NormalizationOverflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_denormalize_amount_round_trip_6_decimals() {
+        let original = U256::from(1_000_000); // 1 USDC
+        let normalized = normalize_amount(original, 6).unwrap();
+        let denormalized = denormalize_amount(normalized, 6).unwrap();
+        assert_eq!(denormalized, original);
+    }
+
+    #[test]
+    fn test_denormalize_amount_round_trip_8_decimals() {
+        let original = U256::from(100_000_000); // 1 WBTC
+        let normalized = normalize_amount(original, 8).unwrap();
+        let denormalized = denormalize_amount(normalized, 8).unwrap();
+        assert_eq!(denormalized, original);
+    }
+
+    #[test]
+    fn test_denormalize_amount_round_trip_18_decimals() {
+        let original = U256::from(1_000_000_000_000_000_000u128);
+        let normalized = normalize_amount(original, 18).unwrap();
+        let denormalized = denormalize_amount(normalized, 18).unwrap();
+        assert_eq!(denormalized, original);
+    }
+
+    #[test]
+    fn test_denormalize_amount_ceil_rounds_up() {
+        let amount = U256::from(1_000_000_000_001u64);
+        assert_eq!(denormalize_amount(amount, 6).unwrap(), U256::from(1));
+        assert_eq!(denormalize_amount_ceil(amount, 6).unwrap(), U256::from(2));
+        assert_eq!(
+            denormalize_amount_ceil(U256::from(1_000_000_000_000u64), 6).unwrap(),
+            U256::from(1)
+        );
+        assert_eq!(denormalize_amount_ceil(amount, 18).unwrap(), amount);
+    }
+
+    #[test]
+    fn test_denormalize_amount_ceil_invalid_decimals() {
+        let result = denormalize_amount_ceil(U256::from(1), 19);
+        assert!(matches!(result, Err(OptionsError::InvalidDecimals(_))));
+    }
+
+    #[test]
+    fn test_strike_payment_in_quote_decimals() {
+        let one = U256::from(10).pow(U256::from(18));
+        // 0.5 options at a 60,000 strike owe 30,000 USDC
+        let payment = strike_payment(U256::from(60_000) * one, one / U256::from(2), 6).unwrap();
+        assert_eq!(payment, U256::from(30_000_000_000u64));
+    }
+
+    #[test]
+    fn test_strike_payment_rounds_up_tiny_exercise() {
+        let one = U256::from(10).pow(U256::from(18));
+        // 1 satoshi (1e10 normalized) at a 60,000 strike is worth 0.0006 USDC
+        let payment =
+            strike_payment(U256::from(60_000) * one, U256::from(10_000_000_000u64), 6).unwrap();
+        assert_eq!(payment, U256::from(600));
+
+        // 1 wei of quantity still owes 1 unit of quote
+        let payment = strike_payment(U256::from(60_000) * one, U256::from(1), 6).unwrap();
+        assert_eq!(payment, U256::from(1));
+    }
+
+    #[test]
+    fn test_strike_value_with_zero_decimals_quote() {
+        let one = U256::from(10).pow(U256::from(18));
+        let strike = U256::from(60_000) * one;
+
+        // Put collateral is the same strike value: 2 puts at 60,000 lock 120,000 tokens
+        let full = strike_payment(strike, U256::from(2) * one, 0).unwrap();
+        assert_eq!(full, U256::from(120_000));
+        let partial = strike_payment(strike, one / U256::from(2), 0).unwrap();
+        assert_eq!(partial, U256::from(30_000));
+
+        // Fractional values round up to a whole token
+        let fractional = strike_payment(one + one / U256::from(2), one, 0).unwrap();
+        assert_eq!(fractional, U256::from(2));
+        assert_eq!(
+            denormalize_amount(one + one / U256::from(2), 0).unwrap(),
+            U256::from(1)
+        );
+    }
+
+    // Cash Settlement Tests
+    #[test]
+    fn test_cash_settlement_amounts_in_the_money() {
+        let one = U256::from(10).pow(U256::from(18));
+        let strike = U256::from(60_000) * one;
+        let spot = U256::from(80_000) * one;
+
+        let (intrinsic_value, payout) = cash_settlement_amounts(spot, strike, one).unwrap();
+
+        assert_eq!(intrinsic_value, U256::from(20_000) * one);
+        assert_eq!(payout, one / U256::from(4));
+    }
+
+    #[test]
+    fn test_cash_settlement_amounts_scales_with_quantity() {
+        let one = U256::from(10).pow(U256::from(18));
+        let strike = U256::from(1_500) * one;
+        let spot = U256::from(2_000) * one;
+        let quantity = U256::from(10) * one;
+
+        let (intrinsic_value, payout) = cash_settlement_amounts(spot, strike, quantity).unwrap();
+
+        assert_eq!(intrinsic_value, U256::from(5_000) * one);
+        assert_eq!(payout, U256::from(25) * one / U256::from(10));
+    }
+
+    #[test]
+    fn test_cash_settlement_amounts_at_the_money_fails() {
+        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+
+        let result = cash_settlement_amounts(strike, strike, U256::from(1));
+        assert!(matches!(result, Err(OptionsError::NotInTheMoney(_))));
+    }
+
+    #[test]
+    fn test_cash_settlement_amounts_out_of_the_money_fails() {
+        let one = U256::from(10).pow(U256::from(18));
+
+        let result =
+            cash_settlement_amounts(U256::from(50_000) * one, U256::from(60_000) * one, one);
+        assert!(matches!(result, Err(OptionsError::NotInTheMoney(_))));
+    }
+
+    #[test]
+    fn test_cash_call_settlement_in_the_money() {
+        let one = U256::from(10).pow(U256::from(18));
+        let (strike, cap, quantity) = (
+            U256::from(60_000) * one,
+            U256::from(20_000) * one,
+            U256::from(2) * one,
+        );
+
+        let amounts =
+            cash_call_settlement(U256::from(70_000) * one, strike, cap, quantity).unwrap();
+
+        assert_eq!(
+            amounts,
+            (U256::from(20_000) * one, U256::from(20_000) * one)
+        );
+    }
+
+    #[test]
+    fn test_cash_call_settlement_above_cap_pays_cap() {
+        let one = U256::from(10).pow(U256::from(18));
+        let (strike, cap, quantity) = (
+            U256::from(60_000) * one,
+            U256::from(20_000) * one,
+            U256::from(2) * one,
+        );
+
+        let amounts =
+            cash_call_settlement(U256::from(90_000) * one, strike, cap, quantity).unwrap();
+
+        assert_eq!(amounts, (U256::from(40_000) * one, U256::ZERO));
+    }
+
+    #[test]
+    fn test_cash_call_settlement_at_and_out_of_the_money_pays_nothing() {
+        let one = U256::from(10).pow(U256::from(18));
+        let (strike, cap, quantity) = (
+            U256::from(60_000) * one,
+            U256::from(20_000) * one,
+            U256::from(2) * one,
+        );
+
+        for price in [U256::from(60_000) * one, U256::from(50_000) * one] {
+            let amounts = cash_call_settlement(price, strike, cap, quantity).unwrap();
+            assert_eq!(amounts, (U256::ZERO, U256::from(40_000) * one));
+        }
+    }
+
+    #[test]
+    fn test_validate_settlement_time_opens_at_expiry() {
+        let expiry = 1_700_000_000u64;
+
+        assert!(matches!(
+            validate_settlement_time(U256::from(expiry), expiry - 1),
+            Err(OptionsError::SettlementBeforeExpiry(_))
+        ));
+        assert!(validate_settlement_time(U256::from(expiry), expiry).is_ok());
+    }
+
+    #[test]
+    fn test_protocol_fee_at_zero_50_and_500_bps() {
+        let amount = U256::from(100_000_000);
+
+        assert_eq!(protocol_fee(amount, U256::ZERO).unwrap(), U256::ZERO);
+        assert_eq!(
+            protocol_fee(amount, U256::from(50)).unwrap(),
+            U256::from(500_000)
+        );
+        assert_eq!(
+            protocol_fee(amount, U256::from(MAX_FEE_BPS)).unwrap(),
+            U256::from(5_000_000)
+        );
+    }
+
+    #[test]
+    fn test_protocol_fee_rounds_down() {
+        assert_eq!(
+            protocol_fee(U256::from(199), U256::from(50)).unwrap(),
+            U256::ZERO
+        );
+        assert_eq!(
+            protocol_fee(U256::from(200), U256::from(50)).unwrap(),
+            U256::from(1)
+        );
+    }
+
+    #[test]
+    fn test_validate_price_freshness_within_window() {
+        let now = 1_900_000_000u64;
+        let updated_at = U256::from(now - 3_600);
+
+        assert!(validate_price_freshness(updated_at, now, 3_600).is_ok());
+        assert!(validate_price_freshness(U256::from(now), now, 3_600).is_ok());
+        assert!(validate_price_freshness(U256::from(now + 60), now, 3_600).is_ok());
+    }
+
+    #[test]
+    fn test_validate_price_freshness_rejects_stale_price() {
+        let now = 1_900_000_000u64;
+        let updated_at = U256::from(now - 3_601);
+
+        let result = validate_price_freshness(updated_at, now, 3_600);
+
+        assert!(matches!(
+            result,
+            Err(OptionsError::StaleSettlementPrice(StaleSettlementPrice { updated_at: u, .. }))
+                if u == updated_at
+        ));
+    }
+
+    #[test]
+    fn test_validate_price_freshness_disabled_at_zero() {
+        assert!(validate_price_freshness(U256::ZERO, 1_900_000_000u64, 0).is_ok());
+    }
+
+    #[test]
+    fn test_spread_collateral_is_max_loss_in_quote_decimals() {
+        let one = U256::from(10).pow(U256::from(18));
+
+        // (70,000 - 60,000) * 0.5 = 5,000 USDC
+        let collateral = spread_collateral(
+            U256::from(60_000) * one,
+            U256::from(70_000) * one,
+            one / U256::from(2),
+            6,
+        )
+        .unwrap();
+        assert_eq!(collateral, U256::from(5_000_000_000u64));
+    }
+
+    #[test]
+    fn test_spread_collateral_rounds_up() {
+        // 1 wei of strike difference on 1 wei of quantity still locks one quote unit
+        let collateral = spread_collateral(U256::from(1), U256::from(2), U256::from(1), 6).unwrap();
+        assert_eq!(collateral, U256::from(1));
+    }
+
+    #[test]
+    fn test_spread_collateral_requires_higher_long_strike() {
+        let one = U256::from(10).pow(U256::from(18));
+
+        let result = spread_collateral(one, one, one, 6);
+        assert!(matches!(result, Err(OptionsError::InvalidSpread(_))));
+
+        let result = spread_collateral(U256::from(2) * one, one, one, 6);
+        assert!(matches!(result, Err(OptionsError::InvalidSpread(_))));
+    }
+
+    // ERC-1155 Balance Tracking Tests
+    #[motsu::test]
+    fn test_mint_increases_balance(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x42; 32]);
+        let quantity = U256::from(100);
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, quantity)
+            .unwrap();
+
+        let balance = contract.sender(alice).balance_of(alice, token_id);
+        assert_eq!(balance, quantity);
+    }
+
+    #[motsu::test]
+    fn test_mint_increases_total_supply(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x42; 32]);
+        let quantity = U256::from(100);
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, quantity)
+            .unwrap();
+
+        let total_supply = contract.sender(alice).total_supply(token_id);
+        assert_eq!(total_supply, quantity);
+    }
+
+    #[motsu::test]
+    fn test_burn_decreases_balance(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x42; 32]);
+        let mint_quantity = U256::from(100);
+        let burn_quantity = U256::from(40);
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, mint_quantity)
+            .unwrap();
+        contract
+            .sender(alice)
+            ._burn(alice, token_id, burn_quantity)
+            .unwrap();
+
+        let balance = contract.sender(alice).balance_of(alice, token_id);
+        assert_eq!(balance, U256::from(60));
+    }
+
+    #[motsu::test]
+    fn test_burn_decreases_total_supply(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x42; 32]);
+        let mint_quantity = U256::from(100);
+        let burn_quantity = U256::from(40);
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, mint_quantity)
+            .unwrap();
+        contract
+            .sender(alice)
+            ._burn(alice, token_id, burn_quantity)
+            .unwrap();
+
+        let total_supply = contract.sender(alice).total_supply(token_id);
+        assert_eq!(total_supply, U256::from(60));
+    }
+
+    #[motsu::test]
+    fn test_burn_insufficient_balance_fails(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x42; 32]);
+        let mint_quantity = U256::from(100);
+        let burn_quantity = U256::from(150);
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, mint_quantity)
+            .unwrap();
+
+        let result = contract.sender(alice)._burn(alice, token_id, burn_quantity);
+        assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+    }
+
+    #[motsu::test]
+    fn test_mint_overflow_fails(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x42; 32]);
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, U256::MAX)
+            .unwrap();
+
+        let result = contract.sender(alice)._mint(alice, token_id, U256::from(1));
+        assert!(matches!(result, Err(OptionsError::Overflow(_))));
+    }
+
+    #[motsu::test]
+    fn test_multiple_mints_accumulate(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x42; 32]);
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, U256::from(50))
+            .unwrap();
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, U256::from(30))
+            .unwrap();
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, U256::from(20))
+            .unwrap();
+
+        let balance = contract.sender(alice).balance_of(alice, token_id);
+        assert_eq!(balance, U256::from(100));
+    }
+
+    #[motsu::test]
+    fn test_mint_then_burn_same_amount_returns_zero(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x42; 32]);
+        let quantity = U256::from(100);
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, quantity)
+            .unwrap();
+        contract
+            .sender(alice)
+            ._burn(alice, token_id, quantity)
+            .unwrap();
+
+        let balance = contract.sender(alice).balance_of(alice, token_id);
+        assert_eq!(balance, U256::ZERO);
+
+        let total_supply = contract.sender(alice).total_supply(token_id);
+        assert_eq!(total_supply, U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn test_total_supply_unknown_token_id_is_zero(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x42; 32]);
+        let unknown_token_id = B256::from([0x43; 32]);
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, U256::from(100))
+            .unwrap();
+
+        let total_supply = contract.sender(alice).total_supply(unknown_token_id);
+        assert_eq!(total_supply, U256::ZERO);
+    }
+
+    #[test]
+    fn test_valid_parameters_pass_validation() {
+        let strike = U256::from(50_000);
+        let expiry = 1_650_000_000u64;
+        let quantity = U256::from(100);
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let current_timestamp = 1_600_000_000u64;
+
+        validate_write_params(
+            strike,
+            expiry,
+            quantity,
+            underlying,
+            quote,
+            current_timestamp,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_zero_strike_fails() {
+        let strike = U256::ZERO;
+        let expiry = 1_650_000_000u64;
+        let quantity = U256::from(100);
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let current_timestamp = 1_600_000_000u64;
+
+        let result = validate_write_params(
+            strike,
+            expiry,
+            quantity,
+            underlying,
+            quote,
+            current_timestamp,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        );
+        assert!(matches!(result, Err(OptionsError::InvalidStrike(_))));
+    }
+
+    #[test]
+    fn test_max_strike_is_ten_to_the_36() {
+        assert_eq!(MAX_STRIKE, U256::from(10).pow(U256::from(36)));
+    }
+
+    #[test]
+    fn test_strike_at_max_succeeds() {
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+
+        validate_write_params(
+            MAX_STRIKE,
+            1_650_000_000u64,
+            U256::from(100),
+            underlying,
+            quote,
+            1_600_000_000u64,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_strike_above_max_fails() {
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+
+        let result = validate_write_params(
+            MAX_STRIKE + U256::from(1),
+            1_650_000_000u64,
+            U256::from(100),
+            underlying,
+            quote,
+            1_600_000_000u64,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        );
+
+        assert!(matches!(
+            result,
+            Err(OptionsError::StrikeTooLarge(StrikeTooLarge { max, .. })) if max == MAX_STRIKE
+        ));
+    }
+
+    #[test]
+    fn test_strike_notional_overflow_fails() {
+        // 8-decimal underlying: the quantity only overflows the product once normalized
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 8,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let max_quantity = U256::MAX / MAX_STRIKE / U256::from(10).pow(U256::from(10));
+
+        validate_write_params(
+            MAX_STRIKE,
+            1_650_000_000u64,
+            max_quantity,
+            underlying,
+            quote,
+            1_600_000_000u64,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        )
+        .unwrap();
+        let result = validate_write_params(
+            MAX_STRIKE,
+            1_650_000_000u64,
+            max_quantity + U256::from(1),
+            underlying,
+            quote,
+            1_600_000_000u64,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        );
+
+        assert!(matches!(result, Err(OptionsError::StrikeTooLarge(_))));
+    }
+
+    #[test]
+    fn test_past_expiry_fails() {
+        let strike = U256::from(50_000);
+        let expiry = 1_500_000_000u64; // Past timestamp
+        let quantity = U256::from(100);
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let current_timestamp = 1_600_000_000u64;
+
+        let result = validate_write_params(
+            strike,
+            expiry,
+            quantity,
+            underlying,
+            quote,
+            current_timestamp,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        );
+        assert!(matches!(result, Err(OptionsError::ExpiredOption(_))));
+    }
+
+    #[test]
+    fn test_expiry_equals_current_timestamp_fails() {
+        let strike = U256::from(50_000);
+        let expiry = 1_600_000_000u64;
+        let quantity = U256::from(100);
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let current_timestamp = 1_600_000_000u64; // Same as expiry
+
+        let result = validate_write_params(
+            strike,
+            expiry,
+            quantity,
+            underlying,
+            quote,
+            current_timestamp,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        );
+        assert!(matches!(result, Err(OptionsError::ExpiredOption(_))));
+    }
+
+    #[test]
+    fn test_zero_quantity_fails() {
+        let strike = U256::from(50_000);
+        let expiry = 1_650_000_000u64;
+        let quantity = U256::ZERO;
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let current_timestamp = 1_600_000_000u64;
+
+        let result = validate_write_params(
+            strike,
+            expiry,
+            quantity,
+            underlying,
+            quote,
+            current_timestamp,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        );
+        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
+    }
+
+    #[test]
+    fn test_same_underlying_and_quote_fails() {
+        let strike = U256::from(50_000);
+        let expiry = 1_650_000_000u64;
+        let quantity = U256::from(100);
+        let same_address = Address::from([0x11; 20]);
+        let underlying = Token {
+            address: same_address,
+            decimals: 18,
+        };
+        let quote = Token {
+            address: same_address,
+            decimals: 6,
+        };
+        let current_timestamp = 1_600_000_000u64;
+
+        let result = validate_write_params(
+            strike,
+            expiry,
+            quantity,
+            underlying,
+            quote,
+            current_timestamp,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        );
+        assert!(matches!(result, Err(OptionsError::SameToken(_))));
+    }
+
+    #[test]
+    fn test_zero_address_tokens_fail() {
+        let token = Address::from([0x11; 20]);
+        for (underlying_address, quote_address) in [
+            (Address::ZERO, token),
+            (token, Address::ZERO),
+            (Address::ZERO, Address::ZERO),
+        ] {
+            let underlying = Token {
+                address: underlying_address,
+                decimals: 18,
+            };
+            let quote = Token {
+                address: quote_address,
+                decimals: 6,
+            };
+
+            let result = validate_write_params(
+                U256::from(50_000),
+                1_650_000_000u64,
+                U256::from(100),
+                underlying,
+                quote,
+                1_600_000_000u64,
+                DEFAULT_MAX_EXPIRY_HORIZON,
+                U256::ZERO,
+            );
+            assert!(matches!(result, Err(OptionsError::ZeroAddress(_))));
+        }
+    }
+
+    #[test]
+    fn test_minimum_valid_expiry_passes() {
+        let strike = U256::from(50_000);
+        let current_timestamp = 1_600_000_000u64;
+        let expiry = current_timestamp + 1; // Minimum valid expiry
+        let quantity = U256::from(100);
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+
+        validate_write_params(
+            strike,
+            expiry,
+            quantity,
+            underlying,
+            quote,
+            current_timestamp,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_expiry_horizon_boundary() {
+        let current_timestamp = 1_600_000_000u64;
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let validate = |expiry: u64, horizon: u64| {
+            validate_write_params(
+                U256::from(50_000),
+                expiry,
+                U256::from(100),
+                underlying,
+                quote,
+                current_timestamp,
+                horizon,
+                U256::ZERO,
+            )
+        };
+
+        // Thirty days out is well within the default horizon, four years is past it
+        assert!(validate(current_timestamp + 30 * 86_400, DEFAULT_MAX_EXPIRY_HORIZON).is_ok());
+        assert!(matches!(
+            validate(
+                current_timestamp + 4 * 365 * 86_400,
+                DEFAULT_MAX_EXPIRY_HORIZON
+            ),
+            Err(OptionsError::ExpiryTooFar(_))
+        ));
+
+        let max_expiry = current_timestamp + DEFAULT_MAX_EXPIRY_HORIZON;
+        assert!(validate(max_expiry, DEFAULT_MAX_EXPIRY_HORIZON).is_ok());
+        let result = validate(max_expiry + 1, DEFAULT_MAX_EXPIRY_HORIZON);
+        assert!(matches!(
+            result,
+            Err(OptionsError::ExpiryTooFar(ExpiryTooFar { expiry, max_expiry: max }))
+                if expiry == U256::from(max_expiry + 1) && max == U256::from(max_expiry)
+        ));
+
+        assert!(validate(current_timestamp + 86_400, 86_400).is_ok());
+        assert!(matches!(
+            validate(current_timestamp + 86_401, 86_400),
+            Err(OptionsError::ExpiryTooFar(_))
+        ));
+    }
+
+    #[test]
+    fn test_expiry_horizon_saturates() {
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+
+        validate_write_params(
+            U256::from(50_000),
+            u64::MAX,
+            U256::from(100),
+            underlying,
+            quote,
+            u64::MAX - 1,
+            DEFAULT_MAX_EXPIRY_HORIZON,
+            U256::ZERO,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_strike_tick() {
+        let one = U256::from(10).pow(U256::from(18));
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let validate = |strike: U256, tick: U256| {
+            validate_write_params(
+                strike,
+                1_650_000_000u64,
+                U256::from(100),
+                underlying,
+                quote,
+                1_600_000_000u64,
+                DEFAULT_MAX_EXPIRY_HORIZON,
+                tick,
+            )
+        };
+
+        // Disabled: any strike, including dust
+        assert!(validate(U256::from(60_000) * one + U256::from(1), U256::ZERO).is_ok());
+
+        let tick = U256::from(100) * one;
+        assert!(validate(U256::from(60_000) * one, tick).is_ok());
+        assert!(validate(tick, tick).is_ok());
+        let misaligned = U256::from(60_050) * one;
+        assert!(matches!(
+            validate(misaligned, tick),
+            Err(OptionsError::InvalidStrikeTick(InvalidStrikeTick { strike, tick: t }))
+                if strike == misaligned && t == tick
+        ));
+        assert!(matches!(
+            validate(U256::from(60_000) * one + U256::from(1), tick),
+            Err(OptionsError::InvalidStrikeTick(_))
+        ));
+    }
+
+    // Fee-on-Transfer Detection Tests
+    #[test]
+    fn test_transfer_from_mock_erc20_succeeds() {
+        let mut token = MockERC20::default();
+        let from = Address::from([0x01; 20]);
+        let to = Address::from([0x02; 20]);
+        let amount = U256::from(1000);
+
+        token.mint(from, U256::from(10000));
+        token.approve(from, from, U256::from(10000));
+
+        let balance_before = token.balance_of(to);
+        let success = token.transfer_from(from, from, to, amount);
+        let balance_after = token.balance_of(to);
+
+        assert!(success);
+        let received = balance_after.checked_sub(balance_before).unwrap();
+        assert_eq!(received, amount);
+    }
+
+    #[test]
+    fn test_fee_on_transfer_error_contains_correct_amounts() {
+        let expected = U256::from(1000);
+        let received = U256::from(990); // 1% fee deducted
+
+        let error =
+            OptionsError::FeeOnTransferDetected(FeeOnTransferDetected { expected, received });
+
+        match error {
+            OptionsError::FeeOnTransferDetected(e) => {
+                assert_eq!(e.expected, expected);
+                assert_eq!(e.received, received);
+            }
+            _ => panic!("Expected FeeOnTransferDetected error"),
+        }
+    }
+
+    #[test]
+    fn test_multiple_safe_transfers_all_succeed() {
+        let mut token = MockERC20::default();
+        let from = Address::from([0x01; 20]);
+        let to1 = Address::from([0x02; 20]);
+        let to2 = Address::from([0x03; 20]);
+        let to3 = Address::from([0x04; 20]);
+        let amount = U256::from(100);
+
+        token.mint(from, U256::from(10000));
+        token.approve(from, from, U256::from(10000));
+
+        let balance_before = token.balance_of(to1);
+        let success = token.transfer_from(from, from, to1, amount);
+        let balance_after = token.balance_of(to1);
+        assert!(success);
+        assert_eq!(balance_after.checked_sub(balance_before).unwrap(), amount);
+
+        let balance_before = token.balance_of(to2);
+        let success = token.transfer_from(from, from, to2, amount);
+        let balance_after = token.balance_of(to2);
+        assert!(success);
+        assert_eq!(balance_after.checked_sub(balance_before).unwrap(), amount);
+
+        let balance_before = token.balance_of(to3);
+        let success = token.transfer_from(from, from, to3, amount);
+        let balance_after = token.balance_of(to3);
+        assert!(success);
+        assert_eq!(balance_after.checked_sub(balance_before).unwrap(), amount);
+    }
+
+    // Option Metadata Storage Tests
+    #[motsu::test]
+    fn test_store_and_retrieve_metadata(contract: Contract<Options>) {
+        let token_id = B256::from([0x42; 32]);
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 8,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let strike = U256::from(50_000);
+        let expiry = 1_700_000_000u64;
+        let option_type = OptionType::Call;
+
+        contract
+            .sender(Address::ZERO)
+            .store_option_metadata(token_id, underlying, quote, strike, expiry, option_type)
+            .unwrap();
+
+        let metadata = contract.sender(Address::ZERO).get_option_metadata(token_id);
+
+        assert_eq!(metadata.underlying, underlying.address);
+        assert_eq!(metadata.quote, quote.address);
+        assert_eq!(metadata.underlying_decimals, underlying.decimals);
+        assert_eq!(metadata.quote_decimals, quote.decimals);
+        assert_eq!(metadata.strike, strike);
+        assert_eq!(metadata.expiry, U256::from(expiry));
+        assert_eq!(metadata.option_type, option_type.to_u8());
+    }
+
+    #[motsu::test]
+    fn test_metadata_fields_match_input_parameters(contract: Contract<Options>) {
+        let token_id = B256::from([0x99; 32]);
+        let underlying = Token {
+            address: Address::from([0xAA; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0xBB; 20]),
+            decimals: 6,
+        };
+        let strike = U256::from(100_000);
+        let expiry = 1_800_000_000u64;
+        let option_type = OptionType::Put;
+
+        contract
+            .sender(Address::ZERO)
+            .store_option_metadata(token_id, underlying, quote, strike, expiry, option_type)
+            .unwrap();
+
+        let metadata = contract.sender(Address::ZERO).get_option_metadata(token_id);
+
+        assert_eq!(metadata.underlying, underlying.address);
+        assert_eq!(metadata.quote, quote.address);
+        assert_eq!(metadata.underlying_decimals, 18);
+        assert_eq!(metadata.quote_decimals, 6);
+        assert_eq!(metadata.strike, U256::from(100_000));
+        assert_eq!(metadata.expiry, U256::from(1_800_000_000u64));
+        assert_eq!(metadata.option_type, 1); // Put = 1
+    }
+
+    #[motsu::test]
+    fn test_same_token_id_retrieves_same_metadata(contract: Contract<Options>) {
+        let token_id = B256::from([0x77; 32]);
+        let underlying = Token {
+            address: Address::from([0x33; 20]),
+            decimals: 8,
+        };
+        let quote = Token {
+            address: Address::from([0x44; 20]),
+            decimals: 6,
+        };
+        let strike = U256::from(60_000);
+        let expiry = 1_750_000_000u64;
+        let option_type = OptionType::Call;
+
+        contract
+            .sender(Address::ZERO)
+            .store_option_metadata(token_id, underlying, quote, strike, expiry, option_type)
+            .unwrap();
+
+        let metadata1 = contract.sender(Address::ZERO).get_option_metadata(token_id);
+
+        let metadata2 = contract.sender(Address::ZERO).get_option_metadata(token_id);
+
+        assert_eq!(metadata1.underlying, metadata2.underlying);
+        assert_eq!(metadata1.quote, metadata2.quote);
+        assert_eq!(metadata1.strike, metadata2.strike);
+        assert_eq!(metadata1.expiry, metadata2.expiry);
+        assert_eq!(metadata1.option_type, metadata2.option_type);
+    }
+
+    #[motsu::test]
+    fn test_different_token_ids_have_independent_metadata(contract: Contract<Options>) {
+        let token_id_1 = B256::from([0x11; 32]);
+        let token_id_2 = B256::from([0x22; 32]);
+
+        let underlying_1 = Token {
+            address: Address::from([0xAA; 20]),
+            decimals: 8,
+        };
+        let quote_1 = Token {
+            address: Address::from([0xBB; 20]),
+            decimals: 6,
+        };
+        let strike_1 = U256::from(50_000);
+        let expiry_1 = 1_700_000_000u64;
+
+        let underlying_2 = Token {
+            address: Address::from([0xCC; 20]),
+            decimals: 18,
+        };
+        let quote_2 = Token {
+            address: Address::from([0xDD; 20]),
+            decimals: 6,
+        };
+        let strike_2 = U256::from(100_000);
+        let expiry_2 = 1_800_000_000u64;
+
+        contract
+            .sender(Address::ZERO)
+            .store_option_metadata(
+                token_id_1,
+                underlying_1,
+                quote_1,
+                strike_1,
+                expiry_1,
+                OptionType::Call,
+            )
+            .unwrap();
+
+        contract
+            .sender(Address::ZERO)
+            .store_option_metadata(
+                token_id_2,
+                underlying_2,
+                quote_2,
+                strike_2,
+                expiry_2,
+                OptionType::Put,
+            )
+            .unwrap();
+
+        let metadata_1 = contract
+            .sender(Address::ZERO)
+            .get_option_metadata(token_id_1);
+
+        let metadata_2 = contract
+            .sender(Address::ZERO)
+            .get_option_metadata(token_id_2);
+
+        // Verify metadata_1
+        assert_eq!(metadata_1.underlying, underlying_1.address);
+        assert_eq!(metadata_1.strike, strike_1);
+        assert_eq!(metadata_1.option_type, 0); // Call
+
+        // Verify metadata_2
+        assert_eq!(metadata_2.underlying, underlying_2.address);
+        assert_eq!(metadata_2.strike, strike_2);
+        assert_eq!(metadata_2.option_type, 1); // Put
+
+        // Verify they're different
+        assert_ne!(metadata_1.underlying, metadata_2.underlying);
+        assert_ne!(metadata_1.strike, metadata_2.strike);
+    }
+
+    #[motsu::test]
+    fn test_store_metadata_with_different_decimals_fails(contract: Contract<Options>) {
+        let token_id = B256::from([0x78; 32]);
+        let underlying = Token {
+            address: Address::from([0x33; 20]),
+            decimals: 8,
+        };
+        let quote = Token {
+            address: Address::from([0x44; 20]),
+            decimals: 6,
+        };
+        let strike = U256::from(60_000);
+        let expiry = 1_750_000_000u64;
+
+        contract
+            .sender(Address::ZERO)
+            .store_option_metadata(
+                token_id,
+                underlying,
+                quote,
+                strike,
+                expiry,
+                OptionType::Call,
+            )
+            .unwrap();
+
+        let result = contract.sender(Address::ZERO).store_option_metadata(
+            token_id,
+            Token {
+                decimals: 18,
+                ..underlying
+            },
+            quote,
+            strike,
+            expiry,
+            OptionType::Call,
+        );
+        assert!(matches!(result, Err(OptionsError::MetadataMismatch(_))));
+
+        let result = contract.sender(Address::ZERO).store_option_metadata(
+            token_id,
+            underlying,
+            Token {
+                decimals: 18,
+                ..quote
+            },
+            strike,
+            expiry,
+            OptionType::Call,
+        );
+        assert!(matches!(result, Err(OptionsError::MetadataMismatch(_))));
+
+        let metadata = contract.sender(Address::ZERO).get_option_metadata(token_id);
+        assert_eq!(metadata.underlying_decimals, 8);
+        assert_eq!(metadata.quote_decimals, 6);
+    }
+
+    #[motsu::test]
+    fn test_store_metadata_with_matching_decimals_succeeds(contract: Contract<Options>) {
+        let token_id = B256::from([0x79; 32]);
+        let underlying = Token {
+            address: Address::from([0x33; 20]),
+            decimals: 8,
+        };
+        let quote = Token {
+            address: Address::from([0x44; 20]),
+            decimals: 6,
+        };
+
+        for _ in 0..2 {
+            contract
+                .sender(Address::ZERO)
+                .store_option_metadata(
+                    token_id,
+                    underlying,
+                    quote,
+                    U256::from(60_000),
+                    1_750_000_000u64,
+                    OptionType::Call,
+                )
+                .unwrap();
+        }
+
+        let metadata = contract.sender(Address::ZERO).get_option_metadata(token_id);
+        assert_eq!(metadata.underlying_decimals, 8);
+        assert_eq!(metadata.quote_decimals, 6);
+    }
+
+    // Writer Position Tracking Tests
+    #[motsu::test]
+    fn test_create_new_position_stores_quantity_and_collateral(contract: Contract<Options>) {
+        let writer = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x01; 32]);
+        let quantity = U256::from(100);
+        let collateral = U256::from(200);
+
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, quantity, collateral)
+            .unwrap();
+
+        let (stored_quantity, stored_collateral) =
+            contract.sender(writer).get_position(writer, token_id);
+
+        assert_eq!(stored_quantity, quantity);
+        assert_eq!(stored_collateral, collateral);
+    }
+
+    #[motsu::test]
+    fn test_increase_existing_position_accumulates_correctly(contract: Contract<Options>) {
+        let writer = Address::from([0xBB; 20]);
+        let token_id = B256::from([0x02; 32]);
+        let initial_quantity = U256::from(50);
+        let initial_collateral = U256::from(100);
+        let additional_quantity = U256::from(30);
+        let additional_collateral = U256::from(60);
+
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, initial_quantity, initial_collateral)
+            .unwrap();
+
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, additional_quantity, additional_collateral)
+            .unwrap();
+
+        let (final_quantity, final_collateral) =
+            contract.sender(writer).get_position(writer, token_id);
+
+        assert_eq!(final_quantity, U256::from(80));
+        assert_eq!(final_collateral, U256::from(160));
+    }
+
+    #[motsu::test]
+    fn test_different_writers_same_token_id_have_independent_positions(
+        contract: Contract<Options>,
+    ) {
+        let writer1 = Address::from([0xCC; 20]);
+        let writer2 = Address::from([0xDD; 20]);
+        let token_id = B256::from([0x03; 32]);
+        let quantity1 = U256::from(100);
+        let collateral1 = U256::from(200);
+        let quantity2 = U256::from(150);
+        let collateral2 = U256::from(300);
+
+        contract
+            .sender(writer1)
+            .create_or_update_position(writer1, token_id, quantity1, collateral1)
+            .unwrap();
+
+        contract
+            .sender(writer2)
+            .create_or_update_position(writer2, token_id, quantity2, collateral2)
+            .unwrap();
+
+        let (stored_quantity1, stored_collateral1) =
+            contract.sender(writer1).get_position(writer1, token_id);
+        let (stored_quantity2, stored_collateral2) =
+            contract.sender(writer2).get_position(writer2, token_id);
+
+        assert_eq!(stored_quantity1, quantity1);
+        assert_eq!(stored_collateral1, collateral1);
+        assert_eq!(stored_quantity2, quantity2);
+        assert_eq!(stored_collateral2, collateral2);
+    }
+
+    #[test]
+    fn test_position_key_is_deterministic() {
+        let writer = Address::from([0xEE; 20]);
+        let token_id = B256::from([0x04; 32]);
+
+        let key1 = Options::position_key(writer, token_id);
+        let key2 = Options::position_key(writer, token_id);
+
+        assert_eq!(key1, key2);
+    }
+
+    // Token ID Generation Tests
+    #[test]
+    fn test_token_id_preimage_hashes_to_token_id() {
+        let underlying = Address::from([0x11; 20]);
+        let quote = Address::from([0x22; 20]);
+        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+        let expiry = 1_700_000_000u64;
+
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let preimage = token_id_preimage(underlying, quote, strike, expiry, option_type);
+
+            assert_eq!(preimage.len(), 81);
+            assert_eq!(&preimage[..20], underlying.as_slice());
+            assert_eq!(&preimage[20..40], quote.as_slice());
+            assert_eq!(&preimage[40..72], strike.to_be_bytes::<32>().as_slice());
+            assert_eq!(&preimage[72..80], expiry.to_be_bytes().as_slice());
+            assert_eq!(preimage[80], option_type.to_u8());
+            assert_eq!(
+                keccak256(&preimage),
+                generate_token_id(underlying, quote, strike, expiry, option_type)
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_token_id_same_parameters_identical() {
+        let underlying = Address::from([0x11; 20]);
+        let quote = Address::from([0x22; 20]);
+        let strike = U256::from(100_000);
+        let expiry = 1_700_000_000u64;
+        let option_type = OptionType::Call;
+
+        let token_id_1 = generate_token_id(underlying, quote, strike, expiry, option_type);
+        let token_id_2 = generate_token_id(underlying, quote, strike, expiry, option_type);
+
+        assert_eq!(token_id_1, token_id_2);
+    }
+
+    #[test]
+    fn test_generate_token_id_different_strikes() {
+        let underlying = Address::from([0x11; 20]);
+        let quote = Address::from([0x22; 20]);
+        let expiry = 1_700_000_000u64;
+        let option_type = OptionType::Call;
+
+        let token_id_1 =
+            generate_token_id(underlying, quote, U256::from(100_000), expiry, option_type);
+        let token_id_2 =
+            generate_token_id(underlying, quote, U256::from(200_000), expiry, option_type);
+
+        assert_ne!(token_id_1, token_id_2);
+    }
+
+    #[test]
+    fn test_generate_token_id_different_expiries() {
+        let underlying = Address::from([0x11; 20]);
+        let quote = Address::from([0x22; 20]);
+        let strike = U256::from(100_000);
+        let option_type = OptionType::Call;
+
+        let token_id_1 =
+            generate_token_id(underlying, quote, strike, 1_700_000_000u64, option_type);
+        let token_id_2 =
+            generate_token_id(underlying, quote, strike, 1_800_000_000u64, option_type);
+
+        assert_ne!(token_id_1, token_id_2);
+    }
+
+    #[test]
+    fn test_generate_token_id_different_option_types() {
+        let underlying = Address::from([0x11; 20]);
+        let quote = Address::from([0x22; 20]);
+        let strike = U256::from(100_000);
+        let expiry = 1_700_000_000u64;
+
+        let token_id_call = generate_token_id(underlying, quote, strike, expiry, OptionType::Call);
+        let token_id_put = generate_token_id(underlying, quote, strike, expiry, OptionType::Put);
+
+        assert_ne!(token_id_call, token_id_put);
+    }
+
+    #[test]
+    fn test_generate_token_id_different_underlying() {
+        let quote = Address::from([0x22; 20]);
+        let strike = U256::from(100_000);
+        let expiry = 1_700_000_000u64;
+        let option_type = OptionType::Call;
+
+        let token_id_1 = generate_token_id(
+            Address::from([0x11; 20]),
+            quote,
+            strike,
+            expiry,
+            option_type,
+        );
+        let token_id_2 = generate_token_id(
+            Address::from([0x33; 20]),
+            quote,
+            strike,
+            expiry,
+            option_type,
+        );
+
+        assert_ne!(token_id_1, token_id_2);
+    }
+
+    #[test]
+    fn test_generate_token_id_different_quote() {
+        let underlying = Address::from([0x11; 20]);
+        let strike = U256::from(100_000);
+        let expiry = 1_700_000_000u64;
+        let option_type = OptionType::Call;
+
+        let token_id_1 = generate_token_id(
+            underlying,
+            Address::from([0x22; 20]),
+            strike,
+            expiry,
+            option_type,
+        );
+        let token_id_2 = generate_token_id(
+            underlying,
+            Address::from([0x33; 20]),
+            strike,
+            expiry,
+            option_type,
+        );
+
+        assert_ne!(token_id_1, token_id_2);
+    }
+
+    #[motsu::test]
+    fn test_write_call_option_zero_strike_fails(contract: Contract<Options>, alice: Address) {
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 8,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let strike = U256::ZERO;
+        let expiry = 1_870_000_000u64;
+        let quantity = U256::from(100_000_000);
+
+        let result = contract
+            .sender(alice)
+            .write_call_option(strike, expiry, quantity, underlying, quote);
+
+        assert!(matches!(result, Err(OptionsError::InvalidStrike(_))));
+    }
+
+    #[motsu::test]
+    fn test_write_call_option_expired_option_fails(contract: Contract<Options>, alice: Address) {
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 8,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+        let expiry = 1_000_000_000u64;
+        let quantity = U256::from(100_000_000);
+
+        let result = contract
+            .sender(alice)
+            .write_call_option(strike, expiry, quantity, underlying, quote);
+
+        assert!(matches!(result, Err(OptionsError::ExpiredOption(_))));
+    }
+
+    #[motsu::test]
+    fn test_write_call_option_zero_quantity_fails(contract: Contract<Options>, alice: Address) {
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 8,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+        let expiry = 1_870_000_000u64;
+        let quantity = U256::ZERO;
+
+        let result = contract
+            .sender(alice)
+            .write_call_option(strike, expiry, quantity, underlying, quote);
+
+        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
+    }
+
+    #[motsu::test]
+    fn test_write_call_option_same_token_fails(contract: Contract<Options>, alice: Address) {
+        let same_address = Address::from([0x11; 20]);
+        let underlying = Token {
+            address: same_address,
+            decimals: 8,
+        };
+        let quote = Token {
+            address: same_address,
+            decimals: 6,
+        };
+        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+        let expiry = 1_870_000_000u64;
+        let quantity = U256::from(100_000_000);
+
+        let result = contract
+            .sender(alice)
+            .write_call_option(strike, expiry, quantity, underlying, quote);
+
+        assert!(matches!(result, Err(OptionsError::SameToken(_))));
+    }
+
+    #[motsu::test]
+    fn test_write_call_option_invalid_decimals_fails(contract: Contract<Options>, alice: Address) {
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 24,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+        let expiry = 1_870_000_000u64;
+        let quantity = U256::from(100_000_000);
+
+        let result = contract
+            .sender(alice)
+            .write_call_option(strike, expiry, quantity, underlying, quote);
+
+        assert!(matches!(result, Err(OptionsError::InvalidDecimals(_))));
+    }
+
+    #[motsu::test]
+    fn test_write_put_option_returns_unimplemented(contract: Contract<Options>, alice: Address) {
+        let underlying = Token {
+            address: Address::ZERO,
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::ZERO,
+            decimals: 6,
+        };
+
+        let result = contract.sender(alice).write_put_option(
+            U256::from(1000),
+            1_234_567_890u64,
+            U256::from(100),
+            underlying,
+            quote,
+        );
+
+        assert!(matches!(result, Err(OptionsError::Unimplemented(_))));
+    }
+
+    #[motsu::test]
+    fn test_exercise_put_unimplemented(contract: Contract<Options>, alice: Address) {
+        let result = contract
+            .sender(alice)
+            .exercise_put(B256::ZERO, U256::from(10), U256::ZERO);
+        assert!(matches!(result, Err(OptionsError::Unimplemented(_))));
+    }
+
+    /// Sets up a covered call on an underlying that charges a 1% transfer fee, as if
+    /// the token turned fee-on-transfer after the option was written.
+    fn setup_fee_on_transfer_call(
+        contract: &Contract<Options>,
+        token: &Contract<FeeOnTransferERC20>,
+        alice: Address,
+        token_id: B256,
+        quantity: U256,
+    ) {
+        let underlying = Token {
+            address: token.address(),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                underlying,
+                quote,
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Call,
+            )
+            .unwrap();
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, quantity)
+            .unwrap();
+        contract
+            .sender(alice)
+            .create_or_update_position(alice, token_id, quantity, quantity)
+            .unwrap();
+        contract
+            .sender(alice)
+            .lock_collateral(underlying.address, quantity)
+            .unwrap();
+        token.sender(alice).mint(contract.address(), quantity);
+    }
+
+    #[motsu::test]
+    fn test_exercise_call_strict_rejects_fee_on_transfer_shortfall(
+        contract: Contract<Options>,
+        token: Contract<FeeOnTransferERC20>,
+    ) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x48; 32]);
+        let quantity = U256::from(1000);
+        setup_fee_on_transfer_call(&contract, &token, alice, token_id, quantity);
+
+        let result = contract
+            .sender(alice)
+            .exercise_call_strict(token_id, quantity, quantity);
+
+        assert!(matches!(
+            result,
+            Err(OptionsError::FeeOnTransferDetected(FeeOnTransferDetected { received, .. }))
+                if received == U256::from(990)
+        ));
+    }
+
+    #[motsu::test]
+    fn test_exercise_call_strict_accepts_shortfall_within_minimum(
+        contract: Contract<Options>,
+        token: Contract<FeeOnTransferERC20>,
+    ) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x49; 32]);
+        let quantity = U256::from(1000);
+        setup_fee_on_transfer_call(&contract, &token, alice, token_id, quantity);
+
+        contract
+            .sender(alice)
+            .exercise_call_strict(token_id, quantity, U256::from(990))
+            .unwrap();
+
+        assert_eq!(token.sender(alice).balance_of(alice), U256::from(990));
+    }
+
+    #[motsu::test]
+    fn test_exercise_call_lenient_accepts_fee_on_transfer_shortfall(
+        contract: Contract<Options>,
+        token: Contract<FeeOnTransferERC20>,
+    ) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x4A; 32]);
+        let quantity = U256::from(1000);
+        setup_fee_on_transfer_call(&contract, &token, alice, token_id, quantity);
+
+        contract
+            .sender(alice)
+            .exercise_call(token_id, quantity)
+            .unwrap();
+
+        assert_eq!(token.sender(alice).balance_of(alice), U256::from(990));
+        assert_eq!(
+            contract.sender(alice).balance_of(alice, token_id),
+            U256::ZERO
+        );
+    }
+
+    #[motsu::test]
+    fn test_batch_exercise_with_put_leg_unimplemented(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let call_id = B256::from([0x46; 32]);
+        let put_id = B256::from([0x47; 32]);
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 18,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
+        let quantity = U256::from(100);
+
+        for (token_id, option_type) in [(call_id, OptionType::Call), (put_id, OptionType::Put)] {
+            contract
+                .sender(alice)
+                .store_option_metadata(
+                    token_id,
+                    underlying,
+                    quote,
+                    U256::from(50_000),
+                    1_870_000_000u64,
+                    option_type,
+                )
+                .unwrap();
+            contract
+                .sender(alice)
+                ._mint(alice, token_id, quantity)
+                .unwrap();
+        }
+        contract
+            .sender(alice)
+            .create_or_update_position(alice, call_id, quantity, quantity)
+            .unwrap();
+        contract
+            .sender(alice)
+            .lock_collateral(underlying.address, quantity)
+            .unwrap();
+
+        let result = contract
+            .sender(alice)
+            .batch_exercise(vec![call_id, put_id], vec![quantity, quantity]);
+
+        assert!(matches!(result, Err(OptionsError::Unimplemented(_))));
+    }
+
+    #[motsu::test]
+    fn test_withdraw_expired_collateral_unknown_series(
+        contract: Contract<Options>,
+        alice: Address,
+    ) {
+        let result = contract
+            .sender(alice)
+            .withdraw_expired_collateral(B256::ZERO, U256::from(10));
+        assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+    }
+
+    #[motsu::test]
+    fn test_validate_call_exercise_with_valid_inputs(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x41; 32]);
+        let quantity = U256::from(100);
+
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Call,
+            )
+            .unwrap();
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, quantity)
+            .unwrap();
+
+        let current_time = 1_800_000_000u64;
+        let result =
+            contract
+                .sender(alice)
+                .validate_call_exercise(alice, token_id, quantity, current_time);
+
+        assert!(result.is_ok());
+    }
+
+    #[motsu::test]
+    fn test_validate_call_exercise_option_not_found(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let non_existent_token = B256::from([0x99; 32]);
+        let quantity = U256::from(100);
+        let current_time = 1_800_000_000u64;
+
+        let result = contract.sender(alice).validate_call_exercise(
+            alice,
+            non_existent_token,
+            quantity,
+            current_time,
+        );
+
+        assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+    }
+
+    #[motsu::test]
+    fn test_validate_call_exercise_zero_underlying(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x4A; 32]);
+        let quantity = U256::from(100);
+
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::ZERO,
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Call,
+            )
+            .unwrap();
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, quantity)
+            .unwrap();
+
+        let result = contract.sender(alice).validate_call_exercise(
+            alice,
+            token_id,
+            quantity,
+            1_800_000_000u64,
+        );
+
+        assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+    }
+
+    #[motsu::test]
+    fn test_validate_call_exercise_after_expiry(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x43; 32]);
+        let expiry = 1_870_000_000u64;
+
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                expiry,
+                OptionType::Call,
+            )
+            .unwrap();
+
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, U256::from(100))
+            .unwrap();
 
-        let balance_before = erc20
-            .balance_of(Call::new_in(self), to)
-            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+        let current_time = expiry + SETTLEMENT_GRACE_PERIOD + 1;
+        let result = contract.sender(alice).validate_call_exercise(
+            alice,
+            token_id,
+            U256::from(50),
+            current_time,
+        );
 
-        let success = erc20
-            .transfer_from(Call::new_in(self), from, to, amount)
-            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+        assert!(matches!(result, Err(OptionsError::ExerciseAfterExpiry(_))));
+    }
 
-        if !success {
-            return Err(OptionsError::TransferFailed(TransferFailed {}));
-        }
+    #[motsu::test]
+    fn test_get_exercise_cost_after_expiry(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x45; 32]);
 
-        let balance_after = erc20
-            .balance_of(Call::new_in(self), to)
-            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_000_000_000,
+                OptionType::Call,
+            )
+            .unwrap();
 
-        let received = balance_after.checked_sub(balance_before).ok_or(
-            OptionsError::UnexpectedBalanceDecrease(UnexpectedBalanceDecrease {}),
-        )?;
+        let result = contract
+            .sender(alice)
+            .get_exercise_cost(token_id, U256::from(50));
 
-        if received != amount {
-            return Err(OptionsError::FeeOnTransferDetected(FeeOnTransferDetected {
-                expected: amount,
-                received,
-            }));
-        }
+        assert!(matches!(result, Err(OptionsError::ExerciseAfterExpiry(_))));
+    }
 
-        Ok(())
+    #[motsu::test]
+    fn test_get_exercise_cost_rejects_puts(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x46; 32]);
+
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000,
+                OptionType::Put,
+            )
+            .unwrap();
+
+        let result = contract
+            .sender(alice)
+            .get_exercise_cost(token_id, U256::from(50));
+
+        assert!(matches!(result, Err(OptionsError::WrongOptionType(_))));
     }
 
-    /// Safely transfers ERC20 tokens from contract to recipient with fee-on-transfer detection.
-    ///
-    /// Checks the recipient's balance before and after transfer to ensure the full
-    /// amount was received. This prevents fee-on-transfer tokens from breaking
-    /// settlement accounting.
-    ///
-    /// # Parameters
-    /// - `token`: ERC20 token contract address
-    /// - `to`: Recipient address
-    /// - `amount`: Amount to transfer
-    ///
-    /// # Errors
-    /// - `TransferFailed`: ERC20 transfer call failed
-    /// - `FeeOnTransferDetected`: Received amount doesn't match requested amount
-    /// - `UnexpectedBalanceDecrease`: Balance decreased instead of increased
-    #[allow(deprecated)]
-    pub fn safe_transfer(
-        &mut self,
-        token: Address,
-        to: Address,
-        amount: U256,
-    ) -> Result<(), OptionsError> {
-        let erc20 = IERC20::new(token);
+    #[motsu::test]
+    fn test_exercise_and_withdrawal_boundaries(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x44; 32]);
+        let expiry = 1_870_000_000u64;
+        let underlying = Token {
+            address: Address::from([0x11; 20]),
+            decimals: 8,
+        };
+        let quote = Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        };
 
-        let balance_before = erc20
-            .balance_of(Call::new_in(self), to)
-            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                underlying,
+                quote,
+                U256::from(50_000),
+                expiry,
+                OptionType::Call,
+            )
+            .unwrap();
 
-        let success = erc20
-            .transfer(Call::new_in(self), to, amount)
-            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, U256::from(100))
+            .unwrap();
 
-        if !success {
-            return Err(OptionsError::TransferFailed(TransferFailed {}));
-        }
+        let exercise_at = |current_time: u64| {
+            contract.sender(alice).validate_call_exercise(
+                alice,
+                token_id,
+                U256::from(50),
+                current_time,
+            )
+        };
+        let write_at = |current_time: u64| {
+            validate_write_params(
+                U256::from(50_000),
+                expiry,
+                U256::from(100),
+                underlying,
+                quote,
+                current_time,
+                DEFAULT_MAX_EXPIRY_HORIZON,
+                U256::ZERO,
+            )
+        };
+        let withdraw_at = |current_time: u64| {
+            validate_withdrawal_time(
+                U256::from(expiry),
+                ExerciseStyle::American.to_u8(),
+                current_time,
+            )
+        };
 
-        let balance_after = erc20
-            .balance_of(Call::new_in(self), to)
-            .map_err(|_| OptionsError::TransferFailed(TransferFailed {}))?;
+        // expiry - 1: everything but withdrawal allowed
+        assert!(exercise_at(expiry - 1).is_ok());
+        assert!(write_at(expiry - 1).is_ok());
+        assert!(matches!(
+            withdraw_at(expiry - 1),
+            Err(OptionsError::WithdrawalBeforeSettlement(_))
+        ));
 
-        let received = balance_after.checked_sub(balance_before).ok_or(
-            OptionsError::UnexpectedBalanceDecrease(UnexpectedBalanceDecrease {}),
-        )?;
+        // expiry: grace period starts, writing is closed
+        assert!(exercise_at(expiry).is_ok());
+        assert!(matches!(
+            write_at(expiry),
+            Err(OptionsError::ExpiredOption(_))
+        ));
+        assert!(matches!(
+            withdraw_at(expiry),
+            Err(OptionsError::WithdrawalBeforeSettlement(_))
+        ));
 
-        if received != amount {
-            return Err(OptionsError::FeeOnTransferDetected(FeeOnTransferDetected {
-                expected: amount,
-                received,
-            }));
-        }
+        // Last second of the grace period
+        let last_grace_second = expiry + SETTLEMENT_GRACE_PERIOD - 1;
+        assert!(exercise_at(last_grace_second).is_ok());
+        assert!(matches!(
+            withdraw_at(last_grace_second),
+            Err(OptionsError::WithdrawalBeforeSettlement(_))
+        ));
 
-        Ok(())
+        // Grace period elapsed: exercise closed, withdrawal open
+        let settlement = expiry + SETTLEMENT_GRACE_PERIOD;
+        assert!(matches!(
+            exercise_at(settlement),
+            Err(OptionsError::ExerciseAfterExpiry(_))
+        ));
+        assert!(withdraw_at(settlement).is_ok());
     }
 
-    /// Stores option metadata for a token ID.
-    ///
-    /// Metadata is stored once per option series on first write. Subsequent writes
-    /// of the same option parameters reuse the existing metadata.
-    ///
-    /// # Parameters
-    /// - `token_id`: ERC-1155 token ID (deterministic hash of option parameters)
-    /// - `underlying`: Underlying token (address and decimals)
-    /// - `quote`: Quote token (address and decimals)
-    /// - `strike`: Strike price (18 decimals normalized)
-    /// - `expiry`: Expiration timestamp
-    /// - `option_type`: Call or Put
-    pub(crate) fn store_option_metadata(
-        &mut self,
-        token_id: B256,
-        underlying: Token,
-        quote: Token,
-        strike: U256,
-        expiry: u64,
-        option_type: OptionType,
-    ) {
-        let mut metadata = self.option_metadata.setter(token_id);
-        metadata.underlying.set(underlying.address);
-        metadata.quote.set(quote.address);
-        metadata
-            .underlying_decimals
-            .set(U8::from(underlying.decimals));
-        metadata.quote_decimals.set(U8::from(quote.decimals));
-        metadata.strike.set(strike);
-        metadata.expiry.set(U256::from(expiry));
-        metadata.option_type.set(U8::from(option_type.to_u8()));
-    }
+    #[test]
+    fn test_validate_withdrawal_time_reports_available_at() {
+        let expiry = 1_870_000_000u64;
 
-    /// Retrieves option metadata for a token ID.
-    ///
-    /// # Parameters
-    /// - `token_id`: ERC-1155 token ID
-    ///
-    /// # Returns
-    /// Option metadata struct with all option parameters
-    pub(crate) fn get_option_metadata(&self, token_id: B256) -> OptionMetadataView {
-        let metadata = self.option_metadata.get(token_id);
-        OptionMetadataView {
-            underlying: metadata.underlying.get(),
-            quote: metadata.quote.get(),
-            underlying_decimals: metadata.underlying_decimals.get().to::<u8>(),
-            quote_decimals: metadata.quote_decimals.get().to::<u8>(),
-            strike: metadata.strike.get(),
-            expiry: metadata.expiry.get(),
-            option_type: metadata.option_type.get().to::<u8>(),
-        }
-    }
+        let result =
+            validate_withdrawal_time(U256::from(expiry), ExerciseStyle::American.to_u8(), expiry);
 
-    /// Generates a composite key for position lookups.
-    ///
-    /// Position key = keccak256(writer, token_id)
-    ///
-    /// Each writer has independent positions per option series.
-    fn position_key(writer: Address, token_id: B256) -> B256 {
-        keccak256([writer.as_slice(), token_id.as_slice()].concat())
+        assert!(matches!(
+            result,
+            Err(OptionsError::WithdrawalBeforeSettlement(WithdrawalBeforeSettlement {
+                available_at,
+                current,
+            })) if available_at == U256::from(expiry + SETTLEMENT_GRACE_PERIOD)
+                && current == U256::from(expiry)
+        ));
     }
 
-    /// Creates or updates a writer's position for an option series.
-    ///
-    /// If position exists, accumulates quantity and collateral using checked arithmetic.
-    /// If position is new, creates it with provided values.
-    ///
-    /// # Parameters
-    /// - `writer`: Writer address
-    /// - `token_id`: ERC-1155 token ID
-    /// - `quantity`: Quantity to add (18 decimals normalized)
-    /// - `collateral`: Collateral to add (18 decimals normalized)
-    ///
-    /// # Errors
-    /// Returns `OptionsError::Overflow` if accumulation would overflow
-    pub(crate) fn create_or_update_position(
-        &mut self,
-        writer: Address,
-        token_id: B256,
-        quantity: U256,
-        collateral: U256,
-    ) -> Result<(), OptionsError> {
-        let key = Self::position_key(writer, token_id);
-        let mut position = self.positions.setter(key);
-
-        let current_quantity = position.quantity_written.get();
-        let current_collateral = position.collateral_locked.get();
+    #[motsu::test]
+    fn test_european_exercise_window_boundaries(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x45; 32]);
+        let expiry = 1_870_000_000u64;
 
-        let new_quantity = current_quantity
-            .checked_add(quantity)
-            .ok_or(OptionsError::Overflow(Overflow {}))?;
-        let new_collateral = current_collateral
-            .checked_add(collateral)
-            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                expiry,
+                OptionType::Call,
+            )
+            .unwrap();
+        {
+            let mut options = contract.sender(alice);
+            let mut metadata = options.option_metadata.setter(token_id);
+            metadata
+                .exercise_style
+                .set(U8::from(ExerciseStyle::European.to_u8()));
+        }
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, U256::from(100))
+            .unwrap();
 
-        position.quantity_written.set(new_quantity);
-        position.collateral_locked.set(new_collateral);
+        let exercise_at = |current_time: u64| {
+            contract.sender(alice).validate_call_exercise(
+                alice,
+                token_id,
+                U256::from(50),
+                current_time,
+            )
+        };
+        let withdraw_at = |current_time: u64| {
+            validate_withdrawal_time(
+                U256::from(expiry),
+                ExerciseStyle::European.to_u8(),
+                current_time,
+            )
+        };
 
-        Ok(())
-    }
+        // Before the window
+        let opens_at = expiry - EUROPEAN_EXERCISE_WINDOW;
+        assert!(matches!(
+            exercise_at(opens_at - 1),
+            Err(OptionsError::ExerciseWindowClosed(ExerciseWindowClosed {
+                opens_at: window_open,
+                closes_at,
+                current,
+            })) if window_open == U256::from(opens_at)
+                && closes_at == U256::from(expiry + EUROPEAN_EXERCISE_WINDOW)
+                && current == U256::from(opens_at - 1)
+        ));
 
-    /// Retrieves a writer's position for an option series.
-    ///
-    /// # Parameters
-    /// - `writer`: Writer address
-    /// - `token_id`: ERC-1155 token ID
-    ///
-    /// # Returns
-    /// Tuple of (quantity_written, collateral_locked)
-    #[must_use]
-    pub fn get_position(&self, writer: Address, token_id: B256) -> (U256, U256) {
-        let key = Self::position_key(writer, token_id);
-        let position = self.positions.get(key);
-        (
-            position.quantity_written.get(),
-            position.collateral_locked.get(),
-        )
+        // In the window, on both sides of expiry
+        assert!(exercise_at(opens_at).is_ok());
+        assert!(exercise_at(expiry).is_ok());
+        let last_window_second = expiry + EUROPEAN_EXERCISE_WINDOW - 1;
+        assert!(exercise_at(last_window_second).is_ok());
+        assert!(matches!(
+            withdraw_at(last_window_second),
+            Err(OptionsError::WithdrawalBeforeSettlement(_))
+        ));
+
+        // After the window: exercise closed, withdrawal open
+        let closes_at = expiry + EUROPEAN_EXERCISE_WINDOW;
+        assert!(matches!(
+            exercise_at(closes_at),
+            Err(OptionsError::ExerciseWindowClosed(_))
+        ));
+        assert!(withdraw_at(closes_at).is_ok());
     }
 
-    /// Validates preconditions for exercising a call option.
-    ///
-    /// Performs comprehensive validation before exercise execution:
-    /// - Option exists (has been written)
-    /// - Not expired
-    /// - Is a call option
-    /// - Non-zero quantity
-    /// - Holder has sufficient option tokens
-    ///
-    /// # Parameters
-    /// - `holder`: Address attempting to exercise
-    /// - `token_id`: ERC-1155 token ID of the option
-    /// - `quantity`: Amount to exercise
-    /// - `current_time`: Current block timestamp
-    ///
-    /// # Errors
-    /// - `OptionNotFound`: Token ID has no metadata (never written)
-    /// - `ExerciseAfterExpiry`: Current time >= expiry
-    /// - `WrongOptionType`: Option is not a call (is a put)
-    /// - `InvalidQuantity`: Quantity is zero
-    /// - `InsufficientBalance`: Holder doesn't have enough option tokens
-    pub(crate) fn validate_call_exercise(
-        &self,
-        holder: Address,
-        token_id: B256,
-        quantity: U256,
-        current_time: u64,
-    ) -> Result<(), OptionsError> {
-        let metadata = self.get_option_metadata(token_id);
-        if metadata.expiry.is_zero() {
-            return Err(OptionsError::OptionNotFound(OptionNotFound {}));
-        }
+    #[test]
+    fn test_european_token_id_differs_from_american() {
+        let underlying = Address::from([0x11; 20]);
+        let quote = Address::from([0x22; 20]);
+        let strike = U256::from(50_000);
+        let expiry = 1_870_000_000u64;
+
+        let american = generate_token_id(underlying, quote, strike, expiry, OptionType::Call);
+        let european = generate_european_token_id(american);
+
+        assert_ne!(american, european);
+        assert_eq!(european, generate_european_token_id(american));
+        assert_ne!(
+            european,
+            generate_european_token_id(generate_token_id(
+                underlying,
+                quote,
+                strike,
+                expiry + 1,
+                OptionType::Call
+            ))
+        );
+    }
 
-        let expiry = metadata.expiry.to::<u64>();
-        if current_time >= expiry {
-            return Err(OptionsError::ExerciseAfterExpiry(ExerciseAfterExpiry {
-                expiry: metadata.expiry,
-                current: U256::from(current_time),
-            }));
-        }
+    #[motsu::test]
+    fn test_write_rejects_series_with_expired_stored_expiry(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+    ) {
+        let alice = Address::from([0xAA; 20]);
+        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+        let expiry = 1_870_000_000u64;
+        let quantity = U256::from(100_000_000);
+        let underlying_token = Token {
+            address: underlying.address(),
+            decimals: 8,
+        };
+        let quote_token = Token {
+            address: quote.address(),
+            decimals: 6,
+        };
+        underlying.sender(alice).set_decimals(8);
+        quote.sender(alice).set_decimals(6);
+        underlying
+            .sender(alice)
+            .mint(alice, quantity * U256::from(2));
+        underlying
+            .sender(alice)
+            .approve(contract.address(), quantity * U256::from(2));
 
-        if metadata.option_type != 0 {
-            return Err(OptionsError::WrongOptionType(WrongOptionType {
-                expected: 0,
-                actual: metadata.option_type,
-            }));
-        }
+        let token_id = contract
+            .sender(alice)
+            .write_call_option(strike, expiry, quantity, underlying_token, quote_token)
+            .unwrap();
 
-        if quantity.is_zero() {
-            return Err(OptionsError::InvalidQuantity(InvalidQuantity {}));
+        // Metadata persisting past expiry must not let the series be written again
+        {
+            let mut options = contract.sender(alice);
+            let mut metadata = options.option_metadata.setter(token_id);
+            metadata.expiry.set(U256::from(1_000_000_000u64));
         }
 
-        let holder_balance = self.balance_of(holder, token_id);
-        if holder_balance < quantity {
-            return Err(OptionsError::InsufficientBalance(InsufficientBalance {
-                available: holder_balance,
-                requested: quantity,
-            }));
-        }
+        let result = contract.sender(alice).write_call_option(
+            strike,
+            expiry,
+            quantity,
+            underlying_token,
+            quote_token,
+        );
 
-        Ok(())
+        assert!(matches!(
+            result,
+            Err(OptionsError::ExpiredOption(ExpiredOption { expiry, .. }))
+                if expiry == U256::from(1_000_000_000u64)
+        ));
+        assert_eq!(
+            contract.sender(alice).total_supply(token_id),
+            quantity * U256::from(10_000_000_000u64)
+        );
+        assert_eq!(underlying.sender(alice).balance_of(alice), quantity);
     }
 
-    /// Reduces a writer's position for an option series.
-    ///
-    /// Decreases both the quantity written and collateral locked proportionally.
-    /// Used when options are exercised or burned.
-    ///
-    /// # Parameters
-    /// - `writer`: Address of the position owner
-    /// - `token_id`: ERC-1155 token ID of the option
-    /// - `quantity`: Amount to reduce the position by
-    ///
-    /// # Errors
-    /// - `InsufficientBalance`: Position quantity less than requested reduction
-    /// - `Overflow`: Arithmetic overflow during calculation (should never occur with valid inputs)
-    pub(crate) fn reduce_position(
-        &mut self,
-        writer: Address,
-        token_id: B256,
-        quantity: U256,
-    ) -> Result<(), OptionsError> {
-        let key = Self::position_key(writer, token_id);
-        let position = self.positions.get(key);
+    #[motsu::test]
+    fn test_withdraw_expired_collateral_returns_underlying(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+    ) {
+        let alice = Address::from([0xAA; 20]);
+        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+        let quantity = U256::from(100_000_000);
+        underlying.sender(alice).set_decimals(8);
+        quote.sender(alice).set_decimals(6);
+        underlying.sender(alice).mint(alice, quantity);
+        underlying
+            .sender(alice)
+            .approve(contract.address(), quantity);
 
-        let current_quantity = position.quantity_written.get();
-        let current_collateral = position.collateral_locked.get();
+        let token_id = contract
+            .sender(alice)
+            .write_call_option(
+                strike,
+                1_870_000_000u64,
+                quantity,
+                Token {
+                    address: underlying.address(),
+                    decimals: 8,
+                },
+                Token {
+                    address: quote.address(),
+                    decimals: 6,
+                },
+            )
+            .unwrap();
 
-        if current_quantity < quantity {
-            return Err(OptionsError::InsufficientBalance(InsufficientBalance {
-                available: current_quantity,
-                requested: quantity,
-            }));
+        let result = contract
+            .sender(alice)
+            .withdraw_expired_collateral(token_id, quantity);
+        assert!(matches!(
+            result,
+            Err(OptionsError::WithdrawalBeforeSettlement(_))
+        ));
+
+        {
+            let mut options = contract.sender(alice);
+            let mut metadata = options.option_metadata.setter(token_id);
+            metadata.expiry.set(U256::from(1_000_000_000u64));
         }
 
-        let new_quantity = current_quantity
-            .checked_sub(quantity)
-            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        contract
+            .sender(alice)
+            .withdraw_expired_collateral(token_id, U256::from(40_000_000))
+            .unwrap();
 
-        let collateral_to_reduce = if current_quantity.is_zero() {
-            U256::ZERO
-        } else {
-            current_collateral
-                .checked_mul(quantity)
-                .ok_or(OptionsError::Overflow(Overflow {}))?
-                .checked_div(current_quantity)
-                .ok_or(OptionsError::Overflow(Overflow {}))?
+        let remaining = U256::from(60_000_000) * U256::from(10_000_000_000u64);
+        assert_eq!(
+            underlying.sender(alice).balance_of(alice),
+            U256::from(40_000_000)
+        );
+        assert_eq!(
+            contract.sender(alice).get_position(alice, token_id),
+            (remaining, remaining)
+        );
+        assert_eq!(
+            contract
+                .sender(alice)
+                .locked_collateral(underlying.address()),
+            U256::from(60_000_000)
+        );
+    }
+
+    #[motsu::test]
+    fn test_withdraw_expired_spread_returns_quote_and_escrowed_long(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+    ) {
+        let alice = Address::from([0xAA; 20]);
+        let one = U256::from(10).pow(U256::from(18));
+        let expiry = 1_870_000_000u64;
+        let quantity = U256::from(100_000_000);
+        let collateral = U256::from(10_000_000_000u64);
+        let underlying_token = Token {
+            address: underlying.address(),
+            decimals: 8,
         };
+        let quote_token = Token {
+            address: quote.address(),
+            decimals: 6,
+        };
+        underlying.sender(alice).set_decimals(8);
+        quote.sender(alice).set_decimals(6);
+        underlying
+            .sender(alice)
+            .mint(alice, quantity * U256::from(2));
+        underlying
+            .sender(alice)
+            .approve(contract.address(), quantity * U256::from(2));
+        quote.sender(alice).mint(alice, collateral);
+        quote.sender(alice).approve(contract.address(), collateral);
 
-        let new_collateral = current_collateral
-            .checked_sub(collateral_to_reduce)
-            .ok_or(OptionsError::Overflow(Overflow {}))?;
+        let long_id = contract
+            .sender(alice)
+            .write_call_option(
+                U256::from(70_000) * one,
+                expiry,
+                quantity,
+                underlying_token,
+                quote_token,
+            )
+            .unwrap();
+        let short_id = contract
+            .sender(alice)
+            .write_call_option(
+                U256::from(60_000) * one,
+                expiry,
+                quantity,
+                underlying_token,
+                quote_token,
+            )
+            .unwrap();
+        let spread_id = contract
+            .sender(alice)
+            .write_call_spread(short_id, long_id, quantity)
+            .unwrap();
 
-        let mut position = self.positions.setter(key);
-        position.quantity_written.set(new_quantity);
-        position.collateral_locked.set(new_collateral);
+        {
+            let mut options = contract.sender(alice);
+            let mut metadata = options.option_metadata.setter(spread_id);
+            metadata.expiry.set(U256::from(1_000_000_000u64));
+        }
 
-        Ok(())
+        contract
+            .sender(alice)
+            .withdraw_expired_collateral(spread_id, quantity)
+            .unwrap();
+
+        let normalized_quantity = quantity * U256::from(10_000_000_000u64);
+        assert_eq!(quote.sender(alice).balance_of(alice), collateral);
+        assert_eq!(
+            contract.sender(alice).balance_of(alice, long_id),
+            normalized_quantity
+        );
+        assert_eq!(
+            contract
+                .sender(alice)
+                .balance_of(contract.address(), long_id),
+            U256::ZERO
+        );
+        assert_eq!(
+            contract.sender(alice).get_position(alice, spread_id),
+            (U256::ZERO, U256::ZERO)
+        );
+        assert_eq!(
+            contract.sender(alice).locked_collateral(quote.address()),
+            U256::ZERO
+        );
+        // The long and short series' underlying stays locked for their own writers
+        assert_eq!(
+            contract
+                .sender(alice)
+                .locked_collateral(underlying.address()),
+            quantity * U256::from(2)
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use alloy_primitives::Address;
-    use motsu::prelude::*;
+    #[motsu::test]
+    fn test_validate_call_exercise_wrong_option_type(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x42; 32]);
 
-    use super::*;
-    use crate::mock_erc20::MockERC20;
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Put,
+            )
+            .unwrap();
 
-    #[test]
-    fn test_mock_erc20_mint_increases_balance() {
-        let mut token = MockERC20::default();
-        let alice = Address::from([1u8; 20]);
-        let amount = U256::from(1000);
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, U256::from(100))
+            .unwrap();
 
-        token.mint(alice, amount);
+        let current_time = 1_800_000_000u64;
+        let result = contract.sender(alice).validate_call_exercise(
+            alice,
+            token_id,
+            U256::from(50),
+            current_time,
+        );
 
-        assert_eq!(token.balance_of(alice), amount);
+        assert!(matches!(result, Err(OptionsError::WrongOptionType(_))));
     }
 
-    #[test]
-    fn test_mock_erc20_transfer_moves_tokens() {
-        let mut token = MockERC20::default();
-        let alice = Address::from([1u8; 20]);
-        let bob = Address::from([2u8; 20]);
-        let amount = U256::from(1000);
+    #[motsu::test]
+    fn test_validate_call_exercise_zero_quantity(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x45; 32]);
 
-        token.mint(alice, amount);
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Call,
+            )
+            .unwrap();
 
-        let transfer_amount = U256::from(600);
-        let success = token.transfer(alice, bob, transfer_amount);
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, U256::from(100))
+            .unwrap();
 
-        assert!(success);
-        assert_eq!(token.balance_of(alice), U256::from(400));
-        assert_eq!(token.balance_of(bob), transfer_amount);
+        let current_time = 1_800_000_000u64;
+        let result = contract.sender(alice).validate_call_exercise(
+            alice,
+            token_id,
+            U256::ZERO,
+            current_time,
+        );
+
+        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
     }
 
-    #[test]
-    fn test_mock_erc20_transfer_from_with_approval() {
-        let mut token = MockERC20::default();
-        let alice = Address::from([1u8; 20]);
-        let bob = Address::from([2u8; 20]);
-        let charlie = Address::from([3u8; 20]);
-        let amount = U256::from(1000);
+    #[motsu::test]
+    fn test_validate_call_exercise_insufficient_balance(contract: Contract<Options>) {
+        let alice = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x46; 32]);
+        let balance = U256::from(100);
 
-        token.mint(alice, amount);
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Call,
+            )
+            .unwrap();
 
-        let approval_amount = U256::from(600);
-        token.approve(alice, bob, approval_amount);
+        contract
+            .sender(alice)
+            ._mint(alice, token_id, balance)
+            .unwrap();
 
-        let transfer_amount = U256::from(400);
-        let success = token.transfer_from(bob, alice, charlie, transfer_amount);
+        let current_time = 1_800_000_000u64;
+        let excessive_quantity = balance.checked_add(U256::from(1)).unwrap();
+        let result = contract.sender(alice).validate_call_exercise(
+            alice,
+            token_id,
+            excessive_quantity,
+            current_time,
+        );
 
-        assert!(success);
-        assert_eq!(token.balance_of(alice), U256::from(600));
-        assert_eq!(token.balance_of(charlie), transfer_amount);
+        assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
     }
 
-    #[test]
-    fn test_mock_erc20_transfer_from_without_approval_fails() {
-        let mut token = MockERC20::default();
-        let alice = Address::from([1u8; 20]);
-        let bob = Address::from([2u8; 20]);
-        let charlie = Address::from([3u8; 20]);
-        let amount = U256::from(1000);
+    #[motsu::test]
+    fn test_reduce_position_successfully(contract: Contract<Options>) {
+        let writer = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x50; 32]);
+        let initial_quantity = U256::from(1000);
+        let initial_collateral = U256::from(5000);
+        let reduction = U256::from(300);
 
-        token.mint(alice, amount);
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, initial_quantity, initial_collateral)
+            .unwrap();
 
-        let transfer_amount = U256::from(400);
-        let success = token.transfer_from(bob, alice, charlie, transfer_amount);
+        let result = contract
+            .sender(writer)
+            .reduce_position(writer, token_id, reduction, false);
 
-        assert!(!success);
+        assert!(result.is_ok());
+
+        let (quantity, collateral) = contract.sender(writer).get_position(writer, token_id);
+        assert_eq!(quantity, U256::from(700));
+        assert_eq!(collateral, U256::from(3500));
     }
 
-    #[test]
-    fn test_mock_erc20_decimals_returns_configured_value() {
-        let mut token = MockERC20::default();
-        let decimals = 6u8;
+    #[motsu::test]
+    fn test_reduce_position_to_zero(contract: Contract<Options>) {
+        let writer = Address::from([0xBB; 20]);
+        let token_id = B256::from([0x51; 32]);
+        let quantity = U256::from(100);
+        let collateral = U256::from(500);
 
-        token.set_decimals(decimals);
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, quantity, collateral)
+            .unwrap();
 
-        assert_eq!(token.decimals(), decimals);
-    }
+        let result = contract
+            .sender(writer)
+            .reduce_position(writer, token_id, quantity, false);
 
-    // Decimal Normalization Tests
-    #[test]
-    fn test_normalize_amount_usdc_6_decimals() {
-        let amount = U256::from(1_000_000); // 1 USDC
-        let result = normalize_amount(amount, 6);
-        assert_eq!(result.unwrap(), U256::from(1_000_000_000_000_000_000u128)); // 10^18
-    }
+        assert!(result.is_ok());
 
-    #[test]
-    fn test_normalize_amount_wbtc_8_decimals() {
-        let amount = U256::from(100_000_000); // 1 WBTC
-        let result = normalize_amount(amount, 8);
-        assert_eq!(result.unwrap(), U256::from(1_000_000_000_000_000_000u128)); // 10^18
+        let (new_quantity, new_collateral) = contract.sender(writer).get_position(writer, token_id);
+        assert_eq!(new_quantity, U256::ZERO);
+        assert_eq!(new_collateral, U256::ZERO);
     }
 
-    #[test]
-    fn test_normalize_amount_18_decimals_no_change() {
-        let amount = U256::from(1_000_000_000_000_000_000u128); // 1 ether
-        let result = normalize_amount(amount, 18);
-        assert_eq!(result.unwrap(), U256::from(1_000_000_000_000_000_000u128));
-    }
+    #[motsu::test]
+    fn test_reduce_position_insufficient_quantity(contract: Contract<Options>) {
+        let writer = Address::from([0xCC; 20]);
+        let token_id = B256::from([0x52; 32]);
+        let quantity = U256::from(100);
+        let collateral = U256::from(500);
 
-    #[test]
-    fn test_normalize_amount_0_decimals() {
-        let amount = U256::from(1);
-        let result = normalize_amount(amount, 0);
-        assert_eq!(result.unwrap(), U256::from(1_000_000_000_000_000_000u128));
-    }
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, quantity, collateral)
+            .unwrap();
 
-    #[test]
-    fn test_normalize_amount_invalid_decimals_24() {
-        let amount = U256::from(1000);
-        let result = normalize_amount(amount, 24);
-        assert!(matches!(result, Err(OptionsError::InvalidDecimals(_))));
-    }
+        let result =
+            contract
+                .sender(writer)
+                .reduce_position(writer, token_id, U256::from(101), false);
 
-    #[test]
-    fn test_normalize_amount_overflow() {
-        let result = normalize_amount(U256::MAX, 0);
-        assert!(matches!(
-            result,
-            Err(OptionsError::NormalizationOverflow(_))
-        ));
+        assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
     }
 
-    #[test]
-    fn test_denormalize_amount_round_trip_6_decimals() {
-        let original = U256::from(1_000_000); // 1 USDC
-        let normalized = normalize_amount(original, 6).unwrap();
-        let denormalized = denormalize_amount(normalized, 6).unwrap();
-        assert_eq!(denormalized, original);
-    }
+    #[motsu::test]
+    fn test_reduce_position_maintains_collateral_ratio(contract: Contract<Options>) {
+        let writer = Address::from([0xDD; 20]);
+        let token_id = B256::from([0x53; 32]);
+        let initial_quantity = U256::from(1000);
+        let initial_collateral = U256::from(10_000);
 
-    #[test]
-    fn test_denormalize_amount_round_trip_8_decimals() {
-        let original = U256::from(100_000_000); // 1 WBTC
-        let normalized = normalize_amount(original, 8).unwrap();
-        let denormalized = denormalize_amount(normalized, 8).unwrap();
-        assert_eq!(denormalized, original);
-    }
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, initial_quantity, initial_collateral)
+            .unwrap();
 
-    #[test]
-    fn test_denormalize_amount_round_trip_18_decimals() {
-        let original = U256::from(1_000_000_000_000_000_000u128);
-        let normalized = normalize_amount(original, 18).unwrap();
-        let denormalized = denormalize_amount(normalized, 18).unwrap();
-        assert_eq!(denormalized, original);
+        contract
+            .sender(writer)
+            .reduce_position(writer, token_id, U256::from(250), false)
+            .unwrap();
+
+        let (quantity, collateral) = contract.sender(writer).get_position(writer, token_id);
+        assert_eq!(quantity, U256::from(750));
+        assert_eq!(collateral, U256::from(7500));
     }
 
-    // ERC-1155 Balance Tracking Tests
     #[motsu::test]
-    fn test_mint_increases_balance(contract: Contract<Options>, alice: Address) {
-        let token_id = B256::from([0x42; 32]);
-        let quantity = U256::from(100);
+    fn test_reduce_position_returns_released_collateral(contract: Contract<Options>) {
+        let writer = Address::from([0xEE; 20]);
+        let token_id = B256::from([0x54; 32]);
 
         contract
-            .sender(alice)
-            ._mint(alice, token_id, quantity)
+            .sender(writer)
+            .create_or_update_position(writer, token_id, U256::from(1000), U256::from(10_000))
             .unwrap();
 
-        let balance = contract.sender(alice).balance_of(alice, token_id);
-        assert_eq!(balance, quantity);
+        let released = contract
+            .sender(writer)
+            .reduce_position(writer, token_id, U256::from(250), false)
+            .unwrap();
+
+        assert_eq!(released, U256::from(2500));
     }
 
     #[motsu::test]
-    fn test_mint_increases_total_supply(contract: Contract<Options>, alice: Address) {
-        let token_id = B256::from([0x42; 32]);
-        let quantity = U256::from(100);
+    fn test_max_writer_obligation_for_put_is_locked_strike_collateral(contract: Contract<Options>) {
+        let writer = Address::from([0xEE; 20]);
+        let token_id = B256::from([0x56; 32]);
+        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
 
         contract
-            .sender(alice)
-            ._mint(alice, token_id, quantity)
+            .sender(writer)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                strike,
+                1_870_000_000u64,
+                OptionType::Put,
+            )
             .unwrap();
 
-        let total_supply = contract.sender(alice).total_supply_of(token_id);
-        assert_eq!(total_supply, quantity);
+        // 2 puts at a 60,000 strike lock 120,000 quote (18 decimals normalized)
+        let quantity = U256::from(2) * U256::from(10).pow(U256::from(18));
+        let collateral = U256::from(120_000) * U256::from(10).pow(U256::from(18));
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, quantity, collateral)
+            .unwrap();
+
+        assert_eq!(
+            contract
+                .sender(writer)
+                .max_writer_obligation(writer, token_id)
+                .unwrap(),
+            U256::from(120_000_000_000u64)
+        );
     }
 
     #[motsu::test]
-    fn test_burn_decreases_balance(contract: Contract<Options>, alice: Address) {
-        let token_id = B256::from([0x42; 32]);
-        let mint_quantity = U256::from(100);
-        let burn_quantity = U256::from(40);
+    fn test_put_obligation_with_zero_decimals_quote(contract: Contract<Options>) {
+        let writer = Address::from([0xEE; 20]);
+        let token_id = B256::from([0x57; 32]);
+        let one = U256::from(10).pow(U256::from(18));
+        let strike = U256::from(60_000) * one;
 
         contract
-            .sender(alice)
-            ._mint(alice, token_id, mint_quantity)
+            .sender(writer)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 0,
+                },
+                strike,
+                1_870_000_000u64,
+                OptionType::Put,
+            )
             .unwrap();
+
+        // 2 puts at a 60,000 strike lock 120,000 whole quote tokens
+        let collateral = U256::from(120_000) * one;
         contract
-            .sender(alice)
-            ._burn(alice, token_id, burn_quantity)
+            .sender(writer)
+            .create_or_update_position(writer, token_id, U256::from(2) * one, collateral)
+            .unwrap();
+        assert_eq!(
+            contract
+                .sender(writer)
+                .max_writer_obligation(writer, token_id)
+                .unwrap(),
+            U256::from(120_000)
+        );
+
+        // Partially exercising 0.5 puts releases 30,000 tokens
+        let released = contract
+            .sender(writer)
+            .reduce_position(writer, token_id, one / U256::from(2), true)
             .unwrap();
+        assert_eq!(denormalize_amount(released, 0).unwrap(), U256::from(30_000));
+        assert_eq!(
+            contract
+                .sender(writer)
+                .max_writer_obligation(writer, token_id)
+                .unwrap(),
+            U256::from(90_000)
+        );
 
-        let balance = contract.sender(alice).balance_of(alice, token_id);
-        assert_eq!(balance, U256::from(60));
+        // Exercising the rest releases everything that is left
+        let released = contract
+            .sender(writer)
+            .reduce_position(writer, token_id, U256::from(3) * one / U256::from(2), true)
+            .unwrap();
+        assert_eq!(denormalize_amount(released, 0).unwrap(), U256::from(90_000));
+        assert_eq!(
+            contract
+                .sender(writer)
+                .max_writer_obligation(writer, token_id)
+                .unwrap(),
+            U256::ZERO
+        );
     }
 
     #[motsu::test]
-    fn test_burn_decreases_total_supply(contract: Contract<Options>, alice: Address) {
-        let token_id = B256::from([0x42; 32]);
-        let mint_quantity = U256::from(100);
-        let burn_quantity = U256::from(40);
+    fn test_reduce_position_to_zero_releases_residual_collateral(contract: Contract<Options>) {
+        let writer = Address::from([0xEE; 20]);
+        let token_id = B256::from([0x55; 32]);
 
         contract
-            .sender(alice)
-            ._mint(alice, token_id, mint_quantity)
-            .unwrap();
-        contract
-            .sender(alice)
-            ._burn(alice, token_id, burn_quantity)
+            .sender(writer)
+            .create_or_update_position(writer, token_id, U256::from(1000), U256::from(1001))
             .unwrap();
 
-        let total_supply = contract.sender(alice).total_supply_of(token_id);
-        assert_eq!(total_supply, U256::from(60));
+        let mut released = U256::ZERO;
+        let mut remaining = U256::from(1000);
+        while !remaining.is_zero() {
+            let step = remaining.min(U256::from(3));
+            released += contract
+                .sender(writer)
+                .reduce_position(writer, token_id, step, false)
+                .unwrap();
+            remaining -= step;
+        }
+
+        assert_eq!(released, U256::from(1001));
+        assert_eq!(
+            contract.sender(writer).get_position(writer, token_id),
+            (U256::ZERO, U256::ZERO)
+        );
     }
 
     #[motsu::test]
-    fn test_burn_insufficient_balance_fails(contract: Contract<Options>, alice: Address) {
-        let token_id = B256::from([0x42; 32]);
-        let mint_quantity = U256::from(100);
-        let burn_quantity = U256::from(150);
+    fn test_many_small_reductions_release_proportional_collateral(contract: Contract<Options>) {
+        let writer = Address::from([0xEE; 20]);
+        let token_id = B256::from([0x58; 32]);
 
         contract
-            .sender(alice)
-            ._mint(alice, token_id, mint_quantity)
+            .sender(writer)
+            .create_or_update_position(writer, token_id, U256::from(1000), U256::from(1500))
             .unwrap();
 
-        let result = contract.sender(alice)._burn(alice, token_id, burn_quantity);
-        assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+        // Flooring each release on its own would release 1 per step here (500 in
+        // total) and leave a 250 surplus locked until the position is closed
+        let mut released = U256::ZERO;
+        for _ in 0..500 {
+            released += contract
+                .sender(writer)
+                .reduce_position(writer, token_id, U256::from(1), true)
+                .unwrap();
+        }
+
+        assert_eq!(released, U256::from(750));
+        assert_eq!(
+            contract.sender(writer).get_position(writer, token_id),
+            (U256::from(500), U256::from(750))
+        );
+
+        released += contract
+            .sender(writer)
+            .reduce_position(writer, token_id, U256::from(500), true)
+            .unwrap();
+        assert_eq!(released, U256::from(1500));
     }
 
     #[motsu::test]
-    fn test_mint_overflow_fails(contract: Contract<Options>, alice: Address) {
-        let token_id = B256::from([0x42; 32]);
+    fn test_write_after_reduction_rebases_released_collateral(contract: Contract<Options>) {
+        let writer = Address::from([0xEE; 20]);
+        let token_id = B256::from([0x59; 32]);
 
         contract
-            .sender(alice)
-            ._mint(alice, token_id, U256::MAX)
+            .sender(writer)
+            .create_or_update_position(writer, token_id, U256::from(2), U256::from(3))
+            .unwrap();
+        let first = contract
+            .sender(writer)
+            .reduce_position(writer, token_id, U256::from(1), false)
+            .unwrap();
+        assert_eq!(first, U256::from(1));
+
+        // 1 option with 2 collateral left, plus 3 options with 3 collateral
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, U256::from(3), U256::from(3))
+            .unwrap();
+        let second = contract
+            .sender(writer)
+            .reduce_position(writer, token_id, U256::from(2), false)
+            .unwrap();
+        let rest = contract
+            .sender(writer)
+            .reduce_position(writer, token_id, U256::from(2), false)
             .unwrap();
 
-        let result = contract.sender(alice)._mint(alice, token_id, U256::from(1));
-        assert!(matches!(result, Err(OptionsError::Overflow(_))));
+        // floor(5 * 2 / 4), then everything that is left
+        assert_eq!(second, U256::from(2));
+        assert_eq!(first + second + rest, U256::from(6));
+        assert_eq!(
+            contract.sender(writer).get_position(writer, token_id),
+            (U256::ZERO, U256::ZERO)
+        );
     }
 
+    // Close Position Tests
     #[motsu::test]
-    fn test_multiple_mints_accumulate(contract: Contract<Options>, alice: Address) {
-        let token_id = B256::from([0x42; 32]);
+    fn test_close_position_after_tokens_transferred_away_fails(contract: Contract<Options>) {
+        let writer = Address::from([0xAA; 20]);
+        let buyer = Address::from([0xBB; 20]);
+        let token_id = B256::from([0x60; 32]);
+        let quantity = U256::from(100);
 
         contract
-            .sender(alice)
-            ._mint(alice, token_id, U256::from(50))
+            .sender(writer)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Call,
+            )
             .unwrap();
         contract
-            .sender(alice)
-            ._mint(alice, token_id, U256::from(30))
+            .sender(writer)
+            .create_or_update_position(writer, token_id, quantity, quantity)
             .unwrap();
         contract
-            .sender(alice)
-            ._mint(alice, token_id, U256::from(20))
+            .sender(writer)
+            ._mint(writer, token_id, quantity)
             .unwrap();
 
-        let balance = contract.sender(alice).balance_of(alice, token_id);
-        assert_eq!(balance, U256::from(100));
+        // Simulate the writer transferring all option tokens to a buyer
+        contract
+            .sender(writer)
+            ._burn(writer, token_id, quantity)
+            .unwrap();
+        contract
+            .sender(writer)
+            ._mint(buyer, token_id, quantity)
+            .unwrap();
+
+        let result = contract
+            .sender(writer)
+            .close_position(token_id, U256::from(40));
+
+        assert!(matches!(
+            result,
+            Err(OptionsError::InsufficientBalance(InsufficientBalance { available, .. }))
+                if available == U256::ZERO
+        ));
+        let (quantity_written, collateral_locked) =
+            contract.sender(writer).get_position(writer, token_id);
+        assert_eq!(quantity_written, quantity);
+        assert_eq!(collateral_locked, quantity);
     }
 
     #[motsu::test]
-    fn test_mint_then_burn_same_amount_returns_zero(contract: Contract<Options>, alice: Address) {
-        let token_id = B256::from([0x42; 32]);
+    fn test_close_position_without_position_fails(contract: Contract<Options>) {
+        let writer = Address::from([0xAA; 20]);
+        let buyer = Address::from([0xBB; 20]);
+        let token_id = B256::from([0x61; 32]);
         let quantity = U256::from(100);
 
         contract
-            .sender(alice)
-            ._mint(alice, token_id, quantity)
+            .sender(writer)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Call,
+            )
             .unwrap();
         contract
-            .sender(alice)
-            ._burn(alice, token_id, quantity)
+            .sender(writer)
+            .create_or_update_position(writer, token_id, quantity, quantity)
+            .unwrap();
+        contract
+            .sender(writer)
+            ._mint(buyer, token_id, quantity)
             .unwrap();
 
-        let balance = contract.sender(alice).balance_of(alice, token_id);
-        assert_eq!(balance, U256::ZERO);
+        let result = contract.sender(buyer).close_position(token_id, quantity);
 
-        let total_supply = contract.sender(alice).total_supply_of(token_id);
-        assert_eq!(total_supply, U256::ZERO);
+        assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+        assert_eq!(contract.sender(buyer).balance_of(buyer, token_id), quantity);
     }
 
-    #[test]
-    fn test_valid_parameters_pass_validation() {
-        let strike = U256::from(50_000);
-        let expiry = 1_700_000_000u64;
-        let quantity = U256::from(100);
-        let underlying = Token {
-            address: Address::from([0x11; 20]),
-            decimals: 18,
-        };
-        let quote = Token {
-            address: Address::from([0x22; 20]),
-            decimals: 6,
-        };
-        let current_timestamp = 1_600_000_000u64;
-
-        validate_write_params(
-            strike,
-            expiry,
-            quantity,
-            underlying,
-            quote,
-            current_timestamp,
-        )
-        .unwrap();
-    }
+    #[motsu::test]
+    fn test_close_position_zero_quantity_fails(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x62; 32]);
 
-    #[test]
-    fn test_zero_strike_fails() {
-        let strike = U256::ZERO;
-        let expiry = 1_700_000_000u64;
-        let quantity = U256::from(100);
-        let underlying = Token {
-            address: Address::from([0x11; 20]),
-            decimals: 18,
-        };
-        let quote = Token {
-            address: Address::from([0x22; 20]),
-            decimals: 6,
-        };
-        let current_timestamp = 1_600_000_000u64;
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Call,
+            )
+            .unwrap();
 
-        let result = validate_write_params(
-            strike,
-            expiry,
-            quantity,
-            underlying,
-            quote,
-            current_timestamp,
-        );
-        assert!(matches!(result, Err(OptionsError::InvalidStrike(_))));
+        let result = contract.sender(alice).close_position(token_id, U256::ZERO);
+        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
     }
 
-    #[test]
-    fn test_past_expiry_fails() {
-        let strike = U256::from(50_000);
-        let expiry = 1_500_000_000u64; // Past timestamp
-        let quantity = U256::from(100);
-        let underlying = Token {
-            address: Address::from([0x11; 20]),
-            decimals: 18,
-        };
-        let quote = Token {
-            address: Address::from([0x22; 20]),
-            decimals: 6,
-        };
-        let current_timestamp = 1_600_000_000u64;
-
-        let result = validate_write_params(
-            strike,
-            expiry,
-            quantity,
-            underlying,
-            quote,
-            current_timestamp,
-        );
-        assert!(matches!(result, Err(OptionsError::ExpiredOption(_))));
+    #[motsu::test]
+    fn test_close_position_option_not_found(contract: Contract<Options>, alice: Address) {
+        let result = contract
+            .sender(alice)
+            .close_position(B256::from([0x63; 32]), U256::from(1));
+        assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
     }
 
-    #[test]
-    fn test_expiry_equals_current_timestamp_fails() {
-        let strike = U256::from(50_000);
-        let expiry = 1_600_000_000u64;
+    #[motsu::test]
+    fn test_roll_position_without_position_fails(contract: Contract<Options>) {
+        let writer = Address::from([0xAA; 20]);
+        let buyer = Address::from([0xBB; 20]);
+        let token_id = B256::from([0x64; 32]);
         let quantity = U256::from(100);
-        let underlying = Token {
-            address: Address::from([0x11; 20]),
-            decimals: 18,
-        };
-        let quote = Token {
-            address: Address::from([0x22; 20]),
-            decimals: 6,
-        };
-        let current_timestamp = 1_600_000_000u64; // Same as expiry
-
-        let result = validate_write_params(
-            strike,
-            expiry,
-            quantity,
-            underlying,
-            quote,
-            current_timestamp,
-        );
-        assert!(matches!(result, Err(OptionsError::ExpiredOption(_))));
-    }
 
-    #[test]
-    fn test_zero_quantity_fails() {
-        let strike = U256::from(50_000);
-        let expiry = 1_700_000_000u64;
-        let quantity = U256::ZERO;
-        let underlying = Token {
-            address: Address::from([0x11; 20]),
-            decimals: 18,
-        };
-        let quote = Token {
-            address: Address::from([0x22; 20]),
-            decimals: 6,
-        };
-        let current_timestamp = 1_600_000_000u64;
+        contract
+            .sender(writer)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 18,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Call,
+            )
+            .unwrap();
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, quantity, quantity)
+            .unwrap();
+        contract
+            .sender(writer)
+            ._mint(buyer, token_id, quantity)
+            .unwrap();
 
-        let result = validate_write_params(
-            strike,
-            expiry,
+        let result = contract.sender(buyer).roll_position(
+            token_id,
+            1_880_000_000u64,
+            U256::from(60_000),
             quantity,
-            underlying,
-            quote,
-            current_timestamp,
         );
-        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
-    }
 
-    #[test]
-    fn test_same_underlying_and_quote_fails() {
-        let strike = U256::from(50_000);
-        let expiry = 1_700_000_000u64;
-        let quantity = U256::from(100);
-        let same_address = Address::from([0x11; 20]);
-        let underlying = Token {
-            address: same_address,
-            decimals: 18,
-        };
-        let quote = Token {
-            address: same_address,
-            decimals: 6,
-        };
-        let current_timestamp = 1_600_000_000u64;
-
-        let result = validate_write_params(
-            strike,
-            expiry,
-            quantity,
-            underlying,
-            quote,
-            current_timestamp,
-        );
-        assert!(matches!(result, Err(OptionsError::SameToken(_))));
+        assert!(matches!(
+            result,
+            Err(OptionsError::InsufficientBalance(InsufficientBalance { available, .. }))
+                if available == U256::ZERO
+        ));
+        assert_eq!(contract.sender(buyer).balance_of(buyer, token_id), quantity);
     }
 
-    #[test]
-    fn test_minimum_valid_expiry_passes() {
-        let strike = U256::from(50_000);
-        let current_timestamp = 1_600_000_000u64;
-        let expiry = current_timestamp + 1; // Minimum valid expiry
-        let quantity = U256::from(100);
-        let underlying = Token {
-            address: Address::from([0x11; 20]),
-            decimals: 18,
-        };
-        let quote = Token {
-            address: Address::from([0x22; 20]),
-            decimals: 6,
-        };
-
-        validate_write_params(
-            strike,
-            expiry,
-            quantity,
-            underlying,
-            quote,
-            current_timestamp,
-        )
-        .unwrap();
-    }
+    #[motsu::test]
+    fn test_roll_position_rejects_puts(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x65; 32]);
 
-    // Fee-on-Transfer Detection Tests
-    #[test]
-    fn test_transfer_from_mock_erc20_succeeds() {
-        let mut token = MockERC20::default();
-        let from = Address::from([0x01; 20]);
-        let to = Address::from([0x02; 20]);
-        let amount = U256::from(1000);
+        contract
+            .sender(alice)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_870_000_000u64,
+                OptionType::Put,
+            )
+            .unwrap();
 
-        token.mint(from, U256::from(10000));
-        token.approve(from, from, U256::from(10000));
+        let result = contract.sender(alice).roll_position(
+            token_id,
+            1_880_000_000u64,
+            U256::from(60_000),
+            U256::from(1),
+        );
+        assert!(matches!(result, Err(OptionsError::WrongOptionType(_))));
+    }
 
-        let balance_before = token.balance_of(to);
-        let success = token.transfer_from(from, from, to, amount);
-        let balance_after = token.balance_of(to);
+    // Pause Tests
+    #[motsu::test]
+    fn test_initialize_sets_owner_once(contract: Contract<Options>, alice: Address) {
+        let owner = Address::from([0x0A; 20]);
 
-        assert!(success);
-        let received = balance_after.checked_sub(balance_before).unwrap();
-        assert_eq!(received, amount);
+        contract.sender(alice).constructor();
+        contract.sender(alice).initialize(owner).unwrap();
+        assert_eq!(contract.sender(alice).owner(), owner);
+
+        let result = contract.sender(alice).initialize(alice);
+        assert!(matches!(result, Err(OptionsError::AlreadyInitialized(_))));
+        assert_eq!(contract.sender(alice).owner(), owner);
     }
 
-    #[test]
-    fn test_fee_on_transfer_error_contains_correct_amounts() {
-        let expected = U256::from(1000);
-        let received = U256::from(990); // 1% fee deducted
+    #[motsu::test]
+    fn test_non_deployer_cannot_initialize(contract: Contract<Options>, alice: Address) {
+        let deployer = Address::from([0x0A; 20]);
+        contract.sender(deployer).constructor();
 
-        let error =
-            OptionsError::FeeOnTransferDetected(FeeOnTransferDetected { expected, received });
+        let result = contract.sender(alice).initialize(alice);
+        assert!(matches!(
+            result,
+            Err(OptionsError::Unauthorized(ref e)) if e.caller == alice
+        ));
+        assert_eq!(contract.sender(alice).owner(), Address::ZERO);
 
-        match error {
-            OptionsError::FeeOnTransferDetected(e) => {
-                assert_eq!(e.expected, expected);
-                assert_eq!(e.received, received);
-            }
-            _ => panic!("Expected FeeOnTransferDetected error"),
-        }
+        contract.sender(deployer).initialize(deployer).unwrap();
+        assert_eq!(contract.sender(alice).owner(), deployer);
     }
 
-    #[test]
-    fn test_multiple_safe_transfers_all_succeed() {
-        let mut token = MockERC20::default();
-        let from = Address::from([0x01; 20]);
-        let to1 = Address::from([0x02; 20]);
-        let to2 = Address::from([0x03; 20]);
-        let to3 = Address::from([0x04; 20]);
-        let amount = U256::from(100);
+    #[motsu::test]
+    fn test_non_owner_cannot_pause_or_unpause(contract: Contract<Options>, alice: Address) {
+        let owner = Address::from([0x0A; 20]);
+        contract.sender(owner).constructor();
+        contract.sender(owner).initialize(owner).unwrap();
 
-        token.mint(from, U256::from(10000));
-        token.approve(from, from, U256::from(10000));
+        let result = contract.sender(alice).pause();
+        assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+        assert!(!contract.sender(alice).is_paused());
 
-        let balance_before = token.balance_of(to1);
-        let success = token.transfer_from(from, from, to1, amount);
-        let balance_after = token.balance_of(to1);
-        assert!(success);
-        assert_eq!(balance_after.checked_sub(balance_before).unwrap(), amount);
+        contract.sender(owner).pause().unwrap();
 
-        let balance_before = token.balance_of(to2);
-        let success = token.transfer_from(from, from, to2, amount);
-        let balance_after = token.balance_of(to2);
-        assert!(success);
-        assert_eq!(balance_after.checked_sub(balance_before).unwrap(), amount);
+        let result = contract.sender(alice).unpause();
+        assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+        assert!(contract.sender(alice).is_paused());
+    }
 
-        let balance_before = token.balance_of(to3);
-        let success = token.transfer_from(from, from, to3, amount);
-        let balance_after = token.balance_of(to3);
-        assert!(success);
-        assert_eq!(balance_after.checked_sub(balance_before).unwrap(), amount);
+    #[motsu::test]
+    fn test_pause_without_owner_fails(contract: Contract<Options>, alice: Address) {
+        let result = contract.sender(alice).pause();
+        assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
     }
 
-    // Option Metadata Storage Tests
     #[motsu::test]
-    fn test_store_and_retrieve_metadata(contract: Contract<Options>) {
-        let token_id = B256::from([0x42; 32]);
+    fn test_paused_blocks_write_and_exercise(contract: Contract<Options>) {
+        let owner = Address::from([0x0A; 20]);
+        let writer = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x4B; 32]);
         let underlying = Token {
             address: Address::from([0x11; 20]),
             decimals: 8,
@@ -1655,842 +11274,2055 @@ mod tests {
             address: Address::from([0x22; 20]),
             decimals: 6,
         };
-        let strike = U256::from(50_000);
-        let expiry = 1_700_000_000u64;
-        let option_type = OptionType::Call;
 
-        contract.sender(Address::ZERO).store_option_metadata(
-            token_id,
+        contract.sender(owner).constructor();
+        contract.sender(owner).initialize(owner).unwrap();
+        contract.sender(owner).pause().unwrap();
+
+        let result = contract.sender(writer).write_call_option(
+            U256::from(50_000),
+            1_870_000_000u64,
+            U256::from(100),
             underlying,
             quote,
-            strike,
-            expiry,
-            option_type,
         );
+        assert!(matches!(result, Err(OptionsError::Paused(_))));
 
-        let metadata = contract.sender(Address::ZERO).get_option_metadata(token_id);
+        let result = contract.sender(writer).write_cash_settled_call_option(
+            U256::from(50_000),
+            1_870_000_000u64,
+            U256::from(100),
+            underlying,
+            quote,
+            Address::from([0x33; 20]),
+        );
+        assert!(matches!(result, Err(OptionsError::Paused(_))));
 
-        assert_eq!(metadata.underlying, underlying.address);
-        assert_eq!(metadata.quote, quote.address);
-        assert_eq!(metadata.underlying_decimals, underlying.decimals);
-        assert_eq!(metadata.quote_decimals, quote.decimals);
-        assert_eq!(metadata.strike, strike);
-        assert_eq!(metadata.expiry, U256::from(expiry));
-        assert_eq!(metadata.option_type, option_type.to_u8());
+        let result = contract
+            .sender(writer)
+            .exercise_call(token_id, U256::from(100));
+        assert!(matches!(result, Err(OptionsError::Paused(_))));
+
+        let result = contract
+            .sender(writer)
+            .exercise_call_cash(token_id, U256::from(100));
+        assert!(matches!(result, Err(OptionsError::Paused(_))));
+
+        contract.sender(owner).unpause().unwrap();
+
+        let result = contract
+            .sender(writer)
+            .exercise_call(token_id, U256::from(100));
+        assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
     }
 
     #[motsu::test]
-    fn test_metadata_fields_match_input_parameters(contract: Contract<Options>) {
-        let token_id = B256::from([0x99; 32]);
+    fn test_paused_does_not_block_withdrawal(contract: Contract<Options>, alice: Address) {
+        let owner = Address::from([0x0A; 20]);
+        contract.sender(owner).constructor();
+        contract.sender(owner).initialize(owner).unwrap();
+        contract.sender(owner).pause().unwrap();
+
+        let result = contract
+            .sender(alice)
+            .withdraw_expired_collateral(B256::ZERO, U256::from(10));
+        assert!(!matches!(result, Err(OptionsError::Paused(_))));
+    }
+
+    // Reentrancy Guard Tests
+    #[motsu::test]
+    fn test_locked_contract_rejects_reentrant_calls(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x4C; 32]);
         let underlying = Token {
-            address: Address::from([0xAA; 20]),
-            decimals: 18,
+            address: Address::from([0x11; 20]),
+            decimals: 8,
         };
         let quote = Token {
-            address: Address::from([0xBB; 20]),
+            address: Address::from([0x22; 20]),
             decimals: 6,
         };
-        let strike = U256::from(100_000);
-        let expiry = 1_800_000_000u64;
-        let option_type = OptionType::Put;
 
-        contract.sender(Address::ZERO).store_option_metadata(
-            token_id,
+        contract.sender(alice).locked.set(true);
+
+        let result = contract.sender(alice).write_call_option(
+            U256::from(50_000),
+            1_870_000_000u64,
+            U256::from(100),
             underlying,
             quote,
-            strike,
-            expiry,
-            option_type,
         );
+        assert!(matches!(result, Err(OptionsError::Reentrancy(_))));
 
-        let metadata = contract.sender(Address::ZERO).get_option_metadata(token_id);
+        let result = contract
+            .sender(alice)
+            .exercise_call(token_id, U256::from(100));
+        assert!(matches!(result, Err(OptionsError::Reentrancy(_))));
 
-        assert_eq!(metadata.underlying, underlying.address);
-        assert_eq!(metadata.quote, quote.address);
-        assert_eq!(metadata.underlying_decimals, 18);
-        assert_eq!(metadata.quote_decimals, 6);
-        assert_eq!(metadata.strike, U256::from(100_000));
-        assert_eq!(metadata.expiry, U256::from(1_800_000_000u64));
-        assert_eq!(metadata.option_type, 1); // Put = 1
+        let result = contract
+            .sender(alice)
+            .exercise_call_cash(token_id, U256::from(100));
+        assert!(matches!(result, Err(OptionsError::Reentrancy(_))));
+
+        let result = contract
+            .sender(alice)
+            .close_position(token_id, U256::from(100));
+        assert!(matches!(result, Err(OptionsError::Reentrancy(_))));
     }
 
     #[motsu::test]
-    fn test_same_token_id_retrieves_same_metadata(contract: Contract<Options>) {
-        let token_id = B256::from([0x77; 32]);
-        let underlying = Token {
-            address: Address::from([0x33; 20]),
-            decimals: 8,
-        };
-        let quote = Token {
-            address: Address::from([0x44; 20]),
-            decimals: 6,
-        };
+    fn test_lock_released_after_failed_call(contract: Contract<Options>, alice: Address) {
+        let token_id = B256::from([0x4D; 32]);
+
+        let result = contract
+            .sender(alice)
+            .exercise_call(token_id, U256::from(100));
+        assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+        assert!(!contract.sender(alice).locked.get());
+
+        let result = contract
+            .sender(alice)
+            .exercise_call(token_id, U256::from(100));
+        assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+    }
+
+    // Assignment Claim Tests
+    /// Stores an expired call series quoted in `quote` and credits `amount` of strike
+    /// payments to `writer`, with the quote held and locked by the contract.
+    fn setup_expired_assignment(
+        contract: &Contract<Options>,
+        quote: &Contract<StandardERC20>,
+        writer: Address,
+        token_id: B256,
+        amount: U256,
+    ) {
+        contract
+            .sender(writer)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: quote.address(),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                1_000_000_000u64,
+                OptionType::Call,
+            )
+            .unwrap();
+        contract
+            .sender(writer)
+            .claimable_assignments
+            .insert(Options::position_key(writer, token_id), amount);
+        contract
+            .sender(writer)
+            .lock_collateral(quote.address(), amount)
+            .unwrap();
+        quote.sender(writer).mint(contract.address(), amount);
+    }
+
+    #[motsu::test]
+    fn test_claim_assignment_after_expiry_pays_writer(
+        contract: Contract<Options>,
+        quote: Contract<StandardERC20>,
+    ) {
+        let writer = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x62; 32]);
+        let amount = U256::from(6_000_000_000u64);
+        setup_expired_assignment(&contract, &quote, writer, token_id, amount);
+
+        assert_eq!(
+            contract.sender(writer).claim_assignment(token_id).unwrap(),
+            amount
+        );
+
+        assert_eq!(quote.sender(writer).balance_of(writer), amount);
+        assert_eq!(
+            contract
+                .sender(writer)
+                .claimable_assignment(writer, token_id),
+            U256::ZERO
+        );
+        assert_eq!(
+            contract.sender(writer).locked_collateral(quote.address()),
+            U256::ZERO
+        );
+        contract.assert_emitted(&AssignmentClaimed {
+            writer,
+            tokenId: token_id,
+            amount,
+        });
+
+        let result = contract.sender(writer).claim_assignment(token_id);
+        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
+    }
+
+    #[motsu::test]
+    fn test_claim_assignment_is_per_writer(
+        contract: Contract<Options>,
+        quote: Contract<StandardERC20>,
+    ) {
+        let writer = Address::from([0xAA; 20]);
+        let other = Address::from([0xBB; 20]);
+        let token_id = B256::from([0x63; 32]);
+        setup_expired_assignment(&contract, &quote, writer, token_id, U256::from(100));
+
+        let result = contract.sender(other).claim_assignment(token_id);
+
+        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
+        assert_eq!(
+            contract
+                .sender(writer)
+                .claimable_assignment(writer, token_id),
+            U256::from(100)
+        );
+    }
+
+    #[motsu::test]
+    fn test_claim_assignment_option_not_found(contract: Contract<Options>, alice: Address) {
+        let result = contract
+            .sender(alice)
+            .claim_assignment(B256::from([0x64; 32]));
+
+        assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+    }
+
+    // Series Status Tests
+    #[motsu::test]
+    fn test_series_status_not_written(contract: Contract<Options>, alice: Address) {
+        let status = contract
+            .sender(alice)
+            .series_status_at(B256::from([0x70; 32]), 1_800_000_000u64);
+        assert_eq!(status, SeriesStatus::NotWritten);
+        assert_eq!(
+            contract.sender(alice).series_status(B256::from([0x70; 32])),
+            0
+        );
+    }
+
+    #[motsu::test]
+    fn test_series_status_through_lifecycle(contract: Contract<Options>) {
+        let writer = Address::from([0xAA; 20]);
+        let token_id = B256::from([0x71; 32]);
+        let expiry = 1_870_000_000u64;
+        let quantity = U256::from(100);
+
+        contract
+            .sender(writer)
+            .store_option_metadata(
+                token_id,
+                Token {
+                    address: Address::from([0x11; 20]),
+                    decimals: 8,
+                },
+                Token {
+                    address: Address::from([0x22; 20]),
+                    decimals: 6,
+                },
+                U256::from(50_000),
+                expiry,
+                OptionType::Call,
+            )
+            .unwrap();
+        contract
+            .sender(writer)
+            .create_or_update_position(writer, token_id, quantity, quantity)
+            .unwrap();
+        contract
+            .sender(writer)
+            ._mint(writer, token_id, quantity)
+            .unwrap();
+
+        let before_expiry = 1_800_000_000u64;
+        assert_eq!(
+            contract
+                .sender(writer)
+                .series_status_at(token_id, before_expiry),
+            SeriesStatus::Active
+        );
+
+        // Partial exercise
+        contract
+            .sender(writer)
+            ._burn(writer, token_id, U256::from(40))
+            .unwrap();
+        contract
+            .sender(writer)
+            .reduce_position(writer, token_id, U256::from(40), false)
+            .unwrap();
+        assert_eq!(
+            contract
+                .sender(writer)
+                .series_status_at(token_id, before_expiry),
+            SeriesStatus::Active
+        );
+
+        // Expiry with open interest remaining
+        assert_eq!(
+            contract
+                .sender(writer)
+                .series_status_at(token_id, expiry - 1),
+            SeriesStatus::Active
+        );
+        assert_eq!(
+            contract.sender(writer).series_status_at(token_id, expiry),
+            SeriesStatus::Expired
+        );
+        assert_eq!(
+            contract
+                .sender(writer)
+                .series_status_at(token_id, expiry + 1),
+            SeriesStatus::Expired
+        );
+
+        // Full settlement of the remaining options
+        contract
+            .sender(writer)
+            ._burn(writer, token_id, U256::from(60))
+            .unwrap();
+        contract
+            .sender(writer)
+            .reduce_position(writer, token_id, U256::from(60), false)
+            .unwrap();
+        assert_eq!(
+            contract
+                .sender(writer)
+                .series_status_at(token_id, expiry + 1),
+            SeriesStatus::Settled
+        );
+        assert_eq!(
+            contract.sender(writer).get_position(writer, token_id),
+            (U256::ZERO, U256::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_series_status_to_u8() {
+        assert_eq!(SeriesStatus::NotWritten.to_u8(), 0);
+        assert_eq!(SeriesStatus::Active.to_u8(), 1);
+        assert_eq!(SeriesStatus::Expired.to_u8(), 2);
+        assert_eq!(SeriesStatus::Settled.to_u8(), 3);
+    }
+
+    #[test]
+    fn test_generate_cash_call_token_id_is_distinct() {
+        let underlying = Address::from([0x11; 20]);
+        let quote = Address::from([0x22; 20]);
+        let oracle = Address::from([0x33; 20]);
         let strike = U256::from(60_000);
-        let expiry = 1_750_000_000u64;
-        let option_type = OptionType::Call;
+        let cap = U256::from(20_000);
+        let expiry = 1_870_000_000u64;
 
-        contract.sender(Address::ZERO).store_option_metadata(
-            token_id,
-            underlying,
-            quote,
-            strike,
-            expiry,
-            option_type,
+        let cash_call = generate_cash_call_token_id(underlying, quote, strike, expiry, cap, oracle);
+
+        assert_ne!(
+            cash_call,
+            generate_token_id(underlying, quote, strike, expiry, OptionType::Call)
         );
+        assert_ne!(
+            cash_call,
+            generate_cash_settled_token_id(
+                underlying,
+                quote,
+                strike,
+                expiry,
+                OptionType::Call,
+                oracle
+            )
+        );
+        assert_ne!(
+            cash_call,
+            generate_cash_call_token_id(
+                underlying,
+                quote,
+                strike,
+                expiry,
+                cap + U256::from(1),
+                oracle
+            )
+        );
+    }
 
-        let metadata1 = contract.sender(Address::ZERO).get_option_metadata(token_id);
+    const CASH_CALL_WRITER: Address = Address::new([0xC1; 20]);
+    const CASH_CALL_HOLDER: Address = Address::new([0xC2; 20]);
+
+    /// Writes one WBTC-style (8 decimals) cash call at strike 60k capped at 20k against a
+    /// 6-decimals quote, moves it to `CASH_CALL_HOLDER` and expires it unsettled.
+    fn setup_expired_cash_call(
+        contract: &Contract<Options>,
+        underlying: &Contract<StandardERC20>,
+        quote: &Contract<StandardERC20>,
+        oracle: &Contract<MockOracle>,
+    ) -> B256 {
+        let one = U256::from(10).pow(U256::from(18));
+        let writer = CASH_CALL_WRITER;
+        underlying.sender(writer).set_decimals(8);
+        quote.sender(writer).set_decimals(6);
+
+        let collateral = U256::from(20_000_000_000u64);
+        quote.sender(writer).mint(writer, collateral);
+        quote.sender(writer).approve(contract.address(), collateral);
+
+        let token_id = contract
+            .sender(writer)
+            .write_cash_call_option(
+                U256::from(60_000) * one,
+                U256::from(20_000) * one,
+                1_870_000_000u64,
+                U256::from(100_000_000),
+                Token {
+                    address: underlying.address(),
+                    decimals: 8,
+                },
+                Token {
+                    address: quote.address(),
+                    decimals: 6,
+                },
+                oracle.address(),
+            )
+            .unwrap();
+        assert_eq!(
+            quote.sender(writer).balance_of(contract.address()),
+            collateral
+        );
 
-        let metadata2 = contract.sender(Address::ZERO).get_option_metadata(token_id);
+        contract
+            .sender(writer)
+            ._burn(writer, token_id, one)
+            .unwrap();
+        contract
+            .sender(writer)
+            ._mint(CASH_CALL_HOLDER, token_id, one)
+            .unwrap();
 
-        assert_eq!(metadata1.underlying, metadata2.underlying);
-        assert_eq!(metadata1.quote, metadata2.quote);
-        assert_eq!(metadata1.strike, metadata2.strike);
-        assert_eq!(metadata1.expiry, metadata2.expiry);
-        assert_eq!(metadata1.option_type, metadata2.option_type);
-    }
+        // Time cannot be advanced in tests, so move expiry into the past instead
+        {
+            let mut options = contract.sender(writer);
+            let mut metadata = options.option_metadata.setter(token_id);
+            metadata.expiry.set(U256::from(1_000_000_000u64));
+        }
 
-    #[motsu::test]
-    fn test_different_token_ids_have_independent_metadata(contract: Contract<Options>) {
-        let token_id_1 = B256::from([0x11; 32]);
-        let token_id_2 = B256::from([0x22; 32]);
+        token_id
+    }
 
-        let underlying_1 = Token {
-            address: Address::from([0xAA; 20]),
-            decimals: 8,
-        };
-        let quote_1 = Token {
-            address: Address::from([0xBB; 20]),
-            decimals: 6,
-        };
-        let strike_1 = U256::from(50_000);
-        let expiry_1 = 1_700_000_000u64;
+    /// Like `setup_expired_cash_call`, then settles the series at `price`.
+    fn setup_settled_cash_call(
+        contract: &Contract<Options>,
+        underlying: &Contract<StandardERC20>,
+        quote: &Contract<StandardERC20>,
+        oracle: &Contract<MockOracle>,
+        price: u64,
+    ) -> B256 {
+        let token_id = setup_expired_cash_call(contract, underlying, quote, oracle);
+        let one = U256::from(10).pow(U256::from(18));
+        oracle
+            .sender(CASH_CALL_WRITER)
+            .set_price(U256::from(price) * one);
+        contract
+            .sender(CASH_CALL_WRITER)
+            .settle_series(token_id)
+            .unwrap();
 
-        let underlying_2 = Token {
-            address: Address::from([0xCC; 20]),
-            decimals: 18,
-        };
-        let quote_2 = Token {
-            address: Address::from([0xDD; 20]),
-            decimals: 6,
-        };
-        let strike_2 = U256::from(100_000);
-        let expiry_2 = 1_800_000_000u64;
+        token_id
+    }
 
-        contract.sender(Address::ZERO).store_option_metadata(
-            token_id_1,
-            underlying_1,
-            quote_1,
-            strike_1,
-            expiry_1,
-            OptionType::Call,
+    #[motsu::test]
+    fn test_expired_cash_call_cannot_be_written_again(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_expired_cash_call(&contract, &underlying, &quote, &oracle);
+        let one = U256::from(10).pow(U256::from(18));
+        let collateral = U256::from(20_000_000_000u64);
+        quote
+            .sender(CASH_CALL_WRITER)
+            .mint(CASH_CALL_WRITER, collateral);
+        quote
+            .sender(CASH_CALL_WRITER)
+            .approve(contract.address(), collateral);
+
+        let result = contract.sender(CASH_CALL_WRITER).write_cash_call_option(
+            U256::from(60_000) * one,
+            U256::from(20_000) * one,
+            1_870_000_000u64,
+            U256::from(100_000_000),
+            Token {
+                address: underlying.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote.address(),
+                decimals: 6,
+            },
+            oracle.address(),
         );
 
-        contract.sender(Address::ZERO).store_option_metadata(
-            token_id_2,
-            underlying_2,
-            quote_2,
-            strike_2,
-            expiry_2,
-            OptionType::Put,
+        assert!(matches!(result, Err(OptionsError::ExpiredOption(_))));
+        assert_eq!(
+            contract.sender(CASH_CALL_WRITER).total_supply(token_id),
+            one
         );
+        assert_eq!(
+            quote.sender(CASH_CALL_WRITER).balance_of(CASH_CALL_WRITER),
+            collateral
+        );
+    }
 
-        let metadata_1 = contract
-            .sender(Address::ZERO)
-            .get_option_metadata(token_id_1);
-
-        let metadata_2 = contract
-            .sender(Address::ZERO)
-            .get_option_metadata(token_id_2);
-
-        // Verify metadata_1
-        assert_eq!(metadata_1.underlying, underlying_1.address);
-        assert_eq!(metadata_1.strike, strike_1);
-        assert_eq!(metadata_1.option_type, 0); // Call
+    #[motsu::test]
+    fn test_settled_cash_call_in_the_money_pays_intrinsic_value(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_settled_cash_call(&contract, &underlying, &quote, &oracle, 70_000);
 
-        // Verify metadata_2
-        assert_eq!(metadata_2.underlying, underlying_2.address);
-        assert_eq!(metadata_2.strike, strike_2);
-        assert_eq!(metadata_2.option_type, 1); // Put
+        contract
+            .sender(CASH_CALL_HOLDER)
+            .exercise_settled_call(token_id, U256::from(100_000_000))
+            .unwrap();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .withdraw_settled_collateral(token_id)
+            .unwrap();
 
-        // Verify they're different
-        assert_ne!(metadata_1.underlying, metadata_2.underlying);
-        assert_ne!(metadata_1.strike, metadata_2.strike);
+        // (70k - 60k) to the holder, the other 10k of the 20k cap back to the writer
+        let ten_thousand_usdc = U256::from(10_000_000_000u64);
+        assert_eq!(
+            quote.sender(CASH_CALL_HOLDER).balance_of(CASH_CALL_HOLDER),
+            ten_thousand_usdc
+        );
+        assert_eq!(
+            quote.sender(CASH_CALL_WRITER).balance_of(CASH_CALL_WRITER),
+            ten_thousand_usdc
+        );
+        assert_eq!(
+            contract
+                .sender(CASH_CALL_WRITER)
+                .locked_collateral(quote.address()),
+            U256::ZERO
+        );
+        assert_eq!(
+            contract.sender(CASH_CALL_WRITER).total_supply(token_id),
+            U256::ZERO
+        );
     }
 
-    // Writer Position Tracking Tests
     #[motsu::test]
-    fn test_create_new_position_stores_quantity_and_collateral(contract: Contract<Options>) {
-        let writer = Address::from([0xAA; 20]);
-        let token_id = B256::from([0x01; 32]);
-        let quantity = U256::from(100);
-        let collateral = U256::from(200);
+    fn test_settled_cash_call_above_cap_pays_cap(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_settled_cash_call(&contract, &underlying, &quote, &oracle, 100_000);
 
         contract
-            .sender(writer)
-            .create_or_update_position(writer, token_id, quantity, collateral)
+            .sender(CASH_CALL_HOLDER)
+            .exercise_settled_call(token_id, U256::from(100_000_000))
+            .unwrap();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .withdraw_settled_collateral(token_id)
             .unwrap();
 
-        let (stored_quantity, stored_collateral) =
-            contract.sender(writer).get_position(writer, token_id);
-
-        assert_eq!(stored_quantity, quantity);
-        assert_eq!(stored_collateral, collateral);
+        assert_eq!(
+            quote.sender(CASH_CALL_HOLDER).balance_of(CASH_CALL_HOLDER),
+            U256::from(20_000_000_000u64)
+        );
+        assert_eq!(
+            quote.sender(CASH_CALL_WRITER).balance_of(CASH_CALL_WRITER),
+            U256::ZERO
+        );
     }
 
     #[motsu::test]
-    fn test_increase_existing_position_accumulates_correctly(contract: Contract<Options>) {
-        let writer = Address::from([0xBB; 20]);
-        let token_id = B256::from([0x02; 32]);
-        let initial_quantity = U256::from(50);
-        let initial_collateral = U256::from(100);
-        let additional_quantity = U256::from(30);
-        let additional_collateral = U256::from(60);
+    fn test_settled_cash_call_out_of_the_money_returns_collateral(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_settled_cash_call(&contract, &underlying, &quote, &oracle, 50_000);
+
+        let result = contract
+            .sender(CASH_CALL_HOLDER)
+            .exercise_settled_call(token_id, U256::from(100_000_000));
+        assert!(matches!(result, Err(OptionsError::NotInTheMoney(_))));
 
         contract
-            .sender(writer)
-            .create_or_update_position(writer, token_id, initial_quantity, initial_collateral)
+            .sender(CASH_CALL_WRITER)
+            .withdraw_settled_collateral(token_id)
             .unwrap();
+        assert_eq!(
+            quote.sender(CASH_CALL_WRITER).balance_of(CASH_CALL_WRITER),
+            U256::from(20_000_000_000u64)
+        );
+    }
+
+    #[motsu::test]
+    fn test_settled_cash_call_at_the_money_returns_collateral(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_settled_cash_call(&contract, &underlying, &quote, &oracle, 60_000);
+
+        let result = contract
+            .sender(CASH_CALL_HOLDER)
+            .exercise_settled_call(token_id, U256::from(100_000_000));
+        assert!(matches!(result, Err(OptionsError::NotInTheMoney(_))));
 
         contract
-            .sender(writer)
-            .create_or_update_position(writer, token_id, additional_quantity, additional_collateral)
+            .sender(CASH_CALL_WRITER)
+            .withdraw_settled_collateral(token_id)
             .unwrap();
+        assert_eq!(
+            quote.sender(CASH_CALL_WRITER).balance_of(CASH_CALL_WRITER),
+            U256::from(20_000_000_000u64)
+        );
+        assert_eq!(
+            contract
+                .sender(CASH_CALL_WRITER)
+                .get_position(CASH_CALL_WRITER, token_id),
+            (U256::ZERO, U256::ZERO)
+        );
+    }
 
-        let (final_quantity, final_collateral) =
-            contract.sender(writer).get_position(writer, token_id);
+    #[motsu::test]
+    fn test_settle_series_twice_fails(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let one = U256::from(10).pow(U256::from(18));
+        let token_id = setup_settled_cash_call(&contract, &underlying, &quote, &oracle, 70_000);
+        oracle
+            .sender(CASH_CALL_WRITER)
+            .set_price(U256::from(90_000) * one);
+
+        let result = contract.sender(CASH_CALL_WRITER).settle_series(token_id);
+
+        assert!(matches!(result, Err(OptionsError::AlreadySettled(_))));
+        assert_eq!(
+            contract.sender(CASH_CALL_WRITER).settlement_price(token_id),
+            U256::from(70_000) * one
+        );
+    }
 
-        assert_eq!(final_quantity, U256::from(80));
-        assert_eq!(final_collateral, U256::from(160));
+    #[motsu::test]
+    fn test_settle_series_normalizes_oracle_decimals(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_expired_cash_call(&contract, &underlying, &quote, &oracle);
+        // Chainlink-style answer with 8 decimals
+        oracle.sender(CASH_CALL_WRITER).set_decimals(8);
+        oracle
+            .sender(CASH_CALL_WRITER)
+            .set_price(U256::from(70_000u64 * 100_000_000));
+
+        let settlement_price = contract
+            .sender(CASH_CALL_WRITER)
+            .settle_series(token_id)
+            .unwrap();
+
+        let expected = U256::from(70_000) * U256::from(10).pow(U256::from(18));
+        assert_eq!(settlement_price, expected);
+        assert_eq!(
+            contract.sender(CASH_CALL_WRITER).settlement_price(token_id),
+            expected
+        );
     }
 
     #[motsu::test]
-    fn test_different_writers_same_token_id_have_independent_positions(
+    fn test_settle_series_rejects_oracle_above_18_decimals(
         contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
     ) {
-        let writer1 = Address::from([0xCC; 20]);
-        let writer2 = Address::from([0xDD; 20]);
-        let token_id = B256::from([0x03; 32]);
-        let quantity1 = U256::from(100);
-        let collateral1 = U256::from(200);
-        let quantity2 = U256::from(150);
-        let collateral2 = U256::from(300);
+        let token_id = setup_expired_cash_call(&contract, &underlying, &quote, &oracle);
+        oracle.sender(CASH_CALL_WRITER).set_decimals(19);
+        oracle.sender(CASH_CALL_WRITER).set_price(U256::from(1));
+
+        let result = contract.sender(CASH_CALL_WRITER).settle_series(token_id);
+
+        assert!(matches!(
+            result,
+            Err(OptionsError::InvalidDecimals(InvalidDecimals {
+                decimals: 19
+            }))
+        ));
+        assert_eq!(
+            contract.sender(CASH_CALL_WRITER).settlement_price(token_id),
+            U256::ZERO
+        );
+    }
 
+    #[motsu::test]
+    fn test_settle_series_rejects_stale_price(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_expired_cash_call(&contract, &underlying, &quote, &oracle);
+        let one = U256::from(10).pow(U256::from(18));
+        contract.sender(CASH_CALL_WRITER).constructor();
         contract
-            .sender(writer1)
-            .create_or_update_position(writer1, token_id, quantity1, collateral1)
+            .sender(CASH_CALL_WRITER)
+            .initialize(CASH_CALL_WRITER)
             .unwrap();
-
         contract
-            .sender(writer2)
-            .create_or_update_position(writer2, token_id, quantity2, collateral2)
+            .sender(CASH_CALL_WRITER)
+            .set_max_settlement_staleness(3_600)
             .unwrap();
+        oracle
+            .sender(CASH_CALL_WRITER)
+            .set_price(U256::from(70_000) * one);
+        oracle
+            .sender(CASH_CALL_WRITER)
+            .set_updated_at(U256::from(1_000_000_000u64));
+
+        let result = contract.sender(CASH_CALL_WRITER).settle_series(token_id);
+
+        assert!(matches!(result, Err(OptionsError::StaleSettlementPrice(_))));
+        assert_eq!(
+            contract.sender(CASH_CALL_WRITER).settlement_price(token_id),
+            U256::ZERO
+        );
+    }
 
-        let (stored_quantity1, stored_collateral1) =
-            contract.sender(writer1).get_position(writer1, token_id);
-        let (stored_quantity2, stored_collateral2) =
-            contract.sender(writer2).get_position(writer2, token_id);
+    const KEEPER: Address = Address::new([0xC3; 20]);
+
+    /// Like `setup_settled_cash_call`, then sets the `auto_exercise` parameters.
+    fn setup_auto_exercise(
+        contract: &Contract<Options>,
+        underlying: &Contract<StandardERC20>,
+        quote: &Contract<StandardERC20>,
+        oracle: &Contract<MockOracle>,
+        keeper_fee_bps: u64,
+        threshold: U256,
+    ) -> B256 {
+        let token_id = setup_settled_cash_call(contract, underlying, quote, oracle, 70_000);
+        contract.sender(CASH_CALL_WRITER).constructor();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .initialize(CASH_CALL_WRITER)
+            .unwrap();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .set_auto_exercise_params(U256::from(keeper_fee_bps), threshold)
+            .unwrap();
 
-        assert_eq!(stored_quantity1, quantity1);
-        assert_eq!(stored_collateral1, collateral1);
-        assert_eq!(stored_quantity2, quantity2);
-        assert_eq!(stored_collateral2, collateral2);
+        token_id
     }
 
-    #[test]
-    fn test_position_key_is_deterministic() {
-        let writer = Address::from([0xEE; 20]);
-        let token_id = B256::from([0x04; 32]);
+    #[motsu::test]
+    fn test_auto_exercise_skips_holders_without_balance(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_auto_exercise(&contract, &underlying, &quote, &oracle, 0, U256::ZERO);
 
-        let key1 = Options::position_key(writer, token_id);
-        let key2 = Options::position_key(writer, token_id);
+        contract
+            .sender(KEEPER)
+            .auto_exercise(
+                token_id,
+                vec![CASH_CALL_WRITER, CASH_CALL_HOLDER, KEEPER, CASH_CALL_HOLDER],
+            )
+            .unwrap();
+        contract
+            .sender(CASH_CALL_HOLDER)
+            .claim_cash(token_id)
+            .unwrap();
 
-        assert_eq!(key1, key2);
+        assert_eq!(
+            quote.sender(CASH_CALL_HOLDER).balance_of(CASH_CALL_HOLDER),
+            U256::from(10_000_000_000u64)
+        );
+        assert_eq!(
+            contract
+                .sender(KEEPER)
+                .balance_of(CASH_CALL_HOLDER, token_id),
+            U256::ZERO
+        );
+        assert_eq!(
+            contract.sender(KEEPER).total_exercised(token_id),
+            U256::from(10).pow(U256::from(18))
+        );
     }
 
-    // Token ID Generation Tests
-    #[test]
-    fn test_generate_token_id_same_parameters_identical() {
-        let underlying = Address::from([0x11; 20]);
-        let quote = Address::from([0x22; 20]);
-        let strike = U256::from(100_000);
-        let expiry = 1_700_000_000u64;
-        let option_type = OptionType::Call;
+    #[motsu::test]
+    fn test_auto_exercise_rejects_oversized_holder_list(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_auto_exercise(&contract, &underlying, &quote, &oracle, 0, U256::ZERO);
 
-        let token_id_1 = generate_token_id(underlying, quote, strike, expiry, option_type);
-        let token_id_2 = generate_token_id(underlying, quote, strike, expiry, option_type);
+        let result = contract
+            .sender(KEEPER)
+            .auto_exercise(token_id, vec![CASH_CALL_HOLDER; MAX_BATCH_SIZE + 1]);
+        assert!(matches!(result, Err(OptionsError::InvalidBatchSize(_))));
 
-        assert_eq!(token_id_1, token_id_2);
+        let result = contract.sender(KEEPER).auto_exercise(token_id, vec![]);
+        assert!(matches!(result, Err(OptionsError::InvalidBatchSize(_))));
     }
 
-    #[test]
-    fn test_generate_token_id_different_strikes() {
-        let underlying = Address::from([0x11; 20]);
-        let quote = Address::from([0x22; 20]);
-        let expiry = 1_700_000_000u64;
-        let option_type = OptionType::Call;
-
-        let token_id_1 =
-            generate_token_id(underlying, quote, U256::from(100_000), expiry, option_type);
-        let token_id_2 =
-            generate_token_id(underlying, quote, U256::from(200_000), expiry, option_type);
+    #[motsu::test]
+    fn test_auto_exercise_requires_settlement_price(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_expired_cash_call(&contract, &underlying, &quote, &oracle);
 
-        assert_ne!(token_id_1, token_id_2);
+        let result = contract
+            .sender(KEEPER)
+            .auto_exercise(token_id, vec![CASH_CALL_HOLDER]);
+
+        assert!(matches!(
+            result,
+            Err(OptionsError::SettlementPriceRequired(_))
+        ));
     }
 
-    #[test]
-    fn test_generate_token_id_different_expiries() {
-        let underlying = Address::from([0x11; 20]);
-        let quote = Address::from([0x22; 20]);
-        let strike = U256::from(100_000);
-        let option_type = OptionType::Call;
+    #[motsu::test]
+    fn test_auto_exercise_threshold_at_intrinsic_value_reverts(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        // Settled at 70k against a 60k strike: 10k intrinsic value per option
+        let intrinsic_value = U256::from(10_000) * U256::from(10).pow(U256::from(18));
+        let token_id =
+            setup_auto_exercise(&contract, &underlying, &quote, &oracle, 0, intrinsic_value);
 
-        let token_id_1 =
-            generate_token_id(underlying, quote, strike, 1_700_000_000u64, option_type);
-        let token_id_2 =
-            generate_token_id(underlying, quote, strike, 1_800_000_000u64, option_type);
+        let result = contract
+            .sender(KEEPER)
+            .auto_exercise(token_id, vec![CASH_CALL_HOLDER]);
 
-        assert_ne!(token_id_1, token_id_2);
+        assert!(matches!(
+            result,
+            Err(OptionsError::BelowAutoExerciseThreshold(_))
+        ));
+        assert_eq!(
+            contract
+                .sender(KEEPER)
+                .balance_of(CASH_CALL_HOLDER, token_id),
+            U256::from(10).pow(U256::from(18))
+        );
     }
 
-    #[test]
-    fn test_generate_token_id_different_option_types() {
-        let underlying = Address::from([0x11; 20]);
-        let quote = Address::from([0x22; 20]);
-        let strike = U256::from(100_000);
-        let expiry = 1_700_000_000u64;
+    #[motsu::test]
+    fn test_auto_exercise_threshold_below_intrinsic_value_exercises(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let intrinsic_value = U256::from(10_000) * U256::from(10).pow(U256::from(18));
+        let token_id = setup_auto_exercise(
+            &contract,
+            &underlying,
+            &quote,
+            &oracle,
+            0,
+            intrinsic_value - U256::from(1),
+        );
 
-        let token_id_call = generate_token_id(underlying, quote, strike, expiry, OptionType::Call);
-        let token_id_put = generate_token_id(underlying, quote, strike, expiry, OptionType::Put);
+        contract
+            .sender(KEEPER)
+            .auto_exercise(token_id, vec![CASH_CALL_HOLDER])
+            .unwrap();
+        contract
+            .sender(CASH_CALL_HOLDER)
+            .claim_cash(token_id)
+            .unwrap();
 
-        assert_ne!(token_id_call, token_id_put);
+        assert_eq!(
+            quote.sender(CASH_CALL_HOLDER).balance_of(CASH_CALL_HOLDER),
+            U256::from(10_000_000_000u64)
+        );
     }
 
-    #[test]
-    fn test_generate_token_id_different_underlying() {
-        let quote = Address::from([0x22; 20]);
-        let strike = U256::from(100_000);
-        let expiry = 1_700_000_000u64;
-        let option_type = OptionType::Call;
+    #[motsu::test]
+    fn test_auto_exercise_pays_keeper_fee(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_auto_exercise(&contract, &underlying, &quote, &oracle, 50, U256::ZERO);
 
-        let token_id_1 = generate_token_id(
-            Address::from([0x11; 20]),
-            quote,
-            strike,
-            expiry,
-            option_type,
+        // Split the holder's option 3:1 with a second holder
+        let one = U256::from(10).pow(U256::from(18));
+        let other_holder = Address::new([0xC4; 20]);
+        let quarter = one / U256::from(4);
+        contract
+            .sender(KEEPER)
+            ._burn(CASH_CALL_HOLDER, token_id, quarter)
+            .unwrap();
+        contract
+            .sender(KEEPER)
+            ._mint(other_holder, token_id, quarter)
+            .unwrap();
+
+        let keeper_fee = contract
+            .sender(KEEPER)
+            .auto_exercise(token_id, vec![CASH_CALL_HOLDER, other_holder])
+            .unwrap();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .withdraw_settled_collateral(token_id)
+            .unwrap();
+        contract
+            .sender(CASH_CALL_HOLDER)
+            .claim_cash(token_id)
+            .unwrap();
+        contract.sender(other_holder).claim_cash(token_id).unwrap();
+
+        // 0.5% of 7,500 and 2,500 USDC
+        assert_eq!(keeper_fee, U256::from(50_000_000));
+        assert_eq!(quote.sender(KEEPER).balance_of(KEEPER), keeper_fee);
+        assert_eq!(
+            quote.sender(CASH_CALL_HOLDER).balance_of(CASH_CALL_HOLDER),
+            U256::from(7_462_500_000u64)
         );
-        let token_id_2 = generate_token_id(
-            Address::from([0x33; 20]),
-            quote,
-            strike,
-            expiry,
-            option_type,
+        assert_eq!(
+            quote.sender(other_holder).balance_of(other_holder),
+            U256::from(2_487_500_000u64)
+        );
+        assert_eq!(
+            quote.sender(CASH_CALL_WRITER).balance_of(CASH_CALL_WRITER),
+            U256::from(10_000_000_000u64)
+        );
+        assert_eq!(
+            quote.sender(KEEPER).balance_of(contract.address()),
+            U256::ZERO
+        );
+        assert_eq!(
+            contract.sender(KEEPER).locked_collateral(quote.address()),
+            U256::ZERO
         );
-
-        assert_ne!(token_id_1, token_id_2);
     }
 
-    #[test]
-    fn test_generate_token_id_different_quote() {
-        let underlying = Address::from([0x11; 20]);
-        let strike = U256::from(100_000);
-        let expiry = 1_700_000_000u64;
-        let option_type = OptionType::Call;
+    #[motsu::test]
+    fn test_auto_exercise_credits_holders_who_claim_independently(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_auto_exercise(&contract, &underlying, &quote, &oracle, 0, U256::ZERO);
+
+        // Split the holder's option between three holders
+        let one = U256::from(10).pow(U256::from(18));
+        let half = one / U256::from(2);
+        let quarter = one / U256::from(4);
+        let second_holder = Address::new([0xC4; 20]);
+        let third_holder = Address::new([0xC5; 20]);
+        contract
+            .sender(KEEPER)
+            ._burn(CASH_CALL_HOLDER, token_id, half)
+            .unwrap();
+        contract
+            .sender(KEEPER)
+            ._mint(second_holder, token_id, quarter)
+            .unwrap();
+        contract
+            .sender(KEEPER)
+            ._mint(third_holder, token_id, quarter)
+            .unwrap();
 
-        let token_id_1 = generate_token_id(
-            underlying,
-            Address::from([0x22; 20]),
-            strike,
-            expiry,
-            option_type,
+        contract
+            .sender(KEEPER)
+            .auto_exercise(
+                token_id,
+                vec![CASH_CALL_HOLDER, second_holder, third_holder],
+            )
+            .unwrap();
+
+        // Nothing is pushed; the payouts stay locked until claimed
+        assert_eq!(
+            quote.sender(CASH_CALL_HOLDER).balance_of(CASH_CALL_HOLDER),
+            U256::ZERO
         );
-        let token_id_2 = generate_token_id(
-            underlying,
-            Address::from([0x33; 20]),
-            strike,
-            expiry,
-            option_type,
+        assert_eq!(
+            contract
+                .sender(KEEPER)
+                .claimable_cash(CASH_CALL_HOLDER, token_id),
+            U256::from(5_000_000_000u64)
+        );
+        assert_eq!(
+            contract
+                .sender(KEEPER)
+                .claimable_cash(second_holder, token_id),
+            U256::from(2_500_000_000u64)
+        );
+        assert_eq!(
+            contract.sender(KEEPER).locked_collateral(quote.address()),
+            U256::from(20_000_000_000u64)
         );
 
-        assert_ne!(token_id_1, token_id_2);
+        let claimed = contract.sender(second_holder).claim_cash(token_id).unwrap();
+        assert_eq!(claimed, U256::from(2_500_000_000u64));
+        contract.assert_emitted(&CashClaimed {
+            holder: second_holder,
+            tokenId: token_id,
+            amount: claimed,
+        });
+        assert_eq!(
+            quote.sender(second_holder).balance_of(second_holder),
+            claimed
+        );
+        assert_eq!(
+            contract
+                .sender(KEEPER)
+                .claimable_cash(second_holder, token_id),
+            U256::ZERO
+        );
+        let result = contract.sender(second_holder).claim_cash(token_id);
+        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
+
+        contract
+            .sender(CASH_CALL_HOLDER)
+            .claim_cash(token_id)
+            .unwrap();
+        contract.sender(third_holder).claim_cash(token_id).unwrap();
+        assert_eq!(
+            quote.sender(CASH_CALL_HOLDER).balance_of(CASH_CALL_HOLDER),
+            U256::from(5_000_000_000u64)
+        );
+        assert_eq!(
+            quote.sender(third_holder).balance_of(third_holder),
+            U256::from(2_500_000_000u64)
+        );
+        assert_eq!(
+            contract.sender(KEEPER).locked_collateral(quote.address()),
+            U256::from(10_000_000_000u64)
+        );
     }
 
     #[motsu::test]
-    fn test_write_call_option_zero_strike_fails(contract: Contract<Options>, alice: Address) {
-        let underlying = Token {
-            address: Address::from([0x11; 20]),
-            decimals: 8,
-        };
-        let quote = Token {
-            address: Address::from([0x22; 20]),
-            decimals: 6,
-        };
-        let strike = U256::ZERO;
-        let expiry = 2_000_000_000u64;
-        let quantity = U256::from(100_000_000);
+    fn test_set_auto_exercise_params_caps_keeper_fee(contract: Contract<Options>) {
+        contract.sender(CASH_CALL_WRITER).constructor();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .initialize(CASH_CALL_WRITER)
+            .unwrap();
 
         let result = contract
-            .sender(alice)
-            .write_call_option(strike, expiry, quantity, underlying, quote);
+            .sender(CASH_CALL_WRITER)
+            .set_auto_exercise_params(U256::from(MAX_KEEPER_FEE_BPS + 1), U256::ZERO);
+        assert!(matches!(result, Err(OptionsError::FeeTooHigh(_))));
 
-        assert!(matches!(result, Err(OptionsError::InvalidStrike(_))));
+        let result = contract
+            .sender(KEEPER)
+            .set_auto_exercise_params(U256::from(MAX_KEEPER_FEE_BPS), U256::ZERO);
+        assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+
+        contract
+            .sender(CASH_CALL_WRITER)
+            .set_auto_exercise_params(U256::from(MAX_KEEPER_FEE_BPS), U256::from(7))
+            .unwrap();
+        assert_eq!(
+            contract.sender(KEEPER).keeper_fee_bps(),
+            U256::from(MAX_KEEPER_FEE_BPS)
+        );
+        assert_eq!(
+            contract.sender(KEEPER).auto_exercise_threshold(),
+            U256::from(7)
+        );
     }
 
+    const TREASURY: Address = Address::new([0xC5; 20]);
+
     #[motsu::test]
-    fn test_write_call_option_expired_option_fails(contract: Contract<Options>, alice: Address) {
-        let underlying = Token {
-            address: Address::from([0x11; 20]),
-            decimals: 8,
-        };
-        let quote = Token {
-            address: Address::from([0x22; 20]),
-            decimals: 6,
-        };
-        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-        let expiry = 1_000_000_000u64;
-        let quantity = U256::from(100_000_000);
+    fn test_set_treasury_policy_validates_cut(contract: Contract<Options>) {
+        contract.sender(CASH_CALL_WRITER).constructor();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .initialize(CASH_CALL_WRITER)
+            .unwrap();
 
         let result = contract
-            .sender(alice)
-            .write_call_option(strike, expiry, quantity, underlying, quote);
+            .sender(CASH_CALL_WRITER)
+            .set_treasury_policy(U256::from(BPS_DENOMINATOR + 1), TREASURY);
+        assert!(matches!(result, Err(OptionsError::FeeTooHigh(_))));
 
-        assert!(matches!(result, Err(OptionsError::ExpiredOption(_))));
-    }
-
-    #[motsu::test]
-    fn test_write_call_option_zero_quantity_fails(contract: Contract<Options>, alice: Address) {
-        let underlying = Token {
-            address: Address::from([0x11; 20]),
-            decimals: 8,
-        };
-        let quote = Token {
-            address: Address::from([0x22; 20]),
-            decimals: 6,
-        };
-        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-        let expiry = 2_000_000_000u64;
-        let quantity = U256::ZERO;
+        let result = contract
+            .sender(CASH_CALL_WRITER)
+            .set_treasury_policy(U256::from(1_000), Address::ZERO);
+        assert!(matches!(result, Err(OptionsError::InvalidRecipient(_))));
 
         let result = contract
-            .sender(alice)
-            .write_call_option(strike, expiry, quantity, underlying, quote);
+            .sender(TREASURY)
+            .set_treasury_policy(U256::from(1_000), TREASURY);
+        assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
 
-        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
+        contract
+            .sender(CASH_CALL_WRITER)
+            .set_treasury_policy(U256::from(1_000), TREASURY)
+            .unwrap();
+        assert_eq!(contract.sender(TREASURY).treasury_bps(), U256::from(1_000));
+        assert_eq!(contract.sender(TREASURY).treasury(), TREASURY);
     }
 
     #[motsu::test]
-    fn test_write_call_option_same_token_fails(contract: Contract<Options>, alice: Address) {
-        let same_address = Address::from([0x11; 20]);
-        let underlying = Token {
-            address: same_address,
-            decimals: 8,
-        };
-        let quote = Token {
-            address: same_address,
-            decimals: 6,
-        };
-        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-        let expiry = 2_000_000_000u64;
-        let quantity = U256::from(100_000_000);
+    fn test_forfeited_value_is_split_between_treasury_and_writer(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_settled_cash_call(&contract, &underlying, &quote, &oracle, 70_000);
+        contract.sender(CASH_CALL_WRITER).constructor();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .initialize(CASH_CALL_WRITER)
+            .unwrap();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .set_treasury_policy(U256::from(2_000), TREASURY)
+            .unwrap();
 
-        let result = contract
-            .sender(alice)
-            .write_call_option(strike, expiry, quantity, underlying, quote);
+        // The holder exercises half and leaves the other half unexercised
+        contract
+            .sender(CASH_CALL_HOLDER)
+            .exercise_settled_call(token_id, U256::from(50_000_000))
+            .unwrap();
 
-        assert!(matches!(result, Err(OptionsError::SameToken(_))));
-    }
+        let treasury_amount = contract
+            .sender(KEEPER)
+            .collect_forfeited_value(token_id)
+            .unwrap();
 
-    #[motsu::test]
-    fn test_write_call_option_invalid_decimals_fails(contract: Contract<Options>, alice: Address) {
-        let underlying = Token {
-            address: Address::from([0x11; 20]),
-            decimals: 24,
-        };
-        let quote = Token {
-            address: Address::from([0x22; 20]),
-            decimals: 6,
-        };
-        let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-        let expiry = 2_000_000_000u64;
-        let quantity = U256::from(100_000_000);
+        // 20% of the 5k intrinsic value of the unexercised half
+        assert_eq!(treasury_amount, U256::from(1_000_000_000u64));
+        contract.assert_emitted(&ForfeitedValueCollected {
+            tokenId: token_id,
+            treasury: TREASURY,
+            quantity: U256::from(5) * U256::from(10).pow(U256::from(17)),
+            treasuryAmount: U256::from(1_000_000_000u64),
+            writersAmount: U256::from(4_000_000_000u64),
+        });
 
         let result = contract
-            .sender(alice)
-            .write_call_option(strike, expiry, quantity, underlying, quote);
-
-        assert!(matches!(result, Err(OptionsError::InvalidDecimals(_))));
-    }
+            .sender(CASH_CALL_HOLDER)
+            .exercise_settled_call(token_id, U256::from(50_000_000));
+        assert!(matches!(result, Err(OptionsError::ExerciseAfterExpiry(_))));
+        let result = contract.sender(KEEPER).collect_forfeited_value(token_id);
+        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
 
-    #[motsu::test]
-    fn test_write_put_option_returns_unimplemented(contract: Contract<Options>, alice: Address) {
-        let underlying = Token {
-            address: Address::ZERO,
-            decimals: 18,
-        };
-        let quote = Token {
-            address: Address::ZERO,
-            decimals: 6,
-        };
+        contract
+            .sender(CASH_CALL_WRITER)
+            .withdraw_settled_collateral(token_id)
+            .unwrap();
 
-        let result = contract.sender(alice).write_put_option(
-            U256::from(1000),
-            1_234_567_890u64,
-            U256::from(100),
-            underlying,
-            quote,
+        // 10k left of the 20k cap plus the other 80% of the forfeited 5k
+        assert_eq!(
+            quote.sender(TREASURY).balance_of(TREASURY),
+            U256::from(1_000_000_000u64)
+        );
+        assert_eq!(
+            quote.sender(CASH_CALL_WRITER).balance_of(CASH_CALL_WRITER),
+            U256::from(14_000_000_000u64)
+        );
+        assert_eq!(
+            quote.sender(CASH_CALL_HOLDER).balance_of(CASH_CALL_HOLDER),
+            U256::from(5_000_000_000u64)
+        );
+        assert_eq!(
+            contract
+                .sender(CASH_CALL_WRITER)
+                .locked_collateral(quote.address()),
+            U256::ZERO
         );
-
-        assert!(matches!(result, Err(OptionsError::Unimplemented(_))));
     }
 
     #[motsu::test]
-    fn test_exercise_put_unimplemented(contract: Contract<Options>, alice: Address) {
-        let result = contract
-            .sender(alice)
-            .exercise_put(B256::ZERO, U256::from(10));
-        assert!(matches!(result, Err(OptionsError::Unimplemented(_))));
+    fn test_forfeiture_requires_treasury_policy(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_settled_cash_call(&contract, &underlying, &quote, &oracle, 70_000);
+
+        let result = contract.sender(KEEPER).collect_forfeited_value(token_id);
+        assert!(matches!(result, Err(OptionsError::NoTreasuryPolicy(_))));
+
+        // Without a policy holders can still exercise long after expiry
+        contract
+            .sender(CASH_CALL_HOLDER)
+            .exercise_settled_call(token_id, U256::from(100_000_000))
+            .unwrap();
+        assert_eq!(
+            quote.sender(CASH_CALL_HOLDER).balance_of(CASH_CALL_HOLDER),
+            U256::from(10_000_000_000u64)
+        );
     }
 
     #[motsu::test]
-    fn test_withdraw_expired_collateral_unimplemented(contract: Contract<Options>, alice: Address) {
-        let result = contract
-            .sender(alice)
-            .withdraw_expired_collateral(B256::ZERO, U256::from(10));
-        assert!(matches!(result, Err(OptionsError::Unimplemented(_))));
+    fn test_forfeiture_of_out_of_the_money_series_fails(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let token_id = setup_settled_cash_call(&contract, &underlying, &quote, &oracle, 50_000);
+        contract.sender(CASH_CALL_WRITER).constructor();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .initialize(CASH_CALL_WRITER)
+            .unwrap();
+        contract
+            .sender(CASH_CALL_WRITER)
+            .set_treasury_policy(U256::from(2_000), TREASURY)
+            .unwrap();
+
+        let result = contract.sender(KEEPER).collect_forfeited_value(token_id);
+        assert!(matches!(result, Err(OptionsError::NotInTheMoney(_))));
     }
 
     #[motsu::test]
-    fn test_validate_call_exercise_with_valid_inputs(contract: Contract<Options>) {
-        let alice = Address::from([0xAA; 20]);
-        let token_id = B256::from([0x41; 32]);
-        let quantity = U256::from(100);
+    fn test_settle_series_snapshots_oracle_bound_physical_call(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        oracle: Contract<MockOracle>,
+    ) {
+        let one = U256::from(10).pow(U256::from(18));
+        let writer = CASH_CALL_WRITER;
+        underlying.sender(writer).set_decimals(8);
+        quote.sender(writer).set_decimals(6);
+        underlying
+            .sender(writer)
+            .mint(writer, U256::from(100_000_000));
+        underlying
+            .sender(writer)
+            .approve(contract.address(), U256::from(100_000_000));
 
-        contract.sender(alice).store_option_metadata(
-            token_id,
-            Token {
-                address: Address::from([0x11; 20]),
-                decimals: 8,
-            },
-            Token {
-                address: Address::from([0x22; 20]),
-                decimals: 6,
-            },
-            U256::from(50_000),
-            2_000_000_000u64,
-            OptionType::Call,
+        let token_id = contract
+            .sender(writer)
+            .write_cash_settled_call_option(
+                U256::from(60_000) * one,
+                1_870_000_000u64,
+                U256::from(100_000_000),
+                Token {
+                    address: underlying.address(),
+                    decimals: 8,
+                },
+                Token {
+                    address: quote.address(),
+                    decimals: 6,
+                },
+                oracle.address(),
+            )
+            .unwrap();
+        // Time cannot be advanced in tests, so move expiry into the past instead
+        {
+            let mut options = contract.sender(writer);
+            let mut metadata = options.option_metadata.setter(token_id);
+            metadata.expiry.set(U256::from(1_000_000_000u64));
+        }
+        oracle.sender(writer).set_price(U256::from(75_000) * one);
+
+        let settlement_price = contract.sender(writer).settle_series(token_id).unwrap();
+
+        assert_eq!(settlement_price, U256::from(75_000) * one);
+        contract.assert_emitted(&SeriesSettled {
+            tokenId: token_id,
+            settlementPrice: settlement_price,
+        });
+    }
+
+    const VAULT_WRITER: Address = Address::new([0xD1; 20]);
+    const VAULT_HOLDER: Address = Address::new([0xD2; 20]);
+    const VAULT_EXPIRY: u64 = 1_870_000_000;
+
+    /// Writes one WBTC-style (8 decimals) call at strike 60k against a 6-decimals quote
+    /// into a freshly initialized vault, then moves 0.4 options to `VAULT_HOLDER`.
+    fn setup_vault_call(
+        contract: &Contract<Options>,
+        underlying: &Contract<StandardERC20>,
+        quote: &Contract<StandardERC20>,
+        vault: &Contract<OptionVault>,
+    ) -> B256 {
+        let one = U256::from(10).pow(U256::from(18));
+        let strike = U256::from(60_000) * one;
+        underlying.sender(VAULT_WRITER).set_decimals(8);
+        quote.sender(VAULT_WRITER).set_decimals(6);
+
+        let token_id = generate_vault_token_id(
+            underlying.address(),
+            quote.address(),
+            strike,
+            VAULT_EXPIRY,
+            vault.address(),
         );
+        vault
+            .sender(VAULT_WRITER)
+            .initialize(
+                underlying.address(),
+                quote.address(),
+                contract.address(),
+                token_id,
+                U256::from(VAULT_EXPIRY),
+            )
+            .unwrap();
 
-        contract
-            .sender(alice)
-            ._mint(alice, token_id, quantity)
+        let quantity = U256::from(100_000_000);
+        underlying.sender(VAULT_WRITER).mint(VAULT_WRITER, quantity);
+        underlying
+            .sender(VAULT_WRITER)
+            .approve(contract.address(), quantity);
+        let written = contract
+            .sender(VAULT_WRITER)
+            .write_vault_call_option(
+                strike,
+                VAULT_EXPIRY,
+                quantity,
+                Token {
+                    address: underlying.address(),
+                    decimals: 8,
+                },
+                Token {
+                    address: quote.address(),
+                    decimals: 6,
+                },
+                vault.address(),
+            )
             .unwrap();
+        assert_eq!(written, token_id);
 
-        let current_time = 1_900_000_000u64;
-        let result =
-            contract
-                .sender(alice)
-                .validate_call_exercise(alice, token_id, quantity, current_time);
+        let transferred = U256::from(4) * one / U256::from(10);
+        contract
+            .sender(VAULT_WRITER)
+            ._burn(VAULT_WRITER, token_id, transferred)
+            .unwrap();
+        contract
+            .sender(VAULT_WRITER)
+            ._mint(VAULT_HOLDER, token_id, transferred)
+            .unwrap();
 
-        assert!(result.is_ok());
+        token_id
     }
 
     #[motsu::test]
-    fn test_validate_call_exercise_option_not_found(contract: Contract<Options>) {
-        let alice = Address::from([0xAA; 20]);
-        let non_existent_token = B256::from([0x99; 32]);
-        let quantity = U256::from(100);
-        let current_time = 1_900_000_000u64;
+    fn test_write_vault_call_deposits_collateral_into_vault(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+    ) {
+        let token_id = setup_vault_call(&contract, &underlying, &quote, &vault);
 
-        let result = contract.sender(alice).validate_call_exercise(
-            alice,
-            non_existent_token,
-            quantity,
-            current_time,
+        assert_eq!(
+            underlying.sender(VAULT_WRITER).balance_of(vault.address()),
+            U256::from(100_000_000)
+        );
+        assert_eq!(
+            underlying
+                .sender(VAULT_WRITER)
+                .balance_of(contract.address()),
+            U256::ZERO
+        );
+        assert_eq!(
+            vault.sender(VAULT_WRITER).get_total_assets(),
+            U256::from(100_000_000)
+        );
+        assert!(!vault
+            .sender(VAULT_WRITER)
+            .balance_of(VAULT_WRITER)
+            .is_zero());
+        assert_eq!(
+            vault
+                .sender(VAULT_WRITER)
+                .get_writer_checkpoints(VAULT_WRITER),
+            vec![U256::ZERO]
+        );
+        // The writer holds no position here; the vault tracks the collateral
+        assert_eq!(
+            contract
+                .sender(VAULT_WRITER)
+                .get_position(VAULT_WRITER, token_id),
+            (U256::ZERO, U256::ZERO)
+        );
+        assert_eq!(
+            contract
+                .sender(VAULT_WRITER)
+                .locked_collateral(underlying.address()),
+            U256::ZERO
         );
-
-        assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
     }
 
     #[motsu::test]
-    fn test_validate_call_exercise_after_expiry(contract: Contract<Options>) {
-        let alice = Address::from([0xAA; 20]);
-        let token_id = B256::from([0x43; 32]);
-        let expiry = 2_000_000_000u64;
+    fn test_non_writer_exercises_vault_call(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+    ) {
+        let token_id = setup_vault_call(&contract, &underlying, &quote, &vault);
 
-        contract.sender(alice).store_option_metadata(
-            token_id,
-            Token {
-                address: Address::from([0x11; 20]),
-                decimals: 8,
-            },
-            Token {
-                address: Address::from([0x22; 20]),
-                decimals: 6,
-            },
-            U256::from(50_000),
-            expiry,
-            OptionType::Call,
-        );
+        // 0.4 options at a 60k strike cost 24,000 USDC
+        let strike_payment = U256::from(24_000_000_000u64);
+        quote
+            .sender(VAULT_HOLDER)
+            .mint(VAULT_HOLDER, strike_payment);
+        quote
+            .sender(VAULT_HOLDER)
+            .approve(contract.address(), strike_payment);
 
         contract
-            .sender(alice)
-            ._mint(alice, token_id, U256::from(100))
+            .sender(VAULT_HOLDER)
+            .exercise_call(token_id, U256::from(40_000_000))
             .unwrap();
 
-        let current_time = expiry + 1;
-        let result = contract.sender(alice).validate_call_exercise(
-            alice,
-            token_id,
-            U256::from(50),
-            current_time,
+        assert_eq!(
+            underlying.sender(VAULT_HOLDER).balance_of(VAULT_HOLDER),
+            U256::from(40_000_000)
+        );
+        assert_eq!(
+            quote.sender(VAULT_HOLDER).balance_of(VAULT_HOLDER),
+            U256::ZERO
+        );
+        assert_eq!(
+            quote.sender(VAULT_HOLDER).balance_of(vault.address()),
+            strike_payment
+        );
+        assert_eq!(
+            vault.sender(VAULT_WRITER).get_total_assets(),
+            U256::from(60_000_000)
+        );
+        assert_eq!(
+            vault.sender(VAULT_WRITER).get_total_exercised(),
+            U256::from(40_000_000)
+        );
+        assert_eq!(
+            contract
+                .sender(VAULT_HOLDER)
+                .balance_of(VAULT_HOLDER, token_id),
+            U256::ZERO
         );
-
-        assert!(matches!(result, Err(OptionsError::ExerciseAfterExpiry(_))));
     }
 
     #[motsu::test]
-    fn test_validate_call_exercise_at_exact_expiry(contract: Contract<Options>) {
-        let alice = Address::from([0xAA; 20]);
-        let token_id = B256::from([0x44; 32]);
-        let expiry = 2_000_000_000u64;
+    fn test_vault_call_exercise_without_strike_payment_fails(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+    ) {
+        let token_id = setup_vault_call(&contract, &underlying, &quote, &vault);
 
-        contract.sender(alice).store_option_metadata(
-            token_id,
-            Token {
-                address: Address::from([0x11; 20]),
-                decimals: 8,
-            },
-            Token {
-                address: Address::from([0x22; 20]),
-                decimals: 6,
-            },
-            U256::from(50_000),
-            expiry,
-            OptionType::Call,
+        let result = contract
+            .sender(VAULT_HOLDER)
+            .exercise_call(token_id, U256::from(40_000_000));
+
+        assert!(matches!(result, Err(OptionsError::TransferFailed(_))));
+        assert_eq!(
+            underlying.sender(VAULT_HOLDER).balance_of(VAULT_HOLDER),
+            U256::ZERO
+        );
+        assert_eq!(
+            vault.sender(VAULT_WRITER).get_total_assets(),
+            U256::from(100_000_000)
         );
+    }
+
+    #[motsu::test]
+    fn test_transferred_options_can_be_exercised(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+    ) {
+        let token_id = setup_vault_call(&contract, &underlying, &quote, &vault);
 
+        let amount = U256::from(10).pow(U256::from(17));
         contract
-            .sender(alice)
-            ._mint(alice, token_id, U256::from(100))
+            .sender(VAULT_WRITER)
+            .safe_transfer_from(
+                VAULT_WRITER,
+                VAULT_HOLDER,
+                token_id,
+                amount,
+                Vec::<u8>::new().into(),
+            )
             .unwrap();
+        contract.assert_emitted(&TransferSingle {
+            operator: VAULT_WRITER,
+            from: VAULT_WRITER,
+            to: VAULT_HOLDER,
+            id: U256::from_be_bytes(token_id.0),
+            value: amount,
+        });
+
+        // 0.5 options at a 60k strike cost 30,000 USDC
+        let strike_payment = U256::from(30_000_000_000u64);
+        quote
+            .sender(VAULT_HOLDER)
+            .mint(VAULT_HOLDER, strike_payment);
+        quote
+            .sender(VAULT_HOLDER)
+            .approve(contract.address(), strike_payment);
 
-        let current_time = expiry;
-        let result = contract.sender(alice).validate_call_exercise(
-            alice,
-            token_id,
-            U256::from(50),
-            current_time,
-        );
+        contract
+            .sender(VAULT_HOLDER)
+            .exercise_call(token_id, U256::from(50_000_000))
+            .unwrap();
 
-        assert!(matches!(result, Err(OptionsError::ExerciseAfterExpiry(_))));
+        assert_eq!(
+            underlying.sender(VAULT_HOLDER).balance_of(VAULT_HOLDER),
+            U256::from(50_000_000)
+        );
+        assert_eq!(
+            contract
+                .sender(VAULT_HOLDER)
+                .balance_of(VAULT_HOLDER, token_id),
+            U256::ZERO
+        );
+        assert_eq!(
+            contract.sender(VAULT_HOLDER).total_supply(token_id),
+            U256::from(5) * amount
+        );
     }
 
-    #[motsu::test]
-    fn test_validate_call_exercise_wrong_option_type(contract: Contract<Options>) {
-        let alice = Address::from([0xAA; 20]);
-        let token_id = B256::from([0x42; 32]);
-
-        contract.sender(alice).store_option_metadata(
-            token_id,
+    /// Initializes `vault` with `series` (options contract, token ID, asset, strike asset,
+    /// expiry) and writes the `setup_vault_call` series into it.
+    fn write_into_vault_initialized_with(
+        contract: &Contract<Options>,
+        underlying: &Contract<StandardERC20>,
+        quote: &Contract<StandardERC20>,
+        vault: &Contract<OptionVault>,
+        series: (Address, B256, Address, Address, u64),
+    ) -> Result<B256, OptionsError> {
+        let (options_contract, token_id, asset, strike_asset, expiry) = series;
+        underlying.sender(VAULT_WRITER).set_decimals(8);
+        quote.sender(VAULT_WRITER).set_decimals(6);
+        vault
+            .sender(VAULT_WRITER)
+            .initialize(
+                asset,
+                strike_asset,
+                options_contract,
+                token_id,
+                U256::from(expiry),
+            )
+            .unwrap();
+        let quantity = U256::from(100_000_000);
+        underlying.sender(VAULT_WRITER).mint(VAULT_WRITER, quantity);
+        underlying
+            .sender(VAULT_WRITER)
+            .approve(contract.address(), quantity);
+
+        contract.sender(VAULT_WRITER).write_vault_call_option(
+            vault_call_strike(),
+            VAULT_EXPIRY,
+            quantity,
             Token {
-                address: Address::from([0x11; 20]),
+                address: underlying.address(),
                 decimals: 8,
             },
             Token {
-                address: Address::from([0x22; 20]),
+                address: quote.address(),
                 decimals: 6,
             },
-            U256::from(50_000),
-            2_000_000_000u64,
-            OptionType::Put,
-        );
+            vault.address(),
+        )
+    }
 
-        contract
-            .sender(alice)
-            ._mint(alice, token_id, U256::from(100))
-            .unwrap();
+    fn vault_call_strike() -> U256 {
+        U256::from(60_000) * U256::from(10).pow(U256::from(18))
+    }
 
-        let current_time = 1_900_000_000u64;
-        let result = contract.sender(alice).validate_call_exercise(
-            alice,
-            token_id,
-            U256::from(50),
-            current_time,
+    fn vault_call_token_id(
+        underlying: &Contract<StandardERC20>,
+        quote: &Contract<StandardERC20>,
+        vault: &Contract<OptionVault>,
+    ) -> B256 {
+        generate_vault_token_id(
+            underlying.address(),
+            quote.address(),
+            vault_call_strike(),
+            VAULT_EXPIRY,
+            vault.address(),
+        )
+    }
+
+    #[motsu::test]
+    fn test_write_vault_call_rejects_mismatched_vault_expiry(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+    ) {
+        let token_id = vault_call_token_id(&underlying, &quote, &vault);
+
+        let result = write_into_vault_initialized_with(
+            &contract,
+            &underlying,
+            &quote,
+            &vault,
+            (
+                contract.address(),
+                token_id,
+                underlying.address(),
+                quote.address(),
+                VAULT_EXPIRY + 1,
+            ),
         );
 
-        assert!(matches!(result, Err(OptionsError::WrongOptionType(_))));
+        assert!(matches!(result, Err(OptionsError::InvalidVault(_))));
+        assert_eq!(
+            underlying.sender(VAULT_WRITER).balance_of(VAULT_WRITER),
+            U256::from(100_000_000)
+        );
     }
 
     #[motsu::test]
-    fn test_validate_call_exercise_zero_quantity(contract: Contract<Options>) {
-        let alice = Address::from([0xAA; 20]);
-        let token_id = B256::from([0x45; 32]);
+    fn test_write_vault_call_rejects_vault_of_other_series(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+    ) {
+        // A vault backing another strike must not be shared with this series
+        let other_token_id = generate_vault_token_id(
+            underlying.address(),
+            quote.address(),
+            vault_call_strike() * U256::from(2),
+            VAULT_EXPIRY,
+            vault.address(),
+        );
 
-        contract.sender(alice).store_option_metadata(
-            token_id,
-            Token {
-                address: Address::from([0x11; 20]),
-                decimals: 8,
-            },
-            Token {
-                address: Address::from([0x22; 20]),
-                decimals: 6,
-            },
-            U256::from(50_000),
-            2_000_000_000u64,
-            OptionType::Call,
+        let result = write_into_vault_initialized_with(
+            &contract,
+            &underlying,
+            &quote,
+            &vault,
+            (
+                contract.address(),
+                other_token_id,
+                underlying.address(),
+                quote.address(),
+                VAULT_EXPIRY,
+            ),
         );
 
-        contract
-            .sender(alice)
-            ._mint(alice, token_id, U256::from(100))
-            .unwrap();
+        assert!(matches!(result, Err(OptionsError::InvalidVault(_))));
+        assert_eq!(vault.sender(VAULT_WRITER).get_total_assets(), U256::ZERO);
+    }
 
-        let current_time = 1_900_000_000u64;
-        let result = contract.sender(alice).validate_call_exercise(
-            alice,
-            token_id,
-            U256::ZERO,
-            current_time,
+    #[motsu::test]
+    fn test_write_vault_call_rejects_mismatched_vault_assets(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+    ) {
+        let token_id = vault_call_token_id(&underlying, &quote, &vault);
+
+        // Asset and strike asset swapped
+        let result = write_into_vault_initialized_with(
+            &contract,
+            &underlying,
+            &quote,
+            &vault,
+            (
+                contract.address(),
+                token_id,
+                quote.address(),
+                underlying.address(),
+                VAULT_EXPIRY,
+            ),
         );
 
-        assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
+        assert!(matches!(result, Err(OptionsError::InvalidVault(_))));
     }
 
     #[motsu::test]
-    fn test_validate_call_exercise_insufficient_balance(contract: Contract<Options>) {
-        let alice = Address::from([0xAA; 20]);
-        let token_id = B256::from([0x46; 32]);
-        let balance = U256::from(100);
-
-        contract.sender(alice).store_option_metadata(
-            token_id,
-            Token {
-                address: Address::from([0x11; 20]),
-                decimals: 8,
-            },
-            Token {
-                address: Address::from([0x22; 20]),
-                decimals: 6,
-            },
-            U256::from(50_000),
-            2_000_000_000u64,
-            OptionType::Call,
+    fn test_write_vault_call_rejects_vault_of_other_options_contract(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+    ) {
+        let token_id = vault_call_token_id(&underlying, &quote, &vault);
+
+        let result = write_into_vault_initialized_with(
+            &contract,
+            &underlying,
+            &quote,
+            &vault,
+            (
+                Address::new([0xD3; 20]),
+                token_id,
+                underlying.address(),
+                quote.address(),
+                VAULT_EXPIRY,
+            ),
         );
 
-        contract
-            .sender(alice)
-            ._mint(alice, token_id, balance)
-            .unwrap();
+        assert!(matches!(result, Err(OptionsError::InvalidVault(_))));
+    }
 
-        let current_time = 1_900_000_000u64;
-        let excessive_quantity = balance.checked_add(U256::from(1)).unwrap();
-        let result = contract.sender(alice).validate_call_exercise(
-            alice,
-            token_id,
-            excessive_quantity,
-            current_time,
-        );
+    #[test]
+    fn test_vault_token_id_is_distinct() {
+        let underlying = Address::from([0x11; 20]);
+        let quote = Address::from([0x22; 20]);
+        let strike = U256::from(60_000);
+        let expiry = 1_870_000_000u64;
+        let vault = Address::from([0x33; 20]);
 
-        assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+        let vault_backed = generate_vault_token_id(underlying, quote, strike, expiry, vault);
+
+        assert_ne!(
+            vault_backed,
+            generate_token_id(underlying, quote, strike, expiry, OptionType::Call)
+        );
+        assert_ne!(
+            vault_backed,
+            generate_cash_settled_token_id(
+                underlying,
+                quote,
+                strike,
+                expiry,
+                OptionType::Call,
+                vault
+            )
+        );
+        assert_ne!(
+            vault_backed,
+            generate_vault_token_id(underlying, quote, strike, expiry, quote)
+        );
     }
 
-    #[motsu::test]
-    fn test_reduce_position_successfully(contract: Contract<Options>) {
-        let writer = Address::from([0xAA; 20]);
-        let token_id = B256::from([0x50; 32]);
-        let initial_quantity = U256::from(1000);
-        let initial_collateral = U256::from(5000);
-        let reduction = U256::from(300);
+    sol! {
+        interface IMockRouter {
+            function swap(address tokenIn, address tokenOut, uint256 amountIn, uint256 amountOut) external;
+        }
+    }
 
+    /// Approves `router` and funds it with 30,000 USDC for swap exercises.
+    fn setup_swap_router(
+        contract: &Contract<Options>,
+        quote: &Contract<StandardERC20>,
+        router: &Contract<MockRouter>,
+    ) {
+        contract.sender(VAULT_WRITER).constructor();
         contract
-            .sender(writer)
-            .create_or_update_position(writer, token_id, initial_quantity, initial_collateral)
+            .sender(VAULT_WRITER)
+            .initialize(VAULT_WRITER)
+            .unwrap();
+        contract
+            .sender(VAULT_WRITER)
+            .set_router_approval(router.address(), true)
             .unwrap();
+        quote
+            .sender(VAULT_WRITER)
+            .mint(router.address(), U256::from(30_000_000_000u64));
+    }
 
-        let result = contract
-            .sender(writer)
-            .reduce_position(writer, token_id, reduction);
+    fn swap_calldata(
+        underlying: &Contract<StandardERC20>,
+        quote: &Contract<StandardERC20>,
+        amount_in: u64,
+        amount_out: u64,
+    ) -> Bytes {
+        use alloy_sol_types::SolCall;
+
+        IMockRouter::swapCall {
+            tokenIn: underlying.address(),
+            tokenOut: quote.address(),
+            amountIn: U256::from(amount_in),
+            amountOut: U256::from(amount_out),
+        }
+        .abi_encode()
+        .into()
+    }
 
-        assert!(result.is_ok());
+    #[motsu::test]
+    fn test_exercise_call_via_swap_pays_strike_from_underlying(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+        router: Contract<MockRouter>,
+    ) {
+        let token_id = setup_vault_call(&contract, &underlying, &quote, &vault);
+        setup_swap_router(&contract, &quote, &router);
+
+        // 0.24 BTC sells for the 24,000 USDC strike of 0.4 options
+        let strike_payment = U256::from(24_000_000_000u64);
+        let returned = contract
+            .sender(VAULT_HOLDER)
+            .exercise_call_via_swap(
+                token_id,
+                U256::from(40_000_000),
+                router.address(),
+                swap_calldata(&underlying, &quote, 24_000_000, 24_000_000_000),
+                U256::from(16_000_000),
+            )
+            .unwrap();
 
-        let (quantity, collateral) = contract.sender(writer).get_position(writer, token_id);
-        assert_eq!(quantity, U256::from(700));
-        assert_eq!(collateral, U256::from(3500));
+        assert_eq!(returned, U256::from(16_000_000));
+        assert_eq!(
+            underlying.sender(VAULT_HOLDER).balance_of(VAULT_HOLDER),
+            U256::from(16_000_000)
+        );
+        assert_eq!(
+            underlying.sender(VAULT_HOLDER).balance_of(router.address()),
+            U256::from(24_000_000)
+        );
+        assert_eq!(
+            quote.sender(VAULT_HOLDER).balance_of(vault.address()),
+            strike_payment
+        );
+        assert_eq!(
+            quote.sender(VAULT_HOLDER).balance_of(VAULT_HOLDER),
+            U256::ZERO
+        );
+        assert_eq!(
+            underlying
+                .sender(VAULT_HOLDER)
+                .balance_of(contract.address()),
+            U256::ZERO
+        );
+        assert_eq!(
+            vault.sender(VAULT_WRITER).get_total_assets(),
+            U256::from(60_000_000)
+        );
+        assert_eq!(
+            contract
+                .sender(VAULT_HOLDER)
+                .balance_of(VAULT_HOLDER, token_id),
+            U256::ZERO
+        );
+        contract.assert_emitted(&ExercisedViaSwap {
+            holder: VAULT_HOLDER,
+            tokenId: token_id,
+            router: router.address(),
+            underlyingSwapped: U256::from(24_000_000),
+            quoteReceived: strike_payment,
+            underlyingReturned: U256::from(16_000_000),
+        });
     }
 
     #[motsu::test]
-    fn test_reduce_position_to_zero(contract: Contract<Options>) {
-        let writer = Address::from([0xBB; 20]);
-        let token_id = B256::from([0x51; 32]);
-        let quantity = U256::from(100);
-        let collateral = U256::from(500);
+    fn test_exercise_call_via_swap_returns_surplus_quote(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+        router: Contract<MockRouter>,
+    ) {
+        let token_id = setup_vault_call(&contract, &underlying, &quote, &vault);
+        setup_swap_router(&contract, &quote, &router);
 
         contract
-            .sender(writer)
-            .create_or_update_position(writer, token_id, quantity, collateral)
+            .sender(VAULT_HOLDER)
+            .exercise_call_via_swap(
+                token_id,
+                U256::from(40_000_000),
+                router.address(),
+                swap_calldata(&underlying, &quote, 25_000_000, 25_000_000_000),
+                U256::ZERO,
+            )
             .unwrap();
 
-        let result = contract
-            .sender(writer)
-            .reduce_position(writer, token_id, quantity);
+        assert_eq!(
+            quote.sender(VAULT_HOLDER).balance_of(VAULT_HOLDER),
+            U256::from(1_000_000_000)
+        );
+        assert_eq!(
+            underlying.sender(VAULT_HOLDER).balance_of(VAULT_HOLDER),
+            U256::from(15_000_000)
+        );
+        assert_eq!(
+            quote.sender(VAULT_HOLDER).balance_of(vault.address()),
+            U256::from(24_000_000_000u64)
+        );
+    }
 
-        assert!(result.is_ok());
+    #[motsu::test]
+    fn test_exercise_call_via_swap_rejects_insufficient_output(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+        router: Contract<MockRouter>,
+    ) {
+        let token_id = setup_vault_call(&contract, &underlying, &quote, &vault);
+        setup_swap_router(&contract, &quote, &router);
 
-        let (new_quantity, new_collateral) = contract.sender(writer).get_position(writer, token_id);
-        assert_eq!(new_quantity, U256::ZERO);
-        assert_eq!(new_collateral, U256::ZERO);
+        let result = contract.sender(VAULT_HOLDER).exercise_call_via_swap(
+            token_id,
+            U256::from(40_000_000),
+            router.address(),
+            swap_calldata(&underlying, &quote, 24_000_000, 23_000_000_000),
+            U256::ZERO,
+        );
+
+        assert!(matches!(
+            result,
+            Err(OptionsError::InsufficientSwapOutput(_))
+        ));
+        assert_eq!(
+            vault.sender(VAULT_WRITER).get_total_assets(),
+            U256::from(100_000_000)
+        );
+        assert_eq!(
+            underlying.sender(VAULT_HOLDER).balance_of(VAULT_HOLDER),
+            U256::ZERO
+        );
     }
 
     #[motsu::test]
-    fn test_reduce_position_insufficient_quantity(contract: Contract<Options>) {
-        let writer = Address::from([0xCC; 20]);
-        let token_id = B256::from([0x52; 32]);
-        let quantity = U256::from(100);
-        let collateral = U256::from(500);
-
-        contract
-            .sender(writer)
-            .create_or_update_position(writer, token_id, quantity, collateral)
-            .unwrap();
+    fn test_exercise_call_via_swap_enforces_min_underlying_out(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+        router: Contract<MockRouter>,
+    ) {
+        let token_id = setup_vault_call(&contract, &underlying, &quote, &vault);
+        setup_swap_router(&contract, &quote, &router);
 
-        let result = contract
-            .sender(writer)
-            .reduce_position(writer, token_id, U256::from(101));
+        // The router takes 0.3 BTC for the strike, leaving 0.1 BTC
+        let result = contract.sender(VAULT_HOLDER).exercise_call_via_swap(
+            token_id,
+            U256::from(40_000_000),
+            router.address(),
+            swap_calldata(&underlying, &quote, 30_000_000, 24_000_000_000),
+            U256::from(15_000_000),
+        );
 
-        assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+        assert!(matches!(result, Err(OptionsError::SlippageExceeded(_))));
+        assert_eq!(
+            contract
+                .sender(VAULT_HOLDER)
+                .balance_of(VAULT_HOLDER, token_id),
+            U256::from(4) * U256::from(10).pow(U256::from(17))
+        );
     }
 
     #[motsu::test]
-    fn test_reduce_position_maintains_collateral_ratio(contract: Contract<Options>) {
-        let writer = Address::from([0xDD; 20]);
-        let token_id = B256::from([0x53; 32]);
-        let initial_quantity = U256::from(1000);
-        let initial_collateral = U256::from(10_000);
+    fn test_exercise_call_via_swap_router_cannot_take_more_than_released(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+        router: Contract<MockRouter>,
+    ) {
+        let token_id = setup_vault_call(&contract, &underlying, &quote, &vault);
+        setup_swap_router(&contract, &quote, &router);
 
-        contract
-            .sender(writer)
-            .create_or_update_position(writer, token_id, initial_quantity, initial_collateral)
-            .unwrap();
+        // Only the released 0.4 BTC is approved to the router
+        let result = contract.sender(VAULT_HOLDER).exercise_call_via_swap(
+            token_id,
+            U256::from(40_000_000),
+            router.address(),
+            swap_calldata(&underlying, &quote, 50_000_000, 24_000_000_000),
+            U256::ZERO,
+        );
 
+        assert!(matches!(result, Err(OptionsError::SwapFailed(_))));
+        assert_eq!(
+            underlying.sender(VAULT_HOLDER).balance_of(router.address()),
+            U256::ZERO
+        );
+        assert_eq!(
+            vault.sender(VAULT_WRITER).get_total_assets(),
+            U256::from(100_000_000)
+        );
+    }
+
+    #[motsu::test]
+    fn test_exercise_call_via_swap_requires_approved_router(
+        contract: Contract<Options>,
+        underlying: Contract<StandardERC20>,
+        quote: Contract<StandardERC20>,
+        vault: Contract<OptionVault>,
+        router: Contract<MockRouter>,
+    ) {
+        let token_id = setup_vault_call(&contract, &underlying, &quote, &vault);
+        contract.sender(VAULT_WRITER).constructor();
         contract
-            .sender(writer)
-            .reduce_position(writer, token_id, U256::from(250))
+            .sender(VAULT_WRITER)
+            .initialize(VAULT_WRITER)
             .unwrap();
 
-        let (quantity, collateral) = contract.sender(writer).get_position(writer, token_id);
-        assert_eq!(quantity, U256::from(750));
-        assert_eq!(collateral, U256::from(7500));
+        let result = contract.sender(VAULT_HOLDER).exercise_call_via_swap(
+            token_id,
+            U256::from(40_000_000),
+            router.address(),
+            swap_calldata(&underlying, &quote, 24_000_000, 24_000_000_000),
+            U256::ZERO,
+        );
+
+        assert!(matches!(result, Err(OptionsError::RouterNotApproved(_))));
+        assert_eq!(router.sender(VAULT_HOLDER).swap_count(), U256::ZERO);
+        assert!(!contract
+            .sender(VAULT_HOLDER)
+            .is_router_approved(router.address()));
     }
 }
 
 #[cfg(test)]
 mod proptests {
+    use motsu::prelude::*;
     use proptest::prelude::*;
 
     use super::*;
+    use crate::mock_erc20::StandardERC20;
 
     proptest! {
+        #[test]
+        fn prop_oracle_answer_normalization(
+            answer in any::<u64>(),
+            decimals in 0u8..=18u8,
+        ) {
+            let answer = U256::from(answer);
+            let scale = U256::from(10).pow(U256::from(18 - decimals));
+
+            let price = normalize_amount(answer, decimals);
+            prop_assert!(price.is_ok());
+            let price = price.unwrap_or_default();
+
+            prop_assert_eq!(price, answer * scale);
+            prop_assert_eq!(price / scale, answer);
+        }
+
+        #[test]
+        fn prop_oracle_answer_above_18_decimals_rejected(
+            answer in any::<U256>(),
+            decimals in 19u8..=u8::MAX,
+        ) {
+            prop_assert!(matches!(
+                normalize_amount(answer, decimals),
+                Err(OptionsError::InvalidDecimals(_))
+            ));
+        }
+
         #[test]
         fn prop_token_id_determinism(
             underlying in any::<Address>(),
@@ -2526,6 +13358,31 @@ mod proptests {
             prop_assert_eq!(denormalized.unwrap(), amount_u256);
         }
 
+        #[test]
+        fn prop_strike_payment_chunks_never_underpay(
+            strike in 1u128..=(u128::from(u64::MAX) * 1_000_000_000u128),
+            chunk_a in 1u64..=u64::MAX,
+            chunk_b in 1u64..=u64::MAX,
+            chunk_c in 1u64..=u64::MAX,
+            quote_decimals in 0u8..=18u8,
+        ) {
+            let strike = U256::from(strike);
+            let chunks = [U256::from(chunk_a), U256::from(chunk_b), U256::from(chunk_c)];
+            let whole: U256 = chunks.iter().copied().sum();
+
+            let whole_payment = strike_payment(strike, whole, quote_decimals);
+            prop_assert!(whole_payment.is_ok());
+
+            let mut chunk_payments = U256::ZERO;
+            for chunk in chunks {
+                let payment = strike_payment(strike, chunk, quote_decimals);
+                prop_assert!(payment.is_ok());
+                chunk_payments += payment.unwrap_or_default();
+            }
+
+            prop_assert!(chunk_payments >= whole_payment.unwrap_or_default());
+        }
+
         #[test]
         fn prop_normalize_never_panics(
             amount in any::<u64>(),
@@ -2566,10 +13423,288 @@ mod proptests {
                 decimals: quote_decimals,
             };
 
-            let result = validate_write_params(strike, expiry, quantity, underlying, quote, current_time);
+            let result = validate_write_params(
+                strike,
+                expiry,
+                quantity,
+                underlying,
+                quote,
+                current_time,
+                DEFAULT_MAX_EXPIRY_HORIZON,
+                U256::ZERO,
+            );
             prop_assert!(result.is_ok() || result.is_err());
         }
 
+        #[test]
+        fn prop_validate_params_rejects_zero_address(
+            token in any::<Address>(),
+            zero_is_underlying in any::<bool>(),
+            strike in 1u64..=u64::MAX,
+            quantity in 1u64..=u64::MAX,
+        ) {
+            let (underlying_address, quote_address) = if zero_is_underlying {
+                (Address::ZERO, token)
+            } else {
+                (token, Address::ZERO)
+            };
+            let underlying = Token {
+                address: underlying_address,
+                decimals: 18,
+            };
+            let quote = Token {
+                address: quote_address,
+                decimals: 6,
+            };
+
+            let result = validate_write_params(
+                U256::from(strike),
+                2,
+                U256::from(quantity),
+                underlying,
+                quote,
+                1,
+                DEFAULT_MAX_EXPIRY_HORIZON,
+                U256::ZERO,
+            );
+            prop_assert!(matches!(result, Err(OptionsError::ZeroAddress(_))));
+        }
+
+        #[test]
+        fn prop_written_option_strike_payment_never_overflows(
+            strike_seed in any::<U256>(),
+            quantity_seed in any::<U256>(),
+            quantity_shift in 0usize..256,
+            underlying_decimals in 0u8..=18,
+            quote_decimals in 0u8..=18,
+        ) {
+            // Spread strikes around MAX_STRIKE and quantities over every magnitude
+            let strike = strike_seed % (MAX_STRIKE + U256::from(2));
+            let quantity = quantity_seed >> quantity_shift;
+            let underlying = Token {
+                address: Address::repeat_byte(0x11),
+                decimals: underlying_decimals,
+            };
+            let quote = Token {
+                address: Address::repeat_byte(0x22),
+                decimals: quote_decimals,
+            };
+
+            let validated = validate_write_params(
+                strike,
+                2,
+                quantity,
+                underlying,
+                quote,
+                1,
+                DEFAULT_MAX_EXPIRY_HORIZON,
+                U256::ZERO,
+            );
+            if validated.is_ok() {
+                let normalized_quantity = normalize_amount(quantity, underlying_decimals).unwrap();
+                prop_assert!(strike_payment(strike, normalized_quantity, quote_decimals).is_ok());
+            }
+        }
+
+        #[test]
+        fn prop_preview_collateral_matches_write_transfer(
+            strike_seed in any::<U256>(),
+            quantity_seed in any::<U256>(),
+            quantity_shift in 0usize..256,
+            underlying_decimals in 0u8..=18,
+            quote_decimals in 0u8..=18,
+        ) {
+            let contract = Contract::<Options>::default();
+            let underlying_token = Contract::<StandardERC20>::default();
+            let quote_token = Contract::<StandardERC20>::default();
+            let writer = Address::repeat_byte(0xAA);
+
+            let strike = strike_seed % (MAX_STRIKE + U256::from(2));
+            let quantity = quantity_seed >> quantity_shift;
+            underlying_token.sender(writer).set_decimals(underlying_decimals);
+            quote_token.sender(writer).set_decimals(quote_decimals);
+            underlying_token.sender(writer).mint(writer, quantity);
+            underlying_token.sender(writer).approve(contract.address(), quantity);
+
+            let preview = contract.sender(writer).preview_required_collateral(
+                strike,
+                quantity,
+                underlying_decimals,
+                quote_decimals,
+                OptionType::Call.to_u8(),
+            );
+            let written = contract.sender(writer).write_call_option(
+                strike,
+                1_870_000_000u64,
+                quantity,
+                Token { address: underlying_token.address(), decimals: underlying_decimals },
+                Token { address: quote_token.address(), decimals: quote_decimals },
+            );
+
+            if written.is_ok() {
+                prop_assert!(preview.is_ok());
+                let (collateral, token) = preview.unwrap_or_default();
+                prop_assert_eq!(token, CollateralToken::Underlying.to_u8());
+                prop_assert_eq!(
+                    underlying_token.sender(writer).balance_of(contract.address()),
+                    collateral
+                );
+                prop_assert_eq!(underlying_token.sender(writer).balance_of(writer), U256::ZERO);
+            }
+        }
+
+        #[test]
+        fn prop_reduce_position_to_zero_leaves_no_collateral(
+            quantity in 1u64..=10_000u64,
+            collateral in 0u64..=1_000_000u64,
+            step in 1u64..=100u64,
+        ) {
+            let contract = Contract::<Options>::default();
+            let writer = Address::repeat_byte(0x01);
+            let token_id = B256::repeat_byte(0x81);
+
+            prop_assert!(contract
+                .sender(writer)
+                .create_or_update_position(writer, token_id, U256::from(quantity), U256::from(collateral))
+                .is_ok());
+
+            let mut released = U256::ZERO;
+            let mut remaining = quantity;
+            while remaining > 0 {
+                let reduction = remaining.min(step);
+                let result = contract
+                    .sender(writer)
+                    .reduce_position(writer, token_id, U256::from(reduction), false);
+                prop_assert!(result.is_ok());
+                released += result.unwrap_or_default();
+                remaining -= reduction;
+            }
+
+            prop_assert_eq!(
+                contract.sender(writer).get_position(writer, token_id),
+                (U256::ZERO, U256::ZERO)
+            );
+            prop_assert_eq!(released, U256::from(collateral));
+        }
+
+        #[test]
+        fn prop_validate_call_exercise_never_panics_on_stored_metadata(
+            expiry in any::<U256>(),
+            option_type in any::<u8>(),
+            underlying_decimals in any::<u8>(),
+            current_time in any::<u64>(),
+            quantity in any::<U256>(),
+        ) {
+            let contract = Contract::<Options>::default();
+            let alice = Address::repeat_byte(0x01);
+            let token_id = B256::repeat_byte(0x82);
+
+            {
+                let mut options = contract.sender(alice);
+                let mut metadata = options.option_metadata.setter(token_id);
+                metadata.underlying.set(Address::repeat_byte(0x11));
+                metadata.quote.set(Address::repeat_byte(0x22));
+                metadata.underlying_decimals.set(U8::from(underlying_decimals));
+                metadata.expiry.set(expiry);
+                metadata.option_type.set(U8::from(option_type));
+            }
+
+            let result = contract
+                .sender(alice)
+                .validate_call_exercise(alice, token_id, quantity, current_time);
+
+            let exercisable_until = expiry.saturating_add(U256::from(SETTLEMENT_GRACE_PERIOD));
+            if expiry.is_zero() {
+                prop_assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+            } else if U256::from(current_time) >= exercisable_until {
+                prop_assert!(matches!(result, Err(OptionsError::ExerciseAfterExpiry(_))));
+            } else if option_type != 0 {
+                prop_assert!(matches!(result, Err(OptionsError::WrongOptionType(_))));
+            }
+
+            let stored = contract.sender(alice).get_option_metadata(token_id);
+            prop_assert_eq!(stored.expiry, expiry);
+            prop_assert_eq!(stored.option_type, option_type);
+            prop_assert_eq!(stored.underlying_decimals, underlying_decimals);
+        }
+
+        #[test]
+        fn prop_validate_call_exercise_never_panics_near_max_expiry(
+            expiry in (u64::MAX - SETTLEMENT_GRACE_PERIOD + 1)..=u64::MAX,
+            decimals in any::<u8>(),
+            current_time in any::<u64>(),
+        ) {
+            let contract = Contract::<Options>::default();
+            let alice = Address::repeat_byte(0x01);
+            let token_id = B256::repeat_byte(0x83);
+
+            prop_assert!(contract
+                .sender(alice)
+                .store_option_metadata(
+                    token_id,
+                    Token { address: Address::repeat_byte(0x11), decimals },
+                    Token { address: Address::repeat_byte(0x22), decimals },
+                    U256::from(1),
+                    expiry,
+                    OptionType::Call,
+                )
+                .is_ok());
+
+            // No tokens were minted, so a live series fails on balance, never on expiry
+            let result = contract
+                .sender(alice)
+                .validate_call_exercise(alice, token_id, U256::from(1), current_time);
+            prop_assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+        }
+
+        #[test]
+        fn prop_metadata_immutable_after_creation(
+            underlying_decimals in 0u8..=18u8,
+            quote_decimals in 0u8..=18u8,
+            new_underlying_decimals in 0u8..=18u8,
+            new_quote_decimals in 0u8..=18u8,
+            strike in 1u64..u64::MAX,
+            new_strike in 1u64..u64::MAX,
+        ) {
+            let contract = Contract::<Options>::default();
+            let alice = Address::repeat_byte(0x01);
+            let token_id = B256::repeat_byte(0x80);
+            let underlying_address = Address::repeat_byte(0x11);
+            let quote_address = Address::repeat_byte(0x22);
+            let expiry = 1_870_000_000u64;
+
+            prop_assert!(contract
+                .sender(alice)
+                .store_option_metadata(
+                    token_id,
+                    Token { address: underlying_address, decimals: underlying_decimals },
+                    Token { address: quote_address, decimals: quote_decimals },
+                    U256::from(strike),
+                    expiry,
+                    OptionType::Call,
+                )
+                .is_ok());
+            let original = contract.sender(alice).get_option_metadata(token_id);
+
+            let _ = contract.sender(alice).store_option_metadata(
+                token_id,
+                Token { address: underlying_address, decimals: new_underlying_decimals },
+                Token { address: quote_address, decimals: new_quote_decimals },
+                U256::from(new_strike),
+                expiry + 1,
+                OptionType::Put,
+            );
+            let stored = contract.sender(alice).get_option_metadata(token_id);
+
+            prop_assert_eq!(stored.underlying, original.underlying);
+            prop_assert_eq!(stored.quote, original.quote);
+            prop_assert_eq!(stored.underlying_decimals, original.underlying_decimals);
+            prop_assert_eq!(stored.quote_decimals, original.quote_decimals);
+            prop_assert_eq!(stored.strike, original.strike);
+            prop_assert_eq!(stored.expiry, original.expiry);
+            prop_assert_eq!(stored.option_type, original.option_type);
+        }
+
         #[test]
         fn prop_position_key_determinism(
             writer in any::<Address>(),
@@ -2724,5 +13859,191 @@ mod proptests {
                 prop_assert!(remaining.unwrap() < balance_u256 || exercise_u256.is_zero());
             }
         }
+
+        #[test]
+        fn prop_denormalize_round_up_exceeds_round_down_by_at_most_one(
+            amount in any::<U256>(),
+            decimals in 0u8..=18u8,
+        ) {
+            let down = denormalize_amount_rounding(amount, decimals, false);
+            let up = denormalize_amount_rounding(amount, decimals, true);
+            prop_assert!(down.is_ok() && up.is_ok());
+            let (down, up) = (down.unwrap_or_default(), up.unwrap_or_default());
+
+            prop_assert!(up >= down);
+            prop_assert!(up - down <= U256::from(1));
+            let scale = U256::from(10).pow(U256::from(18 - decimals));
+            prop_assert_eq!(up == down, amount % scale == U256::ZERO);
+        }
+
+        #[test]
+        fn prop_denormalize_rounding_brackets_amount(
+            amount in any::<u128>(),
+            decimals in 0u8..=18u8,
+        ) {
+            let amount = U256::from(amount);
+            let scale = U256::from(10).pow(U256::from(18 - decimals));
+            let down = denormalize_amount_rounding(amount, decimals, false).unwrap_or_default();
+            let up = denormalize_amount_rounding(amount, decimals, true).unwrap_or_default();
+
+            // Paying `up` never shortchanges the contract; receiving `down` never overpays
+            prop_assert!(down * scale <= amount);
+            prop_assert!(up * scale >= amount);
+        }
+
+        #[test]
+        fn prop_checked_sub_or_zero_matches_f64(a in any::<u32>(), b in any::<u32>()) {
+            let result = checked_sub_or_zero(U256::from(a), U256::from(b));
+
+            prop_assert!(result <= U256::from(a));
+            let expected = (f64::from(a) - f64::from(b)).max(0.0);
+            prop_assert!((f64::from(result.to::<u32>()) - expected).abs() < 0.5);
+        }
+
+        #[test]
+        fn prop_checked_sub_or_zero_never_panics(a in any::<U256>(), b in any::<U256>()) {
+            let result = checked_sub_or_zero(a, b);
+            if a >= b {
+                prop_assert_eq!(result + b, a);
+            } else {
+                prop_assert_eq!(result, U256::ZERO);
+            }
+        }
+
+        #[test]
+        fn prop_saturating_intrinsic_matches_f64(
+            spot in any::<u32>(),
+            strike in any::<u32>(),
+            is_call in any::<bool>(),
+        ) {
+            let value = saturating_intrinsic(U256::from(spot), U256::from(strike), is_call);
+
+            let difference = f64::from(spot) - f64::from(strike);
+            let expected = if is_call { difference } else { -difference }.max(0.0);
+            prop_assert!((f64::from(value.to::<u32>()) - expected).abs() < 0.5);
+        }
+
+        #[test]
+        fn prop_saturating_intrinsic_never_panics(
+            spot in any::<U256>(),
+            strike in any::<U256>(),
+        ) {
+            let call = saturating_intrinsic(spot, strike, true);
+            let put = saturating_intrinsic(spot, strike, false);
+
+            // At most one side is in the money, and it is worth the full difference
+            prop_assert!(call.is_zero() || put.is_zero());
+            prop_assert_eq!(call + put, spot.max(strike) - spot.min(strike));
+        }
+
+        #[test]
+        fn prop_unknown_interface_ids_are_unsupported(interface_id in any::<[u8; 4]>()) {
+            prop_assume!(interface_id != ERC165_INTERFACE_ID);
+
+            let contract = Contract::<Options>::default();
+            prop_assert!(!contract
+                .sender(Address::repeat_byte(0x01))
+                .supports_interface(FixedBytes(interface_id)));
+        }
+
+        #[test]
+        fn prop_series_collateral_equals_sum_of_positions(
+            operations in prop::collection::vec(
+                (0usize..3, any::<bool>(), 1u64..=1_000_000u64, 0u64..=2_000_000u64),
+                1..40,
+            ),
+        ) {
+            let contract = Contract::<Options>::default();
+            let writers = [
+                Address::repeat_byte(0x01),
+                Address::repeat_byte(0x02),
+                Address::repeat_byte(0x03),
+            ];
+            let token_id = B256::repeat_byte(0x84);
+
+            for (writer_index, is_write, quantity, collateral) in operations {
+                let writer = writers[writer_index];
+                if is_write {
+                    prop_assert!(contract
+                        .sender(writer)
+                        .create_or_update_position(
+                            writer,
+                            token_id,
+                            U256::from(quantity),
+                            U256::from(collateral),
+                        )
+                        .is_ok());
+                } else {
+                    let (quantity_written, _) =
+                        contract.sender(writer).get_position(writer, token_id);
+                    let reduction = U256::from(quantity).min(quantity_written);
+                    if !reduction.is_zero() {
+                        prop_assert!(contract
+                            .sender(writer)
+                            .reduce_position(writer, token_id, reduction, true)
+                            .is_ok());
+                    }
+                }
+
+                let sum = writers.iter().fold(U256::ZERO, |sum, &writer| {
+                    sum + contract.sender(writer).get_collateral_locked(writer, token_id)
+                });
+                prop_assert_eq!(contract.sender(writer).get_series_stats(token_id).1, sum);
+            }
+        }
+
+        #[test]
+        fn prop_assignment_is_pro_rata_and_solvent(
+            positions in prop::collection::vec(1u64..=1_000_000u64, 1..5),
+            fraction in 1u64..=1_000u64,
+            strike_value in 0u64..=u64::MAX,
+            holder_index in 0usize..5,
+        ) {
+            let contract = Contract::<Options>::default();
+            let writers: Vec<Address> = (0..positions.len())
+                .map(|i| Address::repeat_byte(u8::try_from(i + 1).unwrap_or(u8::MAX)))
+                .collect();
+            let holder = writers.get(holder_index).copied().unwrap_or(Address::repeat_byte(0xEE));
+            let token_id = B256::repeat_byte(0x85);
+
+            for (&writer, &quantity) in writers.iter().zip(&positions) {
+                prop_assert!(contract
+                    .sender(writer)
+                    .create_or_update_position(
+                        writer,
+                        token_id,
+                        U256::from(quantity),
+                        U256::from(quantity),
+                    )
+                    .is_ok());
+            }
+            let total: u64 = positions.iter().sum();
+            let quantity = U256::from((total * fraction).div_ceil(1_000));
+            let strike_value = U256::from(strike_value);
+
+            let result = contract
+                .sender(holder)
+                .assign_exercise(holder, token_id, quantity, strike_value, None);
+            prop_assert!(result.is_ok());
+            let (strike_owed, _) = result.unwrap_or_default();
+
+            let mut assigned = U256::ZERO;
+            let mut credited = U256::ZERO;
+            for (&writer, &written) in writers.iter().zip(&positions) {
+                let (remaining, _) = contract.sender(writer).get_position(writer, token_id);
+                prop_assert!(remaining <= U256::from(written));
+                assigned += U256::from(written) - remaining;
+                credited += contract.sender(writer).claimable_assignment(writer, token_id);
+            }
+            // Writers are assigned rounded up and credited rounded down, by under a wei
+            // each plus the rounding of the pool index
+            let dust = U256::from(writers.len() + 1);
+            prop_assert!(assigned >= quantity && assigned < quantity + dust);
+            prop_assert!(credited <= strike_owed && strike_owed <= credited + dust);
+            prop_assert!(strike_owed <= strike_value);
+            if !writers.contains(&holder) {
+                prop_assert_eq!(strike_owed, strike_value);
+            }
+        }
     }
 }
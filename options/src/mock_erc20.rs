@@ -1,5 +1,5 @@
 use alloc::collections::BTreeMap;
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, U256, U8};
 use stylus_sdk::prelude::*;
 
 #[derive(Default)]
@@ -158,3 +158,182 @@ impl FeeOnTransferERC20 {
         self.balances.insert(to, current_balance + amount);
     }
 }
+
+sol_storage! {
+    #[entrypoint]
+    pub struct StandardERC20 {
+        mapping(address => uint256) balances;
+        mapping(address => mapping(address => uint256)) allowances;
+        uint8 decimals;
+    }
+}
+
+#[public]
+impl StandardERC20 {
+    #[must_use]
+    pub fn balance_of(&self, account: Address) -> U256 {
+        self.balances.get(account)
+    }
+
+    pub fn transfer(&mut self, to: Address, amount: U256) -> bool {
+        let from = self.vm().msg_sender();
+        let sender_balance = self.balances.get(from);
+
+        if sender_balance < amount {
+            return false;
+        }
+
+        self.balances.insert(from, sender_balance - amount);
+        let recipient_balance = self.balances.get(to);
+        self.balances.insert(to, recipient_balance + amount);
+
+        true
+    }
+
+    pub fn transfer_from(&mut self, from: Address, to: Address, amount: U256) -> bool {
+        let spender = self.vm().msg_sender();
+        let allowance = self.allowances.getter(from).get(spender);
+
+        if allowance < amount {
+            return false;
+        }
+
+        let sender_balance = self.balances.get(from);
+        if sender_balance < amount {
+            return false;
+        }
+
+        self.balances.insert(from, sender_balance - amount);
+        let recipient_balance = self.balances.get(to);
+        self.balances.insert(to, recipient_balance + amount);
+
+        let mut allowance_setter = self.allowances.setter(from);
+        allowance_setter.insert(spender, allowance - amount);
+
+        true
+    }
+
+    pub fn approve(&mut self, spender: Address, amount: U256) {
+        let owner = self.vm().msg_sender();
+        let mut allowance_setter = self.allowances.setter(owner);
+        allowance_setter.insert(spender, amount);
+    }
+
+    pub fn mint(&mut self, to: Address, amount: U256) {
+        let current_balance = self.balances.get(to);
+        self.balances.insert(to, current_balance + amount);
+    }
+
+    #[must_use]
+    pub fn decimals(&self) -> u8 {
+        self.decimals.get().to::<u8>()
+    }
+
+    pub fn set_decimals(&mut self, decimals: u8) {
+        self.decimals.set(U8::from(decimals));
+    }
+}
+
+sol_storage! {
+    #[entrypoint]
+    pub struct MockOracle {
+        uint256 price;
+        uint256 updated_at;
+        uint8 answer_decimals;
+        bool answer_decimals_set;
+    }
+}
+
+#[public]
+impl MockOracle {
+    #[must_use]
+    pub fn latest_price(&self, base: Address, quote: Address) -> U256 {
+        let _ = (base, quote);
+        self.price.get()
+    }
+
+    #[must_use]
+    pub fn latest_timestamp(&self, base: Address, quote: Address) -> U256 {
+        let _ = (base, quote);
+        self.updated_at.get()
+    }
+
+    /// Decimals of `latest_price`, 18 until `set_decimals` is called.
+    #[must_use]
+    pub fn decimals(&self) -> u8 {
+        if self.answer_decimals_set.get() {
+            self.answer_decimals.get().to::<u8>()
+        } else {
+            18
+        }
+    }
+
+    pub fn set_decimals(&mut self, decimals: u8) {
+        self.answer_decimals.set(U8::from(decimals));
+        self.answer_decimals_set.set(true);
+    }
+
+    pub fn set_price(&mut self, price: U256) {
+        let now = self.vm().block_timestamp();
+        self.price.set(price);
+        self.updated_at.set(U256::from(now));
+    }
+
+    pub fn set_updated_at(&mut self, updated_at: U256) {
+        self.updated_at.set(updated_at);
+    }
+}
+
+sol_storage! {
+    #[entrypoint]
+    pub struct MockRouter {
+        uint256 swap_count;
+    }
+}
+
+#[public]
+impl MockRouter {
+    /// Pulls `amount_in` of `token_in` from the caller and pays `amount_out` of
+    /// `token_out` from the router's own balance.
+    #[allow(deprecated)]
+    pub fn swap(
+        &mut self,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        amount_out: U256,
+    ) -> Result<(), Vec<u8>> {
+        let (caller, router) = {
+            let vm = self.vm();
+            (vm.msg_sender(), vm.contract_address())
+        };
+
+        if !amount_in.is_zero()
+            && !crate::IERC20::new(token_in).transfer_from(
+                stylus_sdk::call::Call::new_in(self),
+                caller,
+                router,
+                amount_in,
+            )?
+        {
+            return Err(b"transferFrom failed".to_vec());
+        }
+        if !amount_out.is_zero()
+            && !crate::IERC20::new(token_out).transfer(
+                stylus_sdk::call::Call::new_in(self),
+                caller,
+                amount_out,
+            )?
+        {
+            return Err(b"transfer failed".to_vec());
+        }
+
+        self.swap_count.set(self.swap_count.get() + U256::from(1));
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn swap_count(&self) -> U256 {
+        self.swap_count.get()
+    }
+}
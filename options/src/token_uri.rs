@@ -0,0 +1,201 @@
+//! On-chain ERC-1155 metadata for option tokens.
+//!
+//! `uri` returns a `data:application/json;base64,` URI whose JSON is built from the
+//! stored series metadata, so wallets can display option tokens without an off-chain
+//! server. Everything here is `no_std` formatting over `alloc`.
+
+use alloc::{format, string::String, vec::Vec};
+use alloy_primitives::{Address, U256};
+
+use crate::{OptionMetadataView, OptionType};
+
+/// Prefix of the URIs returned by `uri`.
+pub(crate) const DATA_URI_PREFIX: &str = "data:application/json;base64,";
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encodes bytes as standard base64 (RFC 4648) with `=` padding.
+#[must_use]
+pub(crate) fn base64_encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied().unwrap_or(0);
+        let b2 = chunk.get(2).copied().unwrap_or(0);
+        let sextets = [
+            b0 >> 2,
+            ((b0 & 0x03) << 4) | (b1 >> 4),
+            ((b1 & 0x0f) << 2) | (b2 >> 6),
+            b2 & 0x3f,
+        ];
+
+        // A chunk of n bytes yields n + 1 significant characters
+        for (i, &sextet) in sextets.iter().enumerate() {
+            if i <= chunk.len() {
+                encoded.push(char::from(BASE64_ALPHABET[usize::from(sextet)]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Formats an unsigned integer in base 10.
+#[must_use]
+pub(crate) fn format_decimal(value: U256) -> String {
+    if value.is_zero() {
+        return String::from("0");
+    }
+
+    let ten = U256::from(10);
+    let mut digits = Vec::new();
+    let mut rest = value;
+    while !rest.is_zero() {
+        digits.push(b'0' + (rest % ten).to::<u8>());
+        rest /= ten;
+    }
+    digits.reverse();
+    digits.into_iter().map(char::from).collect()
+}
+
+/// Formats a fixed-point amount with `decimals` decimal places, without trailing zeros.
+///
+/// `format_units(1_500_000, 6)` is `"1.5"` and `format_units(2 * 10^18, 18)` is `"2"`.
+///
+/// # Parameters
+/// - `value`: Amount in units of `10^-decimals`
+/// - `decimals`: Number of decimal places (at most 77)
+#[must_use]
+pub(crate) fn format_units(value: U256, decimals: u8) -> String {
+    let scale = U256::from(10).pow(U256::from(decimals));
+    let whole = format_decimal(value / scale);
+    let fraction = value % scale;
+    if fraction.is_zero() {
+        return whole;
+    }
+
+    let digits = format_decimal(fraction);
+    let padding = "0".repeat(usize::from(decimals) - digits.len());
+    format!("{whole}.{padding}{}", digits.trim_end_matches('0'))
+}
+
+/// Formats an address as `0x`-prefixed lowercase hex.
+#[must_use]
+pub(crate) fn format_address(address: Address) -> String {
+    let mut formatted = String::with_capacity(42);
+    formatted.push_str("0x");
+    for byte in address.as_slice() {
+        formatted.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+        formatted.push(char::from(HEX_DIGITS[usize::from(byte & 0x0f)]));
+    }
+    formatted
+}
+
+/// Builds the ERC-1155 metadata JSON of an option series.
+///
+/// Balances are in 18 decimals, so `decimals` is always 18. The strike is shown in quote
+/// tokens per underlying token and the expiry as Unix seconds.
+///
+/// # Parameters
+/// - `metadata`: Stored metadata of a written series
+#[must_use]
+pub(crate) fn metadata_json(metadata: &OptionMetadataView) -> String {
+    let option_type = if metadata.option_type == OptionType::Put.to_u8() {
+        "PUT"
+    } else {
+        "CALL"
+    };
+    let underlying = format_address(metadata.underlying);
+    let quote = format_address(metadata.quote);
+    let strike = format_units(metadata.strike, 18);
+    let expiry = format_decimal(metadata.expiry);
+
+    format!(
+        concat!(
+            r#"{{"name":"{option_type} {underlying} @ {strike} exp {expiry}","#,
+            r#""decimals":18,"attributes":["#,
+            r#"{{"trait_type":"Option Type","value":"{option_type}"}},"#,
+            r#"{{"trait_type":"Strike","value":"{strike}"}},"#,
+            r#"{{"trait_type":"Expiry","display_type":"date","value":{expiry}}},"#,
+            r#"{{"trait_type":"Underlying","value":"{underlying}"}},"#,
+            r#"{{"trait_type":"Underlying Decimals","value":{underlying_decimals}}},"#,
+            r#"{{"trait_type":"Quote","value":"{quote}"}},"#,
+            r#"{{"trait_type":"Quote Decimals","value":{quote_decimals}}}]}}"#,
+        ),
+        option_type = option_type,
+        underlying = underlying,
+        strike = strike,
+        expiry = expiry,
+        underlying_decimals = metadata.underlying_decimals,
+        quote = quote,
+        quote_decimals = metadata.quote_decimals,
+    )
+}
+
+/// Builds the `data:` URI returned by `uri` for a written series.
+#[must_use]
+pub(crate) fn token_uri(metadata: &OptionMetadataView) -> String {
+    let json = metadata_json(metadata);
+    let mut uri = String::from(DATA_URI_PREFIX);
+    uri.push_str(&base64_encode(json.as_bytes()));
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode_rfc4648_vectors() {
+        for (input, expected) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(base64_encode(input.as_bytes()), expected);
+        }
+        assert_eq!(base64_encode(&[0xfb, 0xff]), "+/8=");
+    }
+
+    #[test]
+    fn test_format_decimal() {
+        assert_eq!(format_decimal(U256::ZERO), "0");
+        assert_eq!(format_decimal(U256::from(7)), "7");
+        assert_eq!(format_decimal(U256::from(2_000_000_000u64)), "2000000000");
+        assert_eq!(
+            format_decimal(U256::MAX),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+    }
+
+    #[test]
+    fn test_format_units() {
+        let one = U256::from(10).pow(U256::from(18));
+        assert_eq!(format_units(U256::from(60_000) * one, 18), "60000");
+        assert_eq!(format_units(one / U256::from(4), 18), "0.25");
+        assert_eq!(format_units(U256::from(1), 18), "0.000000000000000001");
+        assert_eq!(format_units(U256::from(1_500_000), 6), "1.5");
+        assert_eq!(format_units(U256::ZERO, 18), "0");
+        assert_eq!(format_units(U256::from(42), 0), "42");
+    }
+
+    #[test]
+    fn test_format_address() {
+        assert_eq!(
+            format_address(Address::new([0xAB; 20])),
+            "0xabababababababababababababababababababab"
+        );
+        assert_eq!(
+            format_address(Address::ZERO),
+            "0x0000000000000000000000000000000000000000"
+        );
+    }
+}
@@ -0,0 +1,395 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{ExerciseAssigned, Options, OptionsError, Token, WithdrawalBeforeSettlement};
+use stylus_sdk::abi::Bytes;
+use test_erc20::{expiry, TestERC20};
+
+const ALICE: Address = Address::new([0xAA; 20]);
+const BOB: Address = Address::new([0xBB; 20]);
+const CAROL: Address = Address::new([0xCC; 20]);
+
+fn no_data() -> Bytes {
+    Vec::<u8>::new().into()
+}
+
+fn normalized(quantity: u64) -> U256 {
+    U256::from(quantity) * U256::from(10).pow(U256::from(10))
+}
+
+/// Writes `quantity` WBTC (8 decimals) of 60,000 calls as `writer`.
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    writer: Address,
+    quantity: u64,
+) -> B256 {
+    let quantity = U256::from(quantity);
+    underlying_token.sender(writer).mint(writer, quantity);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    contract
+        .sender(writer)
+        .write_call_option(
+            U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap()
+}
+
+fn fund_quote(contract: &Contract<Options>, quote_token: &Contract<TestERC20>, amount: u64) {
+    let amount = U256::from(amount);
+    quote_token.sender(CAROL).mint(CAROL, amount);
+    quote_token
+        .sender(CAROL)
+        .approve(contract.address(), amount);
+}
+
+#[motsu::test]
+fn exercise_is_assigned_pro_rata_across_writers(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        ALICE,
+        100_000_000,
+    );
+    write(&contract, &underlying_token, &quote_token, BOB, 300_000_000);
+    contract
+        .sender(ALICE)
+        .safe_transfer_from(ALICE, CAROL, token_id, normalized(100_000_000), no_data())
+        .unwrap();
+
+    // 0.4 WBTC at 60,000 costs 24,000 USDC: a quarter assigned to Alice, the rest to Bob
+    fund_quote(&contract, &quote_token, 24_000_000_000);
+    contract
+        .sender(CAROL)
+        .exercise_call(token_id, U256::from(40_000_000))
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(ALICE).get_position(ALICE, token_id),
+        (normalized(90_000_000), normalized(90_000_000))
+    );
+    assert_eq!(
+        contract.sender(BOB).get_position(BOB, token_id),
+        (normalized(270_000_000), normalized(270_000_000))
+    );
+    assert_eq!(
+        contract.sender(ALICE).writer_exercised(ALICE, token_id),
+        normalized(10_000_000)
+    );
+    assert_eq!(
+        contract.sender(BOB).writer_exercised(BOB, token_id),
+        normalized(30_000_000)
+    );
+
+    assert_eq!(
+        contract.sender(ALICE).claimable_assignment(ALICE, token_id),
+        U256::from(6_000_000_000u64)
+    );
+    assert_eq!(
+        contract.sender(BOB).claimable_assignment(BOB, token_id),
+        U256::from(18_000_000_000u64)
+    );
+    assert_eq!(quote_token.sender(CAROL).balance_of(CAROL), U256::ZERO);
+    assert_eq!(
+        quote_token.sender(CAROL).balance_of(contract.address()),
+        U256::from(24_000_000_000u64)
+    );
+    assert_eq!(
+        contract
+            .sender(CAROL)
+            .locked_collateral(quote_token.address()),
+        U256::from(24_000_000_000u64)
+    );
+    assert_eq!(
+        underlying_token.sender(CAROL).balance_of(CAROL),
+        U256::from(40_000_000)
+    );
+
+    contract
+        .sender(CAROL)
+        .settle_assignment(ALICE, token_id)
+        .unwrap();
+    contract
+        .sender(CAROL)
+        .settle_assignment(BOB, token_id)
+        .unwrap();
+
+    contract.assert_emitted(&ExerciseAssigned {
+        writer: ALICE,
+        tokenId: token_id,
+        quantity: normalized(10_000_000),
+        strikeCredited: U256::from(6_000_000_000u64),
+        collateralCredited: U256::ZERO,
+    });
+    contract.assert_emitted(&ExerciseAssigned {
+        writer: BOB,
+        tokenId: token_id,
+        quantity: normalized(30_000_000),
+        strikeCredited: U256::from(18_000_000_000u64),
+        collateralCredited: U256::ZERO,
+    });
+    assert_eq!(
+        contract.sender(ALICE).get_position(ALICE, token_id),
+        (normalized(90_000_000), normalized(90_000_000))
+    );
+    assert_eq!(
+        contract.sender(BOB).claimable_assignment(BOB, token_id),
+        U256::from(18_000_000_000u64)
+    );
+}
+
+#[motsu::test]
+fn assignment_is_settled_when_writer_writes_again(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        ALICE,
+        100_000_000,
+    );
+    write(&contract, &underlying_token, &quote_token, BOB, 100_000_000);
+    contract
+        .sender(ALICE)
+        .safe_transfer_from(ALICE, CAROL, token_id, normalized(100_000_000), no_data())
+        .unwrap();
+
+    fund_quote(&contract, &quote_token, 60_000_000_000);
+    contract
+        .sender(CAROL)
+        .exercise_call(token_id, U256::from(50_000_000))
+        .unwrap();
+    write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        ALICE,
+        100_000_000,
+    );
+
+    contract.assert_emitted(&ExerciseAssigned {
+        writer: ALICE,
+        tokenId: token_id,
+        quantity: normalized(25_000_000),
+        strikeCredited: U256::from(15_000_000_000u64),
+        collateralCredited: U256::ZERO,
+    });
+    assert_eq!(
+        contract.sender(ALICE).get_position(ALICE, token_id),
+        (normalized(175_000_000), normalized(175_000_000))
+    );
+
+    // Alice now holds 1.75 of 2.5 WBTC written. Her new pool units were minted rounded
+    // down, so she is assigned a wei more and credited a micro-USDC less than 70%
+    contract
+        .sender(CAROL)
+        .exercise_call(token_id, U256::from(50_000_000))
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(ALICE).get_position(ALICE, token_id).0,
+        U256::from(1_399_999_999_999_999_999u64)
+    );
+    assert_eq!(
+        contract.sender(ALICE).claimable_assignment(ALICE, token_id),
+        U256::from(35_999_999_999u64)
+    );
+    assert_eq!(
+        contract.sender(BOB).get_position(BOB, token_id),
+        (normalized(60_000_000), normalized(60_000_000))
+    );
+    assert_eq!(
+        contract.sender(BOB).claimable_assignment(BOB, token_id),
+        U256::from(24_000_000_000u64)
+    );
+    assert_eq!(
+        contract
+            .sender(CAROL)
+            .locked_collateral(quote_token.address()),
+        U256::from(60_000_000_000u64)
+    );
+}
+
+#[motsu::test]
+fn write_after_full_exercise_starts_new_pool(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        ALICE,
+        100_000_000,
+    );
+    contract
+        .sender(ALICE)
+        .safe_transfer_from(ALICE, CAROL, token_id, normalized(100_000_000), no_data())
+        .unwrap();
+    fund_quote(&contract, &quote_token, 90_000_000_000);
+    contract
+        .sender(CAROL)
+        .exercise_call(token_id, U256::from(100_000_000))
+        .unwrap();
+
+    write(&contract, &underlying_token, &quote_token, BOB, 100_000_000);
+    contract
+        .sender(BOB)
+        .safe_transfer_from(BOB, CAROL, token_id, normalized(100_000_000), no_data())
+        .unwrap();
+    contract
+        .sender(CAROL)
+        .exercise_call(token_id, U256::from(50_000_000))
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(ALICE).get_position(ALICE, token_id),
+        (U256::ZERO, U256::ZERO)
+    );
+    assert_eq!(
+        contract.sender(ALICE).claimable_assignment(ALICE, token_id),
+        U256::from(60_000_000_000u64)
+    );
+    assert_eq!(
+        contract.sender(BOB).get_position(BOB, token_id),
+        (normalized(50_000_000), normalized(50_000_000))
+    );
+    assert_eq!(
+        contract.sender(BOB).claimable_assignment(BOB, token_id),
+        U256::from(30_000_000_000u64)
+    );
+}
+
+#[motsu::test]
+fn writer_pays_only_other_writers_share(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        ALICE,
+        100_000_000,
+    );
+    write(&contract, &underlying_token, &quote_token, BOB, 100_000_000);
+
+    // Alice exercises 0.5 WBTC; her own half is free, Bob's 0.25 WBTC costs 15,000 USDC
+    let strike_owed = U256::from(15_000_000_000u64);
+    quote_token.sender(ALICE).mint(ALICE, strike_owed);
+    quote_token
+        .sender(ALICE)
+        .approve(contract.address(), strike_owed);
+    contract
+        .sender(ALICE)
+        .exercise_call(token_id, U256::from(50_000_000))
+        .unwrap();
+
+    assert_eq!(quote_token.sender(ALICE).balance_of(ALICE), U256::ZERO);
+    assert_eq!(
+        contract.sender(ALICE).claimable_assignment(ALICE, token_id),
+        U256::ZERO
+    );
+    assert_eq!(
+        contract.sender(BOB).claimable_assignment(BOB, token_id),
+        strike_owed
+    );
+    assert_eq!(
+        contract.sender(ALICE).get_position(ALICE, token_id).0,
+        normalized(75_000_000)
+    );
+    assert_eq!(
+        contract.sender(BOB).get_position(BOB, token_id).0,
+        normalized(75_000_000)
+    );
+}
+
+#[motsu::test]
+fn exercise_without_strike_payment_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        ALICE,
+        100_000_000,
+    );
+    contract
+        .sender(ALICE)
+        .safe_transfer_from(ALICE, CAROL, token_id, normalized(100_000_000), no_data())
+        .unwrap();
+
+    let result = contract
+        .sender(CAROL)
+        .exercise_call(token_id, U256::from(10_000_000));
+
+    assert!(matches!(result, Err(OptionsError::TransferFailed(_))));
+}
+
+#[motsu::test]
+fn claim_before_expiry_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        ALICE,
+        100_000_000,
+    );
+    contract
+        .sender(ALICE)
+        .safe_transfer_from(ALICE, CAROL, token_id, normalized(100_000_000), no_data())
+        .unwrap();
+    fund_quote(&contract, &quote_token, 6_000_000_000);
+    contract
+        .sender(CAROL)
+        .exercise_call(token_id, U256::from(10_000_000))
+        .unwrap();
+
+    let result = contract.sender(ALICE).claim_assignment(token_id);
+
+    assert!(matches!(
+        result,
+        Err(OptionsError::WithdrawalBeforeSettlement(WithdrawalBeforeSettlement {
+            available_at,
+            ..
+        })) if available_at == U256::from(expiry())
+    ));
+    assert_eq!(
+        contract.sender(ALICE).claimable_assignment(ALICE, token_id),
+        U256::from(6_000_000_000u64)
+    );
+}
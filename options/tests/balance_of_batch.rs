@@ -0,0 +1,84 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+#[motsu::test]
+fn duplicate_pairs_are_returned_positionally(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let other = Address::from([0xBB; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+    let unknown_id = B256::from([0xFF; 32]);
+
+    let balances = contract
+        .sender(writer)
+        .balance_of_batch(
+            vec![writer, other, writer, writer, other],
+            vec![token_id, token_id, token_id, unknown_id, token_id],
+        )
+        .unwrap();
+
+    let normalized_quantity = write_quantity * U256::from(10).pow(U256::from(10));
+    assert_eq!(
+        balances,
+        vec![
+            normalized_quantity,
+            U256::ZERO,
+            normalized_quantity,
+            U256::ZERO,
+            U256::ZERO,
+        ]
+    );
+}
+
+#[motsu::test]
+fn empty_batch_returns_empty(contract: Contract<Options>) {
+    let alice = Address::from([0xAA; 20]);
+
+    let balances = contract
+        .sender(alice)
+        .balance_of_batch(vec![], vec![])
+        .unwrap();
+
+    assert!(balances.is_empty());
+}
+
+#[motsu::test]
+fn length_mismatch_fails(contract: Contract<Options>) {
+    let alice = Address::from([0xAA; 20]);
+
+    let result = contract.sender(alice).balance_of_batch(vec![alice], vec![]);
+
+    assert!(matches!(result, Err(OptionsError::BatchLengthMismatch(_))));
+}
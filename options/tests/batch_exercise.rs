@@ -0,0 +1,255 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+fn strike(price: u64) -> U256 {
+    U256::from(price) * U256::from(10).pow(U256::from(18))
+}
+
+fn normalized(quantity: u64) -> U256 {
+    U256::from(quantity) * U256::from(10).pow(U256::from(10))
+}
+
+fn write_series(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    writer: Address,
+    prices: &[u64],
+    quantity: U256,
+) -> Vec<B256> {
+    let total = quantity * U256::from(prices.len());
+    underlying_token.sender(writer).mint(writer, total);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), total);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    prices
+        .iter()
+        .map(|&price| {
+            contract
+                .sender(writer)
+                .write_call_option(strike(price), expiry(), quantity, underlying, quote)
+                .unwrap()
+        })
+        .collect()
+}
+
+#[motsu::test]
+fn exercises_several_series_in_one_call(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let quantity = U256::from(100_000_000);
+    let token_ids = write_series(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        writer,
+        &[50_000, 60_000, 70_000],
+        quantity,
+    );
+
+    let quantities = vec![
+        U256::from(10_000_000),
+        U256::from(20_000_000),
+        U256::from(30_000_000),
+    ];
+    contract
+        .sender(writer)
+        .batch_exercise(token_ids.clone(), quantities.clone())
+        .unwrap();
+
+    let total: U256 = quantities.iter().copied().sum();
+    assert_eq!(underlying_token.sender(writer).balance_of(writer), total);
+    assert_eq!(
+        underlying_token
+            .sender(writer)
+            .balance_of(contract.address()),
+        quantity * U256::from(3) - total
+    );
+
+    for (&token_id, &exercised) in token_ids.iter().zip(&quantities) {
+        let remaining = (quantity - exercised) * U256::from(10).pow(U256::from(10));
+        assert_eq!(
+            contract.sender(writer).balance_of(writer, token_id),
+            remaining
+        );
+        assert_eq!(
+            contract.sender(writer).get_position(writer, token_id),
+            (remaining, remaining)
+        );
+        assert_eq!(
+            contract.sender(writer).total_exercised(token_id),
+            quantity * U256::from(10).pow(U256::from(10)) - remaining
+        );
+    }
+}
+
+#[motsu::test]
+fn duplicate_token_ids_accumulate(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let quantity = U256::from(100_000_000);
+    let token_id = write_series(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        writer,
+        &[60_000],
+        quantity,
+    )[0];
+
+    contract
+        .sender(writer)
+        .batch_exercise(
+            vec![token_id, token_id],
+            vec![U256::from(25_000_000), U256::from(35_000_000)],
+        )
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        U256::from(60_000_000)
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, token_id),
+        normalized(40_000_000)
+    );
+    assert_eq!(
+        contract.sender(writer).total_exercised(token_id),
+        normalized(60_000_000)
+    );
+}
+
+#[motsu::test]
+fn duplicate_token_ids_exceeding_balance_fail(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xCC; 20]);
+    let quantity = U256::from(100_000_000);
+    let token_id = write_series(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        writer,
+        &[60_000],
+        quantity,
+    )[0];
+
+    let result = contract.sender(writer).batch_exercise(
+        vec![token_id, token_id],
+        vec![U256::from(60_000_000), U256::from(60_000_000)],
+    );
+
+    assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn failing_leg_reverts_whole_batch(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xDD; 20]);
+    let quantity = U256::from(100_000_000);
+    let token_ids = write_series(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        writer,
+        &[50_000, 60_000],
+        quantity,
+    );
+
+    let result = contract.sender(writer).batch_exercise(
+        token_ids,
+        vec![U256::from(10_000_000), quantity + U256::from(1)],
+    );
+
+    assert!(result.is_err());
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        U256::ZERO
+    );
+    assert_eq!(
+        underlying_token
+            .sender(writer)
+            .balance_of(contract.address()),
+        quantity * U256::from(2)
+    );
+}
+
+#[motsu::test]
+fn unknown_token_id_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xEE; 20]);
+    let quantity = U256::from(100_000_000);
+    let token_id = write_series(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        writer,
+        &[60_000],
+        quantity,
+    )[0];
+
+    let result = contract.sender(writer).batch_exercise(
+        vec![token_id, B256::from([0xFF; 32])],
+        vec![U256::from(10_000_000), U256::from(10_000_000)],
+    );
+
+    assert!(result.is_err());
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn length_mismatch_fails(contract: Contract<Options>) {
+    let alice = Address::from([0x11; 20]);
+
+    let result = contract
+        .sender(alice)
+        .batch_exercise(vec![B256::from([0x01; 32])], vec![]);
+
+    assert!(matches!(result, Err(OptionsError::BatchLengthMismatch(_))));
+}
+
+#[motsu::test]
+fn empty_batch_fails(contract: Contract<Options>) {
+    let alice = Address::from([0x11; 20]);
+
+    let result = contract.sender(alice).batch_exercise(vec![], vec![]);
+
+    assert!(matches!(result, Err(OptionsError::InvalidBatchSize(_))));
+}
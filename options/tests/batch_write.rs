@@ -0,0 +1,235 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token, MAX_BATCH_SIZE};
+use test_erc20::{expiry, TestERC20};
+
+fn strike(price: u64) -> U256 {
+    U256::from(price) * U256::from(10).pow(U256::from(18))
+}
+
+#[motsu::test]
+fn five_strike_ladder_in_one_call(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+
+    let quantities: Vec<U256> = [
+        10_000_000u64,
+        20_000_000,
+        30_000_000,
+        40_000_000,
+        50_000_000,
+    ]
+    .into_iter()
+    .map(U256::from)
+    .collect();
+    let total: U256 = quantities.iter().copied().sum();
+    underlying_token.sender(writer).mint(writer, total);
+    underlying_token.sender(writer).approve(options_addr, total);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strikes = vec![
+        strike(50_000),
+        strike(55_000),
+        strike(60_000),
+        strike(65_000),
+        strike(70_000),
+    ];
+
+    let token_ids = contract
+        .sender(writer)
+        .batch_write_options(
+            strikes,
+            vec![expiry(); 5],
+            quantities.clone(),
+            underlying,
+            quote,
+            vec![0; 5],
+        )
+        .unwrap();
+
+    assert_eq!(token_ids.len(), 5);
+    assert_eq!(contract.sender(writer).series_count(), U256::from(5));
+    for (token_id, quantity) in token_ids.iter().zip(&quantities) {
+        let normalized = *quantity * U256::from(10).pow(U256::from(10));
+        assert_eq!(
+            contract.sender(writer).balance_of(writer, *token_id),
+            normalized
+        );
+        assert_eq!(
+            contract.sender(writer).get_position(writer, *token_id),
+            (normalized, normalized)
+        );
+    }
+}
+
+#[motsu::test]
+fn aggregate_transfer_equals_sum_of_collateral(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let options_addr = contract.address();
+
+    let minted = U256::from(1_000_000_000);
+    underlying_token.sender(writer).mint(writer, minted);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, minted);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let quantities = vec![
+        U256::from(12_345_678),
+        U256::from(100_000_000),
+        U256::from(1),
+    ];
+    let total: U256 = quantities.iter().copied().sum();
+
+    contract
+        .sender(writer)
+        .batch_write_options(
+            vec![strike(50_000), strike(60_000), strike(60_000)],
+            vec![expiry(), expiry(), expiry() + 86_400],
+            quantities,
+            underlying,
+            quote,
+            vec![0; 3],
+        )
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(options_addr),
+        total
+    );
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        minted - total
+    );
+}
+
+#[motsu::test]
+fn one_bad_entry_reverts_whole_batch(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xCC; 20]);
+    let options_addr = contract.address();
+
+    let minted = U256::from(1_000_000_000);
+    underlying_token.sender(writer).mint(writer, minted);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, minted);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let result = contract.sender(writer).batch_write_options(
+        vec![strike(50_000), U256::ZERO, strike(60_000)],
+        vec![expiry(); 3],
+        vec![U256::from(10_000_000); 3],
+        underlying,
+        quote,
+        vec![0; 3],
+    );
+
+    assert!(matches!(result, Err(OptionsError::InvalidStrike(_))));
+    assert_eq!(underlying_token.sender(writer).balance_of(writer), minted);
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(options_addr),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn mismatched_lengths_fail(contract: Contract<Options>) {
+    let writer = Address::from([0xDD; 20]);
+    let underlying = Token {
+        address: Address::from([0x11; 20]),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: Address::from([0x55; 20]),
+        decimals: 6,
+    };
+
+    let result = contract.sender(writer).batch_write_options(
+        vec![strike(50_000), strike(60_000)],
+        vec![expiry()],
+        vec![U256::from(10_000_000); 2],
+        underlying,
+        quote,
+        vec![0; 2],
+    );
+
+    assert!(matches!(result, Err(OptionsError::BatchLengthMismatch(_))));
+}
+
+#[motsu::test]
+fn empty_and_oversized_batches_fail(contract: Contract<Options>) {
+    let writer = Address::from([0xEE; 20]);
+    let underlying = Token {
+        address: Address::from([0x11; 20]),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: Address::from([0x66; 20]),
+        decimals: 6,
+    };
+
+    let result = contract.sender(writer).batch_write_options(
+        vec![],
+        vec![],
+        vec![],
+        underlying,
+        quote,
+        vec![],
+    );
+    assert!(matches!(result, Err(OptionsError::InvalidBatchSize(_))));
+
+    let size = MAX_BATCH_SIZE + 1;
+    let result = contract.sender(writer).batch_write_options(
+        vec![strike(50_000); size],
+        vec![expiry(); size],
+        vec![U256::from(10_000_000); size],
+        underlying,
+        quote,
+        vec![0; size],
+    );
+    assert!(matches!(result, Err(OptionsError::InvalidBatchSize(_))));
+}
@@ -0,0 +1,361 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use stylus_sdk::abi::Bytes;
+use test_erc20::{expiry, TestERC20};
+
+fn strike(price: u64) -> U256 {
+    U256::from(price) * U256::from(10).pow(U256::from(18))
+}
+
+fn normalized(quantity: u64) -> U256 {
+    U256::from(quantity) * U256::from(10).pow(U256::from(10))
+}
+
+fn no_data() -> Bytes {
+    Vec::<u8>::new().into()
+}
+
+/// Writes a 1 WBTC long call at 70,000 and a 0.1 WBTC covered call at 60,000
+/// so both series exist, then funds the writer with quote for spread collateral.
+fn setup(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    writer: Address,
+) -> (B256, B256, Token, Token) {
+    let options_addr = contract.address();
+
+    underlying_token
+        .sender(writer)
+        .mint(writer, U256::from(110_000_000));
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, U256::from(110_000_000));
+    quote_token
+        .sender(writer)
+        .mint(writer, U256::from(100_000_000_000u64));
+    quote_token
+        .sender(writer)
+        .approve(options_addr, U256::from(100_000_000_000u64));
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let long_id = contract
+        .sender(writer)
+        .write_call_option(
+            strike(70_000),
+            expiry(),
+            U256::from(100_000_000),
+            underlying,
+            quote,
+        )
+        .unwrap();
+    let short_id = contract
+        .sender(writer)
+        .write_call_option(
+            strike(60_000),
+            expiry(),
+            U256::from(10_000_000),
+            underlying,
+            quote,
+        )
+        .unwrap();
+
+    (short_id, long_id, underlying, quote)
+}
+
+#[motsu::test]
+fn spread_locks_max_loss_in_quote_and_escrows_long(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+    let (short_id, long_id, _, _) = setup(&contract, &underlying_token, &quote_token, writer);
+
+    let spread_id = contract
+        .sender(writer)
+        .write_call_spread(short_id, long_id, U256::from(50_000_000))
+        .unwrap();
+
+    // (70,000 - 60,000) * 0.5 WBTC = 5,000 USDC
+    let collateral = U256::from(5_000_000_000u64);
+    assert_eq!(
+        quote_token.sender(writer).balance_of(options_addr),
+        collateral
+    );
+    assert_ne!(spread_id, short_id);
+    assert_eq!(
+        contract.sender(writer).get_spread_long_token_id(spread_id),
+        long_id
+    );
+    assert_eq!(
+        contract.sender(writer).get_position(writer, spread_id),
+        (
+            normalized(50_000_000),
+            collateral * U256::from(10).pow(U256::from(12))
+        )
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, spread_id),
+        normalized(50_000_000)
+    );
+    // The fully collateralized short series is untouched
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, short_id),
+        normalized(10_000_000)
+    );
+    assert_eq!(
+        contract.sender(writer).get_position(writer, short_id),
+        (normalized(10_000_000), normalized(10_000_000))
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, long_id),
+        normalized(50_000_000)
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(options_addr, long_id),
+        normalized(50_000_000)
+    );
+    // No additional underlying was locked for the spread
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(options_addr),
+        U256::from(110_000_000)
+    );
+}
+
+#[motsu::test]
+fn holder_exercises_spread_against_its_escrow(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let holder = Address::from([0xB1; 20]);
+    let options_addr = contract.address();
+    let (short_id, long_id, _, _) = setup(&contract, &underlying_token, &quote_token, writer);
+
+    let spread_id = contract
+        .sender(writer)
+        .write_call_spread(short_id, long_id, U256::from(50_000_000))
+        .unwrap();
+    contract
+        .sender(writer)
+        .safe_transfer_from(writer, holder, spread_id, normalized(40_000_000), no_data())
+        .unwrap();
+
+    // 70,000 * 0.4 WBTC for the long leg, less the 4,000 USDC of spread collateral
+    let strike_owed = U256::from(24_000_000_000u64);
+    quote_token.sender(holder).mint(holder, strike_owed);
+    quote_token
+        .sender(holder)
+        .approve(options_addr, strike_owed);
+
+    contract
+        .sender(holder)
+        .exercise_call(spread_id, U256::from(40_000_000))
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(holder).balance_of(holder),
+        U256::from(40_000_000)
+    );
+    assert_eq!(quote_token.sender(holder).balance_of(holder), U256::ZERO);
+    assert_eq!(
+        contract.sender(writer).get_position(writer, spread_id),
+        (
+            normalized(10_000_000),
+            U256::from(1_000_000_000u64) * U256::from(10).pow(U256::from(12))
+        )
+    );
+
+    // The escrowed long calls were exercised against the long writer
+    assert_eq!(
+        contract.sender(writer).get_position(writer, long_id),
+        (normalized(60_000_000), normalized(60_000_000))
+    );
+    assert_eq!(
+        contract
+            .sender(writer)
+            .claimable_assignment(writer, long_id),
+        U256::from(28_000_000_000u64)
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(options_addr, long_id),
+        normalized(10_000_000)
+    );
+    assert_eq!(
+        contract.sender(writer).total_exercised(long_id),
+        normalized(40_000_000)
+    );
+    assert_eq!(
+        contract.sender(writer).total_exercised(spread_id),
+        normalized(40_000_000)
+    );
+
+    // Fully collateralized writers of the short series are not assigned
+    assert_eq!(
+        contract.sender(writer).get_position(writer, short_id),
+        (normalized(10_000_000), normalized(10_000_000))
+    );
+
+    // The remaining spread collateral and the long writer's strike are fully backed
+    assert_eq!(
+        contract
+            .sender(writer)
+            .locked_collateral(quote_token.address()),
+        U256::from(29_000_000_000u64)
+    );
+    assert_eq!(
+        quote_token.sender(writer).balance_of(options_addr),
+        U256::from(29_000_000_000u64)
+    );
+}
+
+#[motsu::test]
+fn closing_spread_returns_collateral_and_long(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBC; 20]);
+    let options_addr = contract.address();
+    let (short_id, long_id, _, _) = setup(&contract, &underlying_token, &quote_token, writer);
+
+    let spread_id = contract
+        .sender(writer)
+        .write_call_spread(short_id, long_id, U256::from(50_000_000))
+        .unwrap();
+    let quote_before = quote_token.sender(writer).balance_of(writer);
+
+    contract
+        .sender(writer)
+        .close_position(spread_id, U256::from(20_000_000))
+        .unwrap();
+
+    assert_eq!(
+        quote_token.sender(writer).balance_of(writer) - quote_before,
+        U256::from(2_000_000_000u64)
+    );
+    assert_eq!(
+        contract.sender(writer).get_position(writer, spread_id),
+        (
+            normalized(30_000_000),
+            U256::from(3_000_000_000u64) * U256::from(10).pow(U256::from(12))
+        )
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, long_id),
+        normalized(70_000_000)
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(options_addr, long_id),
+        normalized(30_000_000)
+    );
+    // The spread's underlying is not released
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(options_addr),
+        U256::from(110_000_000)
+    );
+}
+
+#[motsu::test]
+fn spread_cannot_be_a_spread_leg(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBD; 20]);
+    let (short_id, long_id, _, _) = setup(&contract, &underlying_token, &quote_token, writer);
+
+    let spread_id = contract
+        .sender(writer)
+        .write_call_spread(short_id, long_id, U256::from(20_000_000))
+        .unwrap();
+
+    let result =
+        contract
+            .sender(writer)
+            .write_call_spread(spread_id, long_id, U256::from(10_000_000));
+    assert!(matches!(result, Err(OptionsError::InvalidSpread(_))));
+}
+
+#[motsu::test]
+fn spread_with_mismatched_expiry_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xCC; 20]);
+    let (short_id, _, underlying, quote) =
+        setup(&contract, &underlying_token, &quote_token, writer);
+
+    underlying_token
+        .sender(writer)
+        .mint(writer, U256::from(100_000_000));
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), U256::from(100_000_000));
+    let later_long_id = contract
+        .sender(writer)
+        .write_call_option(
+            strike(70_000),
+            expiry() + 86_400,
+            U256::from(100_000_000),
+            underlying,
+            quote,
+        )
+        .unwrap();
+
+    let result =
+        contract
+            .sender(writer)
+            .write_call_spread(short_id, later_long_id, U256::from(50_000_000));
+    assert!(matches!(result, Err(OptionsError::InvalidSpread(_))));
+}
+
+#[motsu::test]
+fn spread_with_lower_long_strike_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xDD; 20]);
+    let (short_id, long_id, _, _) = setup(&contract, &underlying_token, &quote_token, writer);
+
+    // Selling the 70,000 call against the 60,000 call is not a bounded-risk spread
+    let result =
+        contract
+            .sender(writer)
+            .write_call_spread(long_id, short_id, U256::from(5_000_000));
+    assert!(matches!(result, Err(OptionsError::InvalidSpread(_))));
+}
+
+#[motsu::test]
+fn spread_without_enough_long_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xEE; 20]);
+    let (short_id, long_id, _, _) = setup(&contract, &underlying_token, &quote_token, writer);
+
+    let result =
+        contract
+            .sender(writer)
+            .write_call_spread(short_id, long_id, U256::from(100_000_001));
+    assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+}
@@ -0,0 +1,223 @@
+mod test_erc20;
+mod test_oracle;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token, WrongSettlementType};
+use test_erc20::{expiry, TestERC20};
+use test_oracle::TestOracle;
+
+const WRITER: Address = Address::new([0xAA; 20]);
+/// One option in the underlying's native (8) decimals
+const ONE_OPTION: u64 = 100_000_000;
+/// The 20k cap of one option in the quote's native (6) decimals
+const CAP_COLLATERAL: u64 = 20_000_000_000;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn tokens(
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+) -> (Token, Token) {
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+    (
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: quote_token.address(),
+            decimals: 6,
+        },
+    )
+}
+
+/// Writes one cash call at strike 60k capped at 20k.
+fn write_cash_call(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    oracle: &Contract<TestOracle>,
+) -> B256 {
+    let (underlying, quote) = tokens(underlying_token, quote_token);
+    let collateral = U256::from(CAP_COLLATERAL);
+    quote_token.sender(WRITER).mint(WRITER, collateral);
+    quote_token
+        .sender(WRITER)
+        .approve(contract.address(), collateral);
+
+    contract
+        .sender(WRITER)
+        .write_cash_call_option(
+            U256::from(60_000) * one(),
+            U256::from(20_000) * one(),
+            expiry(),
+            U256::from(ONE_OPTION),
+            underlying,
+            quote,
+            oracle.address(),
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn cash_call_locks_capped_quote_collateral(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let token_id = write_cash_call(&contract, &underlying_token, &quote_token, &oracle);
+
+    assert_eq!(
+        quote_token.sender(WRITER).balance_of(contract.address()),
+        U256::from(CAP_COLLATERAL)
+    );
+    assert_eq!(contract.sender(WRITER).balance_of(WRITER, token_id), one());
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .max_writer_obligation(WRITER, token_id)
+            .unwrap(),
+        U256::from(CAP_COLLATERAL)
+    );
+}
+
+#[motsu::test]
+fn cash_call_is_a_distinct_series(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let cash_token_id = write_cash_call(&contract, &underlying_token, &quote_token, &oracle);
+
+    let (underlying, quote) = tokens(&underlying_token, &quote_token);
+    underlying_token
+        .sender(WRITER)
+        .mint(WRITER, U256::from(ONE_OPTION));
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), U256::from(ONE_OPTION));
+    let physical_token_id = contract
+        .sender(WRITER)
+        .write_call_option(
+            U256::from(60_000) * one(),
+            expiry(),
+            U256::from(ONE_OPTION),
+            underlying,
+            quote,
+        )
+        .unwrap();
+
+    assert_ne!(cash_token_id, physical_token_id);
+    assert_eq!(contract.sender(WRITER).series_count(), U256::from(2));
+}
+
+#[motsu::test]
+fn cash_call_requires_settlement_price(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let token_id = write_cash_call(&contract, &underlying_token, &quote_token, &oracle);
+
+    let result = contract
+        .sender(WRITER)
+        .exercise_settled_call(token_id, U256::from(ONE_OPTION));
+    assert!(matches!(
+        result,
+        Err(OptionsError::SettlementPriceRequired(_))
+    ));
+
+    let result = contract
+        .sender(WRITER)
+        .withdraw_settled_collateral(token_id);
+    assert!(matches!(
+        result,
+        Err(OptionsError::SettlementPriceRequired(_))
+    ));
+}
+
+#[motsu::test]
+fn cash_call_cannot_settle_before_expiry(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let token_id = write_cash_call(&contract, &underlying_token, &quote_token, &oracle);
+    oracle.sender(WRITER).set_price(U256::from(70_000) * one());
+
+    let result = contract.sender(WRITER).settle_series(token_id);
+
+    assert!(matches!(
+        result,
+        Err(OptionsError::SettlementBeforeExpiry(_))
+    ));
+    assert_eq!(
+        contract.sender(WRITER).settlement_price(token_id),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn cash_call_cannot_be_exercised_physically(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let token_id = write_cash_call(&contract, &underlying_token, &quote_token, &oracle);
+
+    let result = contract
+        .sender(WRITER)
+        .exercise_call(token_id, U256::from(ONE_OPTION));
+
+    assert!(matches!(
+        result,
+        Err(OptionsError::WrongSettlementType(WrongSettlementType {
+            expected: 0,
+            actual: 1
+        }))
+    ));
+}
+
+#[motsu::test]
+fn closing_a_cash_call_returns_quote_collateral(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let token_id = write_cash_call(&contract, &underlying_token, &quote_token, &oracle);
+
+    contract
+        .sender(WRITER)
+        .close_position(token_id, U256::from(ONE_OPTION))
+        .unwrap();
+
+    assert_eq!(
+        quote_token.sender(WRITER).balance_of(WRITER),
+        U256::from(CAP_COLLATERAL)
+    );
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .locked_collateral(quote_token.address()),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn settle_series_rejects_unknown_series(contract: Contract<Options>) {
+    let token_id = B256::from([0x99; 32]);
+
+    let result = contract.sender(WRITER).settle_series(token_id);
+
+    assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+}
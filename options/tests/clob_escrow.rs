@@ -0,0 +1,155 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::CLOB;
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use stylus_sdk::abi::Bytes;
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn no_data() -> Bytes {
+    Vec::<u8>::new().into()
+}
+
+/// Initializes `book` to trade `contract`'s options against `quote_token`.
+fn init_book(
+    book: &Contract<CLOB>,
+    contract: &Contract<Options>,
+    quote_token: &Contract<TestERC20>,
+) {
+    book.sender(WRITER)
+        .initialize(contract.address(), quote_token.address(), 6)
+        .unwrap();
+}
+
+/// Writes one call (8-decimals underlying) at `strike` to `receiver`.
+fn write_for(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    strike: u64,
+    receiver: Address,
+) -> B256 {
+    let quantity = U256::from(100_000_000);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    contract
+        .sender(WRITER)
+        .write_call_option_for(
+            U256::from(strike) * one(),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+            receiver,
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn sell_order_escrows_options_in_the_book_and_cancel_returns_them(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    book: Contract<CLOB>,
+) {
+    init_book(&book, &contract, &quote_token);
+    let token_id = write_for(&contract, &underlying_token, &quote_token, 60_000, WRITER);
+    contract
+        .sender(WRITER)
+        .set_approval_for_all(book.address(), true);
+
+    let order_id = book
+        .sender(WRITER)
+        .place_order(token_id, one(), one(), 1, false)
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(WRITER).balance_of(book.address(), token_id),
+        one()
+    );
+    assert_eq!(
+        contract.sender(WRITER).balance_of(WRITER, token_id),
+        U256::ZERO
+    );
+
+    book.sender(WRITER).cancel_order(order_id).unwrap();
+
+    assert_eq!(
+        contract.sender(WRITER).balance_of(book.address(), token_id),
+        U256::ZERO
+    );
+    assert_eq!(contract.sender(WRITER).balance_of(WRITER, token_id), one());
+}
+
+#[motsu::test]
+fn book_accepts_batch_transfers_and_mints(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    book: Contract<CLOB>,
+) {
+    init_book(&book, &contract, &quote_token);
+    let first = write_for(&contract, &underlying_token, &quote_token, 60_000, WRITER);
+    let second = write_for(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        70_000,
+        book.address(),
+    );
+
+    contract
+        .sender(WRITER)
+        .safe_batch_transfer_from(WRITER, book.address(), vec![first], vec![one()], no_data())
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(WRITER).balance_of(book.address(), first),
+        one()
+    );
+    assert_eq!(
+        contract.sender(WRITER).balance_of(book.address(), second),
+        one()
+    );
+}
+
+#[motsu::test]
+fn book_of_another_options_contract_rejects_transfers(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    book: Contract<CLOB>,
+) {
+    book.sender(WRITER)
+        .initialize(Address::repeat_byte(0x01), quote_token.address(), 6)
+        .unwrap();
+    let token_id = write_for(&contract, &underlying_token, &quote_token, 60_000, WRITER);
+
+    let err = contract
+        .sender(WRITER)
+        .safe_transfer_from(WRITER, book.address(), token_id, one(), no_data())
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        OptionsError::ERC1155ReceiverRejected(ref e) if e.receiver == book.address()
+    ));
+}
@@ -0,0 +1,264 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, PositionClosed, Token};
+use stylus_sdk::abi::Bytes;
+use test_erc20::{expiry, TestERC20};
+
+fn no_data() -> Bytes {
+    Vec::<u8>::new().into()
+}
+
+#[motsu::test]
+fn writer_closes_part_then_exercises_rest(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+    let one_token = U256::from(10).pow(U256::from(8));
+    let scale = U256::from(10).pow(U256::from(10));
+
+    let write_quantity = U256::from(100) * one_token;
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    let close_quantity = U256::from(40) * one_token;
+    contract
+        .sender(writer)
+        .close_position(token_id, close_quantity)
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        U256::from(40) * one_token
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, token_id),
+        U256::from(60) * one_token * scale
+    );
+    let (quantity_written, collateral_locked) =
+        contract.sender(writer).get_position(writer, token_id);
+    assert_eq!(quantity_written, U256::from(60) * one_token * scale);
+    assert_eq!(collateral_locked, U256::from(60) * one_token * scale);
+
+    let exercise_quantity = U256::from(60) * one_token;
+    contract
+        .sender(writer)
+        .exercise_call(token_id, exercise_quantity)
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        write_quantity
+    );
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(options_addr),
+        U256::ZERO
+    );
+    assert_eq!(contract.sender(writer).total_supply(token_id), U256::ZERO);
+    let (quantity_written, collateral_locked) =
+        contract.sender(writer).get_position(writer, token_id);
+    assert_eq!(quantity_written, U256::ZERO);
+    assert_eq!(collateral_locked, U256::ZERO);
+}
+
+#[motsu::test]
+fn closing_more_than_written_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    let result = contract
+        .sender(writer)
+        .close_position(token_id, write_quantity + U256::from(1));
+
+    assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(options_addr),
+        write_quantity
+    );
+}
+
+#[motsu::test]
+fn writer_closes_full_position(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xCC; 20]);
+    let options_addr = contract.address();
+    let scale = U256::from(10).pow(U256::from(10));
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(
+            U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            write_quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap();
+
+    contract
+        .sender(writer)
+        .close_position(token_id, write_quantity)
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        write_quantity
+    );
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(options_addr),
+        U256::ZERO
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, token_id),
+        U256::ZERO
+    );
+    assert_eq!(contract.sender(writer).total_supply(token_id), U256::ZERO);
+    assert_eq!(
+        contract.sender(writer).get_position(writer, token_id),
+        (U256::ZERO, U256::ZERO)
+    );
+    contract.assert_emitted(&PositionClosed {
+        writer,
+        tokenId: token_id,
+        quantity: write_quantity,
+        normalizedQuantity: write_quantity * scale,
+        collateralReturned: write_quantity,
+    });
+}
+
+#[motsu::test]
+fn writer_closes_after_buying_back_sold_options(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xDD; 20]);
+    let buyer = Address::from([0xEE; 20]);
+    let options_addr = contract.address();
+    let scale = U256::from(10).pow(U256::from(10));
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(
+            U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            write_quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap();
+
+    let normalized = write_quantity * scale;
+    contract
+        .sender(writer)
+        .safe_transfer_from(writer, buyer, token_id, normalized, no_data())
+        .unwrap();
+
+    let result = contract
+        .sender(writer)
+        .close_position(token_id, write_quantity);
+    assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+
+    contract
+        .sender(buyer)
+        .safe_transfer_from(buyer, writer, token_id, normalized, no_data())
+        .unwrap();
+    contract
+        .sender(writer)
+        .close_position(token_id, write_quantity)
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        write_quantity
+    );
+    assert_eq!(
+        contract.sender(writer).get_position(writer, token_id),
+        (U256::ZERO, U256::ZERO)
+    );
+}
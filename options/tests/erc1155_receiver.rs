@@ -0,0 +1,250 @@
+mod test_erc1155_receiver;
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token, TransferBatch};
+use stylus_sdk::abi::Bytes;
+use test_erc1155_receiver::TestERC1155Receiver;
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+const EOA: Address = Address::new([0xBB; 20]);
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn no_data() -> Bytes {
+    Vec::<u8>::new().into()
+}
+
+/// Writes one call (8-decimals underlying) at `strike` to `receiver` and returns the result.
+fn write_for(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    strike: u64,
+    receiver: Address,
+) -> Result<B256, OptionsError> {
+    let quantity = U256::from(100_000_000);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    contract.sender(WRITER).write_call_option_for(
+        U256::from(strike) * one(),
+        expiry(),
+        quantity,
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: quote_token.address(),
+            decimals: 6,
+        },
+        receiver,
+    )
+}
+
+#[motsu::test]
+fn transfer_to_address_without_code_skips_the_check(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write_for(&contract, &underlying_token, &quote_token, 60_000, WRITER).unwrap();
+
+    contract
+        .sender(WRITER)
+        .safe_transfer_from(WRITER, EOA, token_id, one(), no_data())
+        .unwrap();
+
+    assert_eq!(contract.sender(WRITER).balance_of(EOA, token_id), one());
+}
+
+#[motsu::test]
+fn accepting_contract_receives_transfer(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    receiver: Contract<TestERC1155Receiver>,
+) {
+    let token_id = write_for(&contract, &underlying_token, &quote_token, 60_000, WRITER).unwrap();
+    let amount = one() / U256::from(4);
+
+    contract
+        .sender(WRITER)
+        .safe_transfer_from(WRITER, receiver.address(), token_id, amount, no_data())
+        .unwrap();
+
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .balance_of(receiver.address(), token_id),
+        amount
+    );
+    assert_eq!(receiver.sender(WRITER).received_count(), U256::from(1));
+    assert_eq!(receiver.sender(WRITER).last_operator(), WRITER);
+    assert_eq!(receiver.sender(WRITER).last_from(), WRITER);
+    assert_eq!(receiver.sender(WRITER).last_value(), amount);
+}
+
+#[motsu::test]
+fn rejecting_contract_fails_transfer(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    receiver: Contract<TestERC1155Receiver>,
+) {
+    let token_id = write_for(&contract, &underlying_token, &quote_token, 60_000, WRITER).unwrap();
+    receiver.sender(WRITER).set_rejects(true);
+
+    let err = contract
+        .sender(WRITER)
+        .safe_transfer_from(WRITER, receiver.address(), token_id, one(), no_data())
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        OptionsError::ERC1155ReceiverRejected(ref e) if e.receiver == receiver.address()
+    ));
+}
+
+#[motsu::test]
+fn mint_to_accepting_contract_calls_hook_from_zero_address(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    receiver: Contract<TestERC1155Receiver>,
+) {
+    let token_id = write_for(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        60_000,
+        receiver.address(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .balance_of(receiver.address(), token_id),
+        one()
+    );
+    assert_eq!(receiver.sender(WRITER).received_count(), U256::from(1));
+    assert_eq!(receiver.sender(WRITER).last_operator(), WRITER);
+    assert_eq!(receiver.sender(WRITER).last_from(), Address::ZERO);
+    assert_eq!(receiver.sender(WRITER).last_value(), one());
+}
+
+#[motsu::test]
+fn mint_to_rejecting_contract_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    receiver: Contract<TestERC1155Receiver>,
+) {
+    receiver.sender(WRITER).set_rejects(true);
+
+    let err = write_for(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        60_000,
+        receiver.address(),
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, OptionsError::ERC1155ReceiverRejected(_)));
+}
+
+#[motsu::test]
+fn batch_transfer_to_accepting_contract(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    receiver: Contract<TestERC1155Receiver>,
+) {
+    let first = write_for(&contract, &underlying_token, &quote_token, 60_000, WRITER).unwrap();
+    let second = write_for(&contract, &underlying_token, &quote_token, 70_000, WRITER).unwrap();
+    let amounts = vec![one() / U256::from(2), one() / U256::from(4)];
+
+    contract
+        .sender(WRITER)
+        .safe_batch_transfer_from(
+            WRITER,
+            receiver.address(),
+            vec![first, second],
+            amounts.clone(),
+            no_data(),
+        )
+        .unwrap();
+
+    contract.assert_emitted(&TransferBatch {
+        operator: WRITER,
+        from: WRITER,
+        to: receiver.address(),
+        ids: vec![U256::from_be_bytes(first.0), U256::from_be_bytes(second.0)],
+        values: amounts.clone(),
+    });
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .balance_of(receiver.address(), first),
+        amounts[0]
+    );
+    assert_eq!(
+        contract.sender(WRITER).balance_of(WRITER, second),
+        one() - amounts[1]
+    );
+    assert_eq!(
+        receiver.sender(WRITER).last_value(),
+        amounts[0] + amounts[1]
+    );
+}
+
+#[motsu::test]
+fn batch_transfer_to_rejecting_contract_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    receiver: Contract<TestERC1155Receiver>,
+) {
+    let token_id = write_for(&contract, &underlying_token, &quote_token, 60_000, WRITER).unwrap();
+    receiver.sender(WRITER).set_rejects(true);
+
+    let err = contract
+        .sender(WRITER)
+        .safe_batch_transfer_from(
+            WRITER,
+            receiver.address(),
+            vec![token_id],
+            vec![one()],
+            no_data(),
+        )
+        .unwrap_err();
+
+    assert!(matches!(err, OptionsError::ERC1155ReceiverRejected(_)));
+}
+
+#[motsu::test]
+fn batch_transfer_rejects_length_mismatch(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write_for(&contract, &underlying_token, &quote_token, 60_000, WRITER).unwrap();
+
+    let err = contract
+        .sender(WRITER)
+        .safe_batch_transfer_from(WRITER, EOA, vec![token_id], vec![], no_data())
+        .unwrap_err();
+
+    assert!(matches!(err, OptionsError::BatchLengthMismatch(_)));
+}
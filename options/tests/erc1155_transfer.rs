@@ -0,0 +1,437 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{ApprovalForAll, Options, OptionsError, Token, TransferSingle};
+use stylus_sdk::abi::Bytes;
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+const HOLDER: Address = Address::new([0xBB; 20]);
+const THIRD_PARTY: Address = Address::new([0xCC; 20]);
+const OWNER: Address = Address::new([0x0A; 20]);
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+/// Writes one call (8-decimals underlying) and returns its token ID.
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+) -> B256 {
+    let quantity = U256::from(100_000_000);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    contract
+        .sender(WRITER)
+        .write_call_option(
+            U256::from(60_000) * one(),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap()
+}
+
+fn no_data() -> Bytes {
+    Vec::<u8>::new().into()
+}
+
+#[motsu::test]
+fn transfer_moves_balance_and_keeps_supply(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+    let amount = U256::from(4) * one() / U256::from(10);
+
+    contract
+        .sender(WRITER)
+        .safe_transfer_from(WRITER, HOLDER, token_id, amount, no_data())
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(WRITER).balance_of(WRITER, token_id),
+        one() - amount
+    );
+    assert_eq!(contract.sender(WRITER).balance_of(HOLDER, token_id), amount);
+    assert_eq!(contract.sender(WRITER).total_supply(token_id), one());
+}
+
+#[motsu::test]
+fn recipient_can_transfer_onward(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    contract
+        .sender(WRITER)
+        .safe_transfer_from(WRITER, HOLDER, token_id, one(), no_data())
+        .unwrap();
+    contract
+        .sender(HOLDER)
+        .safe_transfer_from(HOLDER, THIRD_PARTY, token_id, one(), no_data())
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(WRITER).balance_of(HOLDER, token_id),
+        U256::ZERO
+    );
+    assert_eq!(
+        contract.sender(WRITER).balance_of(THIRD_PARTY, token_id),
+        one()
+    );
+}
+
+#[motsu::test]
+fn transfer_to_zero_address_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    let result = contract.sender(WRITER).safe_transfer_from(
+        WRITER,
+        Address::ZERO,
+        token_id,
+        one(),
+        no_data(),
+    );
+
+    assert!(matches!(result, Err(OptionsError::InvalidRecipient(_))));
+    assert_eq!(contract.sender(WRITER).balance_of(WRITER, token_id), one());
+}
+
+#[motsu::test]
+fn transfer_beyond_balance_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    let result = contract.sender(WRITER).safe_transfer_from(
+        WRITER,
+        HOLDER,
+        token_id,
+        one() + U256::from(1),
+        no_data(),
+    );
+
+    assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+    assert_eq!(
+        contract.sender(WRITER).balance_of(HOLDER, token_id),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn unapproved_caller_cannot_transfer(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    let result = contract.sender(THIRD_PARTY).safe_transfer_from(
+        WRITER,
+        THIRD_PARTY,
+        token_id,
+        one(),
+        no_data(),
+    );
+
+    assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+    assert_eq!(contract.sender(WRITER).balance_of(WRITER, token_id), one());
+}
+
+#[motsu::test]
+fn transfer_all_moves_the_whole_balance(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+    let amount = U256::from(3) * one() / U256::from(10);
+    contract
+        .sender(WRITER)
+        .safe_transfer_from(WRITER, HOLDER, token_id, amount, no_data())
+        .unwrap();
+
+    let transferred = contract
+        .sender(WRITER)
+        .transfer_all(HOLDER, token_id)
+        .unwrap();
+
+    assert_eq!(transferred, one() - amount);
+    assert_eq!(
+        contract.sender(WRITER).balance_of(WRITER, token_id),
+        U256::ZERO
+    );
+    assert_eq!(contract.sender(WRITER).balance_of(HOLDER, token_id), one());
+}
+
+#[motsu::test]
+fn transfer_all_to_zero_address_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    let result = contract
+        .sender(WRITER)
+        .transfer_all(Address::ZERO, token_id);
+
+    assert!(matches!(result, Err(OptionsError::InvalidRecipient(_))));
+    assert_eq!(contract.sender(WRITER).balance_of(WRITER, token_id), one());
+}
+
+#[motsu::test]
+fn approved_operator_transfers_for_holder(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    contract
+        .sender(WRITER)
+        .set_approval_for_all(THIRD_PARTY, true);
+    assert!(contract
+        .sender(WRITER)
+        .is_approved_for_all(WRITER, THIRD_PARTY));
+
+    contract
+        .sender(THIRD_PARTY)
+        .safe_transfer_from(WRITER, HOLDER, token_id, one(), no_data())
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(WRITER).balance_of(WRITER, token_id),
+        U256::ZERO
+    );
+    assert_eq!(contract.sender(WRITER).balance_of(HOLDER, token_id), one());
+    contract.assert_emitted(&ApprovalForAll {
+        account: WRITER,
+        operator: THIRD_PARTY,
+        approved: true,
+    });
+    contract.assert_emitted(&TransferSingle {
+        operator: THIRD_PARTY,
+        from: WRITER,
+        to: HOLDER,
+        id: U256::from_be_bytes(token_id.0),
+        value: one(),
+    });
+}
+
+#[motsu::test]
+fn revoked_operator_cannot_transfer(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    contract
+        .sender(WRITER)
+        .set_approval_for_all(THIRD_PARTY, true);
+    contract
+        .sender(WRITER)
+        .set_approval_for_all(THIRD_PARTY, false);
+    assert!(!contract
+        .sender(WRITER)
+        .is_approved_for_all(WRITER, THIRD_PARTY));
+
+    let result =
+        contract
+            .sender(THIRD_PARTY)
+            .safe_transfer_from(WRITER, HOLDER, token_id, one(), no_data());
+
+    assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+    assert_eq!(contract.sender(WRITER).balance_of(WRITER, token_id), one());
+}
+
+#[motsu::test]
+fn approval_is_per_holder(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+    contract
+        .sender(WRITER)
+        .safe_transfer_from(WRITER, HOLDER, token_id, one(), no_data())
+        .unwrap();
+
+    // Approved by the writer only, so the holder's tokens stay out of reach
+    contract
+        .sender(WRITER)
+        .set_approval_for_all(THIRD_PARTY, true);
+    let result = contract.sender(THIRD_PARTY).safe_transfer_from(
+        HOLDER,
+        THIRD_PARTY,
+        token_id,
+        one(),
+        no_data(),
+    );
+
+    assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+}
+
+#[motsu::test]
+fn write_emits_mint_transfer_single(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    contract.assert_emitted(&TransferSingle {
+        operator: WRITER,
+        from: Address::ZERO,
+        to: WRITER,
+        id: U256::from_be_bytes(token_id.0),
+        value: one(),
+    });
+}
+
+#[motsu::test]
+fn exercise_emits_burn_transfer_single(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    // Half an option in the underlying's 8 decimals
+    contract
+        .sender(WRITER)
+        .exercise_call(token_id, U256::from(50_000_000))
+        .unwrap();
+
+    contract.assert_emitted(&TransferSingle {
+        operator: WRITER,
+        from: WRITER,
+        to: Address::ZERO,
+        id: U256::from_be_bytes(token_id.0),
+        value: one() / U256::from(2),
+    });
+}
+
+#[motsu::test]
+fn close_position_emits_burn_transfer_single(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    contract
+        .sender(WRITER)
+        .close_position(token_id, U256::from(100_000_000))
+        .unwrap();
+
+    contract.assert_emitted(&TransferSingle {
+        operator: WRITER,
+        from: WRITER,
+        to: Address::ZERO,
+        id: U256::from_be_bytes(token_id.0),
+        value: one(),
+    });
+}
+
+#[motsu::test]
+fn write_for_receiver_emits_mint_to_receiver(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let quantity = U256::from(100_000_000);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    let token_id = contract
+        .sender(WRITER)
+        .write_call_option_for(
+            U256::from(60_000) * one(),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+            HOLDER,
+        )
+        .unwrap();
+
+    contract.assert_emitted(&TransferSingle {
+        operator: WRITER,
+        from: Address::ZERO,
+        to: HOLDER,
+        id: U256::from_be_bytes(token_id.0),
+        value: one(),
+    });
+}
+
+#[motsu::test]
+fn transfers_revert_while_paused(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+    contract.sender(OWNER).pause().unwrap();
+
+    let single =
+        contract
+            .sender(WRITER)
+            .safe_transfer_from(WRITER, HOLDER, token_id, one(), no_data());
+    let batch = contract.sender(WRITER).safe_batch_transfer_from(
+        WRITER,
+        HOLDER,
+        vec![token_id],
+        vec![one()],
+        no_data(),
+    );
+
+    assert!(matches!(single, Err(OptionsError::Paused(_))));
+    assert!(matches!(batch, Err(OptionsError::Paused(_))));
+    assert_eq!(contract.sender(WRITER).balance_of(WRITER, token_id), one());
+
+    contract.sender(OWNER).unpause().unwrap();
+    contract
+        .sender(WRITER)
+        .safe_transfer_from(WRITER, HOLDER, token_id, one(), no_data())
+        .unwrap();
+    assert_eq!(contract.sender(WRITER).balance_of(HOLDER, token_id), one());
+}
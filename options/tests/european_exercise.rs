@@ -0,0 +1,134 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+const WRITE_QUANTITY: u64 = 100_000_000;
+
+fn tokens(
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+) -> (Token, Token) {
+    underlying_token
+        .sender(WRITER)
+        .mint(WRITER, U256::from(WRITE_QUANTITY));
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+    (
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: quote_token.address(),
+            decimals: 6,
+        },
+    )
+}
+
+fn strike() -> U256 {
+    U256::from(60_000) * U256::from(10).pow(U256::from(18))
+}
+
+fn write_european(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+) -> B256 {
+    let (underlying, quote) = tokens(underlying_token, quote_token);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), U256::from(WRITE_QUANTITY));
+
+    contract
+        .sender(WRITER)
+        .write_european_call_option(
+            strike(),
+            expiry(),
+            U256::from(WRITE_QUANTITY),
+            underlying,
+            quote,
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn european_series_is_distinct_from_american(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let european_id = write_european(&contract, &underlying_token, &quote_token);
+
+    let (underlying, quote) = tokens(&underlying_token, &quote_token);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), U256::from(WRITE_QUANTITY));
+    let american_id = contract
+        .sender(WRITER)
+        .write_call_option(
+            strike(),
+            expiry(),
+            U256::from(WRITE_QUANTITY),
+            underlying,
+            quote,
+        )
+        .unwrap();
+
+    assert_ne!(european_id, american_id);
+    assert_eq!(contract.sender(WRITER).series_count(), U256::from(2));
+}
+
+#[motsu::test]
+fn european_exercise_before_window_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write_european(&contract, &underlying_token, &quote_token);
+
+    let result = contract
+        .sender(WRITER)
+        .exercise_call(token_id, U256::from(WRITE_QUANTITY));
+
+    assert!(matches!(result, Err(OptionsError::ExerciseWindowClosed(_))));
+    assert_eq!(
+        contract.sender(WRITER).balance_of(WRITER, token_id),
+        U256::from(10).pow(U256::from(18))
+    );
+}
+
+#[motsu::test]
+fn american_exercise_before_expiry_is_unchanged(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = tokens(&underlying_token, &quote_token);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), U256::from(WRITE_QUANTITY));
+    let token_id = contract
+        .sender(WRITER)
+        .write_call_option(
+            strike(),
+            expiry(),
+            U256::from(WRITE_QUANTITY),
+            underlying,
+            quote,
+        )
+        .unwrap();
+
+    contract
+        .sender(WRITER)
+        .exercise_call(token_id, U256::from(WRITE_QUANTITY))
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(WRITER).balance_of(WRITER, token_id),
+        U256::ZERO
+    );
+}
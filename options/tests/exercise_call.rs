@@ -3,12 +3,13 @@ mod test_erc20;
 use alloy_primitives::{Address, B256, U256};
 use motsu::prelude::*;
 use options::{Options, Token};
-use test_erc20::TestERC20;
+use test_erc20::{expiry, TestERC20};
 
 #[motsu::test]
 fn writer_exercises_own_options_successfully(
     contract: Contract<Options>,
     underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
 ) {
     let writer = Address::from([0xAA; 20]);
     let options_addr = contract.address();
@@ -24,18 +25,21 @@ fn writer_exercises_own_options_successfully(
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0x22; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
 
     let token_id = contract
         .sender(writer)
         .write_call_option(strike, expiry, write_quantity, underlying, quote)
         .unwrap();
 
-    let exercise_quantity = U256::from(50_000_000) * U256::from(10).pow(U256::from(10));
+    let exercise_quantity = U256::from(50_000_000);
     let result = contract
         .sender(writer)
         .exercise_call(token_id, exercise_quantity);
@@ -47,6 +51,7 @@ fn writer_exercises_own_options_successfully(
 fn option_tokens_burned_correctly(
     contract: Contract<Options>,
     underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
 ) {
     let writer = Address::from([0xBB; 20]);
     let options_addr = contract.address();
@@ -62,11 +67,14 @@ fn option_tokens_burned_correctly(
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0x33; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
 
     let token_id = contract
         .sender(writer)
@@ -76,7 +84,7 @@ fn option_tokens_burned_correctly(
     let normalized_quantity = write_quantity * U256::from(10).pow(U256::from(10));
     let balance_before = contract.sender(writer).balance_of(writer, token_id);
 
-    let exercise_quantity = U256::from(30_000_000) * U256::from(10).pow(U256::from(10));
+    let exercise_quantity = U256::from(30_000_000);
     contract
         .sender(writer)
         .exercise_call(token_id, exercise_quantity)
@@ -85,11 +93,18 @@ fn option_tokens_burned_correctly(
     let balance_after = contract.sender(writer).balance_of(writer, token_id);
 
     assert_eq!(balance_before, normalized_quantity);
-    assert_eq!(balance_after, normalized_quantity - exercise_quantity);
+    assert_eq!(
+        balance_after,
+        (write_quantity - exercise_quantity) * U256::from(10).pow(U256::from(10))
+    );
 }
 
 #[motsu::test]
-fn position_reduced_correctly(contract: Contract<Options>, underlying_token: Contract<TestERC20>) {
+fn position_reduced_correctly(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
     let writer = Address::from([0xCC; 20]);
     let options_addr = contract.address();
 
@@ -104,11 +119,14 @@ fn position_reduced_correctly(contract: Contract<Options>, underlying_token: Con
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0x44; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
 
     let token_id = contract
         .sender(writer)
@@ -119,7 +137,7 @@ fn position_reduced_correctly(contract: Contract<Options>, underlying_token: Con
     let (quantity_before, collateral_before) =
         contract.sender(writer).get_position(writer, token_id);
 
-    let exercise_quantity = U256::from(40_000_000) * U256::from(10).pow(U256::from(10));
+    let exercise_quantity = U256::from(40_000_000);
     contract
         .sender(writer)
         .exercise_call(token_id, exercise_quantity)
@@ -127,16 +145,19 @@ fn position_reduced_correctly(contract: Contract<Options>, underlying_token: Con
 
     let (quantity_after, collateral_after) = contract.sender(writer).get_position(writer, token_id);
 
+    let normalized_remaining =
+        (write_quantity - exercise_quantity) * U256::from(10).pow(U256::from(10));
     assert_eq!(quantity_before, normalized_quantity);
-    assert_eq!(quantity_after, normalized_quantity - exercise_quantity);
+    assert_eq!(quantity_after, normalized_remaining);
     assert_eq!(collateral_before, normalized_quantity);
-    assert_eq!(collateral_after, normalized_quantity - exercise_quantity);
+    assert_eq!(collateral_after, normalized_remaining);
 }
 
 #[motsu::test]
 fn underlying_tokens_transferred(
     contract: Contract<Options>,
     underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
 ) {
     let writer = Address::from([0xDD; 20]);
     let options_addr = contract.address();
@@ -152,11 +173,14 @@ fn underlying_tokens_transferred(
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0x55; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
 
     let token_id = contract
         .sender(writer)
@@ -166,27 +190,27 @@ fn underlying_tokens_transferred(
     let writer_balance_before = underlying_token.sender(writer).balance_of(writer);
     let contract_balance_before = underlying_token.sender(writer).balance_of(options_addr);
 
-    let exercise_quantity_raw = U256::from(25_000_000);
-    let exercise_quantity_normalized = exercise_quantity_raw * U256::from(10).pow(U256::from(10));
+    let exercise_quantity = U256::from(25_000_000);
     contract
         .sender(writer)
-        .exercise_call(token_id, exercise_quantity_normalized)
+        .exercise_call(token_id, exercise_quantity)
         .unwrap();
 
     let writer_balance_after = underlying_token.sender(writer).balance_of(writer);
     let contract_balance_after = underlying_token.sender(writer).balance_of(options_addr);
 
     assert_eq!(writer_balance_before, U256::ZERO);
-    assert_eq!(writer_balance_after, exercise_quantity_raw);
+    assert_eq!(writer_balance_after, exercise_quantity);
     assert_eq!(contract_balance_before, write_quantity);
-    assert_eq!(
-        contract_balance_after,
-        write_quantity - exercise_quantity_raw
-    );
+    assert_eq!(contract_balance_after, write_quantity - exercise_quantity);
 }
 
 #[motsu::test]
-fn exercise_full_position(contract: Contract<Options>, underlying_token: Contract<TestERC20>) {
+fn exercise_full_position(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
     let writer = Address::from([0xEE; 20]);
     let options_addr = contract.address();
 
@@ -201,21 +225,23 @@ fn exercise_full_position(contract: Contract<Options>, underlying_token: Contrac
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0x66; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
 
     let token_id = contract
         .sender(writer)
         .write_call_option(strike, expiry, write_quantity, underlying, quote)
         .unwrap();
 
-    let normalized_quantity = write_quantity * U256::from(10).pow(U256::from(10));
     contract
         .sender(writer)
-        .exercise_call(token_id, normalized_quantity)
+        .exercise_call(token_id, write_quantity)
         .unwrap();
 
     let balance_after = contract.sender(writer).balance_of(writer, token_id);
@@ -230,6 +256,7 @@ fn exercise_full_position(contract: Contract<Options>, underlying_token: Contrac
 fn multiple_partial_exercises_deplete_balance(
     contract: Contract<Options>,
     underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
 ) {
     let writer = Address::from([0x11; 20]);
     let options_addr = contract.address();
@@ -245,35 +272,43 @@ fn multiple_partial_exercises_deplete_balance(
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0x77; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
 
     let token_id = contract
         .sender(writer)
         .write_call_option(strike, expiry, write_quantity, underlying, quote)
         .unwrap();
 
-    let normalized_total = write_quantity * U256::from(10).pow(U256::from(10));
-    let exercise_1 = U256::from(25_000_000) * U256::from(10).pow(U256::from(10));
-    let exercise_2 = U256::from(35_000_000) * U256::from(10).pow(U256::from(10));
-    let exercise_3 = U256::from(40_000_000) * U256::from(10).pow(U256::from(10));
+    let exercise_1 = U256::from(25_000_000);
+    let exercise_2 = U256::from(35_000_000);
+    let exercise_3 = U256::from(40_000_000);
 
     contract
         .sender(writer)
         .exercise_call(token_id, exercise_1)
         .unwrap();
     let balance_after_1 = contract.sender(writer).balance_of(writer, token_id);
-    assert_eq!(balance_after_1, normalized_total - exercise_1);
+    assert_eq!(
+        balance_after_1,
+        (write_quantity - exercise_1) * U256::from(10).pow(U256::from(10))
+    );
 
     contract
         .sender(writer)
         .exercise_call(token_id, exercise_2)
         .unwrap();
     let balance_after_2 = contract.sender(writer).balance_of(writer, token_id);
-    assert_eq!(balance_after_2, normalized_total - exercise_1 - exercise_2);
+    assert_eq!(
+        balance_after_2,
+        (write_quantity - exercise_1 - exercise_2) * U256::from(10).pow(U256::from(10))
+    );
 
     contract
         .sender(writer)
@@ -287,6 +322,7 @@ fn multiple_partial_exercises_deplete_balance(
 fn exercising_more_than_balance_fails(
     contract: Contract<Options>,
     underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
 ) {
     let writer = Address::from([0x22; 20]);
     let options_addr = contract.address();
@@ -302,19 +338,21 @@ fn exercising_more_than_balance_fails(
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0x88; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
 
     let token_id = contract
         .sender(writer)
         .write_call_option(strike, expiry, write_quantity, underlying, quote)
         .unwrap();
 
-    let normalized_quantity = write_quantity * U256::from(10).pow(U256::from(10));
-    let excessive_quantity = normalized_quantity + U256::from(1);
+    let excessive_quantity = write_quantity + U256::from(1);
 
     let result = contract
         .sender(writer)
@@ -327,6 +365,7 @@ fn exercising_more_than_balance_fails(
 fn write_and_exercise_near_expiry_succeeds(
     contract: Contract<Options>,
     underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
 ) {
     let writer = Address::from([0x33; 20]);
     let options_addr = contract.address();
@@ -342,21 +381,23 @@ fn write_and_exercise_near_expiry_succeeds(
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0x99; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
 
     let token_id = contract
         .sender(writer)
         .write_call_option(strike, expiry, write_quantity, underlying, quote)
         .unwrap();
 
-    let normalized_quantity = write_quantity * U256::from(10).pow(U256::from(10));
     let result = contract
         .sender(writer)
-        .exercise_call(token_id, normalized_quantity);
+        .exercise_call(token_id, write_quantity);
 
     assert!(result.is_ok());
 }
@@ -365,6 +406,7 @@ fn write_and_exercise_near_expiry_succeeds(
 fn exercise_with_wrong_token_id_fails(
     contract: Contract<Options>,
     underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
 ) {
     let writer = Address::from([0x55; 20]);
     let options_addr = contract.address();
@@ -380,11 +422,14 @@ fn exercise_with_wrong_token_id_fails(
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0xBB; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
 
     let _token_id = contract
         .sender(writer)
@@ -392,11 +437,10 @@ fn exercise_with_wrong_token_id_fails(
         .unwrap();
 
     let wrong_token_id = B256::from([0xFF; 32]);
-    let normalized_quantity = write_quantity * U256::from(10).pow(U256::from(10));
 
     let result = contract
         .sender(writer)
-        .exercise_call(wrong_token_id, normalized_quantity);
+        .exercise_call(wrong_token_id, write_quantity);
 
     assert!(result.is_err());
 }
@@ -0,0 +1,386 @@
+mod test_erc20;
+mod test_oracle;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{ExerciseAssigned, Options, OptionsError, Token};
+use stylus_sdk::abi::Bytes;
+use test_erc20::{expiry, TestERC20};
+use test_oracle::TestOracle;
+
+fn no_data() -> Bytes {
+    Vec::<u8>::new().into()
+}
+
+fn write_cash_settled_call(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    oracle: &Contract<TestOracle>,
+    writer: Address,
+    write_quantity: U256,
+) -> B256 {
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    contract
+        .sender(writer)
+        .write_cash_settled_call_option(
+            strike,
+            expiry,
+            write_quantity,
+            underlying,
+            quote,
+            oracle.address(),
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn cash_exercise_in_the_money_pays_intrinsic_value(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let write_quantity = U256::from(100_000_000);
+    let token_id = write_cash_settled_call(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        &oracle,
+        writer,
+        write_quantity,
+    );
+
+    // Spot 80,000 against a 60,000 strike: intrinsic value is 20,000 per option,
+    // paid as 20,000 / 80,000 = 0.25 underlying per option
+    let spot = U256::from(80_000) * U256::from(10).pow(U256::from(18));
+    oracle.sender(writer).set_price(spot);
+
+    let exercise_quantity = U256::from(40_000_000);
+    contract
+        .sender(writer)
+        .exercise_call_cash(token_id, exercise_quantity)
+        .unwrap();
+
+    // The writer is also the holder, so they receive both the payout and the
+    // remaining collateral of the exercised options
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        exercise_quantity
+    );
+    assert_eq!(
+        underlying_token
+            .sender(writer)
+            .balance_of(contract.address()),
+        write_quantity - exercise_quantity
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, token_id),
+        (write_quantity - exercise_quantity) * U256::from(10).pow(U256::from(10))
+    );
+    assert_eq!(
+        contract.sender(writer).total_exercised(token_id),
+        exercise_quantity * U256::from(10).pow(U256::from(10))
+    );
+}
+
+#[motsu::test]
+fn cash_exercise_is_assigned_pro_rata_across_writers(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let alice = Address::from([0xA1; 20]);
+    let bob = Address::from([0xB1; 20]);
+    let carol = Address::from([0xC1; 20]);
+    let write_quantity = U256::from(100_000_000);
+    let token_id = write_cash_settled_call(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        &oracle,
+        alice,
+        write_quantity,
+    );
+    write_cash_settled_call(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        &oracle,
+        bob,
+        write_quantity,
+    );
+    let scale = U256::from(10).pow(U256::from(10));
+    contract
+        .sender(alice)
+        .safe_transfer_from(alice, carol, token_id, write_quantity * scale, no_data())
+        .unwrap();
+
+    // 0.4 WBTC at spot 80,000 pays Carol 0.1 WBTC; each writer is assigned 0.2 WBTC and
+    // keeps 0.15 WBTC of its collateral
+    oracle
+        .sender(carol)
+        .set_price(U256::from(80_000) * U256::from(10).pow(U256::from(18)));
+    contract
+        .sender(carol)
+        .exercise_call_cash(token_id, U256::from(40_000_000))
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(carol).balance_of(carol),
+        U256::from(10_000_000)
+    );
+    assert_eq!(underlying_token.sender(carol).balance_of(alice), U256::ZERO);
+    for writer in [alice, bob] {
+        assert_eq!(
+            contract.sender(writer).get_position(writer, token_id),
+            (
+                U256::from(80_000_000) * scale,
+                U256::from(95_000_000) * scale
+            )
+        );
+    }
+
+    contract
+        .sender(carol)
+        .settle_assignment(bob, token_id)
+        .unwrap();
+
+    contract.assert_emitted(&ExerciseAssigned {
+        writer: bob,
+        tokenId: token_id,
+        quantity: U256::from(20_000_000) * scale,
+        strikeCredited: U256::ZERO,
+        collateralCredited: U256::from(15_000_000) * scale,
+    });
+    assert_eq!(
+        contract.sender(bob).claimable_assignment(bob, token_id),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn cash_exercise_at_the_money_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let write_quantity = U256::from(100_000_000);
+    let token_id = write_cash_settled_call(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        &oracle,
+        writer,
+        write_quantity,
+    );
+
+    oracle
+        .sender(writer)
+        .set_price(U256::from(60_000) * U256::from(10).pow(U256::from(18)));
+
+    let result = contract
+        .sender(writer)
+        .exercise_call_cash(token_id, write_quantity);
+
+    assert!(matches!(result, Err(OptionsError::NotInTheMoney(_))));
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, token_id),
+        write_quantity * U256::from(10).pow(U256::from(10))
+    );
+}
+
+#[motsu::test]
+fn cash_exercise_out_of_the_money_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let writer = Address::from([0xCC; 20]);
+    let write_quantity = U256::from(100_000_000);
+    let token_id = write_cash_settled_call(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        &oracle,
+        writer,
+        write_quantity,
+    );
+
+    oracle
+        .sender(writer)
+        .set_price(U256::from(50_000) * U256::from(10).pow(U256::from(18)));
+
+    let result = contract
+        .sender(writer)
+        .exercise_call_cash(token_id, write_quantity);
+
+    assert!(matches!(result, Err(OptionsError::NotInTheMoney(_))));
+}
+
+#[motsu::test]
+fn cash_exercise_of_physical_series_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xDD; 20]);
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry(), write_quantity, underlying, quote)
+        .unwrap();
+
+    let result = contract
+        .sender(writer)
+        .exercise_call_cash(token_id, write_quantity);
+    assert!(matches!(result, Err(OptionsError::NotCashSettled(_))));
+
+    // Without an oracle there is no settlement price to snapshot either
+    let result = contract.sender(writer).settle_series(token_id);
+    assert!(matches!(result, Err(OptionsError::NotCashSettled(_))));
+}
+
+#[motsu::test]
+fn cash_exercise_normalizes_oracle_decimals(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let writer = Address::from([0xEE; 20]);
+    let write_quantity = U256::from(100_000_000);
+    let token_id = write_cash_settled_call(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        &oracle,
+        writer,
+        write_quantity,
+    );
+
+    // Spot 80,000 reported with 8 decimals pays the same 0.25 underlying per option
+    // as an 18 decimals answer
+    oracle.sender(writer).set_decimals(8);
+    oracle
+        .sender(writer)
+        .set_price(U256::from(80_000u64 * 100_000_000));
+
+    let exercise_quantity = U256::from(40_000_000);
+    contract
+        .sender(writer)
+        .exercise_call_cash(token_id, exercise_quantity)
+        .unwrap();
+
+    assert_eq!(
+        underlying_token
+            .sender(writer)
+            .balance_of(contract.address()),
+        write_quantity - exercise_quantity
+    );
+}
+
+#[motsu::test]
+fn cash_settled_series_has_distinct_token_id(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let writer = Address::from([0xEE; 20]);
+    let write_quantity = U256::from(100_000_000);
+    let cash_token_id = write_cash_settled_call(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        &oracle,
+        writer,
+        write_quantity,
+    );
+
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), write_quantity);
+    let physical_token_id = contract
+        .sender(writer)
+        .write_call_option(
+            U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            write_quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap();
+
+    assert_ne!(cash_token_id, physical_token_id);
+}
+
+#[motsu::test]
+fn writing_with_zero_oracle_fails(contract: Contract<Options>) {
+    let writer = Address::from([0xFF; 20]);
+
+    let result = contract.sender(writer).write_cash_settled_call_option(
+        U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+        2_000_000_000u64,
+        U256::from(100_000_000),
+        Token {
+            address: Address::from([0x11; 20]),
+            decimals: 8,
+        },
+        Token {
+            address: Address::from([0x22; 20]),
+            decimals: 6,
+        },
+        Address::ZERO,
+    );
+
+    assert!(matches!(result, Err(OptionsError::InvalidOracle(_))));
+}
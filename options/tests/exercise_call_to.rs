@@ -0,0 +1,168 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+#[motsu::test]
+fn underlying_delivered_to_recipient(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let recipient = Address::from([0xAB; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    let exercise_quantity = U256::from(40_000_000);
+    contract
+        .sender(writer)
+        .exercise_call_to(token_id, exercise_quantity, recipient)
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(recipient),
+        exercise_quantity
+    );
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        U256::ZERO
+    );
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(options_addr),
+        write_quantity - exercise_quantity
+    );
+}
+
+#[motsu::test]
+fn holder_accounting_matches_exercise_call(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let recipient = Address::from([0xBC; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    let exercise_quantity = U256::from(25_000_000);
+    contract
+        .sender(writer)
+        .exercise_call_to(token_id, exercise_quantity, recipient)
+        .unwrap();
+
+    let normalized_exercised = exercise_quantity * U256::from(10).pow(U256::from(10));
+    let normalized_remaining =
+        (write_quantity - exercise_quantity) * U256::from(10).pow(U256::from(10));
+    let (quantity_after, collateral_after) = contract.sender(writer).get_position(writer, token_id);
+
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, token_id),
+        normalized_remaining
+    );
+    assert_eq!(
+        contract.sender(writer).balance_of(recipient, token_id),
+        U256::ZERO
+    );
+    assert_eq!(quantity_after, normalized_remaining);
+    assert_eq!(collateral_after, normalized_remaining);
+    assert_eq!(
+        contract.sender(writer).total_exercised(token_id),
+        normalized_exercised
+    );
+}
+
+#[motsu::test]
+fn zero_recipient_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xCC; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    let result = contract
+        .sender(writer)
+        .exercise_call_to(token_id, write_quantity, Address::ZERO);
+
+    assert!(matches!(result, Err(OptionsError::InvalidRecipient(_))));
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, token_id),
+        write_quantity * U256::from(10).pow(U256::from(10))
+    );
+}
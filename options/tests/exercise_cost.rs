@@ -0,0 +1,202 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use stylus_sdk::abi::Bytes;
+use test_erc20::{expiry, TestERC20};
+
+const OWNER: Address = Address::new([0x0A; 20]);
+const TREASURY: Address = Address::new([0x0B; 20]);
+const WRITER: Address = Address::new([0xAA; 20]);
+const HOLDER: Address = Address::new([0xBB; 20]);
+const FEE_BPS: u64 = 30;
+
+fn pow10(exponent: u8) -> U256 {
+    U256::from(10).pow(U256::from(exponent))
+}
+
+fn no_data() -> Bytes {
+    Vec::<u8>::new().into()
+}
+
+/// Writes two options with a 30 bps fee and hands `quantity` (native decimals) to
+/// `HOLDER`, who did not write into the series.
+fn write_and_transfer(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    underlying_decimals: u8,
+    quote_decimals: u8,
+    quantity: U256,
+) -> B256 {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+    contract
+        .sender(OWNER)
+        .set_fee(U256::from(FEE_BPS), TREASURY)
+        .unwrap();
+
+    let write_quantity = U256::from(2) * pow10(underlying_decimals);
+    let funding = write_quantity * U256::from(2);
+    underlying_token.sender(WRITER).mint(WRITER, funding);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), funding);
+    underlying_token
+        .sender(WRITER)
+        .set_decimals(underlying_decimals);
+    quote_token.sender(WRITER).set_decimals(quote_decimals);
+
+    // 61,234.567 quote per underlying, so strike payments rarely divide evenly
+    let strike = U256::from(61_234_567) * pow10(15);
+    let token_id = contract
+        .sender(WRITER)
+        .write_call_option(
+            strike,
+            expiry(),
+            write_quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: underlying_decimals,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: quote_decimals,
+            },
+        )
+        .unwrap();
+
+    let normalized = quantity * pow10(18 - underlying_decimals);
+    contract
+        .sender(WRITER)
+        .safe_transfer_from(WRITER, HOLDER, token_id, normalized, no_data())
+        .unwrap();
+
+    token_id
+}
+
+/// Exercises with exactly the previewed strike approved and checks every amount.
+fn assert_exercise_matches_preview(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    underlying_decimals: u8,
+    quote_decimals: u8,
+) {
+    // 0.37 options plus one native unit
+    let quantity = U256::from(37) * pow10(underlying_decimals) / U256::from(100) + U256::from(1);
+    let token_id = write_and_transfer(
+        contract,
+        underlying_token,
+        quote_token,
+        underlying_decimals,
+        quote_decimals,
+        quantity,
+    );
+
+    let (strike_payment, underlying_out) = contract
+        .sender(HOLDER)
+        .get_exercise_cost(token_id, quantity)
+        .unwrap();
+    quote_token.sender(HOLDER).mint(HOLDER, strike_payment);
+    quote_token
+        .sender(HOLDER)
+        .approve(contract.address(), strike_payment);
+    let treasury_before = underlying_token.sender(HOLDER).balance_of(TREASURY);
+
+    contract
+        .sender(HOLDER)
+        .exercise_call(token_id, quantity)
+        .unwrap();
+
+    assert_eq!(quote_token.sender(HOLDER).balance_of(HOLDER), U256::ZERO);
+    assert_eq!(
+        quote_token.sender(HOLDER).balance_of(contract.address()),
+        strike_payment
+    );
+    assert_eq!(
+        underlying_token.sender(HOLDER).balance_of(HOLDER),
+        underlying_out
+    );
+    assert_eq!(
+        underlying_token.sender(HOLDER).balance_of(TREASURY) - treasury_before,
+        quantity - underlying_out
+    );
+}
+
+#[motsu::test]
+fn preview_matches_exercise_8_6(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    assert_exercise_matches_preview(&contract, &underlying_token, &quote_token, 8, 6);
+}
+
+#[motsu::test]
+fn preview_matches_exercise_6_18(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    assert_exercise_matches_preview(&contract, &underlying_token, &quote_token, 6, 18);
+}
+
+#[motsu::test]
+fn preview_matches_exercise_18_6(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    assert_exercise_matches_preview(&contract, &underlying_token, &quote_token, 18, 6);
+}
+
+#[motsu::test]
+fn preview_matches_exercise_18_18(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    assert_exercise_matches_preview(&contract, &underlying_token, &quote_token, 18, 18);
+}
+
+#[motsu::test]
+fn preview_matches_exercise_6_8(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    assert_exercise_matches_preview(&contract, &underlying_token, &quote_token, 6, 8);
+}
+
+#[motsu::test]
+fn unwritten_series_is_not_found(contract: Contract<Options>) {
+    let result = contract
+        .sender(HOLDER)
+        .get_exercise_cost(B256::from([0x99; 32]), U256::from(1));
+
+    assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+}
+
+#[motsu::test]
+fn zero_quantity_is_invalid(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write_and_transfer(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        8,
+        6,
+        U256::from(1),
+    );
+
+    let result = contract
+        .sender(HOLDER)
+        .get_exercise_cost(token_id, U256::ZERO);
+
+    assert!(matches!(result, Err(OptionsError::InvalidQuantity(_))));
+}
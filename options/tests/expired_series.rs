@@ -0,0 +1,72 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::TestERC20;
+
+const WRITER: Address = Address::new([0xAA; 20]);
+const WRITE_QUANTITY: u64 = 100_000_000;
+const PAST_EXPIRIES: [u64; 3] = [1, 1_600_000_000, 1_700_000_000];
+
+fn setup(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+) -> (Token, Token) {
+    let quantity = U256::from(WRITE_QUANTITY);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    (
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: quote_token.address(),
+            decimals: 6,
+        },
+    )
+}
+
+#[motsu::test]
+fn writing_with_past_expiry_always_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = setup(&contract, &underlying_token, &quote_token);
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+
+    for expiry in PAST_EXPIRIES {
+        let result = contract.sender(WRITER).write_call_option(
+            strike,
+            expiry,
+            U256::from(WRITE_QUANTITY),
+            underlying,
+            quote,
+        );
+        assert!(matches!(result, Err(OptionsError::ExpiredOption(_))));
+
+        let result = contract.sender(WRITER).write_call_option_for(
+            strike,
+            expiry,
+            U256::from(WRITE_QUANTITY),
+            underlying,
+            quote,
+            Address::from([0xBB; 20]),
+        );
+        assert!(matches!(result, Err(OptionsError::ExpiredOption(_))));
+    }
+
+    assert_eq!(contract.sender(WRITER).series_count(), U256::ZERO);
+    assert_eq!(
+        underlying_token.sender(WRITER).balance_of(WRITER),
+        U256::from(WRITE_QUANTITY)
+    );
+}
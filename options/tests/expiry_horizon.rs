@@ -0,0 +1,164 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token, DEFAULT_MAX_EXPIRY_HORIZON};
+use test_erc20::{expiry, TestERC20};
+
+const OWNER: Address = Address::new([0x0A; 20]);
+const WRITER: Address = Address::new([0xAA; 20]);
+const WRITE_QUANTITY: u64 = 100_000_000;
+const ONE_DAY: u64 = 86_400;
+
+fn setup(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+) -> (Token, Token) {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+
+    let funding = U256::from(WRITE_QUANTITY) * U256::from(2);
+    underlying_token.sender(WRITER).mint(WRITER, funding);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), funding);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    (
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: quote_token.address(),
+            decimals: 6,
+        },
+    )
+}
+
+fn strike() -> U256 {
+    U256::from(60_000) * U256::from(10).pow(U256::from(18))
+}
+
+#[motsu::test]
+fn default_horizon_accepts_existing_expiries(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = setup(&contract, &underlying_token, &quote_token);
+
+    assert_eq!(
+        contract.sender(WRITER).max_expiry_horizon(),
+        DEFAULT_MAX_EXPIRY_HORIZON
+    );
+    contract
+        .sender(WRITER)
+        .write_call_option(
+            strike(),
+            expiry(),
+            U256::from(WRITE_QUANTITY),
+            underlying,
+            quote,
+        )
+        .unwrap();
+}
+
+#[motsu::test]
+fn far_expiry_fails_with_default_horizon(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = setup(&contract, &underlying_token, &quote_token);
+
+    // Year 3000
+    let result = contract.sender(WRITER).write_call_option(
+        strike(),
+        32_503_680_000,
+        U256::from(WRITE_QUANTITY),
+        underlying,
+        quote,
+    );
+
+    assert!(matches!(result, Err(OptionsError::ExpiryTooFar(_))));
+}
+
+#[motsu::test]
+fn owner_can_shorten_and_restore_horizon(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = setup(&contract, &underlying_token, &quote_token);
+
+    contract
+        .sender(OWNER)
+        .set_max_expiry_horizon(ONE_DAY)
+        .unwrap();
+    assert_eq!(contract.sender(WRITER).max_expiry_horizon(), ONE_DAY);
+    let result = contract.sender(WRITER).write_call_option(
+        strike(),
+        expiry(),
+        U256::from(WRITE_QUANTITY),
+        underlying,
+        quote,
+    );
+    assert!(matches!(result, Err(OptionsError::ExpiryTooFar(_))));
+
+    contract.sender(OWNER).set_max_expiry_horizon(0).unwrap();
+    assert_eq!(
+        contract.sender(WRITER).max_expiry_horizon(),
+        DEFAULT_MAX_EXPIRY_HORIZON
+    );
+    contract
+        .sender(WRITER)
+        .write_call_option(
+            strike(),
+            expiry(),
+            U256::from(WRITE_QUANTITY),
+            underlying,
+            quote,
+        )
+        .unwrap();
+}
+
+#[motsu::test]
+fn roll_into_far_expiry_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = setup(&contract, &underlying_token, &quote_token);
+    let token_id = contract
+        .sender(WRITER)
+        .write_call_option(
+            strike(),
+            expiry(),
+            U256::from(WRITE_QUANTITY),
+            underlying,
+            quote,
+        )
+        .unwrap();
+
+    let result = contract.sender(WRITER).roll_position(
+        token_id,
+        32_503_680_000,
+        strike(),
+        U256::from(WRITE_QUANTITY),
+    );
+
+    assert!(matches!(result, Err(OptionsError::ExpiryTooFar(_))));
+}
+
+#[motsu::test]
+fn only_owner_sets_horizon(contract: Contract<Options>) {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+
+    let result = contract.sender(WRITER).set_max_expiry_horizon(ONE_DAY);
+
+    assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+}
@@ -0,0 +1,192 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token, MAX_FEE_BPS, MAX_FEE_TIERS};
+use test_erc20::{expiry, TestERC20};
+
+const OWNER: Address = Address::new([0x0A; 20]);
+const TREASURY: Address = Address::new([0x0B; 20]);
+const WRITER: Address = Address::new([0xAA; 20]);
+/// 1 WBTC (8 decimals)
+const WRITE_QUANTITY: u64 = 100_000_000;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+/// Initializes the contract with a 1% protocol fee and the given tiers.
+fn setup(contract: &Contract<Options>, thresholds: Vec<U256>, fee_bps: Vec<u64>) {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+    contract
+        .sender(OWNER)
+        .set_fee(U256::from(100), TREASURY)
+        .unwrap();
+    contract
+        .sender(OWNER)
+        .set_fee_tiers(thresholds, fee_bps.into_iter().map(U256::from).collect())
+        .unwrap();
+}
+
+/// Writes 1 WBTC of calls at `strike` and returns the fee the treasury received.
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    strike: u64,
+) -> U256 {
+    let quantity = U256::from(WRITE_QUANTITY);
+    let funding = quantity * U256::from(2);
+    underlying_token.sender(WRITER).mint(WRITER, funding);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), funding);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    let before = underlying_token.sender(WRITER).balance_of(TREASURY);
+    contract
+        .sender(WRITER)
+        .write_call_option(
+            U256::from(strike) * one(),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap();
+    underlying_token.sender(WRITER).balance_of(TREASURY) - before
+}
+
+#[motsu::test]
+fn crossing_thresholds_discounts_later_writes(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    setup(&contract, vec![one(), one() * U256::from(2)], vec![50, 20]);
+    assert_eq!(
+        contract.sender(WRITER).fee_tier_of(WRITER),
+        (U256::ZERO, U256::from(100))
+    );
+
+    // The write that reaches the first threshold still pays the full 1%
+    let fee = write(&contract, &underlying_token, &quote_token, 60_000);
+    assert_eq!(fee, U256::from(1_000_000));
+    assert_eq!(contract.sender(WRITER).write_volume_of(WRITER), one());
+    assert_eq!(
+        contract.sender(WRITER).fee_tier_of(WRITER),
+        (U256::from(1), U256::from(50))
+    );
+
+    // Volume counts across series
+    let fee = write(&contract, &underlying_token, &quote_token, 70_000);
+    assert_eq!(fee, U256::from(500_000));
+    assert_eq!(
+        contract.sender(WRITER).fee_tier_of(WRITER),
+        (U256::from(2), U256::from(20))
+    );
+
+    let fee = write(&contract, &underlying_token, &quote_token, 60_000);
+    assert_eq!(fee, U256::from(200_000));
+}
+
+#[motsu::test]
+fn tier_fee_never_exceeds_protocol_fee(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    setup(&contract, vec![U256::ZERO], vec![300]);
+
+    assert_eq!(
+        contract.sender(WRITER).fee_tier_of(WRITER),
+        (U256::from(1), U256::from(100))
+    );
+    let fee = write(&contract, &underlying_token, &quote_token, 60_000);
+    assert_eq!(fee, U256::from(1_000_000));
+}
+
+#[motsu::test]
+fn volume_is_tracked_per_payer(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    setup(&contract, vec![one()], vec![50]);
+    write(&contract, &underlying_token, &quote_token, 60_000);
+
+    let other = Address::new([0xBB; 20]);
+    assert_eq!(contract.sender(other).write_volume_of(other), U256::ZERO);
+    assert_eq!(
+        contract.sender(other).fee_tier_of(other),
+        (U256::ZERO, U256::from(100))
+    );
+}
+
+#[motsu::test]
+fn set_fee_tiers_replaces_previous_tiers(contract: Contract<Options>) {
+    setup(&contract, vec![one(), one() * U256::from(2)], vec![50, 20]);
+
+    contract
+        .sender(OWNER)
+        .set_fee_tiers(vec![one() * U256::from(5)], vec![U256::from(10)])
+        .unwrap();
+    assert_eq!(
+        contract.sender(OWNER).fee_tiers(),
+        (vec![one() * U256::from(5)], vec![U256::from(10)])
+    );
+
+    contract
+        .sender(OWNER)
+        .set_fee_tiers(vec![], vec![])
+        .unwrap();
+    assert_eq!(contract.sender(OWNER).fee_tiers(), (vec![], vec![]));
+}
+
+#[motsu::test]
+fn set_fee_tiers_validates_input(contract: Contract<Options>) {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+
+    let err = contract
+        .sender(WRITER)
+        .set_fee_tiers(vec![one()], vec![U256::from(50)])
+        .unwrap_err();
+    assert!(matches!(err, OptionsError::Unauthorized(_)));
+
+    let err = contract
+        .sender(OWNER)
+        .set_fee_tiers(vec![one()], vec![])
+        .unwrap_err();
+    assert!(matches!(err, OptionsError::BatchLengthMismatch(_)));
+
+    let err = contract
+        .sender(OWNER)
+        .set_fee_tiers(vec![one(), one()], vec![U256::from(50), U256::from(20)])
+        .unwrap_err();
+    assert!(matches!(err, OptionsError::InvalidFeeTiers(_)));
+
+    let count = MAX_FEE_TIERS + 1;
+    let err = contract
+        .sender(OWNER)
+        .set_fee_tiers(
+            (0..count).map(U256::from).collect(),
+            vec![U256::ZERO; count],
+        )
+        .unwrap_err();
+    assert!(matches!(err, OptionsError::InvalidFeeTiers(_)));
+
+    let err = contract
+        .sender(OWNER)
+        .set_fee_tiers(vec![one()], vec![U256::from(MAX_FEE_BPS + 1)])
+        .unwrap_err();
+    assert!(matches!(err, OptionsError::FeeTooHigh(_)));
+}
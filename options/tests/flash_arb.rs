@@ -0,0 +1,203 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use clob::{CLOBError, FlashArb, CLOB};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+const ARB: Address = Address::new([0xBB; 20]);
+
+/// One underlying (8 decimals) per option.
+const QUANTITY: u64 = 100_000_000;
+/// Strike of 60,000 quote (6 decimals) per underlying.
+const STRIKE_PAYMENT: u64 = 60_000_000_000;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+/// Writes one call at a 60,000 strike and rests it as an ask at `price` quote per
+/// option (18 decimals normalized).
+fn list_call(
+    contract: &Contract<Options>,
+    book: &Contract<CLOB>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    price: U256,
+) -> B256 {
+    book.sender(WRITER)
+        .initialize(contract.address(), quote_token.address(), 6)
+        .unwrap();
+
+    let quantity = U256::from(QUANTITY);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    let token_id = contract
+        .sender(WRITER)
+        .write_call_option(
+            U256::from(60_000) * one(),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap();
+
+    contract
+        .sender(WRITER)
+        .set_approval_for_all(book.address(), true);
+    book.sender(WRITER)
+        .place_order(token_id, price, one(), 1, false)
+        .unwrap();
+
+    token_id
+}
+
+/// Funds `ARB` with `amount` quote tokens approved to the book.
+fn fund_arb(book: &Contract<CLOB>, quote_token: &Contract<TestERC20>, amount: U256) {
+    quote_token.sender(ARB).mint(ARB, amount);
+    quote_token.sender(ARB).approve(book.address(), amount);
+}
+
+#[motsu::test]
+fn mispriced_ask_is_bought_and_exercised(
+    contract: Contract<Options>,
+    book: Contract<CLOB>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    // Listed at 100 quote, far below the intrinsic value of an in-the-money call
+    let token_id = list_call(
+        &contract,
+        &book,
+        &underlying_token,
+        &quote_token,
+        U256::from(100) * one(),
+    );
+    let premium = U256::from(100_000_000);
+    fund_arb(&book, &quote_token, premium + U256::from(STRIKE_PAYMENT));
+
+    let underlying_out = book
+        .sender(ARB)
+        .flash_arb(token_id, one(), premium, U256::from(QUANTITY))
+        .unwrap();
+
+    assert_eq!(underlying_out, U256::from(QUANTITY));
+    assert_eq!(
+        underlying_token.sender(ARB).balance_of(ARB),
+        U256::from(QUANTITY)
+    );
+    assert_eq!(quote_token.sender(ARB).balance_of(ARB), U256::ZERO);
+    assert_eq!(
+        contract.sender(ARB).balance_of(book.address(), token_id),
+        U256::ZERO
+    );
+    assert_eq!(
+        quote_token.sender(ARB).balance_of(book.address()),
+        U256::ZERO
+    );
+    book.assert_emitted(&FlashArb {
+        taker: ARB,
+        tokenId: token_id,
+        quantity: one(),
+        premium,
+        strikePayment: U256::from(STRIKE_PAYMENT),
+        underlyingOut: U256::from(QUANTITY),
+    });
+}
+
+#[motsu::test]
+fn arb_reverts_when_underlying_out_is_below_minimum(
+    contract: Contract<Options>,
+    book: Contract<CLOB>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = list_call(
+        &contract,
+        &book,
+        &underlying_token,
+        &quote_token,
+        U256::from(100) * one(),
+    );
+    let premium = U256::from(100_000_000);
+    fund_arb(&book, &quote_token, premium + U256::from(STRIKE_PAYMENT));
+
+    let err = book
+        .sender(ARB)
+        .flash_arb(token_id, one(), premium, U256::from(QUANTITY + 1))
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        CLOBError::InsufficientUnderlyingOut(ref e)
+            if e.received == U256::from(QUANTITY)
+                && e.min_underlying_out == U256::from(QUANTITY + 1)
+    ));
+}
+
+#[motsu::test]
+fn arb_reverts_when_premium_exceeds_maximum(
+    contract: Contract<Options>,
+    book: Contract<CLOB>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = list_call(
+        &contract,
+        &book,
+        &underlying_token,
+        &quote_token,
+        U256::from(100) * one(),
+    );
+    let premium = U256::from(100_000_000);
+    fund_arb(&book, &quote_token, premium + U256::from(STRIKE_PAYMENT));
+
+    let err = book
+        .sender(ARB)
+        .flash_arb(token_id, one(), premium - U256::from(1), U256::ZERO)
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        CLOBError::PremiumTooHigh(ref e) if e.premium == premium
+    ));
+}
+
+#[motsu::test]
+fn arb_rejects_fractional_underlying_quantity(
+    contract: Contract<Options>,
+    book: Contract<CLOB>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = list_call(
+        &contract,
+        &book,
+        &underlying_token,
+        &quote_token,
+        U256::from(100) * one(),
+    );
+
+    // 8-decimals underlying: option token units below 1e10 are not exercisable
+    let quantity = one() / U256::from(2) + U256::from(1);
+    let err = book
+        .sender(ARB)
+        .flash_arb(token_id, quantity, U256::MAX, U256::ZERO)
+        .unwrap_err();
+
+    assert!(matches!(err, CLOBError::UnexercisableQuantity(_)));
+}
@@ -0,0 +1,100 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{OptionType, Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+const ALICE: Address = Address::new([0xAA; 20]);
+
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    strike: U256,
+) -> B256 {
+    let quantity = U256::from(100_000_000);
+    underlying_token.sender(ALICE).mint(ALICE, quantity);
+    underlying_token
+        .sender(ALICE)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(ALICE).set_decimals(8);
+    quote_token.sender(ALICE).set_decimals(6);
+
+    contract
+        .sender(ALICE)
+        .write_call_option(
+            strike,
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn written_series_resolves_to_its_terms(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let token_id = write(&contract, &underlying_token, &quote_token, strike);
+
+    assert_eq!(
+        contract.sender(ALICE).get_series(token_id).unwrap(),
+        (
+            underlying_token.address(),
+            quote_token.address(),
+            8,
+            6,
+            strike,
+            U256::from(expiry()),
+            OptionType::Call.to_u8(),
+        )
+    );
+}
+
+#[motsu::test]
+fn series_resolve_independently(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let one = U256::from(10).pow(U256::from(18));
+    let low = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(50_000) * one,
+    );
+    let high = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(70_000) * one,
+    );
+
+    assert_eq!(
+        contract.sender(ALICE).get_series(low).unwrap().4,
+        U256::from(50_000) * one
+    );
+    assert_eq!(
+        contract.sender(ALICE).get_series(high).unwrap().4,
+        U256::from(70_000) * one
+    );
+}
+
+#[motsu::test]
+fn unknown_token_id_reverts(contract: Contract<Options>) {
+    let result = contract.sender(ALICE).get_series(B256::from([0x99; 32]));
+
+    assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+}
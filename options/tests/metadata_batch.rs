@@ -0,0 +1,126 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+
+#[motsu::test]
+fn metadata_is_returned_positionally(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let quantity = U256::from(100_000_000);
+    underlying_token
+        .sender(WRITER)
+        .mint(WRITER, U256::from(3) * quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), U256::from(3) * quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    let one = U256::from(10).pow(U256::from(18));
+    let strikes = [50_000u64, 60_000, 70_000].map(|strike| U256::from(strike) * one);
+    let token_ids = strikes.map(|strike| {
+        contract
+            .sender(WRITER)
+            .write_call_option(
+                strike,
+                expiry(),
+                quantity,
+                Token {
+                    address: underlying_token.address(),
+                    decimals: 8,
+                },
+                Token {
+                    address: quote_token.address(),
+                    decimals: 6,
+                },
+            )
+            .unwrap()
+    });
+    let unknown_id = B256::from([0xFF; 32]);
+
+    // Unknown series in the middle to check positions don't shift
+    let metadata = contract.sender(WRITER).metadata_batch(vec![
+        token_ids[2],
+        unknown_id,
+        token_ids[0],
+        token_ids[1],
+    ]);
+
+    assert_eq!(metadata.len(), 4);
+    for (view, strike) in [&metadata[0], &metadata[2], &metadata[3]]
+        .into_iter()
+        .zip([strikes[2], strikes[0], strikes[1]])
+    {
+        assert_eq!(view.strike, strike);
+        assert_eq!(view.expiry, U256::from(expiry()));
+        assert_eq!(view.underlying, underlying_token.address());
+        assert_eq!(view.quote, quote_token.address());
+        assert_eq!(view.underlying_decimals, 8);
+        assert_eq!(view.quote_decimals, 6);
+    }
+
+    let unknown = &metadata[1];
+    assert_eq!(unknown.expiry, U256::ZERO);
+    assert_eq!(unknown.strike, U256::ZERO);
+    assert_eq!(unknown.underlying, Address::ZERO);
+    assert_eq!(unknown.quote, Address::ZERO);
+}
+
+#[motsu::test]
+fn single_series_metadata_matches_batch(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let quantity = U256::from(100_000_000);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    let token_id = contract
+        .sender(WRITER)
+        .write_call_option(
+            U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap();
+
+    let view = contract.sender(WRITER).option_metadata_of(token_id);
+    let batched = contract.sender(WRITER).metadata_batch(vec![token_id])[0];
+
+    assert_eq!(view.expiry, U256::from(expiry()));
+    assert_eq!(view.strike, batched.strike);
+    assert_eq!(view.underlying, batched.underlying);
+    assert_eq!(view.quote, batched.quote);
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .option_metadata_of(B256::from([0xFF; 32]))
+            .expiry,
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn empty_batch_returns_empty(contract: Contract<Options>) {
+    assert!(contract.sender(WRITER).metadata_batch(vec![]).is_empty());
+}
@@ -0,0 +1,102 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, TestERC20};
+
+#[motsu::test]
+fn written_and_exercised_totals_track_lifecycle(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token
+        .sender(writer)
+        .mint(writer, write_quantity * U256::from(2));
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity * U256::from(2));
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+    contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    let normalized_quantity = write_quantity * U256::from(10).pow(U256::from(10));
+    let total_written = normalized_quantity * U256::from(2);
+    assert_eq!(
+        contract.sender(writer).total_written(token_id),
+        total_written
+    );
+    assert_eq!(
+        contract.sender(writer).total_exercised(token_id),
+        U256::ZERO
+    );
+
+    let partial_exercise = U256::from(30_000_000);
+    let normalized_partial_exercise = partial_exercise * U256::from(10).pow(U256::from(10));
+    contract
+        .sender(writer)
+        .exercise_call(token_id, partial_exercise)
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(writer).total_written(token_id),
+        total_written
+    );
+    assert_eq!(
+        contract.sender(writer).total_exercised(token_id),
+        normalized_partial_exercise
+    );
+    assert_eq!(
+        contract.sender(writer).total_supply(token_id),
+        total_written - normalized_partial_exercise
+    );
+
+    contract
+        .sender(writer)
+        .exercise_call(token_id, write_quantity * U256::from(2) - partial_exercise)
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(writer).total_written(token_id),
+        total_written
+    );
+    assert_eq!(
+        contract.sender(writer).total_exercised(token_id),
+        total_written
+    );
+    assert_eq!(contract.sender(writer).total_supply(token_id), U256::ZERO);
+}
+
+#[motsu::test]
+fn totals_are_zero_for_unknown_token_id(contract: Contract<Options>) {
+    let alice = Address::from([0xCC; 20]);
+    let token_id = B256::from([0x99; 32]);
+
+    assert_eq!(contract.sender(alice).total_written(token_id), U256::ZERO);
+    assert_eq!(contract.sender(alice).total_exercised(token_id), U256::ZERO);
+}
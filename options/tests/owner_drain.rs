@@ -0,0 +1,164 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+const OWNER: Address = Address::new([0x0A; 20]);
+const TREASURY: Address = Address::new([0x0B; 20]);
+
+/// Writes 1 WBTC of calls, leaving that amount locked in the contract.
+fn write_locked_collateral(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    writer: Address,
+) -> B256 {
+    let options_addr = contract.address();
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+
+    contract
+        .sender(writer)
+        .write_call_option(strike, expiry(), write_quantity, underlying, quote)
+        .unwrap()
+}
+
+#[motsu::test]
+fn drains_surplus_and_leaves_locked_collateral(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+    let token_id = write_locked_collateral(&contract, &underlying_token, &quote_token, writer);
+
+    // Tokens sent to the contract by mistake
+    let surplus = U256::from(25_000_000);
+    underlying_token.sender(writer).mint(options_addr, surplus);
+
+    contract
+        .sender(OWNER)
+        .owner_drain(underlying_token.address(), TREASURY)
+        .unwrap();
+
+    assert_eq!(underlying_token.sender(OWNER).balance_of(TREASURY), surplus);
+    assert_eq!(
+        underlying_token.sender(OWNER).balance_of(options_addr),
+        U256::from(100_000_000)
+    );
+    assert_eq!(
+        contract
+            .sender(OWNER)
+            .locked_collateral(underlying_token.address()),
+        U256::from(100_000_000)
+    );
+
+    // Writer can still exercise the full position
+    contract
+        .sender(writer)
+        .exercise_call(token_id, U256::from(100_000_000))
+        .unwrap();
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        U256::from(100_000_000)
+    );
+}
+
+#[motsu::test]
+fn drain_that_would_touch_locked_collateral_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let options_addr = contract.address();
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+    write_locked_collateral(&contract, &underlying_token, &quote_token, writer);
+
+    let result = contract
+        .sender(OWNER)
+        .owner_drain(underlying_token.address(), TREASURY);
+
+    assert!(matches!(result, Err(OptionsError::InsufficientSurplus(_))));
+    assert_eq!(
+        underlying_token.sender(OWNER).balance_of(options_addr),
+        U256::from(100_000_000)
+    );
+    assert_eq!(
+        underlying_token.sender(OWNER).balance_of(TREASURY),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn locked_collateral_tracks_exercise(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xCC; 20]);
+    let options_addr = contract.address();
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+    let token_id = write_locked_collateral(&contract, &underlying_token, &quote_token, writer);
+
+    contract
+        .sender(writer)
+        .exercise_call(token_id, U256::from(40_000_000))
+        .unwrap();
+
+    assert_eq!(
+        contract
+            .sender(OWNER)
+            .locked_collateral(underlying_token.address()),
+        U256::from(60_000_000)
+    );
+
+    // Only the released collateral left the contract, so there is still no surplus
+    let result = contract
+        .sender(OWNER)
+        .owner_drain(underlying_token.address(), TREASURY);
+    assert!(matches!(result, Err(OptionsError::InsufficientSurplus(_))));
+    assert_eq!(
+        underlying_token.sender(OWNER).balance_of(options_addr),
+        U256::from(60_000_000)
+    );
+}
+
+#[motsu::test]
+fn non_owner_cannot_drain(contract: Contract<Options>, underlying_token: Contract<TestERC20>) {
+    let alice = Address::from([0xDD; 20]);
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+    underlying_token
+        .sender(alice)
+        .mint(contract.address(), U256::from(1_000));
+
+    let result = contract
+        .sender(alice)
+        .owner_drain(underlying_token.address(), alice);
+
+    assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+}
@@ -0,0 +1,89 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+
+fn scale() -> U256 {
+    U256::from(10).pow(U256::from(10))
+}
+
+#[motsu::test]
+fn position_tracks_write_and_partial_exercise(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(WRITER).mint(WRITER, write_quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), write_quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    let token_id = contract
+        .sender(WRITER)
+        .write_call_option(
+            U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            write_quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap();
+
+    let written = write_quantity * scale();
+    assert_eq!(
+        contract.sender(WRITER).get_position(WRITER, token_id),
+        (written, written)
+    );
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .get_collateral_locked(WRITER, token_id),
+        written
+    );
+
+    contract
+        .sender(WRITER)
+        .exercise_call(token_id, U256::from(30_000_000))
+        .unwrap();
+
+    let remaining = U256::from(70_000_000) * scale();
+    assert_eq!(
+        contract.sender(WRITER).get_position(WRITER, token_id),
+        (remaining, remaining)
+    );
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .get_collateral_locked(WRITER, token_id),
+        remaining
+    );
+}
+
+#[motsu::test]
+fn unknown_position_is_zero(contract: Contract<Options>) {
+    let token_id = B256::from([0x99; 32]);
+
+    assert_eq!(
+        contract.sender(WRITER).get_position(WRITER, token_id),
+        (U256::ZERO, U256::ZERO)
+    );
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .get_collateral_locked(WRITER, token_id),
+        U256::ZERO
+    );
+}
@@ -0,0 +1,187 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token, MAX_FEE_BPS};
+use test_erc20::{expiry, TestERC20};
+
+const OWNER: Address = Address::new([0x0A; 20]);
+const TREASURY: Address = Address::new([0x0B; 20]);
+const WRITE_QUANTITY: u64 = 100_000_000;
+
+/// Sets the fee, funds the writer with collateral plus the write fee and writes 1 WBTC.
+fn write_with_fee(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    writer: Address,
+    fee_bps: u64,
+) -> B256 {
+    let options_addr = contract.address();
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+    contract
+        .sender(OWNER)
+        .set_fee(U256::from(fee_bps), TREASURY)
+        .unwrap();
+
+    let write_quantity = U256::from(WRITE_QUANTITY);
+    let funding = write_quantity + write_quantity * U256::from(fee_bps) / U256::from(10_000);
+    underlying_token.sender(writer).mint(writer, funding);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, funding);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+
+    contract
+        .sender(writer)
+        .write_call_option(strike, expiry(), write_quantity, underlying, quote)
+        .unwrap()
+}
+
+fn assert_fees(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    writer: Address,
+    fee_bps: u64,
+) {
+    let options_addr = contract.address();
+    let token_id = write_with_fee(contract, underlying_token, quote_token, writer, fee_bps);
+    let fee = U256::from(WRITE_QUANTITY * fee_bps / 10_000);
+
+    // Writer paid the fee on top; collateral accounting is unaffected
+    assert_eq!(underlying_token.sender(writer).balance_of(TREASURY), fee);
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        U256::ZERO
+    );
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(options_addr),
+        U256::from(WRITE_QUANTITY)
+    );
+    assert_eq!(
+        contract
+            .sender(writer)
+            .locked_collateral(underlying_token.address()),
+        U256::from(WRITE_QUANTITY)
+    );
+
+    contract
+        .sender(writer)
+        .exercise_call(token_id, U256::from(WRITE_QUANTITY))
+        .unwrap();
+
+    // Exercise fee is deducted from the underlying delivered
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        U256::from(WRITE_QUANTITY) - fee
+    );
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(TREASURY),
+        fee * U256::from(2)
+    );
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(options_addr),
+        U256::ZERO
+    );
+    assert_eq!(
+        contract
+            .sender(writer)
+            .locked_collateral(underlying_token.address()),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn zero_fee_charges_nothing(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    assert_fees(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        Address::from([0xAA; 20]),
+        0,
+    );
+}
+
+#[motsu::test]
+fn fee_at_50_bps(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    assert_fees(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        Address::from([0xBB; 20]),
+        50,
+    );
+}
+
+#[motsu::test]
+fn fee_at_max_bps(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    assert_fees(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        Address::from([0xCC; 20]),
+        MAX_FEE_BPS,
+    );
+}
+
+#[motsu::test]
+fn fee_above_max_fails(contract: Contract<Options>) {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+
+    let result = contract
+        .sender(OWNER)
+        .set_fee(U256::from(MAX_FEE_BPS + 1), TREASURY);
+
+    assert!(matches!(result, Err(OptionsError::FeeTooHigh(_))));
+    assert_eq!(contract.sender(OWNER).fee_bps(), U256::ZERO);
+}
+
+#[motsu::test]
+fn non_zero_fee_requires_recipient(contract: Contract<Options>) {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+
+    let result = contract
+        .sender(OWNER)
+        .set_fee(U256::from(50), Address::ZERO);
+
+    assert!(matches!(result, Err(OptionsError::InvalidRecipient(_))));
+}
+
+#[motsu::test]
+fn non_owner_cannot_set_fee(contract: Contract<Options>) {
+    let alice = Address::from([0xDD; 20]);
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+
+    let result = contract.sender(alice).set_fee(U256::from(50), alice);
+
+    assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+}
@@ -0,0 +1,66 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, ReentrantERC20, TestERC20};
+
+#[motsu::test]
+fn reentrant_exercise_from_token_transfer_reverts(
+    contract: Contract<Options>,
+    malicious_token: Contract<ReentrantERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    malicious_token.sender(writer).mint(writer, write_quantity);
+    malicious_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: malicious_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    malicious_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    let exercise_quantity = U256::from(40_000_000);
+    malicious_token
+        .sender(writer)
+        .arm(token_id, exercise_quantity);
+
+    contract
+        .sender(writer)
+        .exercise_call(token_id, exercise_quantity)
+        .unwrap();
+
+    assert!(malicious_token.sender(writer).reentry_attempted());
+    assert!(malicious_token.sender(writer).reentry_reverted());
+
+    // Only the outer exercise settled
+    let normalized_remaining =
+        (write_quantity - exercise_quantity) * U256::from(10).pow(U256::from(10));
+    assert_eq!(
+        contract.sender(writer).balance_of(writer, token_id),
+        normalized_remaining
+    );
+    assert_eq!(
+        malicious_token.sender(writer).balance_of(options_addr),
+        write_quantity - exercise_quantity
+    );
+}
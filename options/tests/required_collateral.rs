@@ -0,0 +1,276 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{CollateralToken, OptionType, Options, OptionsError, Token, MAX_STRIKE};
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+
+fn tokens(
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+) -> (Token, Token) {
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+    (
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: quote_token.address(),
+            decimals: 6,
+        },
+    )
+}
+
+fn strike(price: u64) -> U256 {
+    U256::from(price) * U256::from(10).pow(U256::from(18))
+}
+
+#[motsu::test]
+fn call_collateral_matches_amount_pulled_by_write(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = tokens(&underlying_token, &quote_token);
+    let quantity = U256::from(123_456_789);
+    let balance = U256::from(10).pow(U256::from(12));
+    underlying_token.sender(WRITER).mint(WRITER, balance);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), balance);
+
+    let required = contract
+        .sender(WRITER)
+        .required_collateral(
+            strike(60_000),
+            quantity,
+            underlying,
+            quote,
+            OptionType::Call.to_u8(),
+        )
+        .unwrap();
+    contract
+        .sender(WRITER)
+        .write_call_option(strike(60_000), expiry(), quantity, underlying, quote)
+        .unwrap();
+
+    assert_eq!(required, quantity);
+    assert_eq!(
+        underlying_token.sender(WRITER).balance_of(WRITER),
+        balance - required
+    );
+    assert_eq!(
+        underlying_token
+            .sender(WRITER)
+            .balance_of(contract.address()),
+        required
+    );
+}
+
+#[motsu::test]
+fn put_collateral_is_strike_value_in_quote_decimals(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = tokens(&underlying_token, &quote_token);
+
+    // 1 WBTC put at 60,000 locks 60,000 USDC
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .required_collateral(
+                strike(60_000),
+                U256::from(100_000_000),
+                underlying,
+                quote,
+                OptionType::Put.to_u8(),
+            )
+            .unwrap(),
+        U256::from(60_000_000_000u64)
+    );
+
+    // One satoshi at 60,000 is worth 0.0006 USDC, below one quote unit: rounded up
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .required_collateral(
+                strike(60_000),
+                U256::from(1),
+                underlying,
+                quote,
+                OptionType::Put.to_u8(),
+            )
+            .unwrap(),
+        U256::from(1)
+    );
+}
+
+#[motsu::test]
+fn invalid_underlying_decimals_fail_like_write(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (mut underlying, quote) = tokens(&underlying_token, &quote_token);
+    underlying.decimals = 19;
+
+    let required = contract.sender(WRITER).required_collateral(
+        strike(60_000),
+        U256::from(1),
+        underlying,
+        quote,
+        OptionType::Call.to_u8(),
+    );
+    let written = contract.sender(WRITER).write_call_option(
+        strike(60_000),
+        expiry(),
+        U256::from(1),
+        underlying,
+        quote,
+    );
+
+    assert!(matches!(required, Err(OptionsError::InvalidDecimals(_))));
+    assert!(matches!(written, Err(OptionsError::InvalidDecimals(_))));
+}
+
+#[motsu::test]
+fn strike_notional_overflow_fails_like_write(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = tokens(&underlying_token, &quote_token);
+    let quantity = U256::MAX / U256::from(10).pow(U256::from(10));
+
+    let required = contract.sender(WRITER).required_collateral(
+        MAX_STRIKE,
+        quantity,
+        underlying,
+        quote,
+        OptionType::Call.to_u8(),
+    );
+    let written = contract.sender(WRITER).write_call_option(
+        MAX_STRIKE,
+        expiry(),
+        quantity,
+        underlying,
+        quote,
+    );
+
+    assert!(matches!(required, Err(OptionsError::StrikeTooLarge(_))));
+    assert!(matches!(written, Err(OptionsError::StrikeTooLarge(_))));
+}
+
+#[motsu::test]
+fn unknown_option_type_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = tokens(&underlying_token, &quote_token);
+
+    let result = contract.sender(WRITER).required_collateral(
+        strike(60_000),
+        U256::from(1),
+        underlying,
+        quote,
+        2,
+    );
+
+    assert!(matches!(result, Err(OptionsError::WrongOptionType(_))));
+}
+
+#[motsu::test]
+fn preview_needs_only_decimals(contract: Contract<Options>) {
+    // 1 WBTC put at 60,000 locks 60,000 USDC
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .preview_required_collateral(
+                strike(60_000),
+                U256::from(100_000_000),
+                8,
+                6,
+                OptionType::Put.to_u8(),
+            )
+            .unwrap(),
+        (
+            U256::from(60_000_000_000u64),
+            CollateralToken::Quote.to_u8()
+        )
+    );
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .preview_required_collateral(
+                strike(60_000),
+                U256::from(100_000_000),
+                8,
+                6,
+                OptionType::Call.to_u8(),
+            )
+            .unwrap(),
+        (U256::from(100_000_000), CollateralToken::Underlying.to_u8())
+    );
+}
+
+#[motsu::test]
+fn preview_matches_required_collateral(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let (underlying, quote) = tokens(&underlying_token, &quote_token);
+
+    for option_type in [OptionType::Call.to_u8(), OptionType::Put.to_u8()] {
+        let required = contract
+            .sender(WRITER)
+            .required_collateral(
+                strike(65_432),
+                U256::from(7),
+                underlying,
+                quote,
+                option_type,
+            )
+            .unwrap();
+        let (previewed, _) = contract
+            .sender(WRITER)
+            .preview_required_collateral(strike(65_432), U256::from(7), 8, 6, option_type)
+            .unwrap();
+        assert_eq!(previewed, required);
+    }
+}
+
+#[motsu::test]
+fn preview_returns_typed_errors(contract: Contract<Options>) {
+    let invalid_decimals = contract.sender(WRITER).preview_required_collateral(
+        strike(60_000),
+        U256::from(1),
+        19,
+        6,
+        OptionType::Call.to_u8(),
+    );
+    let overflow = contract.sender(WRITER).preview_required_collateral(
+        MAX_STRIKE,
+        U256::MAX / U256::from(10).pow(U256::from(10)),
+        8,
+        6,
+        OptionType::Call.to_u8(),
+    );
+    let wrong_type =
+        contract
+            .sender(WRITER)
+            .preview_required_collateral(strike(60_000), U256::from(1), 8, 6, 2);
+
+    assert!(matches!(
+        invalid_decimals,
+        Err(OptionsError::InvalidDecimals(_))
+    ));
+    assert!(matches!(overflow, Err(OptionsError::StrikeTooLarge(_))));
+    assert!(matches!(wrong_type, Err(OptionsError::WrongOptionType(_))));
+}
@@ -0,0 +1,261 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+const OWNER: Address = Address::new([0x0A; 20]);
+const TREASURY: Address = Address::new([0x0B; 20]);
+const WRITER: Address = Address::new([0xAA; 20]);
+const WRITE_QUANTITY: u64 = 100_000_000;
+
+/// Expiry the rolls move into, 30 days after the written series.
+fn next_expiry() -> u64 {
+    expiry() + 30 * 86_400
+}
+
+fn strike(price: u64) -> U256 {
+    U256::from(price) * U256::from(10).pow(U256::from(18))
+}
+
+/// Writes 1 WBTC of calls at a 60k strike for `WRITER`, minting `funding` underlying.
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    funding: U256,
+) -> B256 {
+    underlying_token.sender(WRITER).mint(WRITER, funding);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), funding);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    contract
+        .sender(WRITER)
+        .write_call_option(
+            strike(60_000),
+            expiry(),
+            U256::from(WRITE_QUANTITY),
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn roll_moves_tokens_and_position_to_new_series(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let old_token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(WRITE_QUANTITY),
+    );
+
+    let new_token_id = contract
+        .sender(WRITER)
+        .roll_position(
+            old_token_id,
+            next_expiry(),
+            strike(70_000),
+            U256::from(WRITE_QUANTITY),
+        )
+        .unwrap();
+
+    let one = U256::from(10).pow(U256::from(18));
+    assert_ne!(new_token_id, old_token_id);
+    assert_eq!(
+        contract.sender(WRITER).balance_of(WRITER, old_token_id),
+        U256::ZERO
+    );
+    assert_eq!(
+        contract.sender(WRITER).get_position(WRITER, old_token_id),
+        (U256::ZERO, U256::ZERO)
+    );
+    assert_eq!(
+        contract.sender(WRITER).balance_of(WRITER, new_token_id),
+        one
+    );
+    assert_eq!(
+        contract.sender(WRITER).get_position(WRITER, new_token_id),
+        (one, one)
+    );
+
+    // The collateral stayed in the contract; nothing moved from or to the writer
+    assert_eq!(
+        underlying_token.sender(WRITER).balance_of(WRITER),
+        U256::ZERO
+    );
+    assert_eq!(
+        underlying_token
+            .sender(WRITER)
+            .balance_of(contract.address()),
+        U256::from(WRITE_QUANTITY)
+    );
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .locked_collateral(underlying_token.address()),
+        U256::from(WRITE_QUANTITY)
+    );
+}
+
+#[motsu::test]
+fn partial_roll_leaves_rest_of_old_position(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let old_token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(WRITE_QUANTITY),
+    );
+
+    let new_token_id = contract
+        .sender(WRITER)
+        .roll_position(
+            old_token_id,
+            next_expiry(),
+            strike(60_000),
+            U256::from(WRITE_QUANTITY / 4),
+        )
+        .unwrap();
+
+    let quarter = U256::from(10).pow(U256::from(18)) / U256::from(4);
+    let rest = quarter * U256::from(3);
+    assert_eq!(
+        contract.sender(WRITER).get_position(WRITER, old_token_id),
+        (rest, rest)
+    );
+    assert_eq!(
+        contract.sender(WRITER).get_position(WRITER, new_token_id),
+        (quarter, quarter)
+    );
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .locked_collateral(underlying_token.address()),
+        U256::from(WRITE_QUANTITY)
+    );
+}
+
+#[motsu::test]
+fn roll_charges_protocol_fee_on_new_series(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let old_token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(WRITE_QUANTITY),
+    );
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+    contract
+        .sender(OWNER)
+        .set_fee(U256::from(30), TREASURY)
+        .unwrap();
+
+    let fee = U256::from(WRITE_QUANTITY * 30 / 10_000);
+    underlying_token.sender(WRITER).mint(WRITER, fee);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), fee);
+
+    contract
+        .sender(WRITER)
+        .roll_position(
+            old_token_id,
+            next_expiry(),
+            strike(70_000),
+            U256::from(WRITE_QUANTITY),
+        )
+        .unwrap();
+
+    assert_eq!(underlying_token.sender(WRITER).balance_of(TREASURY), fee);
+    assert_eq!(
+        underlying_token.sender(WRITER).balance_of(WRITER),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn roll_applies_write_validation_to_new_series(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let old_token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(WRITE_QUANTITY),
+    );
+
+    let result = contract.sender(WRITER).roll_position(
+        old_token_id,
+        1_600_000_000,
+        strike(70_000),
+        U256::from(WRITE_QUANTITY),
+    );
+    assert!(matches!(result, Err(OptionsError::ExpiredOption(_))));
+
+    let result = contract.sender(WRITER).roll_position(
+        old_token_id,
+        next_expiry(),
+        U256::ZERO,
+        U256::from(WRITE_QUANTITY),
+    );
+    assert!(matches!(result, Err(OptionsError::InvalidStrike(_))));
+}
+
+#[motsu::test]
+fn roll_rejects_more_than_written(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let old_token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(WRITE_QUANTITY),
+    );
+
+    let result = contract.sender(WRITER).roll_position(
+        old_token_id,
+        next_expiry(),
+        strike(70_000),
+        U256::from(WRITE_QUANTITY + 1),
+    );
+
+    assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+}
+
+#[motsu::test]
+fn roll_of_unknown_series_fails(contract: Contract<Options>) {
+    let result = contract.sender(WRITER).roll_position(
+        B256::from([0x99; 32]),
+        next_expiry(),
+        strike(70_000),
+        U256::from(WRITE_QUANTITY),
+    );
+
+    assert!(matches!(result, Err(OptionsError::OptionNotFound(_))));
+}
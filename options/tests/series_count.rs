@@ -0,0 +1,50 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, TestERC20};
+
+#[motsu::test]
+fn series_count_ignores_repeat_writes(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token
+        .sender(writer)
+        .mint(writer, write_quantity * U256::from(5));
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity * U256::from(5));
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let expiry = expiry();
+    let strikes = [50_000u64, 60_000, 70_000, 50_000, 70_000];
+
+    assert_eq!(contract.sender(writer).series_count(), U256::ZERO);
+
+    for strike in strikes {
+        let strike = U256::from(strike) * U256::from(10).pow(U256::from(18));
+        contract
+            .sender(writer)
+            .write_call_option(strike, expiry, write_quantity, underlying, quote)
+            .unwrap();
+    }
+
+    assert_eq!(contract.sender(writer).series_count(), U256::from(3));
+}
@@ -0,0 +1,153 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, SeriesIndexOutOfBounds, Token};
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+const WRITE_QUANTITY: u64 = 100_000_000;
+
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    strike: u64,
+) -> B256 {
+    let quantity = U256::from(WRITE_QUANTITY);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    contract
+        .sender(WRITER)
+        .write_call_option(
+            U256::from(strike) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap()
+}
+
+fn page(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    offset: u64,
+    limit: u64,
+) -> Vec<B256> {
+    contract.sender(WRITER).get_series_by_pair(
+        underlying_token.address(),
+        quote_token.address(),
+        U256::from(offset),
+        U256::from(limit),
+    )
+}
+
+#[motsu::test]
+fn registry_lists_series_across_pairs_in_creation_order(
+    contract: Contract<Options>,
+    btc: Contract<TestERC20>,
+    eth: Contract<TestERC20>,
+    usdc: Contract<TestERC20>,
+) {
+    let btc_low = write(&contract, &btc, &usdc, 50_000);
+    let eth_low = write(&contract, &eth, &usdc, 3_000);
+    let btc_high = write(&contract, &btc, &usdc, 70_000);
+
+    assert_eq!(contract.sender(WRITER).get_series_count(), U256::from(3));
+    assert_eq!(
+        contract.sender(WRITER).get_series_count(),
+        contract.sender(WRITER).series_count()
+    );
+    for (index, token_id) in [btc_low, eth_low, btc_high].into_iter().enumerate() {
+        assert_eq!(
+            contract
+                .sender(WRITER)
+                .get_series_at(U256::from(index))
+                .unwrap(),
+            token_id
+        );
+    }
+
+    assert_eq!(page(&contract, &btc, &usdc, 0, 10), vec![btc_low, btc_high]);
+    assert_eq!(page(&contract, &eth, &usdc, 0, 10), vec![eth_low]);
+    assert!(page(&contract, &usdc, &btc, 0, 10).is_empty());
+}
+
+#[motsu::test]
+fn rewriting_a_series_does_not_duplicate_it(
+    contract: Contract<Options>,
+    btc: Contract<TestERC20>,
+    usdc: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &btc, &usdc, 50_000);
+    write(&contract, &btc, &usdc, 50_000);
+
+    assert_eq!(contract.sender(WRITER).get_series_count(), U256::from(1));
+    assert_eq!(page(&contract, &btc, &usdc, 0, 10), vec![token_id]);
+}
+
+#[motsu::test]
+fn pair_pages_respect_offset_and_limit(
+    contract: Contract<Options>,
+    btc: Contract<TestERC20>,
+    usdc: Contract<TestERC20>,
+) {
+    let token_ids: Vec<B256> = [40_000, 50_000, 60_000, 70_000, 80_000]
+        .into_iter()
+        .map(|strike| write(&contract, &btc, &usdc, strike))
+        .collect();
+
+    assert_eq!(page(&contract, &btc, &usdc, 0, 2), token_ids[..2]);
+    assert_eq!(page(&contract, &btc, &usdc, 2, 2), token_ids[2..4]);
+    assert_eq!(page(&contract, &btc, &usdc, 4, 2), token_ids[4..]);
+    assert!(page(&contract, &btc, &usdc, 5, 2).is_empty());
+    assert!(page(&contract, &btc, &usdc, 0, 0).is_empty());
+    assert_eq!(
+        contract.sender(WRITER).get_series_by_pair(
+            btc.address(),
+            usdc.address(),
+            U256::MAX,
+            U256::MAX
+        ),
+        Vec::<B256>::new()
+    );
+    assert_eq!(
+        contract.sender(WRITER).get_series_by_pair(
+            btc.address(),
+            usdc.address(),
+            U256::from(1),
+            U256::MAX
+        ),
+        token_ids[1..]
+    );
+}
+
+#[motsu::test]
+fn series_index_past_the_end_fails(
+    contract: Contract<Options>,
+    btc: Contract<TestERC20>,
+    usdc: Contract<TestERC20>,
+) {
+    write(&contract, &btc, &usdc, 50_000);
+
+    let result = contract.sender(WRITER).get_series_at(U256::from(1));
+
+    assert!(matches!(
+        result,
+        Err(OptionsError::SeriesIndexOutOfBounds(SeriesIndexOutOfBounds { index, count }))
+            if index == U256::from(1) && count == U256::from(1)
+    ));
+}
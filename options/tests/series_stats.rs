@@ -0,0 +1,109 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, TestERC20};
+
+const ALICE: Address = Address::new([0xAA; 20]);
+const BOB: Address = Address::new([0xBB; 20]);
+
+fn scale() -> U256 {
+    U256::from(10).pow(U256::from(10))
+}
+
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    writer: Address,
+    quantity: u64,
+) -> B256 {
+    let quantity = U256::from(quantity);
+    underlying_token.sender(writer).mint(writer, quantity);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    contract
+        .sender(writer)
+        .write_call_option(
+            U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn unwritten_series_has_zero_stats(contract: Contract<Options>) {
+    assert_eq!(
+        contract
+            .sender(ALICE)
+            .get_series_stats(B256::from([0x99; 32])),
+        (U256::ZERO, U256::ZERO, U256::ZERO)
+    );
+}
+
+#[motsu::test]
+fn stats_track_writes_exercises_and_closes(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        ALICE,
+        100_000_000,
+    );
+    write(&contract, &underlying_token, &quote_token, BOB, 50_000_000);
+
+    assert_eq!(
+        contract.sender(ALICE).get_series_stats(token_id),
+        (
+            U256::from(150_000_000) * scale(),
+            U256::from(150_000_000) * scale(),
+            U256::ZERO
+        )
+    );
+
+    // A third of the exercise is assigned to Bob, whose 0.1 WBTC costs 6,000 USDC
+    let strike_owed = U256::from(6_000_000_000u64);
+    quote_token.sender(ALICE).mint(ALICE, strike_owed);
+    quote_token
+        .sender(ALICE)
+        .approve(contract.address(), strike_owed);
+    contract
+        .sender(ALICE)
+        .exercise_call(token_id, U256::from(30_000_000))
+        .unwrap();
+    contract
+        .sender(BOB)
+        .close_position(token_id, U256::from(20_000_000))
+        .unwrap();
+
+    let (total_supply, total_collateral, total_exercised) =
+        contract.sender(ALICE).get_series_stats(token_id);
+    assert_eq!(total_supply, U256::from(100_000_000) * scale());
+    assert_eq!(total_exercised, U256::from(30_000_000) * scale());
+    assert_eq!(
+        total_collateral,
+        contract
+            .sender(ALICE)
+            .get_collateral_locked(ALICE, token_id)
+            + contract.sender(BOB).get_collateral_locked(BOB, token_id)
+    );
+    assert_eq!(total_collateral, total_supply);
+}
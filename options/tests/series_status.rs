@@ -0,0 +1,101 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, SeriesStatus, Token};
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+const WRITE_QUANTITY: u64 = 100_000_000;
+
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+) -> B256 {
+    let quantity = U256::from(WRITE_QUANTITY);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    contract
+        .sender(WRITER)
+        .write_call_option(
+            U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn unwritten_series_is_not_written(contract: Contract<Options>) {
+    assert_eq!(
+        contract
+            .sender(WRITER)
+            .series_status(B256::from([0x99; 32])),
+        SeriesStatus::NotWritten.to_u8()
+    );
+}
+
+#[motsu::test]
+fn written_series_is_active_until_fully_closed(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+    assert_eq!(
+        contract.sender(WRITER).series_status(token_id),
+        SeriesStatus::Active.to_u8()
+    );
+
+    contract
+        .sender(WRITER)
+        .close_position(token_id, U256::from(WRITE_QUANTITY / 4))
+        .unwrap();
+    assert_eq!(
+        contract.sender(WRITER).series_status(token_id),
+        SeriesStatus::Active.to_u8()
+    );
+
+    contract
+        .sender(WRITER)
+        .close_position(token_id, U256::from(WRITE_QUANTITY * 3 / 4))
+        .unwrap();
+    assert_eq!(
+        contract.sender(WRITER).series_status(token_id),
+        SeriesStatus::Settled.to_u8()
+    );
+}
+
+#[motsu::test]
+fn fully_exercised_series_is_settled(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token);
+
+    // The writer exercises against their own position, so no strike is owed
+    contract
+        .sender(WRITER)
+        .exercise_call(token_id, U256::from(WRITE_QUANTITY))
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(WRITER).series_status(token_id),
+        SeriesStatus::Settled.to_u8()
+    );
+}
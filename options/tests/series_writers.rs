@@ -0,0 +1,117 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, TestERC20};
+
+const ALICE: Address = Address::new([0xAA; 20]);
+const BOB: Address = Address::new([0xBB; 20]);
+const WRITE_QUANTITY: u64 = 100_000_000;
+
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    writer: Address,
+    strike: u64,
+) -> B256 {
+    let quantity = U256::from(WRITE_QUANTITY);
+    underlying_token.sender(writer).mint(writer, quantity);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    contract
+        .sender(writer)
+        .write_call_option(
+            U256::from(strike) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn unwritten_series_has_no_writers(contract: Contract<Options>) {
+    assert!(contract
+        .sender(ALICE)
+        .get_series_writers(B256::from([0x99; 32]))
+        .is_empty());
+}
+
+#[motsu::test]
+fn writers_are_listed_in_order_of_first_write(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token, ALICE, 60_000);
+    write(&contract, &underlying_token, &quote_token, BOB, 60_000);
+
+    assert_eq!(
+        contract.sender(ALICE).get_series_writers(token_id),
+        vec![ALICE, BOB]
+    );
+}
+
+#[motsu::test]
+fn repeated_writes_list_a_writer_once(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token, ALICE, 60_000);
+    write(&contract, &underlying_token, &quote_token, BOB, 60_000);
+    write(&contract, &underlying_token, &quote_token, ALICE, 60_000);
+
+    assert_eq!(
+        contract.sender(ALICE).get_series_writers(token_id),
+        vec![ALICE, BOB]
+    );
+}
+
+#[motsu::test]
+fn closed_writer_stays_listed_once(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(&contract, &underlying_token, &quote_token, ALICE, 60_000);
+    contract
+        .sender(ALICE)
+        .close_position(token_id, U256::from(WRITE_QUANTITY))
+        .unwrap();
+    write(&contract, &underlying_token, &quote_token, ALICE, 60_000);
+
+    assert_eq!(
+        contract.sender(ALICE).get_series_writers(token_id),
+        vec![ALICE]
+    );
+}
+
+#[motsu::test]
+fn writers_are_tracked_per_series(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let first = write(&contract, &underlying_token, &quote_token, ALICE, 60_000);
+    let second = write(&contract, &underlying_token, &quote_token, BOB, 70_000);
+
+    assert_eq!(
+        contract.sender(ALICE).get_series_writers(first),
+        vec![ALICE]
+    );
+    assert_eq!(contract.sender(ALICE).get_series_writers(second), vec![BOB]);
+}
@@ -0,0 +1,177 @@
+mod test_erc20;
+mod test_oracle;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+use test_oracle::TestOracle;
+
+const MAX_STALENESS: u64 = 3_600;
+
+fn write_cash_settled_call(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    oracle: &Contract<TestOracle>,
+    writer: Address,
+    write_quantity: U256,
+) -> B256 {
+    contract.sender(writer).constructor();
+    contract.sender(writer).initialize(writer).unwrap();
+    contract
+        .sender(writer)
+        .set_max_settlement_staleness(MAX_STALENESS)
+        .unwrap();
+
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), write_quantity);
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    contract
+        .sender(writer)
+        .write_cash_settled_call_option(
+            U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            write_quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+            oracle.address(),
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn cash_exercise_with_fresh_price_succeeds(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let write_quantity = U256::from(100_000_000);
+    let token_id = write_cash_settled_call(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        &oracle,
+        writer,
+        write_quantity,
+    );
+
+    oracle
+        .sender(writer)
+        .set_price(U256::from(80_000) * U256::from(10).pow(U256::from(18)));
+
+    contract
+        .sender(writer)
+        .exercise_call_cash(token_id, write_quantity)
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        write_quantity
+    );
+}
+
+#[motsu::test]
+fn cash_exercise_with_stale_price_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let write_quantity = U256::from(100_000_000);
+    let token_id = write_cash_settled_call(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        &oracle,
+        writer,
+        write_quantity,
+    );
+
+    oracle
+        .sender(writer)
+        .set_price(U256::from(80_000) * U256::from(10).pow(U256::from(18)));
+    oracle
+        .sender(writer)
+        .set_updated_at(U256::from(1_000_000_000u64));
+
+    let result = contract
+        .sender(writer)
+        .exercise_call_cash(token_id, write_quantity);
+
+    assert!(matches!(result, Err(OptionsError::StaleSettlementPrice(_))));
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn stale_price_accepted_when_check_disabled(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+    oracle: Contract<TestOracle>,
+) {
+    let writer = Address::from([0xCC; 20]);
+    let write_quantity = U256::from(100_000_000);
+    let token_id = write_cash_settled_call(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        &oracle,
+        writer,
+        write_quantity,
+    );
+    contract
+        .sender(writer)
+        .set_max_settlement_staleness(0)
+        .unwrap();
+
+    oracle
+        .sender(writer)
+        .set_price(U256::from(80_000) * U256::from(10).pow(U256::from(18)));
+    oracle
+        .sender(writer)
+        .set_updated_at(U256::from(1_000_000_000u64));
+
+    contract
+        .sender(writer)
+        .exercise_call_cash(token_id, write_quantity)
+        .unwrap();
+}
+
+#[motsu::test]
+fn only_owner_sets_max_staleness(contract: Contract<Options>) {
+    let owner = Address::from([0xDD; 20]);
+    let alice = Address::from([0xEE; 20]);
+    contract.sender(owner).constructor();
+    contract.sender(owner).initialize(owner).unwrap();
+
+    let result = contract
+        .sender(alice)
+        .set_max_settlement_staleness(MAX_STALENESS);
+    assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+
+    contract
+        .sender(owner)
+        .set_max_settlement_staleness(MAX_STALENESS)
+        .unwrap();
+    assert_eq!(
+        contract.sender(alice).max_settlement_staleness(),
+        MAX_STALENESS
+    );
+}
@@ -0,0 +1,140 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{DecimalsChanged, Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+const OWNER: Address = Address::new([0x01; 20]);
+const WRITER: Address = Address::new([0xAA; 20]);
+
+/// Writes a 1 WBTC-style (8 decimals) call against a 6-decimals quote token.
+fn write_series(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+) -> B256 {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    let quantity = U256::from(100_000_000);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+
+    contract
+        .sender(WRITER)
+        .write_call_option(strike, expiry(), quantity, underlying, quote)
+        .unwrap()
+}
+
+#[motsu::test]
+fn strict_decimals_is_disabled_by_default(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write_series(&contract, &underlying_token, &quote_token);
+    underlying_token.sender(WRITER).set_decimals(18);
+
+    assert!(!contract.sender(WRITER).strict_decimals());
+    contract
+        .sender(WRITER)
+        .exercise_call(token_id, U256::from(50_000_000))
+        .unwrap();
+}
+
+#[motsu::test]
+fn strict_exercise_succeeds_when_decimals_unchanged(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write_series(&contract, &underlying_token, &quote_token);
+    contract.sender(OWNER).set_strict_decimals(true).unwrap();
+
+    contract
+        .sender(WRITER)
+        .exercise_call(token_id, U256::from(50_000_000))
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(WRITER).balance_of(WRITER),
+        U256::from(50_000_000)
+    );
+}
+
+#[motsu::test]
+fn strict_exercise_reverts_when_underlying_decimals_change(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write_series(&contract, &underlying_token, &quote_token);
+    contract.sender(OWNER).set_strict_decimals(true).unwrap();
+    underlying_token.sender(WRITER).set_decimals(18);
+
+    let result = contract
+        .sender(WRITER)
+        .exercise_call(token_id, U256::from(50_000_000));
+
+    assert!(matches!(
+        result,
+        Err(OptionsError::DecimalsChanged(DecimalsChanged {
+            stored: 8,
+            current: 18
+        }))
+    ));
+    assert_eq!(
+        underlying_token.sender(WRITER).balance_of(WRITER),
+        U256::ZERO
+    );
+}
+
+#[motsu::test]
+fn strict_exercise_reverts_when_quote_decimals_change(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write_series(&contract, &underlying_token, &quote_token);
+    contract.sender(OWNER).set_strict_decimals(true).unwrap();
+    quote_token.sender(WRITER).set_decimals(18);
+
+    let result = contract
+        .sender(WRITER)
+        .exercise_call(token_id, U256::from(50_000_000));
+
+    assert!(matches!(
+        result,
+        Err(OptionsError::DecimalsChanged(DecimalsChanged {
+            stored: 6,
+            current: 18
+        }))
+    ));
+}
+
+#[motsu::test]
+fn only_owner_can_set_strict_decimals(contract: Contract<Options>) {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+
+    let result = contract.sender(WRITER).set_strict_decimals(true);
+
+    assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+    assert!(!contract.sender(WRITER).strict_decimals());
+}
@@ -0,0 +1,142 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token, MAX_STRIKE};
+use test_erc20::{expiry, TestERC20};
+
+#[motsu::test]
+fn option_at_max_strike_is_written_and_exercisable(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(MAX_STRIKE, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    contract
+        .sender(writer)
+        .exercise_call(token_id, write_quantity)
+        .unwrap();
+
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        write_quantity
+    );
+}
+
+#[motsu::test]
+fn option_above_max_strike_is_rejected(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let expiry = expiry();
+
+    let result = contract.sender(writer).write_call_option(
+        MAX_STRIKE + U256::from(1),
+        expiry,
+        write_quantity,
+        underlying,
+        quote,
+    );
+
+    assert!(matches!(result, Err(OptionsError::StrikeTooLarge(_))));
+    assert_eq!(
+        underlying_token.sender(writer).balance_of(writer),
+        write_quantity
+    );
+}
+
+#[motsu::test]
+fn write_overflowing_series_notional_is_rejected(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xCC; 20]);
+    let options_addr = contract.address();
+
+    // Largest quantity whose notional at MAX_STRIKE still fits in a U256
+    let max_quantity = U256::MAX / MAX_STRIKE;
+    let total = max_quantity + U256::from(1);
+    underlying_token.sender(writer).mint(writer, total);
+    underlying_token.sender(writer).approve(options_addr, total);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 18,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(18);
+    quote_token.sender(writer).set_decimals(6);
+
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(MAX_STRIKE, expiry, max_quantity, underlying, quote)
+        .unwrap();
+
+    // A second write to the same series would push its total past the bound
+    let result = contract.sender(writer).write_call_option(
+        MAX_STRIKE,
+        expiry,
+        U256::from(1),
+        underlying,
+        quote,
+    );
+
+    assert!(matches!(result, Err(OptionsError::StrikeTooLarge(_))));
+    assert_eq!(
+        contract.sender(writer).total_written(token_id),
+        max_quantity
+    );
+}
@@ -0,0 +1,149 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+const OWNER: Address = Address::new([0x0A; 20]);
+const WRITER: Address = Address::new([0xAA; 20]);
+const WRITE_QUANTITY: u64 = 100_000_000;
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+fn setup(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+) {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+
+    let funding = U256::from(WRITE_QUANTITY) * U256::from(2);
+    underlying_token.sender(WRITER).mint(WRITER, funding);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), funding);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+}
+
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    strike: U256,
+) -> Result<B256, OptionsError> {
+    contract.sender(WRITER).write_call_option(
+        strike,
+        expiry(),
+        U256::from(WRITE_QUANTITY),
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: quote_token.address(),
+            decimals: 6,
+        },
+    )
+}
+
+#[motsu::test]
+fn disabled_tick_accepts_any_strike(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    setup(&contract, &underlying_token, &quote_token);
+
+    assert_eq!(contract.sender(WRITER).strike_tick(), U256::ZERO);
+    write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(60_000) * one() + U256::from(1),
+    )
+    .unwrap();
+}
+
+#[motsu::test]
+fn enabled_tick_accepts_aligned_strikes(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    setup(&contract, &underlying_token, &quote_token);
+    let tick = U256::from(500) * one();
+    contract.sender(OWNER).set_strike_tick(tick).unwrap();
+
+    assert_eq!(contract.sender(WRITER).strike_tick(), tick);
+    write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(60_000) * one(),
+    )
+    .unwrap();
+    write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(60_500) * one(),
+    )
+    .unwrap();
+}
+
+#[motsu::test]
+fn enabled_tick_rejects_misaligned_strikes(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    setup(&contract, &underlying_token, &quote_token);
+    let tick = U256::from(500) * one();
+    contract.sender(OWNER).set_strike_tick(tick).unwrap();
+
+    let result = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(60_250) * one(),
+    );
+
+    assert!(matches!(result, Err(OptionsError::InvalidStrikeTick(_))));
+}
+
+#[motsu::test]
+fn disabling_tick_accepts_misaligned_strikes_again(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    setup(&contract, &underlying_token, &quote_token);
+    contract
+        .sender(OWNER)
+        .set_strike_tick(U256::from(500) * one())
+        .unwrap();
+    contract.sender(OWNER).set_strike_tick(U256::ZERO).unwrap();
+
+    write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        U256::from(60_250) * one(),
+    )
+    .unwrap();
+}
+
+#[motsu::test]
+fn only_owner_sets_strike_tick(contract: Contract<Options>) {
+    contract.sender(OWNER).constructor();
+    contract.sender(OWNER).initialize(OWNER).unwrap();
+
+    let result = contract.sender(WRITER).set_strike_tick(U256::from(1));
+
+    assert!(matches!(result, Err(OptionsError::Unauthorized(_))));
+}
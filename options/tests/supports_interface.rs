@@ -0,0 +1,35 @@
+use alloy_primitives::{Address, FixedBytes};
+use motsu::prelude::*;
+use options::{
+    Options, ERC1155_BATCH_RECEIVED, ERC1155_INTERFACE_ID, ERC1155_METADATA_URI_INTERFACE_ID,
+    ERC1155_RECEIVED, ERC165_INTERFACE_ID,
+};
+
+const CALLER: Address = Address::new([0xAA; 20]);
+
+#[motsu::test]
+fn reports_known_interface_ids(contract: Contract<Options>) {
+    let cases = [
+        (ERC165_INTERFACE_ID, true),
+        // Standard ERC-1155 interfaces take uint256 token IDs, unlike this ABI
+        (ERC1155_INTERFACE_ID, false),
+        (ERC1155_METADATA_URI_INTERFACE_ID, false),
+        // ERC-1155 token receiver interface, which the contract doesn't implement
+        ([0x4e, 0x23, 0x12, 0xe0], false),
+        (ERC1155_RECEIVED, false),
+        (ERC1155_BATCH_RECEIVED, false),
+        // ERC-165 reserves 0xffffffff as never supported
+        ([0xff; 4], false),
+        ([0x00; 4], false),
+    ];
+
+    for (interface_id, expected) in cases {
+        assert_eq!(
+            contract
+                .sender(CALLER)
+                .supports_interface(FixedBytes(interface_id)),
+            expected,
+            "interface {interface_id:02x?}"
+        );
+    }
+}
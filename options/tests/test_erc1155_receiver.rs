@@ -0,0 +1,92 @@
+extern crate alloc;
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use options::{ERC1155_BATCH_RECEIVED, ERC1155_RECEIVED};
+use stylus_sdk::{abi::Bytes, prelude::*};
+
+sol_storage! {
+    #[entrypoint]
+    pub struct TestERC1155Receiver {
+        bool rejects;
+        uint256 received_count;
+        address last_operator;
+        address last_from;
+        uint256 last_value;
+    }
+}
+
+#[public]
+impl TestERC1155Receiver {
+    /// Makes both hooks answer with a wrong selector when `rejects` is set.
+    pub fn set_rejects(&mut self, rejects: bool) {
+        self.rejects.set(rejects);
+    }
+
+    #[selector(name = "onERC1155Received")]
+    pub fn on_erc1155_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        id: U256,
+        value: U256,
+        data: Bytes,
+    ) -> FixedBytes<4> {
+        let _ = (id, data);
+        self.record(operator, from, value);
+        if self.rejects.get() {
+            FixedBytes::ZERO
+        } else {
+            FixedBytes::from(ERC1155_RECEIVED)
+        }
+    }
+
+    #[selector(name = "onERC1155BatchReceived")]
+    pub fn on_erc1155_batch_received(
+        &mut self,
+        operator: Address,
+        from: Address,
+        ids: Vec<U256>,
+        values: Vec<U256>,
+        data: Bytes,
+    ) -> FixedBytes<4> {
+        let _ = (ids, data);
+        let total = values.iter().fold(U256::ZERO, |acc, value| acc + value);
+        self.record(operator, from, total);
+        if self.rejects.get() {
+            FixedBytes::ZERO
+        } else {
+            FixedBytes::from(ERC1155_BATCH_RECEIVED)
+        }
+    }
+
+    #[must_use]
+    pub fn received_count(&self) -> U256 {
+        self.received_count.get()
+    }
+
+    #[must_use]
+    pub fn last_operator(&self) -> Address {
+        self.last_operator.get()
+    }
+
+    #[must_use]
+    pub fn last_from(&self) -> Address {
+        self.last_from.get()
+    }
+
+    /// Sum of the values of the last accepted or rejected call.
+    #[must_use]
+    pub fn last_value(&self) -> U256 {
+        self.last_value.get()
+    }
+}
+
+impl TestERC1155Receiver {
+    fn record(&mut self, operator: Address, from: Address, value: U256) {
+        self.received_count
+            .set(self.received_count.get() + U256::from(1));
+        self.last_operator.set(operator);
+        self.last_from.set(from);
+        self.last_value.set(value);
+    }
+}
@@ -1,14 +1,39 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
-use alloy_primitives::{Address, U256};
-use stylus_sdk::prelude::*;
+use alloy_primitives::{Address, B256, U256, U8};
+use stylus_sdk::{call::Call, prelude::*};
+
+/// Seconds from the start of the current day to the expiry returned by [`expiry`].
+const EXPIRY_OFFSET: u64 = 180 * 86_400;
+
+/// Expiry `EXPIRY_OFFSET` after the current block timestamp, rounded down to the start of the
+/// day so repeated calls within a test agree.
+///
+/// motsu reports wall-clock time as the block timestamp, so this stays inside the default
+/// expiry horizon whenever the tests run.
+#[allow(dead_code)]
+pub fn expiry() -> u64 {
+    let block_timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock before unix epoch")
+        .as_secs();
+
+    block_timestamp - block_timestamp % 86_400 + EXPIRY_OFFSET
+}
+
+sol_interface! {
+    interface IOptions {
+        function exerciseCall(bytes32 token_id, uint256 quantity) external;
+    }
+}
 
 sol_storage! {
     #[entrypoint]
     pub struct TestERC20 {
         mapping(address => uint256) balances;
         mapping(address => mapping(address => uint256)) allowances;
+        uint8 decimals;
     }
 }
 
@@ -57,6 +82,119 @@ impl TestERC20 {
         true
     }
 
+    pub fn approve(&mut self, spender: Address, amount: U256) -> bool {
+        let owner = self.vm().msg_sender();
+        let mut allowance_setter = self.allowances.setter(owner);
+        allowance_setter.insert(spender, amount);
+
+        true
+    }
+
+    pub fn mint(&mut self, to: Address, amount: U256) {
+        let current_balance = self.balances.get(to);
+        self.balances.insert(to, current_balance + amount);
+    }
+
+    #[must_use]
+    pub fn decimals(&self) -> u8 {
+        self.decimals.get().to::<u8>()
+    }
+
+    /// Sets the reported decimals, e.g. to simulate an upgradeable token changing them.
+    pub fn set_decimals(&mut self, decimals: u8) {
+        self.decimals.set(U8::from(decimals));
+    }
+}
+
+sol_storage! {
+    /// Malicious ERC20 that calls back into `exercise_call` from `transfer`.
+    #[entrypoint]
+    pub struct ReentrantERC20 {
+        mapping(address => uint256) balances;
+        mapping(address => mapping(address => uint256)) allowances;
+        bytes32 attack_token_id;
+        uint256 attack_quantity;
+        bool armed;
+        bool reentry_attempted;
+        bool reentry_reverted;
+        uint8 decimals;
+    }
+}
+
+#[public]
+impl ReentrantERC20 {
+    #[must_use]
+    pub fn balance_of(&self, account: Address) -> U256 {
+        self.balances.get(account)
+    }
+
+    /// Arms the attack: the next `transfer` re-enters `exercise_call` on the caller.
+    pub fn arm(&mut self, token_id: B256, quantity: U256) {
+        self.attack_token_id.set(token_id);
+        self.attack_quantity.set(quantity);
+        self.armed.set(true);
+    }
+
+    #[must_use]
+    pub fn reentry_attempted(&self) -> bool {
+        self.reentry_attempted.get()
+    }
+
+    #[must_use]
+    pub fn reentry_reverted(&self) -> bool {
+        self.reentry_reverted.get()
+    }
+
+    #[allow(deprecated)]
+    pub fn transfer(&mut self, to: Address, amount: U256) -> bool {
+        let from = self.vm().msg_sender();
+
+        if self.armed.get() {
+            self.armed.set(false);
+            self.reentry_attempted.set(true);
+
+            let options = IOptions::new(from);
+            let token_id = self.attack_token_id.get();
+            let quantity = self.attack_quantity.get();
+            let result = options.exercise_call(Call::new_in(self), token_id, quantity);
+            self.reentry_reverted.set(result.is_err());
+        }
+
+        let sender_balance = self.balances.get(from);
+        if sender_balance < amount {
+            return false;
+        }
+
+        self.balances.insert(from, sender_balance - amount);
+        let recipient_balance = self.balances.get(to);
+        self.balances.insert(to, recipient_balance + amount);
+
+        true
+    }
+
+    pub fn transfer_from(&mut self, from: Address, to: Address, amount: U256) -> bool {
+        let spender = self.vm().msg_sender();
+        let allowance = self.allowances.getter(from).get(spender);
+
+        if allowance < amount {
+            return false;
+        }
+
+        let sender_balance = self.balances.get(from);
+        if sender_balance < amount {
+            return false;
+        }
+
+        self.balances.insert(from, sender_balance - amount);
+        let recipient_balance = self.balances.get(to);
+        self.balances.insert(to, recipient_balance + amount);
+
+        let mut allowance_setter = self.allowances.setter(from);
+        allowance_setter.insert(spender, allowance - amount);
+
+        true
+    }
+
     pub fn approve(&mut self, spender: Address, amount: U256) {
         let owner = self.vm().msg_sender();
         let mut allowance_setter = self.allowances.setter(owner);
@@ -67,4 +205,13 @@ impl TestERC20 {
         let current_balance = self.balances.get(to);
         self.balances.insert(to, current_balance + amount);
     }
+
+    #[must_use]
+    pub fn decimals(&self) -> u8 {
+        self.decimals.get().to::<u8>()
+    }
+
+    pub fn set_decimals(&mut self, decimals: u8) {
+        self.decimals.set(U8::from(decimals));
+    }
 }
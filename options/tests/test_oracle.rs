@@ -0,0 +1,55 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use alloy_primitives::{Address, U256, U8};
+use stylus_sdk::prelude::*;
+
+sol_storage! {
+    #[entrypoint]
+    pub struct TestOracle {
+        uint256 price;
+        uint256 updated_at;
+        uint8 answer_decimals;
+        bool answer_decimals_set;
+    }
+}
+
+#[public]
+impl TestOracle {
+    #[must_use]
+    pub fn latest_price(&self, base: Address, quote: Address) -> U256 {
+        let _ = (base, quote);
+        self.price.get()
+    }
+
+    #[must_use]
+    pub fn latest_timestamp(&self, base: Address, quote: Address) -> U256 {
+        let _ = (base, quote);
+        self.updated_at.get()
+    }
+
+    /// Decimals of `latest_price`, 18 until `set_decimals` is called.
+    #[must_use]
+    pub fn decimals(&self) -> u8 {
+        if self.answer_decimals_set.get() {
+            self.answer_decimals.get().to::<u8>()
+        } else {
+            18
+        }
+    }
+
+    pub fn set_decimals(&mut self, decimals: u8) {
+        self.answer_decimals.set(U8::from(decimals));
+        self.answer_decimals_set.set(true);
+    }
+
+    pub fn set_price(&mut self, price: U256) {
+        let now = self.vm().block_timestamp();
+        self.price.set(price);
+        self.updated_at.set(U256::from(now));
+    }
+
+    pub fn set_updated_at(&mut self, updated_at: U256) {
+        self.updated_at.set(updated_at);
+    }
+}
@@ -0,0 +1,158 @@
+mod test_erc20;
+mod test_oracle;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{DecimalsMismatch, Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+use test_oracle::TestOracle;
+
+const WRITER: Address = Address::new([0xAA; 20]);
+const WRITE_QUANTITY: u64 = 100_000_000;
+
+fn fund_writer(contract: &Contract<Options>, underlying_token: &Contract<TestERC20>) {
+    let quantity = U256::from(WRITE_QUANTITY);
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+}
+
+fn write(
+    contract: &Contract<Options>,
+    underlying: Token,
+    quote: Token,
+) -> Result<(), OptionsError> {
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    contract
+        .sender(WRITER)
+        .write_call_option(
+            strike,
+            expiry(),
+            U256::from(WRITE_QUANTITY),
+            underlying,
+            quote,
+        )
+        .map(|_| ())
+}
+
+#[motsu::test]
+fn matching_decimals_are_accepted(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    fund_writer(&contract, &underlying_token);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    write(
+        &contract,
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: quote_token.address(),
+            decimals: 6,
+        },
+    )
+    .unwrap();
+
+    assert_eq!(contract.sender(WRITER).series_count(), U256::from(1));
+}
+
+#[motsu::test]
+fn misdeclared_quote_decimals_are_rejected(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    fund_writer(&contract, &underlying_token);
+    underlying_token.sender(WRITER).set_decimals(8);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    // A USDC-like quote declared with 18 decimals
+    let result = write(
+        &contract,
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: quote_token.address(),
+            decimals: 18,
+        },
+    );
+
+    assert!(matches!(
+        result,
+        Err(OptionsError::DecimalsMismatch(DecimalsMismatch {
+            token,
+            declared: 18,
+            actual: 6,
+        })) if token == quote_token.address()
+    ));
+    assert_eq!(contract.sender(WRITER).series_count(), U256::ZERO);
+    assert_eq!(
+        underlying_token.sender(WRITER).balance_of(WRITER),
+        U256::from(WRITE_QUANTITY)
+    );
+}
+
+#[motsu::test]
+fn misdeclared_underlying_decimals_are_rejected(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    fund_writer(&contract, &underlying_token);
+    underlying_token.sender(WRITER).set_decimals(18);
+    quote_token.sender(WRITER).set_decimals(6);
+
+    let result = write(
+        &contract,
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: quote_token.address(),
+            decimals: 6,
+        },
+    );
+
+    assert!(matches!(
+        result,
+        Err(OptionsError::DecimalsMismatch(DecimalsMismatch {
+            token,
+            declared: 8,
+            actual: 18,
+        })) if token == underlying_token.address()
+    ));
+}
+
+#[motsu::test]
+fn token_without_decimals_is_rejected(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    not_a_token: Contract<TestOracle>,
+) {
+    fund_writer(&contract, &underlying_token);
+    underlying_token.sender(WRITER).set_decimals(8);
+
+    let result = write(
+        &contract,
+        Token {
+            address: underlying_token.address(),
+            decimals: 8,
+        },
+        Token {
+            address: not_a_token.address(),
+            decimals: 6,
+        },
+    );
+
+    assert!(matches!(result, Err(OptionsError::DecimalsUnavailable(_))));
+    assert_eq!(contract.sender(WRITER).series_count(), U256::ZERO);
+}
@@ -0,0 +1,69 @@
+mod test_erc20;
+
+use alloy_primitives::{keccak256, Address, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+#[motsu::test]
+fn preimage_hashes_to_written_token_id(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, quantity);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(
+            strike,
+            expiry,
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap();
+
+    let preimage = contract
+        .sender(writer)
+        .token_id_preimage(
+            underlying_token.address(),
+            quote_token.address(),
+            strike,
+            expiry,
+            0,
+        )
+        .unwrap();
+
+    assert_eq!(keccak256(preimage.as_slice()), token_id);
+}
+
+#[motsu::test]
+fn preimage_rejects_unknown_option_type(contract: Contract<Options>) {
+    let result = contract
+        .sender(Address::from([0xAA; 20]))
+        .token_id_preimage(
+            Address::from([0x11; 20]),
+            Address::from([0x22; 20]),
+            U256::from(60_000),
+            2_000_000_000,
+            2,
+        );
+
+    assert!(matches!(result, Err(OptionsError::WrongOptionType(_))));
+}
@@ -0,0 +1,205 @@
+mod test_erc20;
+
+use alloy_primitives::{hex, Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+const WRITER: Address = Address::new([0xAA; 20]);
+const PREFIX: &str = "data:application/json;base64,";
+
+fn one() -> U256 {
+    U256::from(10).pow(U256::from(18))
+}
+
+/// Decodes standard padded base64.
+fn base64_decode(encoded: &str) -> Vec<u8> {
+    let sextet = |c: u8| match c {
+        b'A'..=b'Z' => c - b'A',
+        b'a'..=b'z' => c - b'a' + 26,
+        b'0'..=b'9' => c - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => panic!("invalid base64 character {c}"),
+    };
+
+    let mut decoded = Vec::new();
+    for chunk in encoded.as_bytes().chunks(4) {
+        let significant: Vec<u8> = chunk
+            .iter()
+            .take_while(|&&c| c != b'=')
+            .map(|&c| sextet(c))
+            .collect();
+        let bits = significant
+            .iter()
+            .fold(0u32, |bits, &s| (bits << 6) | u32::from(s))
+            << (6 * (4 - significant.len()));
+        let bytes = bits.to_be_bytes();
+        decoded.extend_from_slice(&bytes[1..significant.len()]);
+    }
+    decoded
+}
+
+/// Writes one whole underlying token of calls at `strike` and returns the token ID.
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    underlying_decimals: u8,
+    quote_decimals: u8,
+    strike: U256,
+) -> B256 {
+    let quantity = U256::from(10).pow(U256::from(underlying_decimals));
+    underlying_token.sender(WRITER).mint(WRITER, quantity);
+    underlying_token
+        .sender(WRITER)
+        .approve(contract.address(), quantity);
+    underlying_token
+        .sender(WRITER)
+        .set_decimals(underlying_decimals);
+    quote_token.sender(WRITER).set_decimals(quote_decimals);
+
+    contract
+        .sender(WRITER)
+        .write_call_option(
+            strike,
+            expiry(),
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: underlying_decimals,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: quote_decimals,
+            },
+        )
+        .unwrap()
+}
+
+fn decoded_json(contract: &Contract<Options>, token_id: B256) -> String {
+    let uri = contract.sender(WRITER).uri(token_id).unwrap();
+    let encoded = uri.strip_prefix(PREFIX).expect("data URI prefix");
+    String::from_utf8(base64_decode(encoded)).unwrap()
+}
+
+fn expected_json(
+    underlying: Address,
+    quote: Address,
+    underlying_decimals: u8,
+    quote_decimals: u8,
+    strike: &str,
+) -> String {
+    let underlying = format!("0x{}", hex::encode(underlying));
+    let quote = format!("0x{}", hex::encode(quote));
+    format!(
+        concat!(
+            r#"{{"name":"CALL {underlying} @ {strike} exp {expiry}","#,
+            r#""decimals":18,"attributes":["#,
+            r#"{{"trait_type":"Option Type","value":"CALL"}},"#,
+            r#"{{"trait_type":"Strike","value":"{strike}"}},"#,
+            r#"{{"trait_type":"Expiry","display_type":"date","value":{expiry}}},"#,
+            r#"{{"trait_type":"Underlying","value":"{underlying}"}},"#,
+            r#"{{"trait_type":"Underlying Decimals","value":{underlying_decimals}}},"#,
+            r#"{{"trait_type":"Quote","value":"{quote}"}},"#,
+            r#"{{"trait_type":"Quote Decimals","value":{quote_decimals}}}]}}"#,
+        ),
+        underlying = underlying,
+        strike = strike,
+        expiry = expiry(),
+        underlying_decimals = underlying_decimals,
+        quote = quote,
+        quote_decimals = quote_decimals,
+    )
+}
+
+#[motsu::test]
+fn uri_round_trips_wbtc_usdc_metadata(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        8,
+        6,
+        U256::from(60_000) * one(),
+    );
+
+    assert_eq!(
+        decoded_json(&contract, token_id),
+        expected_json(
+            underlying_token.address(),
+            quote_token.address(),
+            8,
+            6,
+            "60000"
+        )
+    );
+}
+
+#[motsu::test]
+fn uri_formats_fractional_strike_with_18_decimal_tokens(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        18,
+        18,
+        one() / U256::from(4),
+    );
+
+    assert_eq!(
+        decoded_json(&contract, token_id),
+        expected_json(
+            underlying_token.address(),
+            quote_token.address(),
+            18,
+            18,
+            "0.25"
+        )
+    );
+}
+
+#[motsu::test]
+fn uri_round_trips_6_decimal_underlying(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let token_id = write(
+        &contract,
+        &underlying_token,
+        &quote_token,
+        6,
+        18,
+        U256::from(3) * one() / U256::from(2),
+    );
+
+    assert_eq!(
+        decoded_json(&contract, token_id),
+        expected_json(
+            underlying_token.address(),
+            quote_token.address(),
+            6,
+            18,
+            "1.5"
+        )
+    );
+}
+
+#[motsu::test]
+fn uri_of_unwritten_series_fails(contract: Contract<Options>) {
+    let err = contract
+        .sender(WRITER)
+        .uri(B256::from([0x99; 32]))
+        .unwrap_err();
+
+    assert!(matches!(err, OptionsError::OptionNotFound(_)));
+}
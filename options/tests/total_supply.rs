@@ -0,0 +1,198 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, TestERC20};
+
+#[motsu::test]
+fn total_supply_increases_on_write(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token
+        .sender(writer)
+        .mint(writer, write_quantity * U256::from(2));
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity * U256::from(2));
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    let normalized_quantity = write_quantity * U256::from(10).pow(U256::from(10));
+    assert_eq!(
+        contract.sender(writer).total_supply(token_id),
+        normalized_quantity
+    );
+
+    contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(writer).total_supply(token_id),
+        normalized_quantity * U256::from(2)
+    );
+}
+
+#[motsu::test]
+fn total_supply_decreases_on_exercise(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    let exercise_quantity = U256::from(30_000_000);
+    contract
+        .sender(writer)
+        .exercise_call(token_id, exercise_quantity)
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(writer).total_supply(token_id),
+        (write_quantity - exercise_quantity) * U256::from(10).pow(U256::from(10))
+    );
+
+    contract
+        .sender(writer)
+        .exercise_call(token_id, write_quantity - exercise_quantity)
+        .unwrap();
+
+    assert_eq!(contract.sender(writer).total_supply(token_id), U256::ZERO);
+}
+
+#[motsu::test]
+fn total_supply_is_zero_for_unknown_token_id(contract: Contract<Options>) {
+    let alice = Address::from([0xCC; 20]);
+
+    assert_eq!(
+        contract.sender(alice).total_supply(B256::from([0x99; 32])),
+        U256::ZERO
+    );
+}
+
+/// Writes 1 WBTC of calls as `writer` and returns the token ID.
+fn write(
+    contract: &Contract<Options>,
+    underlying_token: &Contract<TestERC20>,
+    quote_token: &Contract<TestERC20>,
+    writer: Address,
+) -> B256 {
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), write_quantity);
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    contract
+        .sender(writer)
+        .write_call_option(
+            U256::from(60_000) * U256::from(10).pow(U256::from(18)),
+            expiry(),
+            write_quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap()
+}
+
+#[motsu::test]
+fn never_written_series_does_not_exist(contract: Contract<Options>) {
+    let alice = Address::from([0xCC; 20]);
+    let token_id = B256::from([0x99; 32]);
+
+    assert!(!contract.sender(alice).exists(token_id));
+    assert_eq!(contract.sender(alice).total_supply(token_id), U256::ZERO);
+}
+
+#[motsu::test]
+fn written_series_exists_with_supply(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let token_id = write(&contract, &underlying_token, &quote_token, writer);
+
+    assert!(contract.sender(writer).exists(token_id));
+    assert_eq!(
+        contract.sender(writer).total_supply(token_id),
+        U256::from(10).pow(U256::from(18))
+    );
+}
+
+#[motsu::test]
+fn fully_exercised_series_still_exists(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let token_id = write(&contract, &underlying_token, &quote_token, writer);
+
+    contract
+        .sender(writer)
+        .exercise_call(token_id, U256::from(100_000_000))
+        .unwrap();
+
+    assert!(contract.sender(writer).exists(token_id));
+    assert_eq!(contract.sender(writer).total_supply(token_id), U256::ZERO);
+}
@@ -0,0 +1,48 @@
+use alloy_primitives::Address;
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+
+const CALLER: Address = Address::new([0xAA; 20]);
+const TOKEN: Address = Address::new([0x10; 20]);
+
+#[motsu::test]
+fn accepts_up_to_18_decimals(contract: Contract<Options>) {
+    for decimals in [0, 6, 8, 18] {
+        assert!(contract
+            .sender(CALLER)
+            .validate_token(Token {
+                address: TOKEN,
+                decimals,
+            })
+            .is_ok());
+    }
+}
+
+#[motsu::test]
+fn rejects_19_decimals(contract: Contract<Options>) {
+    let err = contract
+        .sender(CALLER)
+        .validate_token(Token {
+            address: TOKEN,
+            decimals: 19,
+        })
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        OptionsError::InvalidDecimals(ref e) if e.decimals == 19
+    ));
+}
+
+#[motsu::test]
+fn rejects_zero_address(contract: Contract<Options>) {
+    let err = contract
+        .sender(CALLER)
+        .validate_token(Token {
+            address: Address::ZERO,
+            decimals: 18,
+        })
+        .unwrap_err();
+
+    assert!(matches!(err, OptionsError::ZeroAddress(_)));
+}
@@ -2,13 +2,14 @@ mod test_erc20;
 
 use alloy_primitives::{Address, U256};
 use motsu::prelude::*;
-use options::{Options, Token};
-use test_erc20::TestERC20;
+use options::{OptionWritten, Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
 
 #[motsu::test]
 fn write_call_option_happy_path(
     contract: Contract<Options>,
     underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
 ) {
     let writer = Address::from([0xAA; 20]);
     let options_addr = contract.address();
@@ -24,11 +25,14 @@ fn write_call_option_happy_path(
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0x22; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
     let quantity = U256::from(100_000_000);
 
     let token_id = contract
@@ -43,6 +47,7 @@ fn write_call_option_happy_path(
 fn write_same_option_twice_returns_same_token_id(
     contract: Contract<Options>,
     underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
 ) {
     let writer = Address::from([0xAA; 20]);
     let options_addr = contract.address();
@@ -58,11 +63,14 @@ fn write_same_option_twice_returns_same_token_id(
         decimals: 8,
     };
     let quote = Token {
-        address: Address::from([0x22; 20]),
+        address: quote_token.address(),
         decimals: 6,
     };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
     let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
-    let expiry = 2_000_000_000u64;
+    let expiry = expiry();
     let quantity = U256::from(100_000_000);
 
     let token_id_1 = contract
@@ -83,6 +91,8 @@ fn different_options_return_different_token_ids(
     contract: Contract<Options>,
     underlying_token1: Contract<TestERC20>,
     underlying_token2: Contract<TestERC20>,
+    quote_token1: Contract<TestERC20>,
+    quote_token2: Contract<TestERC20>,
 ) {
     let writer = Address::from([0xAA; 20]);
     let options_addr = contract.address();
@@ -103,7 +113,7 @@ fn different_options_return_different_token_ids(
         decimals: 8,
     };
     let quote1 = Token {
-        address: Address::from([0x22; 20]),
+        address: quote_token1.address(),
         decimals: 6,
     };
     let strike1 = U256::from(60_000) * U256::from(10).pow(U256::from(18));
@@ -113,12 +123,17 @@ fn different_options_return_different_token_ids(
         decimals: 18,
     };
     let quote2 = Token {
-        address: Address::from([0x44; 20]),
+        address: quote_token2.address(),
         decimals: 6,
     };
     let strike2 = U256::from(3_000) * U256::from(10).pow(U256::from(18));
 
-    let expiry = 2_000_000_000u64;
+    underlying_token1.sender(writer).set_decimals(8);
+    quote_token1.sender(writer).set_decimals(6);
+    underlying_token2.sender(writer).set_decimals(18);
+    quote_token2.sender(writer).set_decimals(6);
+
+    let expiry = expiry();
     let quantity = U256::from(100_000_000);
 
     let token_id_1 = contract
@@ -133,3 +148,114 @@ fn different_options_return_different_token_ids(
 
     assert_ne!(token_id_1, token_id_2);
 }
+
+#[motsu::test]
+fn rewrite_with_different_decimals_fails(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer1 = Address::from([0xAA; 20]);
+    let writer2 = Address::from([0xBB; 20]);
+    let options_addr = contract.address();
+
+    let quantity = U256::from(100_000_000);
+    for writer in [writer1, writer2] {
+        underlying_token.sender(writer).mint(writer, quantity);
+        underlying_token
+            .sender(writer)
+            .approve(options_addr, quantity);
+    }
+
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer1).set_decimals(8);
+    quote_token.sender(writer1).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer1)
+        .write_call_option(
+            strike,
+            expiry,
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            quote,
+        )
+        .unwrap();
+
+    let result = contract.sender(writer2).write_call_option(
+        strike,
+        expiry,
+        quantity,
+        Token {
+            address: underlying_token.address(),
+            decimals: 18,
+        },
+        quote,
+    );
+
+    assert!(matches!(result, Err(OptionsError::MetadataMismatch(_))));
+    assert_eq!(
+        contract.sender(writer2).balance_of(writer2, token_id),
+        U256::ZERO
+    );
+    assert_eq!(
+        underlying_token.sender(writer2).balance_of(writer2),
+        quantity
+    );
+}
+
+#[motsu::test]
+fn option_written_event_carries_series_terms(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, quantity);
+    underlying_token
+        .sender(writer)
+        .approve(contract.address(), quantity);
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(
+            strike,
+            expiry,
+            quantity,
+            Token {
+                address: underlying_token.address(),
+                decimals: 8,
+            },
+            Token {
+                address: quote_token.address(),
+                decimals: 6,
+            },
+        )
+        .unwrap();
+
+    let normalized_quantity = U256::from(10).pow(U256::from(18));
+    contract.assert_emitted(&OptionWritten {
+        writer,
+        tokenId: token_id,
+        payer: writer,
+        quantity: normalized_quantity,
+        collateral: normalized_quantity,
+        optionType: 0,
+        strike,
+        expiry: U256::from(expiry),
+    });
+}
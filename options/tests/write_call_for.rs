@@ -0,0 +1,209 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, U256};
+use motsu::prelude::*;
+use options::{Options, OptionsError, Token};
+use test_erc20::{expiry, TestERC20};
+
+#[motsu::test]
+fn tokens_and_position_go_to_receiver(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let payer = Address::from([0xAA; 20]);
+    let receiver = Address::from([0xAB; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(payer).mint(payer, write_quantity);
+    underlying_token
+        .sender(payer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(payer).set_decimals(8);
+    quote_token.sender(payer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(payer)
+        .write_call_option_for(strike, expiry, write_quantity, underlying, quote, receiver)
+        .unwrap();
+
+    let normalized_quantity = write_quantity * U256::from(10).pow(U256::from(10));
+    assert_eq!(
+        contract.sender(payer).balance_of(receiver, token_id),
+        normalized_quantity
+    );
+    assert_eq!(
+        contract.sender(payer).balance_of(payer, token_id),
+        U256::ZERO
+    );
+    assert_eq!(
+        contract.sender(payer).get_position(receiver, token_id),
+        (normalized_quantity, normalized_quantity)
+    );
+    assert_eq!(
+        contract.sender(payer).get_position(payer, token_id),
+        (U256::ZERO, U256::ZERO)
+    );
+    assert_eq!(underlying_token.sender(payer).balance_of(payer), U256::ZERO);
+    assert_eq!(
+        underlying_token.sender(payer).balance_of(options_addr),
+        write_quantity
+    );
+}
+
+#[motsu::test]
+fn receiver_can_exercise(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let payer = Address::from([0xBB; 20]);
+    let receiver = Address::from([0xBC; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(payer).mint(payer, write_quantity);
+    underlying_token
+        .sender(payer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(payer).set_decimals(8);
+    quote_token.sender(payer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(payer)
+        .write_call_option_for(strike, expiry, write_quantity, underlying, quote, receiver)
+        .unwrap();
+
+    let result = contract
+        .sender(payer)
+        .exercise_call(token_id, write_quantity);
+    assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+
+    let exercise_quantity = U256::from(30_000_000);
+    contract
+        .sender(receiver)
+        .exercise_call(token_id, exercise_quantity)
+        .unwrap();
+
+    let normalized_remaining =
+        (write_quantity - exercise_quantity) * U256::from(10).pow(U256::from(10));
+    assert_eq!(
+        underlying_token.sender(receiver).balance_of(receiver),
+        exercise_quantity
+    );
+    assert_eq!(
+        contract.sender(receiver).get_position(receiver, token_id),
+        (normalized_remaining, normalized_remaining)
+    );
+}
+
+#[motsu::test]
+fn payer_cannot_withdraw_receivers_collateral(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let payer = Address::from([0xCC; 20]);
+    let receiver = Address::from([0xCD; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(payer).mint(payer, write_quantity);
+    underlying_token
+        .sender(payer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(payer).set_decimals(8);
+    quote_token.sender(payer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(payer)
+        .write_call_option_for(strike, expiry, write_quantity, underlying, quote, receiver)
+        .unwrap();
+
+    let result = contract
+        .sender(payer)
+        .withdraw_expired_collateral(token_id, write_quantity);
+    assert!(result.is_err());
+
+    let result = contract
+        .sender(payer)
+        .close_position(token_id, write_quantity);
+    assert!(matches!(result, Err(OptionsError::InsufficientBalance(_))));
+
+    assert_eq!(
+        underlying_token.sender(payer).balance_of(options_addr),
+        write_quantity
+    );
+}
+
+#[motsu::test]
+fn zero_receiver_fails(contract: Contract<Options>) {
+    let payer = Address::from([0xDD; 20]);
+    let underlying = Token {
+        address: Address::from([0x11; 20]),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: Address::from([0x55; 20]),
+        decimals: 6,
+    };
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let result = contract.sender(payer).write_call_option_for(
+        strike,
+        expiry,
+        U256::from(100_000_000),
+        underlying,
+        quote,
+        Address::ZERO,
+    );
+    assert!(matches!(result, Err(OptionsError::InvalidRecipient(_))));
+
+    let result = contract.sender(payer).write_put_option_for(
+        strike,
+        expiry,
+        U256::from(100_000_000),
+        underlying,
+        quote,
+        Address::ZERO,
+    );
+    assert!(matches!(result, Err(OptionsError::InvalidRecipient(_))));
+}
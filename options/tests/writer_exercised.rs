@@ -0,0 +1,122 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, TestERC20};
+
+#[motsu::test]
+fn cumulative_exercised_matches_sum_of_partial_exercises(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(writer).writer_exercised(writer, token_id),
+        U256::ZERO
+    );
+
+    let mut exercised = U256::ZERO;
+    for exercise_quantity in [10_000_000u64, 25_000_000, 15_000_000] {
+        let exercise_quantity = U256::from(exercise_quantity);
+        contract
+            .sender(writer)
+            .exercise_call(token_id, exercise_quantity)
+            .unwrap();
+        exercised += exercise_quantity;
+
+        assert_eq!(
+            contract.sender(writer).writer_exercised(writer, token_id),
+            exercised * U256::from(10).pow(U256::from(10))
+        );
+    }
+}
+
+#[motsu::test]
+fn closing_position_does_not_count_as_exercised(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xBB; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    let exercise_quantity = U256::from(20_000_000);
+    contract
+        .sender(writer)
+        .exercise_call(token_id, exercise_quantity)
+        .unwrap();
+    contract
+        .sender(writer)
+        .close_position(token_id, U256::from(50_000_000))
+        .unwrap();
+
+    assert_eq!(
+        contract.sender(writer).writer_exercised(writer, token_id),
+        exercise_quantity * U256::from(10).pow(U256::from(10))
+    );
+}
+
+#[motsu::test]
+fn writer_exercised_is_zero_for_unknown_position(contract: Contract<Options>) {
+    let alice = Address::from([0xCC; 20]);
+    let token_id = B256::from([0x99; 32]);
+
+    assert_eq!(
+        contract.sender(alice).writer_exercised(alice, token_id),
+        U256::ZERO
+    );
+}
@@ -0,0 +1,88 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use options::{Options, Token};
+use test_erc20::{expiry, TestERC20};
+
+#[motsu::test]
+fn call_obligation_matches_locked_collateral(
+    contract: Contract<Options>,
+    underlying_token: Contract<TestERC20>,
+    quote_token: Contract<TestERC20>,
+) {
+    let writer = Address::from([0xAA; 20]);
+    let options_addr = contract.address();
+
+    let write_quantity = U256::from(100_000_000);
+    underlying_token.sender(writer).mint(writer, write_quantity);
+    underlying_token
+        .sender(writer)
+        .approve(options_addr, write_quantity);
+
+    let underlying = Token {
+        address: underlying_token.address(),
+        decimals: 8,
+    };
+    let quote = Token {
+        address: quote_token.address(),
+        decimals: 6,
+    };
+    underlying_token.sender(writer).set_decimals(8);
+    quote_token.sender(writer).set_decimals(6);
+
+    let strike = U256::from(60_000) * U256::from(10).pow(U256::from(18));
+    let expiry = expiry();
+
+    let token_id = contract
+        .sender(writer)
+        .write_call_option(strike, expiry, write_quantity, underlying, quote)
+        .unwrap();
+
+    assert_eq!(
+        contract
+            .sender(writer)
+            .max_writer_obligation(writer, token_id)
+            .unwrap(),
+        write_quantity
+    );
+    assert_eq!(
+        contract
+            .sender(writer)
+            .locked_collateral(underlying_token.address()),
+        write_quantity
+    );
+
+    let exercise_quantity = U256::from(30_000_000);
+    contract
+        .sender(writer)
+        .exercise_call(token_id, exercise_quantity)
+        .unwrap();
+
+    assert_eq!(
+        contract
+            .sender(writer)
+            .max_writer_obligation(writer, token_id)
+            .unwrap(),
+        write_quantity - exercise_quantity
+    );
+    assert_eq!(
+        contract
+            .sender(writer)
+            .locked_collateral(underlying_token.address()),
+        write_quantity - exercise_quantity
+    );
+}
+
+#[motsu::test]
+fn obligation_is_zero_without_position(contract: Contract<Options>) {
+    let writer = Address::from([0xBB; 20]);
+
+    assert_eq!(
+        contract
+            .sender(writer)
+            .max_writer_obligation(writer, B256::from([0x99; 32]))
+            .unwrap(),
+        U256::ZERO
+    );
+}
@@ -8,10 +8,25 @@ pub fn print_abi_from_args() {
 }
 
 use alloc::{vec, vec::Vec};
-use alloy_primitives::{Address, U256, U8};
+use alloy_primitives::{Address, B256, U256, U8};
 use alloy_sol_types::sol;
+use stylus_sdk::call::Call;
 use stylus_sdk::prelude::*;
-use stylus_sdk::storage::{StorageAddress, StorageBool, StorageU256, StorageU8};
+#[cfg(test)]
+mod mock_erc20;
+
+use stylus_sdk::storage::{
+    StorageAddress, StorageB256, StorageBool, StorageMap, StorageU256, StorageU8, StorageVec,
+};
+
+/// Maximum expiry bounty paid to a keeper, in basis points of the vault's total assets.
+pub const MAX_EXPIRY_BOUNTY_BPS: u64 = 10;
+
+/// Basis points denominator (100%).
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum number of rows returned by one `writer_checkpoint_details` call.
+pub const MAX_CHECKPOINT_PAGE_SIZE: usize = 100;
 
 sol! {
     /// Deposit checkpoint for FIFO assignment tracking.
@@ -45,6 +60,25 @@ sol! {
         uint256 collateral_returned
     );
 
+    /// Emitted when the vault is marked as expired.
+    event Expired(uint256 timestamp);
+
+    /// Emitted when the first `mark_expired` caller after expiry is paid the bounty.
+    event ExpiryBountyPaid(address indexed keeper, uint256 amount);
+
+    /// Emitted when vault shares are minted (`from` zero) or transferred.
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    /// Emitted once, on the first claim after expiry, with the final assignment
+    /// boundary: checkpoints before `boundary_checkpoint` are fully assigned and
+    /// `boundary_partial` of the boundary checkpoint is assigned.
+    event BatchAssigned(
+        bytes32 indexed token_id,
+        uint256 total_exercised,
+        uint256 boundary_checkpoint,
+        uint256 boundary_partial
+    );
+
     /// Errors that can occur in the OptionVault contract.
     #[derive(Debug)]
     error Unimplemented();
@@ -58,33 +92,128 @@ sol! {
     error InsufficientBacking(uint256 shares, uint256 options_outstanding);
     #[derive(Debug)]
     error ZeroAmount();
+    #[derive(Debug)]
+    error TransferFailed();
+    #[derive(Debug)]
+    error Overflow();
+    #[derive(Debug)]
+    error InsufficientAssets(uint256 available, uint256 requested);
+    #[derive(Debug)]
+    error AlreadyClaimed(address writer);
+    #[derive(Debug)]
+    error ZeroAddress();
+    #[derive(Debug)]
+    error AlreadyInitialized();
+    #[derive(Debug)]
+    error InsufficientShares(uint256 available, uint256 requested);
+    #[derive(Debug)]
+    error InvalidTokenId(bytes32 expected, bytes32 actual);
+}
+
+sol_interface! {
+    /// Minimal ERC20 interface for collateral transfers.
+    interface IERC20 {
+        function balanceOf(address account) external view returns (uint256);
+        function transfer(address to, uint256 value) external returns (bool);
+        function transferFrom(address from, address to, uint256 value) external returns (bool);
+    }
 }
 
 sol_storage! {
+    /// Stored deposit checkpoint (see `DepositCheckpoint`).
+    pub struct CheckpointStorage {
+        StorageAddress writer;
+        StorageU256 amount;
+        StorageU256 cumulative_total;
+    }
+
     #[entrypoint]
     pub struct OptionVault {
+        // Set by the first (and only) successful `initialize`
+        StorageBool initialized;
+
         // Asset token address (underlying for calls, quote for puts)
         StorageAddress asset;
 
+        // Token strike payments are made in (quote for calls, underlying for puts)
+        StorageAddress strike_asset;
+
         // Hardcoded decimals offset for inflation attack protection
         // Value: 3 (provides 1000x security multiplier)
         StorageU8 decimals_offset;
 
         // Option series this vault backs
         StorageAddress options_contract;
+        StorageB256 token_id;
         StorageU256 expiry;
 
         // Backing constraints
         StorageU256 options_outstanding;
         StorageBool expired;
 
-        // FIFO deposit tracking for assignment (simplified for stub)
+        // FIFO deposit tracking for assignment
         StorageU256 checkpoint_count;
+        StorageMap<U256, CheckpointStorage> checkpoints;
+        StorageMap<Address, StorageVec<StorageU256>> writer_checkpoints;
         StorageU256 total_exercised;
+        // Set once `BatchAssigned` has been emitted
+        StorageBool assignment_finalized;
+
+        // Post-expiry claims
+        StorageMap<Address, StorageBool> already_claimed;
+        StorageU256 strike_claimed;
+        // Assigned amount of all claims so far, reaching `total_exercised` on the last
+        StorageU256 assigned_claimed;
 
         // Total assets held by vault (for ERC-4626 compliance)
         StorageU256 total_assets;
+
+        // Initializer, allowed to configure the expiry bounty
+        StorageAddress owner;
+        // Asset tokens escrowed by the owner for the first `mark_expired` caller
+        // (not part of `total_assets`)
+        StorageU256 expiry_bounty;
+
+        // Vault shares (ERC-4626)
+        StorageMap<Address, StorageU256> shares;
+        StorageU256 total_shares;
+    }
+}
+
+/// Splits a deposit into the amounts assigned and not assigned by exercises.
+///
+/// Deposits are assigned in FIFO order: the deposit covers the range
+/// `(cumulative_total - amount, cumulative_total]` of all deposits, and the first
+/// `total_exercised` of that range is assigned.
+///
+/// # Arguments
+/// * `amount` - Deposited amount
+/// * `cumulative_total` - Total deposited up to and including this deposit
+/// * `total_exercised` - Total assets withdrawn by exercises
+///
+/// # Returns
+/// Tuple of (assigned, unassigned), summing to `amount`
+pub(crate) fn split_assignment(
+    amount: U256,
+    cumulative_total: U256,
+    total_exercised: U256,
+) -> (U256, U256) {
+    let start = cumulative_total.saturating_sub(amount);
+    let assigned = total_exercised.saturating_sub(start).min(amount);
+    (assigned, amount - assigned)
+}
+
+/// Checks that a vault with the given expiry has expired at `current`.
+///
+/// Expiry is inclusive: the vault counts as expired from `current == expiry` on.
+///
+/// # Errors
+/// - `NotExpired` if `current < expiry`
+pub(crate) fn check_expired_at(expiry: U256, current: U256) -> Result<(), VaultError> {
+    if current < expiry {
+        return Err(VaultError::NotExpired(NotExpired { expiry, current }));
     }
+    Ok(())
 }
 
 // Private helper methods
@@ -113,17 +242,94 @@ impl OptionVault {
     fn decimals_offset(&self) -> U8 {
         self.decimals_offset.get()
     }
+
+    /// Transfers asset tokens held by the vault to `to`.
+    ///
+    /// # Errors
+    /// - `TransferFailed` if the transfer reverts or returns false
+    #[allow(deprecated)]
+    fn transfer_asset(&mut self, to: Address, amount: U256) -> Result<(), VaultError> {
+        let success = IERC20::new(self.asset())
+            .transfer(Call::new_in(self), to, amount)
+            .map_err(|_| VaultError::TransferFailed(TransferFailed {}))?;
+        if !success {
+            return Err(VaultError::TransferFailed(TransferFailed {}));
+        }
+        Ok(())
+    }
+
+    /// Returns the part of the escrowed expiry bounty a keeper is paid.
+    ///
+    /// Capped at `MAX_EXPIRY_BOUNTY_BPS` of the total assets, so the bounty stays a
+    /// tiny slice of the series it expires.
+    ///
+    /// # Errors
+    /// - `Overflow` if the cap calculation overflows
+    fn expiry_bounty_payout(&self) -> Result<U256, VaultError> {
+        let cap = self
+            .total_assets()
+            .checked_mul(U256::from(MAX_EXPIRY_BOUNTY_BPS))
+            .ok_or(VaultError::Overflow(Overflow {}))?
+            / U256::from(BPS_DENOMINATOR);
+        Ok(self.expiry_bounty.get().min(cap))
+    }
+
+    /// Returns the virtual share and asset totals used for share conversion.
+    ///
+    /// # Returns
+    /// Tuple of (`total_shares + 10^offset`, `total_assets + 1`)
+    ///
+    /// # Errors
+    /// - `Overflow` if either total overflows
+    fn virtual_totals(&self) -> Result<(U256, U256), VaultError> {
+        let virtual_shares = U256::from(10)
+            .checked_pow(U256::from(self.decimals_offset().to::<u8>()))
+            .and_then(|offset| self.total_shares.get().checked_add(offset))
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+        let virtual_assets = self
+            .total_assets()
+            .checked_add(U256::from(1))
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+
+        Ok((virtual_shares, virtual_assets))
+    }
+
+    /// Converts assets to shares, rounding down.
+    ///
+    /// Uses virtual shares and assets for inflation attack protection:
+    /// `shares = assets * (total_shares + 10^offset) / (total_assets + 1)`.
+    ///
+    /// # Arguments
+    /// * `assets` - Amount of asset tokens
+    ///
+    /// # Returns
+    /// Number of shares worth `assets`
+    ///
+    /// # Errors
+    /// - `Overflow` if the calculation overflows
+    fn convert_to_shares(&self, assets: U256) -> Result<U256, VaultError> {
+        let (virtual_shares, virtual_assets) = self.virtual_totals()?;
+
+        assets
+            .checked_mul(virtual_shares)
+            .map(|scaled| scaled / virtual_assets)
+            .ok_or(VaultError::Overflow(Overflow {}))
+    }
 }
 
 #[public]
 impl OptionVault {
     /// Initializes the vault with the asset token and hardcoded inflation protection.
     ///
+    /// The caller becomes the vault owner, who can configure the expiry bounty.
+    ///
     /// TODO: Replace with proper constructor when upgrading to stylus-sdk that supports it.
     ///
     /// # Arguments
     /// * `asset` - The ERC20 token used as collateral
+    /// * `strike_asset` - The ERC20 token strike payments are made in
     /// * `options_contract` - The OptionToken contract address
+    /// * `token_id` - The option series token ID
     /// * `expiry` - The option expiry timestamp
     ///
     /// # Security
@@ -131,20 +337,47 @@ impl OptionVault {
     /// This provides a 1000x security multiplier without requiring pricing oracles.
     ///
     /// # Errors
-    /// Currently returns no errors (stub implementation).
+    /// - `AlreadyInitialized` if the vault has already been initialized
+    /// - `ZeroAddress` if `asset`, `strike_asset` or `options_contract` is zero
+    /// - `AlreadyExpired` if `expiry` is not in the future
     ///
     /// # WARNING
-    /// This is a temporary initialization pattern. In production, this MUST be replaced
-    /// with a proper constructor or initialization guard to prevent re-initialization attacks.
+    /// This is a temporary initialization pattern. The `initialized` guard prevents
+    /// re-initialization, but the first call is unprotected: the vault must be deployed and
+    /// initialized atomically so it cannot be front-run.
     pub fn initialize(
         &mut self,
         asset: Address,
+        strike_asset: Address,
         options_contract: Address,
+        token_id: B256,
         expiry: U256,
     ) -> Result<(), VaultError> {
-        // TODO: Add initialization guard to prevent calling this twice
-        // Store asset
+        if self.initialized.get() {
+            return Err(VaultError::AlreadyInitialized(AlreadyInitialized {}));
+        }
+
+        if asset == Address::ZERO
+            || strike_asset == Address::ZERO
+            || options_contract == Address::ZERO
+        {
+            return Err(VaultError::ZeroAddress(ZeroAddress {}));
+        }
+
+        let current = U256::from(self.vm().block_timestamp());
+        if current >= expiry {
+            return Err(VaultError::AlreadyExpired(AlreadyExpired {
+                expiry,
+                current,
+            }));
+        }
+
+        self.initialized.set(true);
+        self.owner.set(self.vm().msg_sender());
+
+        // Store assets
         self.asset.set(asset);
+        self.strike_asset.set(strike_asset);
 
         // Hardcode decimals_offset=3 for uniform inflation protection
         // NOT a parameter - prevents bypass attacks
@@ -152,6 +385,7 @@ impl OptionVault {
 
         // Store option series info
         self.options_contract.set(options_contract);
+        self.token_id.set(token_id);
         self.expiry.set(expiry);
 
         // Initialize state
@@ -159,7 +393,10 @@ impl OptionVault {
         self.expired.set(false);
         self.checkpoint_count.set(U256::ZERO);
         self.total_exercised.set(U256::ZERO);
+        self.assignment_finalized.set(false);
+        self.strike_claimed.set(U256::ZERO);
         self.total_assets.set(U256::ZERO);
+        self.total_shares.set(U256::ZERO);
 
         Ok(())
     }
@@ -174,43 +411,362 @@ impl OptionVault {
     /// Number of shares minted
     ///
     /// # Errors
+    /// See `deposit_with_checkpoint`
+    pub fn deposit(&mut self, assets: U256, receiver: Address) -> Result<U256, VaultError> {
+        let (shares, _) = self.deposit_with_checkpoint(assets, receiver)?;
+        Ok(shares)
+    }
+
+    /// Deposits like `deposit`, also returning the checkpoint the deposit created.
+    ///
+    /// Lets the depositor learn its position in the FIFO assignment queue without
+    /// reading the `Deposit` log.
+    ///
+    /// # Arguments
+    /// * `assets` - Amount of asset tokens to deposit
+    /// * `receiver` - Address to receive vault shares
+    ///
+    /// # Returns
+    /// Tuple of (shares minted, checkpoint index)
+    ///
+    /// # Errors
     /// - `AlreadyExpired` if called after expiry
     /// - `ZeroAmount` if assets is zero
-    pub fn deposit(&mut self, _assets: U256, _receiver: Address) -> Result<U256, VaultError> {
-        Err(VaultError::Unimplemented(Unimplemented {}))
+    /// - `Overflow` if share or total calculations overflow
+    /// - `TransferFailed` if the asset transfer fails
+    #[allow(deprecated)]
+    pub fn deposit_with_checkpoint(
+        &mut self,
+        assets: U256,
+        receiver: Address,
+    ) -> Result<(U256, U256), VaultError> {
+        if assets.is_zero() {
+            return Err(VaultError::ZeroAmount(ZeroAmount {}));
+        }
+
+        let expiry = self.expiry.get();
+        let current = U256::from(self.vm().block_timestamp());
+        if current >= expiry {
+            return Err(VaultError::AlreadyExpired(AlreadyExpired {
+                expiry,
+                current,
+            }));
+        }
+
+        let shares = self.convert_to_shares(assets)?;
+
+        let total_assets = self
+            .total_assets()
+            .checked_add(assets)
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+        let total_shares = self
+            .total_shares
+            .get()
+            .checked_add(shares)
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+        let receiver_shares = self
+            .shares
+            .get(receiver)
+            .checked_add(shares)
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+
+        self.total_assets.set(total_assets);
+        self.total_shares.set(total_shares);
+        self.shares.insert(receiver, receiver_shares);
+
+        // Deposits are never removed, so the cumulative total equals all assets ever
+        // deposited and is taken from the previous checkpoint
+        let checkpoint_index = self.checkpoint_count.get();
+        let previous_total = if checkpoint_index.is_zero() {
+            U256::ZERO
+        } else {
+            self.checkpoints
+                .getter(checkpoint_index - U256::from(1))
+                .cumulative_total
+                .get()
+        };
+        let cumulative_total = previous_total
+            .checked_add(assets)
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+
+        let mut checkpoint = self.checkpoints.setter(checkpoint_index);
+        checkpoint.writer.set(receiver);
+        checkpoint.amount.set(assets);
+        checkpoint.cumulative_total.set(cumulative_total);
+        self.checkpoint_count.set(checkpoint_index + U256::from(1));
+        self.writer_checkpoints
+            .setter(receiver)
+            .push(checkpoint_index);
+
+        let caller = self.vm().msg_sender();
+        let vault = self.vm().contract_address();
+        let success = IERC20::new(self.asset())
+            .transfer_from(Call::new_in(self), caller, vault, assets)
+            .map_err(|_| VaultError::TransferFailed(TransferFailed {}))?;
+        if !success {
+            return Err(VaultError::TransferFailed(TransferFailed {}));
+        }
+
+        log(
+            self.vm(),
+            Transfer {
+                from: Address::ZERO,
+                to: receiver,
+                value: shares,
+            },
+        );
+        log(
+            self.vm(),
+            Deposit {
+                writer: receiver,
+                assets,
+                shares,
+                checkpoint_index,
+                cumulative_total,
+            },
+        );
+
+        Ok((shares, checkpoint_index))
     }
 
     /// Withdraws assets from the vault during option exercise.
-    /// Can only be called by the associated OptionToken contract.
+    /// Can only be called by the associated OptionToken contract, for the series the
+    /// vault was initialized with.
     ///
     /// # Arguments
+    /// * `token_id` - The exercised option series
     /// * `assets` - Amount of assets to withdraw
     /// * `recipient` - Address to receive the assets
     ///
     /// # Returns
     /// Amount of assets withdrawn
     ///
+    /// Exercised assets are assigned to deposits in FIFO order: a checkpoint is fully
+    /// assigned once `total_exercised` reaches its `cumulative_total`. Only the total
+    /// is recorded, so the cost does not grow with the number of deposits.
+    ///
     /// # Errors
     /// - `UnauthorizedCaller` if caller is not the options contract
+    /// - `InvalidTokenId` if `token_id` is not the vault's series
     /// - `AlreadyExpired` if called after expiry
+    /// - `ZeroAmount` if assets is zero
+    /// - `InsufficientAssets` if the vault holds fewer assets than requested
+    /// - `Overflow` if `total_exercised` overflows
+    /// - `TransferFailed` if the asset transfer fails
+    #[allow(deprecated)]
     pub fn exercise_withdraw(
         &mut self,
-        _assets: U256,
-        _recipient: Address,
+        token_id: B256,
+        assets: U256,
+        recipient: Address,
     ) -> Result<U256, VaultError> {
-        Err(VaultError::Unimplemented(Unimplemented {}))
+        let expected = self.options_contract.get();
+        let actual = self.vm().msg_sender();
+        if actual != expected {
+            return Err(VaultError::UnauthorizedCaller(UnauthorizedCaller {
+                expected,
+                actual,
+            }));
+        }
+
+        let expected = self.token_id.get();
+        if token_id != expected {
+            return Err(VaultError::InvalidTokenId(InvalidTokenId {
+                expected,
+                actual: token_id,
+            }));
+        }
+
+        let expiry = self.expiry.get();
+        let current = U256::from(self.vm().block_timestamp());
+        if current >= expiry {
+            return Err(VaultError::AlreadyExpired(AlreadyExpired {
+                expiry,
+                current,
+            }));
+        }
+
+        if assets.is_zero() {
+            return Err(VaultError::ZeroAmount(ZeroAmount {}));
+        }
+
+        let available = self.total_assets();
+        let total_assets = available
+            .checked_sub(assets)
+            .ok_or(VaultError::InsufficientAssets(InsufficientAssets {
+                available,
+                requested: assets,
+            }))?;
+        let total_exercised = self
+            .total_exercised
+            .get()
+            .checked_add(assets)
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+
+        self.total_assets.set(total_assets);
+        self.total_exercised.set(total_exercised);
+
+        let success = IERC20::new(self.asset())
+            .transfer(Call::new_in(self), recipient, assets)
+            .map_err(|_| VaultError::TransferFailed(TransferFailed {}))?;
+        if !success {
+            return Err(VaultError::TransferFailed(TransferFailed {}));
+        }
+
+        log(
+            self.vm(),
+            ExerciseWithdraw {
+                recipient,
+                assets,
+                total_exercised,
+            },
+        );
+
+        Ok(assets)
     }
 
     /// Claims strike payments (if assigned) or collateral (if unassigned) after expiry.
     /// Uses FIFO assignment based on deposit order.
     ///
+    /// The caller's deposits are split into assigned and unassigned amounts against
+    /// `total_exercised`. Unassigned collateral is returned; strike payments are shared
+    /// among assigned deposits pro rata, out of all strike tokens the vault received.
+    /// Pro rata shares round down, so the last assigned writer to claim instead receives
+    /// every strike token left in the vault, draining it exactly.
+    ///
+    /// The first claim finalizes assignment and emits `BatchAssigned`. The caller's
+    /// shares are burned, emitting `Transfer` to the zero address.
+    ///
     /// # Returns
     /// Tuple of (strike_payment, collateral_returned)
     ///
     /// # Errors
-    /// - `NotExpired` if called before expiry
+    /// - `NotExpired` if the vault has not been marked expired
+    /// - `AlreadyClaimed` if the caller has already claimed
+    /// - `Overflow` if entitlement calculations overflow
+    /// - `TransferFailed` if a token transfer fails
+    #[allow(deprecated)]
     pub fn claim(&mut self) -> Result<(U256, U256), VaultError> {
-        Err(VaultError::Unimplemented(Unimplemented {}))
+        if !self.is_expired() {
+            return Err(VaultError::NotExpired(NotExpired {
+                expiry: self.expiry.get(),
+                current: U256::from(self.vm().block_timestamp()),
+            }));
+        }
+
+        let writer = self.vm().msg_sender();
+        if self.already_claimed.get(writer) {
+            return Err(VaultError::AlreadyClaimed(AlreadyClaimed { writer }));
+        }
+
+        let total_exercised = self.total_exercised.get();
+        if !self.assignment_finalized.get() {
+            self.assignment_finalized.set(true);
+            let (boundary_checkpoint, boundary_partial) = self.get_assignment_boundary();
+            log(
+                self.vm(),
+                BatchAssigned {
+                    token_id: self.token_id.get(),
+                    total_exercised,
+                    boundary_checkpoint,
+                    boundary_partial,
+                },
+            );
+        }
+
+        let mut assigned = U256::ZERO;
+        let mut collateral_returned = U256::ZERO;
+        for index in self.get_writer_checkpoints(writer) {
+            let (deposit_assigned, deposit_unassigned) = self.checkpoint_assignment(index);
+            assigned += deposit_assigned;
+            collateral_returned += deposit_unassigned;
+        }
+
+        let vault = self.vm().contract_address();
+        let strike_asset = IERC20::new(self.strike_asset.get());
+        let assigned_claimed = self
+            .assigned_claimed
+            .get()
+            .checked_add(assigned)
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+        let strike_payment = if assigned.is_zero() {
+            U256::ZERO
+        } else {
+            let strike_balance = strike_asset
+                .balance_of(Call::new_in(self), vault)
+                .map_err(|_| VaultError::TransferFailed(TransferFailed {}))?;
+            if assigned_claimed == total_exercised {
+                // Last assigned claim absorbs the rounding residual of earlier claims
+                strike_balance
+            } else {
+                let total_strike = strike_balance
+                    .checked_add(self.strike_claimed.get())
+                    .ok_or(VaultError::Overflow(Overflow {}))?;
+                total_strike
+                    .checked_mul(assigned)
+                    .ok_or(VaultError::Overflow(Overflow {}))?
+                    / total_exercised
+            }
+        };
+
+        self.already_claimed.insert(writer, true);
+        self.assigned_claimed.set(assigned_claimed);
+        let strike_claimed = self
+            .strike_claimed
+            .get()
+            .checked_add(strike_payment)
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+        self.strike_claimed.set(strike_claimed);
+        let total_assets = self
+            .total_assets()
+            .checked_sub(collateral_returned)
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+        self.total_assets.set(total_assets);
+        let shares = self.shares.get(writer);
+        let total_shares = self
+            .total_shares
+            .get()
+            .checked_sub(shares)
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+        self.shares.insert(writer, U256::ZERO);
+        self.total_shares.set(total_shares);
+
+        if !strike_payment.is_zero() {
+            let success = strike_asset
+                .transfer(Call::new_in(self), writer, strike_payment)
+                .map_err(|_| VaultError::TransferFailed(TransferFailed {}))?;
+            if !success {
+                return Err(VaultError::TransferFailed(TransferFailed {}));
+            }
+        }
+
+        if !collateral_returned.is_zero() {
+            let success = IERC20::new(self.asset())
+                .transfer(Call::new_in(self), writer, collateral_returned)
+                .map_err(|_| VaultError::TransferFailed(TransferFailed {}))?;
+            if !success {
+                return Err(VaultError::TransferFailed(TransferFailed {}));
+            }
+        }
+
+        log(
+            self.vm(),
+            Transfer {
+                from: writer,
+                to: Address::ZERO,
+                value: shares,
+            },
+        );
+        log(
+            self.vm(),
+            Claim {
+                writer,
+                strike_payment,
+                collateral_returned,
+            },
+        );
+
+        Ok((strike_payment, collateral_returned))
     }
 
     /// Burns vault shares along with option tokens for early collateral redemption.
@@ -236,10 +792,118 @@ impl OptionVault {
 
     /// Marks the vault as expired. Can be called by anyone after expiry time.
     ///
+    /// Idempotent: once the vault is marked, further calls are no-ops and emit
+    /// nothing. The first successful call emits `Expired`.
+    ///
+    /// When the owner escrowed an expiry bounty, the first successful caller is paid it,
+    /// capped at `MAX_EXPIRY_BOUNTY_BPS` of the total assets, and the rest is returned to
+    /// the owner. Emits `ExpiryBountyPaid`.
+    ///
     /// # Errors
     /// - `NotExpired` if current time is before expiry
+    /// - `Overflow` if the bounty cap calculation overflows
+    /// - `TransferFailed` if paying the bounty fails
     pub fn mark_expired(&mut self) -> Result<(), VaultError> {
-        Err(VaultError::Unimplemented(Unimplemented {}))
+        if self.expired.get() {
+            return Ok(());
+        }
+
+        let current = U256::from(self.vm().block_timestamp());
+        check_expired_at(self.expiry.get(), current)?;
+
+        let bounty = self.expiry_bounty.get();
+        let payout = self.expiry_bounty_payout()?;
+        self.expired.set(true);
+        self.expiry_bounty.set(U256::ZERO);
+
+        if !payout.is_zero() {
+            let keeper = self.vm().msg_sender();
+            self.transfer_asset(keeper, payout)?;
+            log(
+                self.vm(),
+                ExpiryBountyPaid {
+                    keeper,
+                    amount: payout,
+                },
+            );
+        }
+        if bounty > payout {
+            let owner = self.owner.get();
+            self.transfer_asset(owner, bounty - payout)?;
+        }
+
+        log(self.vm(), Expired { timestamp: current });
+        Ok(())
+    }
+
+    /// Sets the bounty paid to the first `mark_expired` caller after expiry.
+    ///
+    /// The bounty is escrowed in asset tokens: raising it pulls the difference from the
+    /// owner and lowering it refunds the difference, so zero disables it and returns the
+    /// escrow. It is kept apart from the collateral and never paid out by `claim`.
+    ///
+    /// # Arguments
+    /// * `bounty` - Bounty in asset tokens (paid capped at `MAX_EXPIRY_BOUNTY_BPS` of
+    ///   the total assets)
+    ///
+    /// # Errors
+    /// - `UnauthorizedCaller` if caller is not the owner
+    /// - `AlreadyExpired` if the vault has been marked expired
+    /// - `TransferFailed` if the asset transfer fails
+    #[allow(deprecated)]
+    pub fn set_expiry_bounty(&mut self, bounty: U256) -> Result<(), VaultError> {
+        let (caller, vault) = {
+            let vm = self.vm();
+            (vm.msg_sender(), vm.contract_address())
+        };
+        let owner = self.owner.get();
+        if caller != owner {
+            return Err(VaultError::UnauthorizedCaller(UnauthorizedCaller {
+                expected: owner,
+                actual: caller,
+            }));
+        }
+
+        if self.expired.get() {
+            return Err(VaultError::AlreadyExpired(AlreadyExpired {
+                expiry: self.expiry.get(),
+                current: U256::from(self.vm().block_timestamp()),
+            }));
+        }
+
+        let previous = self.expiry_bounty.get();
+        self.expiry_bounty.set(bounty);
+
+        if bounty > previous {
+            let success = IERC20::new(self.asset())
+                .transfer_from(Call::new_in(self), owner, vault, bounty - previous)
+                .map_err(|_| VaultError::TransferFailed(TransferFailed {}))?;
+            if !success {
+                return Err(VaultError::TransferFailed(TransferFailed {}));
+            }
+        } else if previous > bounty {
+            self.transfer_asset(owner, previous - bounty)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the vault owner (zero if not initialized).
+    #[must_use]
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Returns the expiry bounty currently escrowed (zero when disabled or paid).
+    #[must_use]
+    pub fn expiry_bounty(&self) -> U256 {
+        self.expiry_bounty.get()
+    }
+
+    /// Returns whether a writer has claimed their entitlement.
+    #[must_use]
+    pub fn has_claimed(&self, writer: Address) -> bool {
+        self.already_claimed.get(writer)
     }
 
     // ========================================
@@ -258,6 +922,52 @@ impl OptionVault {
         self.total_exercised.get()
     }
 
+    /// Returns the index of the first checkpoint not yet fully assigned.
+    ///
+    /// Checkpoints before the cursor are fully assigned; the checkpoint at the cursor
+    /// may be partially assigned. Cumulative totals only grow, so the cursor is found
+    /// by binary search against `total_exercised` rather than stored.
+    #[must_use]
+    pub fn get_assignment_cursor(&self) -> U256 {
+        let total_exercised = self.total_exercised.get();
+        let (mut low, mut high) = (U256::ZERO, self.checkpoint_count.get());
+        while low < high {
+            let mid = (low + high) / U256::from(2);
+            if self.checkpoints.getter(mid).cumulative_total.get() <= total_exercised {
+                low = mid + U256::from(1);
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    /// Returns the assignment boundary as (checkpoint, partial).
+    ///
+    /// `checkpoint` is the assignment cursor and `partial` the amount of that
+    /// checkpoint assigned so far (zero when every checkpoint is fully assigned).
+    /// The boundary is immutable once the vault has expired.
+    #[must_use]
+    pub fn get_assignment_boundary(&self) -> (U256, U256) {
+        let cursor = self.get_assignment_cursor();
+        if cursor >= self.checkpoint_count.get() {
+            return (cursor, U256::ZERO);
+        }
+        let checkpoint = self.checkpoints.getter(cursor);
+        let (partial, _) = split_assignment(
+            checkpoint.amount.get(),
+            checkpoint.cumulative_total.get(),
+            self.total_exercised.get(),
+        );
+        (cursor, partial)
+    }
+
+    /// Returns whether assignment has been finalized by the first claim.
+    #[must_use]
+    pub fn is_assignment_finalized(&self) -> bool {
+        self.assignment_finalized.get()
+    }
+
     /// Returns the total amount of options outstanding.
     #[must_use]
     pub fn get_options_outstanding(&self) -> U256 {
@@ -276,32 +986,205 @@ impl OptionVault {
         self.expiry.get()
     }
 
+    /// Returns the option series this vault backs.
+    ///
+    /// Lets the options contract check a vault before its first deposit.
+    ///
+    /// # Returns
+    /// Tuple of (options_contract, token_id, asset, strike_asset, expiry), zeroed if not
+    /// initialized
+    #[must_use]
+    pub fn get_series(&self) -> (Address, B256, Address, Address, U256) {
+        (
+            self.options_contract.get(),
+            self.token_id.get(),
+            self.asset(),
+            self.strike_asset.get(),
+            self.expiry.get(),
+        )
+    }
+
+    /// Returns the vault shares held by an account.
+    #[must_use]
+    pub fn balance_of(&self, account: Address) -> U256 {
+        self.shares.get(account)
+    }
+
+    /// Returns the total vault shares minted.
+    #[must_use]
+    pub fn total_shares(&self) -> U256 {
+        self.total_shares.get()
+    }
+
+    /// Returns the total vault shares minted (ERC-20 `totalSupply`).
+    #[must_use]
+    pub fn total_supply(&self) -> U256 {
+        self.total_shares.get()
+    }
+
+    /// Transfers vault shares from the caller to `to`.
+    ///
+    /// Shares carry the claim on vault assets, but FIFO checkpoints do not move with
+    /// them: assignment and `claim` stay with the original depositor, so a buyer of
+    /// shares takes on no assignment and receives nothing from `claim`. `claim` burns
+    /// whatever shares the depositor holds at that point.
+    ///
+    /// # Returns
+    /// `true` on success (ERC-20 convention)
+    ///
+    /// # Errors
+    /// - `ZeroAddress` if `to` is the zero address
+    /// - `InsufficientShares` if the caller holds fewer than `amount` shares
+    pub fn transfer(&mut self, to: Address, amount: U256) -> Result<bool, VaultError> {
+        if to == Address::ZERO {
+            return Err(VaultError::ZeroAddress(ZeroAddress {}));
+        }
+
+        let from = self.vm().msg_sender();
+        let available = self.shares.get(from);
+        let from_shares = available
+            .checked_sub(amount)
+            .ok_or(VaultError::InsufficientShares(InsufficientShares {
+                available,
+                requested: amount,
+            }))?;
+        self.shares.insert(from, from_shares);
+
+        let to_shares = self
+            .shares
+            .get(to)
+            .checked_add(amount)
+            .ok_or(VaultError::Overflow(Overflow {}))?;
+        self.shares.insert(to, to_shares);
+
+        log(
+            self.vm(),
+            Transfer {
+                from,
+                to,
+                value: amount,
+            },
+        );
+
+        Ok(true)
+    }
+
+    /// Returns the total assets deposited in the vault.
+    #[must_use]
+    pub fn get_total_assets(&self) -> U256 {
+        self.total_assets()
+    }
+
+    /// Returns the smallest deposit that would mint at least one share.
+    ///
+    /// Smaller deposits round down to zero shares, so UIs can use this to warn
+    /// users away from dust deposits. Computed as
+    /// `ceil((total_assets + 1) / (total_shares + 10^offset))`.
+    ///
+    /// # Returns
+    /// Minimum asset amount worth one share at the current exchange rate
+    ///
+    /// # Errors
+    /// - `Overflow` if the virtual totals overflow
+    pub fn min_deposit_for_nonzero_shares(&self) -> Result<U256, VaultError> {
+        let (virtual_shares, virtual_assets) = self.virtual_totals()?;
+        Ok(virtual_assets.div_ceil(virtual_shares))
+    }
+
     /// Returns the checkpoint at the given index.
     ///
-    /// TODO: Implement when checkpoint storage is added.
+    /// # Arguments
+    /// * `index` - Checkpoint index
+    ///
+    /// # Returns
+    /// Checkpoint data (writer, amount, cumulative_total), zeroed if it doesn't exist
+    #[must_use]
+    pub fn get_checkpoint(&self, index: U256) -> (Address, U256, U256) {
+        let checkpoint = self.checkpoints.getter(index);
+        (
+            checkpoint.writer.get(),
+            checkpoint.amount.get(),
+            checkpoint.cumulative_total.get(),
+        )
+    }
+
+    /// Returns how much of a checkpoint's deposit is assigned under FIFO.
+    ///
+    /// Once the vault has expired this is the final split `claim` pays out against.
     ///
     /// # Arguments
-    /// * `_index` - Checkpoint index
+    /// * `index` - Checkpoint index
     ///
     /// # Returns
-    /// Checkpoint data (writer, amount, cumulative_total)
+    /// Tuple of (assigned, unassigned), zeroed if the checkpoint doesn't exist
     #[must_use]
-    pub const fn get_checkpoint(&self, _index: U256) -> (Address, U256, U256) {
-        (Address::ZERO, U256::ZERO, U256::ZERO)
+    pub fn checkpoint_assignment(&self, index: U256) -> (U256, U256) {
+        let checkpoint = self.checkpoints.getter(index);
+        split_assignment(
+            checkpoint.amount.get(),
+            checkpoint.cumulative_total.get(),
+            self.total_exercised.get(),
+        )
     }
 
     /// Returns the list of checkpoint indices for a writer.
     ///
-    /// TODO: Implement when checkpoint storage is added.
+    /// # Arguments
+    /// * `writer` - Writer address
+    ///
+    /// # Returns
+    /// Array of checkpoint indices in deposit order
+    #[must_use]
+    pub fn get_writer_checkpoints(&self, writer: Address) -> Vec<U256> {
+        let indices = self.writer_checkpoints.getter(writer);
+        (0..indices.len()).filter_map(|i| indices.get(i)).collect()
+    }
+
+    /// Returns the number of checkpoints created for a writer.
+    ///
+    /// # Arguments
+    /// * `writer` - Writer address
+    #[must_use]
+    pub fn get_writer_checkpoint_count(&self, writer: Address) -> U256 {
+        U256::from(self.writer_checkpoints.getter(writer).len())
+    }
+
+    /// Returns a page of a writer's checkpoints with their FIFO assignment.
+    ///
+    /// Combines `get_writer_checkpoints`, `get_checkpoint` and `checkpoint_assignment`.
+    /// Page through with `offset` until fewer than `limit` rows come back.
     ///
     /// # Arguments
-    /// * `_writer` - Writer address
+    /// * `writer` - Writer address
+    /// * `offset` - Position in the writer's checkpoint list to start from
+    /// * `limit` - Maximum number of rows, capped at `MAX_CHECKPOINT_PAGE_SIZE`
     ///
     /// # Returns
-    /// Array of checkpoint indices
+    /// Rows of (index, amount, cumulative_total, assigned, unassigned) in deposit order
     #[must_use]
-    pub const fn get_writer_checkpoints(&self, _writer: Address) -> Vec<U256> {
-        Vec::new()
+    pub fn writer_checkpoint_details(
+        &self,
+        writer: Address,
+        offset: U256,
+        limit: U256,
+    ) -> Vec<(U256, U256, U256, U256, U256)> {
+        let indices = self.writer_checkpoints.getter(writer);
+        let start = offset.saturating_to::<usize>().min(indices.len());
+        let end = start
+            .saturating_add(limit.saturating_to::<usize>().min(MAX_CHECKPOINT_PAGE_SIZE))
+            .min(indices.len());
+
+        (start..end)
+            .filter_map(|i| indices.get(i))
+            .map(|index| {
+                let checkpoint = self.checkpoints.getter(index);
+                let (amount, cumulative_total) =
+                    (checkpoint.amount.get(), checkpoint.cumulative_total.get());
+                let (assigned, unassigned) =
+                    split_assignment(amount, cumulative_total, self.total_exercised.get());
+                (index, amount, cumulative_total, assigned, unassigned)
+            })
+            .collect()
     }
 }
 
@@ -314,6 +1197,952 @@ pub enum VaultError {
     UnauthorizedCaller(UnauthorizedCaller),
     InsufficientBacking(InsufficientBacking),
     ZeroAmount(ZeroAmount),
+    TransferFailed(TransferFailed),
+    Overflow(Overflow),
+    InsufficientAssets(InsufficientAssets),
+    AlreadyClaimed(AlreadyClaimed),
+    ZeroAddress(ZeroAddress),
+    AlreadyInitialized(AlreadyInitialized),
+    InsufficientShares(InsufficientShares),
+    InvalidTokenId(InvalidTokenId),
 }
 
-// TODO: Add tests once vault implementation is complete
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Address;
+    use motsu::prelude::*;
+
+    use super::*;
+    use crate::mock_erc20::MockERC20;
+
+    const OPTIONS_CONTRACT: Address = Address::new([0x0F; 20]);
+    const TOKEN_ID: B256 = B256::new([0x77; 32]);
+    const HOLDER: Address = Address::new([0x0E; 20]);
+    const WRITER_A: Address = Address::new([0xAA; 20]);
+    const WRITER_B: Address = Address::new([0xBB; 20]);
+    const WRITER_C: Address = Address::new([0xCC; 20]);
+
+    /// Deposits 100, 200 and 300 from three writers, exercises 150 and pays 300 strike
+    /// tokens into the vault, then marks it expired.
+    fn setup_exercised_vault(
+        vault: &Contract<OptionVault>,
+        asset: &Contract<MockERC20>,
+        strike_asset: &Contract<MockERC20>,
+    ) {
+        vault
+            .sender(OPTIONS_CONTRACT)
+            .initialize(
+                asset.address(),
+                strike_asset.address(),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            )
+            .unwrap();
+
+        for (writer, amount) in [(WRITER_A, 100u64), (WRITER_B, 200), (WRITER_C, 300)] {
+            let amount = U256::from(amount);
+            asset.sender(writer).mint(writer, amount);
+            asset.sender(writer).approve(vault.address(), amount);
+            vault.sender(writer).deposit(amount, writer).unwrap();
+        }
+
+        vault
+            .sender(OPTIONS_CONTRACT)
+            .exercise_withdraw(TOKEN_ID, U256::from(150), HOLDER)
+            .unwrap();
+        strike_asset
+            .sender(HOLDER)
+            .mint(vault.address(), U256::from(300));
+
+        vault.sender(OPTIONS_CONTRACT).expired.set(true);
+    }
+
+    #[test]
+    fn test_split_assignment() {
+        let (amount, cumulative_total) = (U256::from(200), U256::from(300));
+
+        assert_eq!(
+            split_assignment(amount, cumulative_total, U256::from(50)),
+            (U256::ZERO, amount)
+        );
+        assert_eq!(
+            split_assignment(amount, cumulative_total, U256::from(150)),
+            (U256::from(50), U256::from(150))
+        );
+        assert_eq!(
+            split_assignment(amount, cumulative_total, U256::from(600)),
+            (amount, U256::ZERO)
+        );
+    }
+
+    #[motsu::test]
+    fn test_claim_fully_assigned_writer_receives_strike(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+
+        let claimed = vault.sender(WRITER_A).claim().unwrap();
+
+        // All 100 of A's deposit is assigned: 100 / 150 of the 300 strike tokens
+        assert_eq!(claimed, (U256::from(200), U256::ZERO));
+        assert_eq!(
+            strike_asset.sender(WRITER_A).balance_of(WRITER_A),
+            U256::from(200)
+        );
+        assert_eq!(asset.sender(WRITER_A).balance_of(WRITER_A), U256::ZERO);
+        assert!(vault.sender(WRITER_A).has_claimed(WRITER_A));
+    }
+
+    #[motsu::test]
+    fn test_claim_partially_assigned_writer_receives_both(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+
+        let claimed = vault.sender(WRITER_B).claim().unwrap();
+
+        // 50 of B's 200 is assigned, the remaining 150 is returned
+        assert_eq!(claimed, (U256::from(100), U256::from(150)));
+        assert_eq!(
+            strike_asset.sender(WRITER_B).balance_of(WRITER_B),
+            U256::from(100)
+        );
+        assert_eq!(asset.sender(WRITER_B).balance_of(WRITER_B), U256::from(150));
+    }
+
+    #[motsu::test]
+    fn test_claim_unassigned_writer_receives_collateral(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+
+        let claimed = vault.sender(WRITER_C).claim().unwrap();
+
+        assert_eq!(claimed, (U256::ZERO, U256::from(300)));
+        assert_eq!(
+            strike_asset.sender(WRITER_C).balance_of(WRITER_C),
+            U256::ZERO
+        );
+        assert_eq!(asset.sender(WRITER_C).balance_of(WRITER_C), U256::from(300));
+    }
+
+    #[motsu::test]
+    fn test_claims_are_independent_of_order(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+
+        assert_eq!(
+            vault.sender(WRITER_C).claim().unwrap(),
+            (U256::ZERO, U256::from(300))
+        );
+        assert_eq!(
+            vault.sender(WRITER_B).claim().unwrap(),
+            (U256::from(100), U256::from(150))
+        );
+        assert_eq!(
+            vault.sender(WRITER_A).claim().unwrap(),
+            (U256::from(200), U256::ZERO)
+        );
+
+        assert_eq!(vault.sender(WRITER_A).get_total_assets(), U256::ZERO);
+        assert_eq!(
+            asset.sender(WRITER_A).balance_of(vault.address()),
+            U256::ZERO
+        );
+        assert_eq!(
+            strike_asset.sender(WRITER_A).balance_of(vault.address()),
+            U256::ZERO
+        );
+    }
+
+    #[motsu::test]
+    fn test_last_assigned_claim_absorbs_rounding_residual(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+        strike_asset
+            .sender(HOLDER)
+            .mint(vault.address(), U256::from(1));
+
+        // 301 * 100 / 150 rounds down to 200
+        assert_eq!(
+            vault.sender(WRITER_A).claim().unwrap(),
+            (U256::from(200), U256::ZERO)
+        );
+        // B holds the last assigned amount and receives the remaining 101, not 100
+        assert_eq!(
+            vault.sender(WRITER_B).claim().unwrap(),
+            (U256::from(101), U256::from(150))
+        );
+        assert_eq!(
+            strike_asset.sender(WRITER_A).balance_of(vault.address()),
+            U256::ZERO
+        );
+    }
+
+    #[motsu::test]
+    fn test_first_claim_emits_batch_assigned(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+        assert!(!vault.sender(WRITER_A).is_assignment_finalized());
+
+        vault.sender(WRITER_C).claim().unwrap();
+
+        // 150 exercised: A's 100 is fully assigned, 50 of B's checkpoint is assigned
+        vault.assert_emitted(&BatchAssigned {
+            token_id: TOKEN_ID,
+            total_exercised: U256::from(150),
+            boundary_checkpoint: U256::from(1),
+            boundary_partial: U256::from(50),
+        });
+        assert!(vault.sender(WRITER_A).is_assignment_finalized());
+
+        // Later claims see the already-finalized boundary and do not emit again
+        vault.sender(WRITER_A).claim().unwrap();
+        assert!(vault.sender(WRITER_A).is_assignment_finalized());
+        assert_eq!(
+            vault.sender(WRITER_A).get_assignment_boundary(),
+            (U256::from(1), U256::from(50))
+        );
+    }
+
+    #[motsu::test]
+    fn test_checkpoint_assignment_early_checkpoint_is_fully_assigned(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+
+        assert_eq!(
+            vault.sender(WRITER_A).checkpoint_assignment(U256::ZERO),
+            (U256::from(100), U256::ZERO)
+        );
+    }
+
+    #[motsu::test]
+    fn test_checkpoint_assignment_boundary_checkpoint_is_partial(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+
+        // 50 of the 150 exercised falls into B's (100, 300] range
+        assert_eq!(
+            vault.sender(WRITER_B).checkpoint_assignment(U256::from(1)),
+            (U256::from(50), U256::from(150))
+        );
+    }
+
+    #[motsu::test]
+    fn test_checkpoint_assignment_late_checkpoint_is_unassigned(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+
+        assert_eq!(
+            vault.sender(WRITER_C).checkpoint_assignment(U256::from(2)),
+            (U256::ZERO, U256::from(300))
+        );
+        assert_eq!(
+            vault.sender(WRITER_C).checkpoint_assignment(U256::from(3)),
+            (U256::ZERO, U256::ZERO)
+        );
+    }
+
+    /// Deposits 100 from A, 100 from B, then 100 and 100 more from A, and exercises
+    /// 250 so A's three checkpoints straddle the assignment boundary.
+    fn setup_straddling_writer(vault: &Contract<OptionVault>, asset: &Contract<MockERC20>) {
+        vault
+            .sender(OPTIONS_CONTRACT)
+            .initialize(
+                asset.address(),
+                Address::new([0x05; 20]),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            )
+            .unwrap();
+
+        for writer in [WRITER_A, WRITER_B, WRITER_A, WRITER_A] {
+            let amount = U256::from(100);
+            asset.sender(writer).mint(writer, amount);
+            asset.sender(writer).approve(vault.address(), amount);
+            vault.sender(writer).deposit(amount, writer).unwrap();
+        }
+
+        vault
+            .sender(OPTIONS_CONTRACT)
+            .exercise_withdraw(TOKEN_ID, U256::from(250), HOLDER)
+            .unwrap();
+    }
+
+    #[motsu::test]
+    fn test_writer_checkpoint_details_split_at_boundary(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+    ) {
+        setup_straddling_writer(&vault, &asset);
+
+        let rows =
+            vault
+                .sender(WRITER_A)
+                .writer_checkpoint_details(WRITER_A, U256::ZERO, U256::from(10));
+
+        assert_eq!(
+            rows,
+            vec![
+                // (0, 100] is fully assigned
+                (
+                    U256::ZERO,
+                    U256::from(100),
+                    U256::from(100),
+                    U256::from(100),
+                    U256::ZERO
+                ),
+                // 50 of the 250 exercised falls into (200, 300]
+                (
+                    U256::from(2),
+                    U256::from(100),
+                    U256::from(300),
+                    U256::from(50),
+                    U256::from(50)
+                ),
+                // (300, 400] is untouched
+                (
+                    U256::from(3),
+                    U256::from(100),
+                    U256::from(400),
+                    U256::ZERO,
+                    U256::from(100)
+                ),
+            ]
+        );
+    }
+
+    #[motsu::test]
+    fn test_writer_checkpoint_details_paginates(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+    ) {
+        setup_straddling_writer(&vault, &asset);
+
+        let page = vault.sender(WRITER_A).writer_checkpoint_details(
+            WRITER_A,
+            U256::from(1),
+            U256::from(1),
+        );
+        assert_eq!(
+            page,
+            vec![(
+                U256::from(2),
+                U256::from(100),
+                U256::from(300),
+                U256::from(50),
+                U256::from(50)
+            )]
+        );
+
+        assert!(vault
+            .sender(WRITER_A)
+            .writer_checkpoint_details(WRITER_A, U256::from(3), U256::from(10))
+            .is_empty());
+        assert!(vault
+            .sender(WRITER_A)
+            .writer_checkpoint_details(WRITER_A, U256::MAX, U256::MAX)
+            .is_empty());
+        assert!(vault
+            .sender(WRITER_C)
+            .writer_checkpoint_details(WRITER_C, U256::ZERO, U256::from(10))
+            .is_empty());
+    }
+
+    #[motsu::test]
+    fn test_claim_burns_writer_shares(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+        let shares = vault.sender(WRITER_B).balance_of(WRITER_B);
+        let total_shares = vault.sender(WRITER_B).total_supply();
+
+        vault.sender(WRITER_B).claim().unwrap();
+
+        assert_eq!(vault.sender(WRITER_B).balance_of(WRITER_B), U256::ZERO);
+        assert_eq!(vault.sender(WRITER_B).total_supply(), total_shares - shares);
+        vault.assert_emitted(&Transfer {
+            from: WRITER_B,
+            to: Address::ZERO,
+            value: shares,
+        });
+
+        vault.sender(WRITER_A).claim().unwrap();
+        vault.sender(WRITER_C).claim().unwrap();
+        assert_eq!(vault.sender(WRITER_A).total_supply(), U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn test_claim_twice_fails(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+
+        vault.sender(WRITER_B).claim().unwrap();
+        let result = vault.sender(WRITER_B).claim();
+
+        assert!(matches!(result, Err(VaultError::AlreadyClaimed(_))));
+    }
+
+    #[motsu::test]
+    fn test_claim_before_expiry_fails(vault: Contract<OptionVault>) {
+        let result = vault.sender(WRITER_A).claim();
+
+        assert!(matches!(result, Err(VaultError::NotExpired(_))));
+    }
+
+    #[motsu::test]
+    fn test_mark_expired_before_expiry_fails(vault: Contract<OptionVault>) {
+        vault
+            .sender(WRITER_A)
+            .initialize(
+                Address::new([0x11; 20]),
+                Address::new([0x22; 20]),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            )
+            .unwrap();
+
+        let result = vault.sender(WRITER_A).mark_expired();
+
+        assert!(matches!(result, Err(VaultError::NotExpired(_))));
+        assert!(!vault.sender(WRITER_A).is_expired());
+    }
+
+    #[motsu::test]
+    fn test_mark_expired_after_expiry(vault: Contract<OptionVault>) {
+        vault
+            .sender(WRITER_A)
+            .initialize(
+                Address::new([0x11; 20]),
+                Address::new([0x22; 20]),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            )
+            .unwrap();
+
+        // Time cannot be advanced in tests, so move expiry into the past instead
+        vault
+            .sender(WRITER_A)
+            .expiry
+            .set(U256::from(1_000_000_000u64));
+
+        vault.sender(WRITER_A).mark_expired().unwrap();
+
+        assert!(vault.sender(WRITER_A).is_expired());
+    }
+
+    #[motsu::test]
+    fn test_mark_expired_twice_is_noop(vault: Contract<OptionVault>) {
+        vault
+            .sender(WRITER_A)
+            .initialize(
+                Address::new([0x11; 20]),
+                Address::new([0x22; 20]),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            )
+            .unwrap();
+
+        // Time cannot be advanced in tests, so move expiry into the past instead
+        vault
+            .sender(WRITER_A)
+            .expiry
+            .set(U256::from(1_000_000_000u64));
+
+        vault.sender(WRITER_A).mark_expired().unwrap();
+        vault.sender(WRITER_B).mark_expired().unwrap();
+
+        assert!(vault.sender(WRITER_A).is_expired());
+    }
+
+    /// Initializes a vault owned by `WRITER_A`, deposits 1,000,000 from `WRITER_B` and
+    /// escrows `bounty` from the owner.
+    fn setup_bounty_vault(
+        vault: &Contract<OptionVault>,
+        asset: &Contract<MockERC20>,
+        strike_asset: &Contract<MockERC20>,
+        bounty: U256,
+    ) {
+        vault
+            .sender(WRITER_A)
+            .initialize(
+                asset.address(),
+                strike_asset.address(),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            )
+            .unwrap();
+
+        let deposit = U256::from(1_000_000);
+        asset.sender(WRITER_B).mint(WRITER_B, deposit);
+        asset.sender(WRITER_B).approve(vault.address(), deposit);
+        vault.sender(WRITER_B).deposit(deposit, WRITER_B).unwrap();
+
+        asset.sender(WRITER_A).mint(WRITER_A, bounty);
+        asset.sender(WRITER_A).approve(vault.address(), bounty);
+        vault.sender(WRITER_A).set_expiry_bounty(bounty).unwrap();
+
+        // Time cannot be advanced in tests, so move expiry into the past instead
+        vault
+            .sender(WRITER_A)
+            .expiry
+            .set(U256::from(1_000_000_000u64));
+    }
+
+    #[motsu::test]
+    fn test_expiry_bounty_is_paid_once(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_bounty_vault(&vault, &asset, &strike_asset, U256::from(500));
+        assert_eq!(vault.sender(WRITER_A).expiry_bounty(), U256::from(500));
+
+        vault.sender(WRITER_C).mark_expired().unwrap();
+        vault.sender(HOLDER).mark_expired().unwrap();
+
+        assert_eq!(asset.sender(WRITER_C).balance_of(WRITER_C), U256::from(500));
+        assert_eq!(asset.sender(HOLDER).balance_of(HOLDER), U256::ZERO);
+        assert_eq!(vault.sender(WRITER_A).expiry_bounty(), U256::ZERO);
+        assert_eq!(
+            asset.sender(WRITER_A).balance_of(vault.address()),
+            U256::from(1_000_000)
+        );
+        vault.assert_emitted(&ExpiryBountyPaid {
+            keeper: WRITER_C,
+            amount: U256::from(500),
+        });
+    }
+
+    #[motsu::test]
+    fn test_expiry_bounty_is_capped(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_bounty_vault(&vault, &asset, &strike_asset, U256::from(5_000));
+
+        vault.sender(WRITER_C).mark_expired().unwrap();
+
+        // 10 bps of the 1,000,000 deposited; the rest goes back to the owner
+        assert_eq!(
+            asset.sender(WRITER_C).balance_of(WRITER_C),
+            U256::from(1_000)
+        );
+        assert_eq!(
+            asset.sender(WRITER_A).balance_of(WRITER_A),
+            U256::from(4_000)
+        );
+    }
+
+    #[motsu::test]
+    fn test_disabled_expiry_bounty_pays_nothing(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_bounty_vault(&vault, &asset, &strike_asset, U256::from(500));
+
+        vault
+            .sender(WRITER_A)
+            .set_expiry_bounty(U256::ZERO)
+            .unwrap();
+        assert_eq!(asset.sender(WRITER_A).balance_of(WRITER_A), U256::from(500));
+
+        vault.sender(WRITER_C).mark_expired().unwrap();
+
+        assert!(vault.sender(WRITER_C).is_expired());
+        assert_eq!(asset.sender(WRITER_C).balance_of(WRITER_C), U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn test_set_expiry_bounty_requires_owner(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_bounty_vault(&vault, &asset, &strike_asset, U256::ZERO);
+
+        let result = vault.sender(WRITER_B).set_expiry_bounty(U256::from(1));
+
+        assert!(matches!(result, Err(VaultError::UnauthorizedCaller(_))));
+        assert_eq!(vault.sender(WRITER_B).owner(), WRITER_A);
+    }
+
+    #[test]
+    fn test_check_expired_at_one_second_before_expiry_fails() {
+        let expiry = U256::from(1_700_000_000u64);
+
+        let result = check_expired_at(expiry, expiry - U256::from(1));
+
+        assert!(matches!(
+            result,
+            Err(VaultError::NotExpired(NotExpired { expiry: e, current }))
+                if e == expiry && current == expiry - U256::from(1)
+        ));
+    }
+
+    #[test]
+    fn test_check_expired_at_expiry_succeeds() {
+        let expiry = U256::from(1_700_000_000u64);
+
+        assert!(check_expired_at(expiry, expiry).is_ok());
+    }
+
+    #[test]
+    fn test_check_expired_at_one_second_after_expiry_succeeds() {
+        let expiry = U256::from(1_700_000_000u64);
+
+        assert!(check_expired_at(expiry, expiry + U256::from(1)).is_ok());
+    }
+
+    #[motsu::test]
+    fn test_min_deposit_for_nonzero_shares_empty_vault(vault: Contract<OptionVault>) {
+        vault
+            .sender(WRITER_A)
+            .initialize(
+                Address::new([0x11; 20]),
+                Address::new([0x22; 20]),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            )
+            .unwrap();
+
+        // ceil(1 / 10^3): a single unit already mints 1_000 shares
+        let minimum = vault.sender(WRITER_A).min_deposit_for_nonzero_shares();
+
+        assert_eq!(minimum.unwrap(), U256::from(1));
+    }
+
+    #[motsu::test]
+    fn test_min_deposit_for_nonzero_shares_inflated_vault(vault: Contract<OptionVault>) {
+        vault
+            .sender(WRITER_A)
+            .initialize(
+                Address::new([0x11; 20]),
+                Address::new([0x22; 20]),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            )
+            .unwrap();
+        vault
+            .sender(WRITER_A)
+            .total_assets
+            .set(U256::from(5_000_000));
+        vault.sender(WRITER_A).total_shares.set(U256::from(2_000));
+
+        // ceil(5_000_001 / 3_000)
+        let minimum = vault
+            .sender(WRITER_A)
+            .min_deposit_for_nonzero_shares()
+            .unwrap();
+
+        assert_eq!(minimum, U256::from(1_667));
+        assert_eq!(
+            vault.sender(WRITER_A).convert_to_shares(minimum).unwrap(),
+            U256::from(1)
+        );
+        assert_eq!(
+            vault
+                .sender(WRITER_A)
+                .convert_to_shares(minimum - U256::from(1))
+                .unwrap(),
+            U256::ZERO
+        );
+    }
+
+    #[motsu::test]
+    fn test_deposit_after_expiry_fails(vault: Contract<OptionVault>, asset: Contract<MockERC20>) {
+        vault
+            .sender(WRITER_A)
+            .initialize(
+                asset.address(),
+                Address::new([0x22; 20]),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            )
+            .unwrap();
+        vault
+            .sender(WRITER_A)
+            .expiry
+            .set(U256::from(1_000_000_000u64));
+        asset.sender(WRITER_A).mint(WRITER_A, U256::from(1_000));
+        asset
+            .sender(WRITER_A)
+            .approve(vault.address(), U256::from(1_000));
+
+        let result = vault.sender(WRITER_A).deposit(U256::from(1_000), WRITER_A);
+
+        assert!(matches!(result, Err(VaultError::AlreadyExpired(_))));
+    }
+
+    #[motsu::test]
+    fn test_exercise_after_expiry_fails(vault: Contract<OptionVault>, asset: Contract<MockERC20>) {
+        vault
+            .sender(OPTIONS_CONTRACT)
+            .initialize(
+                asset.address(),
+                Address::new([0x22; 20]),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            )
+            .unwrap();
+        vault
+            .sender(OPTIONS_CONTRACT)
+            .expiry
+            .set(U256::from(1_000_000_000u64));
+
+        let result =
+            vault
+                .sender(OPTIONS_CONTRACT)
+                .exercise_withdraw(TOKEN_ID, U256::from(50), HOLDER);
+
+        assert!(matches!(result, Err(VaultError::AlreadyExpired(_))));
+    }
+
+    #[motsu::test]
+    fn test_initialize_twice_fails_and_preserves_state(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+    ) {
+        let expiry = U256::from(2_000_000_000u64);
+        vault
+            .sender(OPTIONS_CONTRACT)
+            .initialize(
+                asset.address(),
+                Address::new([0x22; 20]),
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                expiry,
+            )
+            .unwrap();
+
+        let attacker = Address::new([0x66; 20]);
+        let result = vault.sender(attacker).initialize(
+            Address::new([0x33; 20]),
+            Address::new([0x44; 20]),
+            attacker,
+            B256::new([0x88; 32]),
+            U256::from(3_000_000_000u64),
+        );
+
+        assert!(matches!(result, Err(VaultError::AlreadyInitialized(_))));
+        assert_eq!(
+            vault.sender(WRITER_A).get_series(),
+            (
+                OPTIONS_CONTRACT,
+                TOKEN_ID,
+                asset.address(),
+                Address::new([0x22; 20]),
+                expiry
+            )
+        );
+
+        // Deposits still pull from the original asset
+        asset.sender(WRITER_A).mint(WRITER_A, U256::from(100));
+        asset
+            .sender(WRITER_A)
+            .approve(vault.address(), U256::from(100));
+        vault
+            .sender(WRITER_A)
+            .deposit(U256::from(100), WRITER_A)
+            .unwrap();
+        assert_eq!(
+            asset.sender(WRITER_A).balance_of(vault.address()),
+            U256::from(100)
+        );
+
+        // Only the original options contract may withdraw
+        let result = vault
+            .sender(attacker)
+            .exercise_withdraw(TOKEN_ID, U256::from(50), attacker);
+        assert!(matches!(result, Err(VaultError::UnauthorizedCaller(_))));
+    }
+
+    #[motsu::test]
+    fn test_exercise_for_other_series_fails(
+        vault: Contract<OptionVault>,
+        asset: Contract<MockERC20>,
+        strike_asset: Contract<MockERC20>,
+    ) {
+        setup_exercised_vault(&vault, &asset, &strike_asset);
+        let other = B256::new([0x88; 32]);
+
+        let result =
+            vault
+                .sender(OPTIONS_CONTRACT)
+                .exercise_withdraw(other, U256::from(50), HOLDER);
+
+        assert!(matches!(
+            result,
+            Err(VaultError::InvalidTokenId(InvalidTokenId { expected, actual }))
+                if expected == TOKEN_ID && actual == other
+        ));
+        assert_eq!(
+            vault.sender(OPTIONS_CONTRACT).get_total_exercised(),
+            U256::from(150)
+        );
+    }
+
+    #[motsu::test]
+    fn test_initialize_rejects_past_expiry(vault: Contract<OptionVault>) {
+        let result = vault.sender(WRITER_A).initialize(
+            Address::new([0x11; 20]),
+            Address::new([0x22; 20]),
+            OPTIONS_CONTRACT,
+            TOKEN_ID,
+            U256::from(1_000_000_000u64),
+        );
+
+        assert!(matches!(result, Err(VaultError::AlreadyExpired(_))));
+        assert_eq!(vault.sender(WRITER_A).get_expiry(), U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn test_initialize_rejects_zero_addresses(vault: Contract<OptionVault>) {
+        let asset = Address::new([0x11; 20]);
+        let strike_asset = Address::new([0x22; 20]);
+        for (asset, strike_asset, options_contract) in [
+            (Address::ZERO, strike_asset, OPTIONS_CONTRACT),
+            (asset, Address::ZERO, OPTIONS_CONTRACT),
+            (asset, strike_asset, Address::ZERO),
+        ] {
+            let result = vault.sender(WRITER_A).initialize(
+                asset,
+                strike_asset,
+                options_contract,
+                TOKEN_ID,
+                U256::from(2_000_000_000u64),
+            );
+
+            assert!(matches!(result, Err(VaultError::ZeroAddress(_))));
+        }
+        assert_eq!(vault.sender(WRITER_A).get_expiry(), U256::ZERO);
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use motsu::prelude::*;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::mock_erc20::MockERC20;
+
+    const OPTIONS_CONTRACT: Address = Address::new([0x0F; 20]);
+    const HOLDER: Address = Address::new([0x0E; 20]);
+
+    proptest! {
+        #[test]
+        fn prop_claims_drain_vault_exactly(
+            deposits in prop::collection::vec(1u64..=1_000_000_000u64, 1..=12),
+            exercises in prop::collection::vec(1u64..=1_000_000_000u64, 0..=6),
+            strike_total in 0u64..=u64::MAX,
+        ) {
+            let vault = Contract::<OptionVault>::default();
+            let asset = Contract::<MockERC20>::default();
+            let strike_asset = Contract::<MockERC20>::default();
+            vault
+                .sender(OPTIONS_CONTRACT)
+                .initialize(
+                    asset.address(),
+                    strike_asset.address(),
+                    OPTIONS_CONTRACT,
+                    B256::ZERO,
+                    U256::from(2_000_000_000u64),
+                )
+                .unwrap();
+
+            let writers: Vec<Address> = (1..=deposits.len())
+                .map(|i| Address::repeat_byte(u8::try_from(i).unwrap_or(u8::MAX)))
+                .collect();
+            let mut deposited = U256::ZERO;
+            for (&writer, &amount) in writers.iter().zip(&deposits) {
+                let amount = U256::from(amount);
+                asset.sender(writer).mint(writer, amount);
+                asset.sender(writer).approve(vault.address(), amount);
+                vault.sender(writer).deposit(amount, writer).unwrap();
+                deposited += amount;
+            }
+
+            // Exercises are capped at what is left in the vault
+            let mut exercised = U256::ZERO;
+            for amount in exercises {
+                let amount = U256::from(amount).min(deposited - exercised);
+                if amount.is_zero() {
+                    break;
+                }
+                vault
+                    .sender(OPTIONS_CONTRACT)
+                    .exercise_withdraw(B256::ZERO, amount, HOLDER)
+                    .unwrap();
+                exercised += amount;
+            }
+            let strike_total = if exercised.is_zero() {
+                U256::ZERO
+            } else {
+                U256::from(strike_total)
+            };
+            strike_asset.sender(HOLDER).mint(vault.address(), strike_total);
+            vault.sender(OPTIONS_CONTRACT).expired.set(true);
+
+            // Claim in reverse deposit order so the residual does not always land on
+            // the last depositor
+            let mut strike_paid = U256::ZERO;
+            let mut collateral_returned = U256::ZERO;
+            for &writer in writers.iter().rev() {
+                let (strike, collateral) = vault.sender(writer).claim().unwrap();
+                strike_paid += strike;
+                collateral_returned += collateral;
+            }
+
+            prop_assert_eq!(strike_paid, strike_total);
+            prop_assert_eq!(collateral_returned, deposited - exercised);
+            prop_assert_eq!(vault.sender(HOLDER).get_total_assets(), U256::ZERO);
+            prop_assert_eq!(
+                strike_asset.sender(HOLDER).balance_of(vault.address()),
+                U256::ZERO
+            );
+            prop_assert_eq!(asset.sender(HOLDER).balance_of(vault.address()), U256::ZERO);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use vault::OptionVault;
+
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+
+fn setup(vault: &Contract<OptionVault>, asset: &Contract<TestERC20>, depositors: &[Address]) {
+    vault
+        .sender(depositors[0])
+        .initialize(
+            asset.address(),
+            Address::from([0x0D; 20]),
+            Address::from([0x0F; 20]),
+            TOKEN_ID,
+            U256::from(2_000_000_000u64),
+        )
+        .unwrap();
+
+    let amount = U256::from(1_000_000);
+    for &depositor in depositors {
+        asset.sender(depositor).mint(depositor, amount);
+        asset.sender(depositor).approve(vault.address(), amount);
+    }
+}
+
+#[motsu::test]
+fn checkpoints_preserve_deposit_order(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    let alice = Address::from([0xAA; 20]);
+    setup(&vault, &asset, &[alice]);
+
+    let amounts = [100u64, 250, 50];
+    for amount in amounts {
+        vault
+            .sender(alice)
+            .deposit(U256::from(amount), alice)
+            .unwrap();
+    }
+
+    assert_eq!(
+        vault.sender(alice).get_writer_checkpoints(alice),
+        vec![U256::from(0), U256::from(1), U256::from(2)]
+    );
+    assert_eq!(
+        vault.sender(alice).get_writer_checkpoint_count(alice),
+        U256::from(3)
+    );
+
+    let mut cumulative_total = U256::ZERO;
+    for (index, amount) in amounts.into_iter().enumerate() {
+        cumulative_total += U256::from(amount);
+        assert_eq!(
+            vault.sender(alice).get_checkpoint(U256::from(index)),
+            (alice, U256::from(amount), cumulative_total)
+        );
+    }
+}
+
+#[motsu::test]
+fn writers_checkpoints_do_not_collide(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    let alice = Address::from([0xAA; 20]);
+    let bob = Address::from([0xBB; 20]);
+    setup(&vault, &asset, &[alice, bob]);
+
+    vault.sender(alice).deposit(U256::from(100), alice).unwrap();
+    vault.sender(bob).deposit(U256::from(200), bob).unwrap();
+    vault.sender(alice).deposit(U256::from(300), alice).unwrap();
+
+    assert_eq!(
+        vault.sender(alice).get_writer_checkpoints(alice),
+        vec![U256::from(0), U256::from(2)]
+    );
+    assert_eq!(
+        vault.sender(bob).get_writer_checkpoints(bob),
+        vec![U256::from(1)]
+    );
+    assert_eq!(vault.sender(bob).get_checkpoint_count(), U256::from(3));
+
+    assert_eq!(
+        vault.sender(bob).get_checkpoint(U256::from(1)),
+        (bob, U256::from(200), U256::from(300))
+    );
+    assert_eq!(
+        vault.sender(alice).get_checkpoint(U256::from(2)),
+        (alice, U256::from(300), U256::from(600))
+    );
+}
+
+#[motsu::test]
+fn writer_without_deposits_has_no_checkpoints(vault: Contract<OptionVault>) {
+    let alice = Address::from([0xAA; 20]);
+
+    assert!(vault.sender(alice).get_writer_checkpoints(alice).is_empty());
+    assert_eq!(
+        vault.sender(alice).get_writer_checkpoint_count(alice),
+        U256::ZERO
+    );
+    assert_eq!(
+        vault.sender(alice).get_checkpoint(U256::ZERO),
+        (Address::ZERO, U256::ZERO, U256::ZERO)
+    );
+}
+
+#[motsu::test]
+fn consecutive_deposits_return_incrementing_checkpoint_indices(
+    vault: Contract<OptionVault>,
+    asset: Contract<TestERC20>,
+) {
+    let alice = Address::from([0xAA; 20]);
+    let bob = Address::from([0xBB; 20]);
+    setup(&vault, &asset, &[alice, bob]);
+
+    let (_, first) = vault
+        .sender(alice)
+        .deposit_with_checkpoint(U256::from(100), alice)
+        .unwrap();
+    let (_, second) = vault
+        .sender(bob)
+        .deposit_with_checkpoint(U256::from(200), bob)
+        .unwrap();
+    let (_, third) = vault
+        .sender(alice)
+        .deposit_with_checkpoint(U256::from(300), alice)
+        .unwrap();
+
+    assert_eq!(first, U256::from(0));
+    assert_eq!(second, U256::from(1));
+    assert_eq!(third, U256::from(2));
+    assert_eq!(
+        vault.sender(alice).get_checkpoint(second),
+        (bob, U256::from(200), U256::from(300))
+    );
+}
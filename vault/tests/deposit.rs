@@ -0,0 +1,143 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use vault::{OptionVault, VaultError};
+
+const EXPIRY: u64 = 2_000_000_000;
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+
+fn setup(vault: &Contract<OptionVault>, asset: &Contract<TestERC20>, depositors: &[Address]) {
+    vault
+        .sender(depositors[0])
+        .initialize(
+            asset.address(),
+            Address::from([0x0D; 20]),
+            Address::from([0x0F; 20]),
+            TOKEN_ID,
+            U256::from(EXPIRY),
+        )
+        .unwrap();
+
+    let amount = U256::from(10).pow(U256::from(30));
+    for &depositor in depositors {
+        asset.sender(depositor).mint(depositor, amount);
+        asset.sender(depositor).approve(vault.address(), amount);
+    }
+}
+
+#[motsu::test]
+fn first_deposit_mints_virtual_offset_shares(
+    vault: Contract<OptionVault>,
+    asset: Contract<TestERC20>,
+) {
+    let alice = Address::from([0xAA; 20]);
+    setup(&vault, &asset, &[alice]);
+
+    let assets = U256::from(100_000_000);
+    let shares = vault.sender(alice).deposit(assets, alice).unwrap();
+
+    // assets * (0 + 10^3) / (0 + 1)
+    assert_eq!(shares, assets * U256::from(1_000));
+    assert_eq!(vault.sender(alice).balance_of(alice), shares);
+    assert_eq!(vault.sender(alice).total_shares(), shares);
+    assert_eq!(vault.sender(alice).get_total_assets(), assets);
+    assert_eq!(asset.sender(alice).balance_of(vault.address()), assets);
+    assert_eq!(vault.sender(alice).get_checkpoint_count(), U256::from(1));
+    assert_eq!(
+        vault.sender(alice).get_checkpoint(U256::ZERO),
+        (alice, assets, assets)
+    );
+}
+
+#[motsu::test]
+fn subsequent_deposits_mint_proportional_shares(
+    vault: Contract<OptionVault>,
+    asset: Contract<TestERC20>,
+) {
+    let alice = Address::from([0xAA; 20]);
+    let bob = Address::from([0xBB; 20]);
+    setup(&vault, &asset, &[alice, bob]);
+
+    let first = U256::from(100_000_000);
+    let second = U256::from(50_000_000);
+    let alice_shares = vault.sender(alice).deposit(first, alice).unwrap();
+    let bob_shares = vault.sender(bob).deposit(second, bob).unwrap();
+
+    // second * (alice_shares + 10^3) / (first + 1)
+    let expected = second * (alice_shares + U256::from(1_000)) / (first + U256::from(1));
+    assert_eq!(bob_shares, expected);
+    assert_eq!(vault.sender(bob).balance_of(bob), bob_shares);
+    assert_eq!(vault.sender(bob).total_shares(), alice_shares + bob_shares);
+    assert_eq!(vault.sender(bob).get_total_assets(), first + second);
+    assert_eq!(vault.sender(bob).get_checkpoint_count(), U256::from(2));
+    assert_eq!(
+        vault.sender(bob).get_checkpoint(U256::from(1)),
+        (bob, second, first + second)
+    );
+}
+
+#[motsu::test]
+fn deposit_for_receiver_credits_receiver(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    let alice = Address::from([0xAA; 20]);
+    let receiver = Address::from([0xCC; 20]);
+    setup(&vault, &asset, &[alice]);
+
+    let shares = vault
+        .sender(alice)
+        .deposit(U256::from(1_000), receiver)
+        .unwrap();
+
+    assert_eq!(vault.sender(alice).balance_of(receiver), shares);
+    assert_eq!(vault.sender(alice).balance_of(alice), U256::ZERO);
+    assert_eq!(vault.sender(alice).get_checkpoint(U256::ZERO).0, receiver);
+}
+
+#[motsu::test]
+fn donation_does_not_inflate_share_price(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    let attacker = Address::from([0xAA; 20]);
+    let victim = Address::from([0xBB; 20]);
+    setup(&vault, &asset, &[attacker, victim]);
+
+    // Classic inflation attack: deposit 1 wei, then donate directly to the vault
+    let attacker_shares = vault
+        .sender(attacker)
+        .deposit(U256::from(1), attacker)
+        .unwrap();
+    let donation = U256::from(10).pow(U256::from(24));
+    asset.sender(attacker).transfer(vault.address(), donation);
+
+    let victim_assets = U256::from(10).pow(U256::from(18));
+    let victim_shares = vault.sender(victim).deposit(victim_assets, victim).unwrap();
+
+    // The donation is not counted in total_assets, so the victim is not diluted
+    assert!(!victim_shares.is_zero());
+    let total_assets = vault.sender(victim).get_total_assets();
+    let total_shares = vault.sender(victim).total_shares();
+    let victim_claim = victim_shares * total_assets / total_shares;
+    assert!(victim_claim >= victim_assets - U256::from(1));
+    assert!(attacker_shares < victim_shares);
+}
+
+#[motsu::test]
+fn zero_deposit_fails(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    let alice = Address::from([0xAA; 20]);
+    setup(&vault, &asset, &[alice]);
+
+    let result = vault.sender(alice).deposit(U256::ZERO, alice);
+
+    assert!(matches!(result, Err(VaultError::ZeroAmount(_))));
+}
+
+#[motsu::test]
+fn deposit_without_approval_fails(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    let alice = Address::from([0xAA; 20]);
+    let bob = Address::from([0xBB; 20]);
+    setup(&vault, &asset, &[alice]);
+    asset.sender(bob).mint(bob, U256::from(1_000));
+
+    let result = vault.sender(bob).deposit(U256::from(1_000), bob);
+
+    assert!(matches!(result, Err(VaultError::TransferFailed(_))));
+}
@@ -0,0 +1,148 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use vault::{OptionVault, VaultError};
+
+const OPTIONS_CONTRACT: Address = Address::new([0x0F; 20]);
+const HOLDER: Address = Address::new([0x0E; 20]);
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+
+/// Deposits 100, 200 and 300 from three writers (cumulative totals 100, 300, 600).
+fn setup_three_writers(vault: &Contract<OptionVault>, asset: &Contract<TestERC20>) {
+    vault
+        .sender(OPTIONS_CONTRACT)
+        .initialize(
+            asset.address(),
+            Address::from([0x0D; 20]),
+            OPTIONS_CONTRACT,
+            TOKEN_ID,
+            U256::from(2_000_000_000u64),
+        )
+        .unwrap();
+
+    for (byte, amount) in [(0xAA, 100u64), (0xBB, 200), (0xCC, 300)] {
+        let writer = Address::repeat_byte(byte);
+        let amount = U256::from(amount);
+        asset.sender(writer).mint(writer, amount);
+        asset.sender(writer).approve(vault.address(), amount);
+        vault.sender(writer).deposit(amount, writer).unwrap();
+    }
+}
+
+#[motsu::test]
+fn exercise_assigns_earliest_deposits_first(
+    vault: Contract<OptionVault>,
+    asset: Contract<TestERC20>,
+) {
+    setup_three_writers(&vault, &asset);
+
+    // Partially assigns the first deposit
+    vault
+        .sender(OPTIONS_CONTRACT)
+        .exercise_withdraw(TOKEN_ID, U256::from(50), HOLDER)
+        .unwrap();
+    assert_eq!(vault.sender(HOLDER).get_assignment_cursor(), U256::ZERO);
+
+    // Completes the first deposit and partially assigns the second
+    vault
+        .sender(OPTIONS_CONTRACT)
+        .exercise_withdraw(TOKEN_ID, U256::from(100), HOLDER)
+        .unwrap();
+    assert_eq!(vault.sender(HOLDER).get_assignment_cursor(), U256::from(1));
+
+    // Exactly reaches the second deposit's cumulative total
+    vault
+        .sender(OPTIONS_CONTRACT)
+        .exercise_withdraw(TOKEN_ID, U256::from(150), HOLDER)
+        .unwrap();
+    assert_eq!(vault.sender(HOLDER).get_assignment_cursor(), U256::from(2));
+
+    assert_eq!(vault.sender(HOLDER).get_total_exercised(), U256::from(300));
+    assert_eq!(vault.sender(HOLDER).get_total_assets(), U256::from(300));
+    assert_eq!(asset.sender(HOLDER).balance_of(HOLDER), U256::from(300));
+    assert_eq!(
+        asset.sender(HOLDER).balance_of(vault.address()),
+        U256::from(300)
+    );
+}
+
+#[motsu::test]
+fn exercise_spanning_several_deposits_advances_cursor(
+    vault: Contract<OptionVault>,
+    asset: Contract<TestERC20>,
+) {
+    setup_three_writers(&vault, &asset);
+
+    vault
+        .sender(OPTIONS_CONTRACT)
+        .exercise_withdraw(TOKEN_ID, U256::from(600), HOLDER)
+        .unwrap();
+
+    assert_eq!(vault.sender(HOLDER).get_assignment_cursor(), U256::from(3));
+    assert_eq!(vault.sender(HOLDER).get_total_assets(), U256::ZERO);
+}
+
+#[motsu::test]
+fn exercise_after_many_dust_deposits_finds_cursor(
+    vault: Contract<OptionVault>,
+    asset: Contract<TestERC20>,
+) {
+    vault
+        .sender(OPTIONS_CONTRACT)
+        .initialize(
+            asset.address(),
+            Address::from([0x0D; 20]),
+            OPTIONS_CONTRACT,
+            TOKEN_ID,
+            U256::from(2_000_000_000u64),
+        )
+        .unwrap();
+    let writer = Address::repeat_byte(0xAA);
+    asset.sender(writer).mint(writer, U256::from(200));
+    asset
+        .sender(writer)
+        .approve(vault.address(), U256::from(200));
+    for _ in 0..200 {
+        vault.sender(writer).deposit(U256::from(1), writer).unwrap();
+    }
+
+    vault
+        .sender(OPTIONS_CONTRACT)
+        .exercise_withdraw(TOKEN_ID, U256::from(137), HOLDER)
+        .unwrap();
+
+    // 137 one-unit checkpoints are fully assigned, the next is untouched
+    assert_eq!(
+        vault.sender(HOLDER).get_assignment_cursor(),
+        U256::from(137)
+    );
+    assert_eq!(
+        vault.sender(HOLDER).get_assignment_boundary(),
+        (U256::from(137), U256::ZERO)
+    );
+}
+
+#[motsu::test]
+fn exercise_from_other_caller_fails(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    setup_three_writers(&vault, &asset);
+
+    let result = vault
+        .sender(HOLDER)
+        .exercise_withdraw(TOKEN_ID, U256::from(50), HOLDER);
+
+    assert!(matches!(result, Err(VaultError::UnauthorizedCaller(_))));
+}
+
+#[motsu::test]
+fn exercise_more_than_total_assets_fails(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    setup_three_writers(&vault, &asset);
+
+    let result =
+        vault
+            .sender(OPTIONS_CONTRACT)
+            .exercise_withdraw(TOKEN_ID, U256::from(601), HOLDER);
+
+    assert!(matches!(result, Err(VaultError::InsufficientAssets(_))));
+}
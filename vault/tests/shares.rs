@@ -0,0 +1,134 @@
+mod test_erc20;
+
+use alloy_primitives::{Address, B256, U256};
+use motsu::prelude::*;
+use test_erc20::TestERC20;
+use vault::{OptionVault, VaultError};
+
+const TOKEN_ID: B256 = B256::new([0x77; 32]);
+const ALICE: Address = Address::new([0xAA; 20]);
+const BOB: Address = Address::new([0xBB; 20]);
+const CAROL: Address = Address::new([0xCC; 20]);
+
+fn setup(vault: &Contract<OptionVault>, asset: &Contract<TestERC20>, depositors: &[Address]) {
+    vault
+        .sender(depositors[0])
+        .initialize(
+            asset.address(),
+            Address::from([0x0D; 20]),
+            Address::from([0x0F; 20]),
+            TOKEN_ID,
+            U256::from(2_000_000_000u64),
+        )
+        .unwrap();
+
+    let amount = U256::from(1_000_000);
+    for &depositor in depositors {
+        asset.sender(depositor).mint(depositor, amount);
+        asset.sender(depositor).approve(vault.address(), amount);
+    }
+}
+
+#[motsu::test]
+fn deposit_mints_shares_to_receiver(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    setup(&vault, &asset, &[ALICE]);
+
+    let shares = vault.sender(ALICE).deposit(U256::from(100), BOB).unwrap();
+
+    assert_eq!(vault.sender(ALICE).balance_of(BOB), shares);
+    assert_eq!(vault.sender(ALICE).balance_of(ALICE), U256::ZERO);
+    assert_eq!(vault.sender(ALICE).total_supply(), shares);
+}
+
+#[motsu::test]
+fn transfer_moves_shares(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    setup(&vault, &asset, &[ALICE]);
+    let shares = vault.sender(ALICE).deposit(U256::from(100), ALICE).unwrap();
+    let sent = shares / U256::from(4);
+
+    assert!(vault.sender(ALICE).transfer(BOB, sent).unwrap());
+
+    assert_eq!(vault.sender(ALICE).balance_of(ALICE), shares - sent);
+    assert_eq!(vault.sender(ALICE).balance_of(BOB), sent);
+    assert_eq!(vault.sender(ALICE).total_supply(), shares);
+}
+
+#[motsu::test]
+fn transfer_more_than_balance_fails(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    setup(&vault, &asset, &[ALICE]);
+    let shares = vault.sender(ALICE).deposit(U256::from(100), ALICE).unwrap();
+
+    let result = vault.sender(ALICE).transfer(BOB, shares + U256::from(1));
+
+    assert!(matches!(result, Err(VaultError::InsufficientShares(_))));
+    assert_eq!(vault.sender(ALICE).balance_of(ALICE), shares);
+    assert_eq!(vault.sender(ALICE).balance_of(BOB), U256::ZERO);
+}
+
+#[motsu::test]
+fn transfer_to_zero_address_fails(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    setup(&vault, &asset, &[ALICE]);
+    let shares = vault.sender(ALICE).deposit(U256::from(100), ALICE).unwrap();
+
+    let result = vault.sender(ALICE).transfer(Address::ZERO, shares);
+
+    assert!(matches!(result, Err(VaultError::ZeroAddress(_))));
+}
+
+#[motsu::test]
+fn balances_sum_to_total_supply(vault: Contract<OptionVault>, asset: Contract<TestERC20>) {
+    setup(&vault, &asset, &[ALICE, BOB]);
+    let alice_shares = vault.sender(ALICE).deposit(U256::from(100), ALICE).unwrap();
+    vault.sender(BOB).deposit(U256::from(250), BOB).unwrap();
+
+    vault
+        .sender(ALICE)
+        .transfer(CAROL, alice_shares / U256::from(2))
+        .unwrap();
+    vault
+        .sender(BOB)
+        .transfer(ALICE, U256::from(1_000))
+        .unwrap();
+    vault.sender(CAROL).transfer(CAROL, U256::from(10)).unwrap();
+
+    let sum = [ALICE, BOB, CAROL]
+        .into_iter()
+        .map(|account| vault.sender(ALICE).balance_of(account))
+        .fold(U256::ZERO, |total, balance| total + balance);
+    assert_eq!(sum, vault.sender(ALICE).total_supply());
+}
+
+#[motsu::test]
+fn shares_and_checkpoints_belong_to_receiver(
+    vault: Contract<OptionVault>,
+    asset: Contract<TestERC20>,
+) {
+    setup(&vault, &asset, &[ALICE]);
+    let shares = vault.sender(ALICE).deposit(U256::from(100), BOB).unwrap();
+
+    assert_eq!(vault.sender(ALICE).balance_of(BOB), shares);
+    assert_eq!(
+        vault.sender(ALICE).get_writer_checkpoints(BOB),
+        vec![U256::ZERO]
+    );
+    assert!(vault.sender(ALICE).get_writer_checkpoints(ALICE).is_empty());
+    assert_eq!(vault.sender(ALICE).get_checkpoint(U256::ZERO).0, BOB);
+}
+
+#[motsu::test]
+fn transfer_leaves_checkpoints_with_depositor(
+    vault: Contract<OptionVault>,
+    asset: Contract<TestERC20>,
+) {
+    setup(&vault, &asset, &[ALICE]);
+    let shares = vault.sender(ALICE).deposit(U256::from(100), ALICE).unwrap();
+
+    vault.sender(ALICE).transfer(BOB, shares).unwrap();
+
+    assert_eq!(
+        vault.sender(ALICE).get_writer_checkpoints(ALICE),
+        vec![U256::ZERO]
+    );
+    assert!(vault.sender(ALICE).get_writer_checkpoints(BOB).is_empty());
+    assert_eq!(vault.sender(ALICE).get_checkpoint(U256::ZERO).0, ALICE);
+}